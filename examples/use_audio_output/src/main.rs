@@ -0,0 +1,69 @@
+use leptos::html::Audio;
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_audio_output, use_devices_list_with_options, UseAudioOutputReturn,
+    UseDevicesListOptions, UseDevicesListReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let audio = create_node_ref::<Audio>();
+
+    let UseDevicesListReturn {
+        audio_outputs,
+        permission_granted,
+        ensure_permissions,
+        ..
+    } = use_devices_list_with_options(UseDevicesListOptions::default().video(false));
+
+    let UseAudioOutputReturn {
+        is_supported,
+        sink_id,
+        set_sink_id,
+        error,
+    } = use_audio_output(audio);
+
+    view! {
+        <audio
+            node_ref=audio
+            src="https://interactive-examples.mdn.mozilla.net/media/cc0-audio/t-rex-roar.mp3"
+            controls
+        ></audio>
+
+        <button on:click=move |_| ensure_permissions() disabled=permission_granted>
+            "Show device names"
+        </button>
+
+        <select on:change=move |e| set_sink_id.set(event_target_value(&e))>
+            <option value="" selected=move || sink_id.get().is_empty()>
+                "Default"
+            </option>
+            <For each=move || audio_outputs.get() key=|device| device.device_id() let:device>
+                {
+                    let id = device.device_id();
+                    let label = device.label();
+
+                    view! {
+                        <option value=id.clone() selected=move || sink_id.get() == id>
+                            {if label.is_empty() { "Unknown device".to_string() } else { label }}
+                        </option>
+                    }
+                }
+            </For>
+        </select>
+
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Sink id: " <code>{sink_id}</code></p>
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
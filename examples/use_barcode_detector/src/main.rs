@@ -0,0 +1,64 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_barcode_detector, use_user_media_with_options, UseBarcodeDetectorReturn,
+    UseUserMediaOptions, UseUserMediaReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let video_ref = create_node_ref::<html::Video>();
+
+    let UseUserMediaReturn { stream, .. } =
+        use_user_media_with_options(UseUserMediaOptions::default().enabled(true));
+
+    create_effect(move |_| {
+        if let (Some(video), Some(Ok(stream))) = (video_ref.get(), stream.get()) {
+            video.set_src_object(Some(&stream));
+        }
+    });
+
+    let UseBarcodeDetectorReturn {
+        is_supported,
+        barcodes,
+        error,
+        is_active,
+        pause,
+        resume,
+    } = use_barcode_detector(video_ref);
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Scanning: " <BooleanDisplay value=is_active/></p>
+
+        <button on:click=move |_| pause() disabled=move || !is_active.get()>"Pause"</button>
+        <button on:click=move |_| resume() disabled=move || is_active.get()>"Resume"</button>
+
+        <video node_ref=video_ref autoplay=true muted=true class="h-96 w-auto"></video>
+
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+
+        <ul>
+            <For
+                each=move || barcodes.get()
+                key=|barcode| barcode.raw_value.clone()
+                let:barcode
+            >
+                <li>
+                    <code>{format!("{:?}", barcode.format)}</code>
+                    ": "
+                    {barcode.raw_value}
+                </li>
+            </For>
+        </ul>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
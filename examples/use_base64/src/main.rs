@@ -0,0 +1,54 @@
+use leptos::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{
+    use_base64, use_file_dialog_with_options, Base64Source, UseBase64Return,
+    UseFileDialogOptions, UseFileDialogReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (text, set_text) = create_signal("Hello, world!".to_string());
+
+    let UseBase64Return {
+        base64: text_base64,
+        ..
+    } = use_base64(Signal::derive(move || text.get().into()));
+
+    let UseFileDialogReturn { files, open, .. } =
+        use_file_dialog_with_options(UseFileDialogOptions::default().multiple(false));
+
+    let UseBase64Return {
+        base64: file_base64,
+        ..
+    } = use_base64(Signal::derive(move || {
+        Base64Source::from(files.get().into_iter().next())
+    }));
+
+    view! {
+        <div>
+            <p>"Text"</p>
+            <input
+                prop:value=text
+                on:input=move |e| set_text.set(event_target_value(&e))
+                type="text"
+            />
+            <p>"Base64"</p>
+            <textarea class="w-full h-24" prop:value=text_base64 readonly/>
+        </div>
+
+        <div>
+            <button on:click=move |_| open()>"Choose file"</button>
+            <p>"Base64"</p>
+            <textarea class="w-full h-24" prop:value=file_base64 readonly/>
+        </div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
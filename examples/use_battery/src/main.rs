@@ -0,0 +1,36 @@
+use leptos::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::use_battery;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let battery = use_battery();
+
+    view! {
+        <pre>
+            {move || format!(
+                concat!(
+                    "is_supported: {}\n",
+                    "charging: {}\n",
+                    "charging_time: {}\n",
+                    "discharging_time: {}\n",
+                    "level: {}\n",
+                ),
+                battery.is_supported.get(),
+                battery.charging.get(),
+                battery.charging_time.get(),
+                battery.discharging_time.get(),
+                battery.level.get(),
+            )}
+        </pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
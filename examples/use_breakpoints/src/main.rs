@@ -13,6 +13,7 @@ fn Demo() -> impl IntoView {
     let sm_width = *breakpoints.get(&Sm).expect("It's there!");
 
     let current = screen_size.current();
+    let active = screen_size.active();
     let xs = screen_size.lt(Sm);
     let xse = screen_size.le(Sm);
     let sm = screen_size.between(Sm, Md);
@@ -29,6 +30,9 @@ fn Demo() -> impl IntoView {
             <div class=label_classes.clone()>"Current breakpoints :"</div>
             <code>{move || format!("{:?}", current.get())}</code>
 
+            <div class=label_classes.clone()>"Active breakpoint :"</div>
+            <code>{move || format!("{:?}", active.get())}</code>
+
             <div class=label_classes.clone()>
                 <code class="font-bold">"xs"</code>
                 <small>" (< " {move || sm_width.to_string()} "px)"</small>
@@ -0,0 +1,67 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_browser_location, UseBrowserLocationReturn};
+use wasm_bindgen::JsValue;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseBrowserLocationReturn {
+        href,
+        origin,
+        pathname,
+        search,
+        hash,
+        length,
+        trigger,
+        update,
+        ..
+    } = use_browser_location();
+
+    let push = move |url: &'static str| {
+        let _ = window()
+            .history()
+            .unwrap()
+            .push_state_with_url(&JsValue::NULL, "", Some(url));
+        update();
+    };
+    let push = store_value(push);
+
+    let set_hash = move |value: &str| {
+        let _ = window().location().set_hash(value);
+    };
+
+    view! {
+        <p>
+            <button on:click=move |_| push.with_value(|push| push("?page=1"))>"Push ?page=1"</button>
+            <button on:click=move |_| push.with_value(|push| push("?page=2"))>"Push ?page=2"</button>
+            <button on:click=move |_| set_hash("#top")>"Set #top"</button>
+            <button on:click=move |_| set_hash("#bottom")>"Set #bottom"</button>
+        </p>
+        <Note>"Use the back and forward buttons of your browser to see the trigger change"</Note>
+        <pre>
+            "href: "
+            {href}
+            "\norigin: "
+            {origin}
+            "\npathname: "
+            {pathname}
+            "\nsearch: "
+            {search}
+            "\nhash: "
+            {hash}
+            "\nlength: "
+            {length}
+            "\ntrigger: "
+            {move || format!("{:?}", trigger.get())}
+        </pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
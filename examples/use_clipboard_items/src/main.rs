@@ -0,0 +1,85 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{
+    use_clipboard_items_with_options, use_permission, ClipboardItemData,
+    UseClipboardItemsOptions, UseClipboardItemsReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (input, set_input) = create_signal("<b>Hello</b> <i>World</i>".to_owned());
+
+    let UseClipboardItemsReturn {
+        is_supported,
+        content,
+        copied,
+        error,
+        copy,
+        read,
+    } = use_clipboard_items_with_options(UseClipboardItemsOptions::default().read(true));
+
+    let permission_read = use_permission("clipboard-read");
+    let permission_write = use_permission("clipboard-write");
+
+    view! {
+        <Show
+            when=move || is_supported.get()
+            fallback=|| view! { <p>Your browser does not support the Clipboard API</p> }
+        >
+            <Note>
+                Clipboard Permission:
+                read <b>{move || permission_read.get().to_string()}</b> |
+                write <b>{move || permission_write.get().to_string()}</b>
+            </Note>
+            <p>
+                Current clipboard items:
+                <ul>
+                    <For
+                        each=move || content.get().into_iter().enumerate()
+                        key=|(i, _)| *i
+                        let:item
+                    >
+                        <li>
+                            <code>{item.1.mime_types().collect::<Vec<_>>().join(", ")}</code>
+                        </li>
+                    </For>
+                </ul>
+            </p>
+            <input
+                value=input
+                on:input=move |e| set_input.set(event_target_value(&e))
+                type="text"
+            />
+            <button on:click={
+                let copy = copy.clone();
+                move |_| {
+                    copy(
+                        vec![
+                            ClipboardItemData::new()
+                                .with_text("text/html", &input.get())
+                                .with_text("text/plain", &input.get()),
+                        ],
+                    )
+                }
+            }>
+                <Show when=move || copied.get() fallback=|| "Copy as HTML">
+                    Copied!
+                </Show>
+            </button>
+            <button on:click={
+                let read = read.clone();
+                move |_| read()
+            }>"Read clipboard"</button>
+            <p>{move || error.get().map(|err| err.to_string())}</p>
+        </Show>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
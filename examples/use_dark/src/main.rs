@@ -0,0 +1,28 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{use_dark, UseDarkReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseDarkReturn {
+        is_dark,
+        toggle,
+        store,
+        ..
+    } = use_dark();
+
+    view! {
+        <button on:click=move |_| toggle()>"Toggle dark mode"</button>
+        <p>"Dark mode: " <BooleanDisplay value=is_dark/></p>
+        <Note>"Stored mode: " {move || store.get().to_string()}</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
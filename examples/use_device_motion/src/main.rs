@@ -0,0 +1,42 @@
+use leptos::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::use_device_motion;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let motion = use_device_motion();
+
+    view! {
+        <Show when=move || !motion.permission_granted.get()>
+            <button on:click={
+                let request_permission = motion.request_permission.clone();
+                move |_| request_permission()
+            }>"Request permission"</button>
+        </Show>
+        <pre>
+            {move || format!(
+                concat!(
+                    "is_supported: {}\n",
+                    "acceleration: {:?}\n",
+                    "acceleration_including_gravity: {:?}\n",
+                    "rotation_rate: {:?}\n",
+                    "interval: {}\n",
+                ),
+                motion.is_supported.get(),
+                motion.acceleration.get(),
+                motion.acceleration_including_gravity.get(),
+                motion.rotation_rate.get(),
+                motion.interval.get(),
+            )}
+        </pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -7,6 +7,12 @@ fn Demo() -> impl IntoView {
     let orientation = use_device_orientation();
 
     view! {
+        <Show when=move || !orientation.permission_granted.get()>
+            <button on:click={
+                let request_permission = orientation.request_permission.clone();
+                move |_| request_permission()
+            }>"Request permission"</button>
+        </Show>
         <pre>
             {move || format!(
                 concat!(
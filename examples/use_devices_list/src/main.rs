@@ -0,0 +1,46 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_devices_list, UseDevicesListReturn};
+use web_sys::MediaDeviceInfo;
+
+#[component]
+fn DeviceList(title: &'static str, devices: Signal<Vec<MediaDeviceInfo>>) -> impl IntoView {
+    view! {
+        <h3>{title}</h3>
+        <ul>
+            <For each=move || devices.get() key=|device| device.device_id() let:device>
+                <li>{device.label()}</li>
+            </For>
+        </ul>
+    }
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseDevicesListReturn {
+        audio_inputs,
+        audio_outputs,
+        video_inputs,
+        permission_granted,
+        ensure_permissions,
+        ..
+    } = use_devices_list();
+
+    view! {
+        <p>"Permission granted: " <BooleanDisplay value=permission_granted/></p>
+        <button on:click=move |_| ensure_permissions()>"Request permissions"</button>
+
+        <DeviceList title="Cameras" devices=video_inputs/>
+        <DeviceList title="Microphones" devices=audio_inputs/>
+        <DeviceList title="Speakers" devices=audio_outputs/>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
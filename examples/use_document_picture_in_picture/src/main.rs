@@ -0,0 +1,64 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_document_picture_in_picture_with_options, UseDocumentPictureInPictureOptions,
+    UseDocumentPictureInPictureReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (count, set_count) = create_signal(0);
+
+    let UseDocumentPictureInPictureReturn {
+        is_supported,
+        is_open,
+        body,
+        error,
+        open,
+        close,
+        ..
+    } = use_document_picture_in_picture_with_options(
+        UseDocumentPictureInPictureOptions::default()
+            .width(320u32)
+            .height(240u32),
+    );
+
+    view! {
+        <button on:click=move |_| open() disabled=move || !is_supported.get() || is_open.get()>
+            "Open Picture-in-Picture window"
+        </button>
+        <button on:click=move |_| close() disabled=move || !is_open.get()>
+            "Close"
+        </button>
+
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Open: " <BooleanDisplay value=is_open/></p>
+        <p>"Count: " {count}</p>
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+
+        {move || {
+            body.get()
+                .map(|body| {
+                    view! {
+                        <Portal mount=web_sys::Element::from(body)>
+                            <div class="p-4">
+                                <p>"Count: " {count}</p>
+                                <button on:click=move |_| {
+                                    set_count.update(|count| *count += 1)
+                                }>"Increment"</button>
+                            </div>
+                        </Portal>
+                    }
+                })
+        }}
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,31 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_document_title_with_options, UseDocumentTitleOptions};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (title, set_title) = use_document_title_with_options(
+        UseDocumentTitleOptions::default()
+            .title_template(|title: &str| format!("{title} | Leptos-Use"))
+            .observe(true),
+    );
+
+    view! {
+        <input
+            class="block"
+            prop:value=move || title.get().unwrap_or_default()
+            on:input=move |e| set_title.set(Some(event_target_value(&e)))
+            type="text"
+        />
+        <Note>"Type to change the title of this tab"</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -16,7 +16,8 @@ fn Demo() -> impl IntoView {
         drop_zone_el,
         UseDropZoneOptions::default()
             .on_drop(move |_| set_dropped(true))
-            .on_enter(move |_| set_dropped(false)),
+            .on_enter(move |_| set_dropped(false))
+            .directory(true),
     );
 
     view! {
@@ -0,0 +1,72 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::use_event_bus;
+
+#[derive(Clone, Debug)]
+enum CounterEvent {
+    Increment,
+    Reset,
+}
+
+#[component]
+fn Controls() -> impl IntoView {
+    let bus = use_event_bus::<CounterEvent>("counter");
+    let bus_reset = bus.clone();
+
+    view! {
+        <button on:click=move |_| bus.emit(CounterEvent::Increment)>"Increment"</button>
+        <button on:click=move |_| bus_reset.emit(CounterEvent::Reset)>"Reset"</button>
+    }
+}
+
+#[component]
+fn Counter() -> impl IntoView {
+    let (count, set_count) = create_signal(0);
+    let (first, set_first) = create_signal(false);
+
+    let bus = use_event_bus::<CounterEvent>("counter");
+
+    bus.on(move |event: &CounterEvent| match event {
+        CounterEvent::Increment => set_count.update(|count| *count += 1),
+        CounterEvent::Reset => set_count.set(0),
+    });
+
+    bus.once(move |_: &CounterEvent| set_first.set(true));
+
+    view! {
+        <p>"Count: " {count}</p>
+        <p>"Received an event: " {move || if first.get() { "yes" } else { "no" }}</p>
+    }
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (show_counter, set_show_counter) = create_signal(true);
+
+    view! {
+        <p>
+            <Controls/>
+        </p>
+        <label>
+            <input
+                type="checkbox"
+                prop:checked=show_counter
+                on:change=move |_| set_show_counter.update(|show| *show = !*show)
+            />
+            " Show counter"
+        </label>
+        <Show when=move || show_counter.get()>
+            <Counter/>
+        </Show>
+        <Note>"The counter stops listening when it's hidden"</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,38 @@
+use leptos::*;
+use leptos_use::core::ConnectionReadyState;
+use leptos_use::docs::demo_or_body;
+use leptos_use::utils::FromToStringCodec;
+use leptos_use::{use_event_source, UseEventSourceReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseEventSourceReturn {
+        ready_state,
+        data,
+        error,
+        open,
+        close,
+        ..
+    } = use_event_source::<String, FromToStringCodec>("https://sse.dev/test");
+
+    let connected = move || ready_state.get() == ConnectionReadyState::Open;
+
+    view! {
+        <div>
+            <button on:click=move |_| open() disabled=connected>"Open"</button>
+            <button on:click=move |_| close() disabled=move || !connected()>"Close"</button>
+        </div>
+        <p>"ready_state: " {move || ready_state().to_string()}</p>
+        <p>"error: " {move || error().map(|e| e.to_string())}</p>
+        <pre>{move || data().unwrap_or_default()}</pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
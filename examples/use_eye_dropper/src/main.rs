@@ -0,0 +1,33 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_eye_dropper_with_options, UseEyeDropperOptions, UseEyeDropperReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseEyeDropperReturn {
+        is_supported,
+        srgb_hex,
+        open,
+    } = use_eye_dropper_with_options(UseEyeDropperOptions::default().initial_value("#000000"));
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+
+        <button on:click=move |_| open() disabled=move || !is_supported.get()>"Open Eye Dropper"</button>
+
+        <p class="flex items-center gap-2">
+            "Color: "
+            <span class="inline-block w-8 h-8 rounded" style:background-color=srgb_hex></span>
+            <code>{srgb_hex}</code>
+        </p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
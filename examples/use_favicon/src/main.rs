@@ -1,6 +1,6 @@
 use leptos::*;
 use leptos_use::docs::demo_or_body;
-use leptos_use::{use_favicon_with_options, UseFaviconOptions};
+use leptos_use::{text_favicon, use_favicon_with_options, UseFaviconOptions};
 
 #[component]
 fn Demo() -> impl IntoView {
@@ -97,6 +97,18 @@ fn Demo() -> impl IntoView {
                     alt="favicon-orange"
                 />
             </a>
+
+            <a
+                class=classes
+                href="#"
+                on:click=move |e| {
+                    e.prevent_default();
+                    set_icon.set(Some(text_favicon("🦀")));
+                }
+            >
+
+                <span class="block text-2xl leading-8">"🦀"</span>
+            </a>
         </p>
     }
 }
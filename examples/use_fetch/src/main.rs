@@ -0,0 +1,51 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_fetch_with_options, UseFetchOptions, UseFetchReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (url, set_url) = create_signal("https://httpbin.org/get".to_string());
+
+    let UseFetchReturn {
+        data,
+        error,
+        is_fetching,
+        is_finished,
+        status_code,
+        download_progress,
+        execute,
+        abort,
+        ..
+    } = use_fetch_with_options(url, UseFetchOptions::default().refetch(true));
+
+    view! {
+        <input
+            type="text"
+            class="w-full"
+            value=url
+            on:change=move |e| set_url(event_target_value(&e))
+        />
+        <div>
+            <button on:click=move |_| execute() disabled=is_fetching>"Execute"</button>
+            <button on:click=move |_| abort() disabled=move || !is_fetching()>"Abort"</button>
+        </div>
+        <p>"is_fetching: " <BooleanDisplay value=is_fetching/></p>
+        <p>"is_finished: " <BooleanDisplay value=is_finished/></p>
+        <p>"status_code: " {move || format!("{:?}", status_code())}</p>
+        <p>
+            "download_progress: "
+            {move || download_progress().map(|p| format!("{} / {:?} bytes", p.loaded, p.total))}
+        </p>
+        <p>"error: " {move || error().map(|e| e.to_string())}</p>
+        <pre>{move || data().unwrap_or_default()}</pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
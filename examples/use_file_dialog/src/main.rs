@@ -0,0 +1,64 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_file_dialog_with_options, UseFileDialogOptions, UseFileDialogReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (multiple, set_multiple) = create_signal(true);
+    let (images_only, set_images_only) = create_signal(false);
+
+    let UseFileDialogReturn { files, open, reset } = use_file_dialog_with_options(
+        UseFileDialogOptions::default()
+            .multiple(multiple)
+            .accept(Signal::derive(move || {
+                if images_only.get() { "image/*" } else { "*" }.to_string()
+            })),
+    );
+
+    view! {
+        <label>
+            <input
+                type="checkbox"
+                prop:checked=multiple
+                on:change=move |e| set_multiple.set(event_target_checked(&e))
+            />
+            " Multiple "
+            <BooleanDisplay value=multiple/>
+        </label>
+        <label>
+            <input
+                type="checkbox"
+                prop:checked=images_only
+                on:change=move |e| set_images_only.set(event_target_checked(&e))
+            />
+            " Images only"
+        </label>
+
+        <div>
+            <button on:click=move |_| open()>"Choose files"</button>
+            <button on:click=move |_| reset() disabled=move || files.with(Vec::is_empty)>
+                "Reset"
+            </button>
+        </div>
+
+        <p>"Selected files: " {move || files.with(Vec::len)}</p>
+        <ul>
+            {move || {
+                files
+                    .get()
+                    .into_iter()
+                    .map(|file| view! { <li>{format!("{} ({} bytes)", file.name(), file.size())}</li> })
+                    .collect_view()
+            }}
+        </ul>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,62 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_file_system_access_with_options, FilePickerType, FileSystemAccessData,
+    UseFileSystemAccessOptions, UseFileSystemAccessReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseFileSystemAccessReturn {
+        is_supported,
+        data,
+        set_data,
+        file_name,
+        is_dirty,
+        error,
+        open_file,
+        save,
+        save_as,
+        ..
+    } = use_file_system_access_with_options(UseFileSystemAccessOptions::default().types(vec![
+        FilePickerType::new("Text files")
+            .accept("text/plain", vec![".txt".to_string(), ".md".to_string()]),
+    ]));
+
+    let text = move || match data.get() {
+        Some(FileSystemAccessData::Text(text)) => text,
+        _ => String::new(),
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+
+        <button on:click=move |_| open_file()>"Open"</button>
+        <button on:click=move |_| save() disabled=move || !is_dirty.get()>
+            "Save"
+        </button>
+        <button on:click=move |_| save_as()>"Save as"</button>
+
+        <p>"File: " {move || file_name.get().unwrap_or_else(|| "-".to_string())}</p>
+        <p>"Unsaved changes: " <BooleanDisplay value=is_dirty/></p>
+
+        <textarea
+            class="w-full h-48"
+            prop:value=text
+            on:input=move |e| {
+                set_data.set(Some(FileSystemAccessData::Text(event_target_value(&e))))
+            }
+        />
+
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
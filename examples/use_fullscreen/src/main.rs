@@ -0,0 +1,36 @@
+use leptos::html::Div;
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_fullscreen, UseFullscreenReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let el = create_node_ref::<Div>();
+
+    let UseFullscreenReturn {
+        is_supported,
+        is_fullscreen,
+        toggle,
+        ..
+    } = use_fullscreen(el);
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+
+        <div node_ref=el class="p-8 bg-[--bg] rounded">
+            <p>"Fullscreen: " <BooleanDisplay value=is_fullscreen/></p>
+            <button on:click=move |_| toggle()>
+                {move || if is_fullscreen.get() { "Exit fullscreen" } else { "Go fullscreen" }}
+            </button>
+        </div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
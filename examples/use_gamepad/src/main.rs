@@ -0,0 +1,67 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_gamepad, UseGamepadReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseGamepadReturn {
+        is_supported,
+        gamepads,
+        is_active,
+        ..
+    } = use_gamepad();
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Polling: " <BooleanDisplay value=is_active/></p>
+
+        <Show
+            when=move || gamepads.with(|gamepads| !gamepads.is_empty())
+            fallback=|| view! { <p>"Connect a gamepad and press a button"</p> }
+        >
+            <For
+                each=move || gamepads.get().into_iter().map(|gamepad| gamepad.index)
+                key=|index| *index
+                let:index
+            >
+                {move || {
+                    gamepads
+                        .with(|gamepads| gamepads.iter().find(|g| g.index == index).cloned())
+                        .map(|gamepad| {
+                            view! {
+                                <div>
+                                    <h3>{gamepad.id}</h3>
+                                    <p>
+                                        "Buttons: "
+                                        {gamepad
+                                            .buttons
+                                            .iter()
+                                            .map(|button| if button.pressed { "●" } else { "○" })
+                                            .collect::<String>()}
+                                    </p>
+                                    <p>
+                                        "Axes: "
+                                        {gamepad
+                                            .axes
+                                            .iter()
+                                            .map(|axis| format!("{axis:.2}"))
+                                            .collect::<Vec<_>>()
+                                            .join(", ")}
+                                    </p>
+                                </div>
+                            }
+                        })
+                }}
+            </For>
+        </Show>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
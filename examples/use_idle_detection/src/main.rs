@@ -0,0 +1,46 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_idle_detection, UseIdleDetectionReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseIdleDetectionReturn {
+        is_supported,
+        user_state,
+        screen_state,
+        error,
+        is_active,
+        start,
+        stop,
+    } = use_idle_detection();
+
+    view! {
+        <Show
+            when=move || is_supported.get()
+            fallback=|| view! { <p>"Idle Detection API not supported"</p> }
+        >
+            <button on:click={
+                let start = start.clone();
+                move |_| start()
+            }>"Start"</button>
+            <button on:click={
+                let stop = stop.clone();
+                move |_| stop()
+            }>"Stop"</button>
+
+            <p>"Active: " <BooleanDisplay value=is_active/></p>
+            <p>"User state: " {move || format!("{:?}", user_state.get())}</p>
+            <p>"Screen state: " {move || format!("{:?}", screen_state.get())}</p>
+            <p>{move || error.get().map(|err| err.to_string())}</p>
+        </Show>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,45 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_image, UseImageReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (id, set_id) = create_signal(1);
+
+    let src = Signal::derive(move || format!("https://place.dog/300/200?id={}", id.get()));
+
+    let UseImageReturn {
+        is_loading,
+        is_ready,
+        error,
+        ..
+    } = use_image(src);
+
+    view! {
+        <button on:click=move |_| set_id.update(|id| *id += 1)>"Load another image"</button>
+        <p>"Loading: " <BooleanDisplay value=is_loading/></p>
+        <p>"Ready: " <BooleanDisplay value=is_ready/></p>
+
+        <Show
+            when=move || is_ready.get()
+            fallback=move || {
+                view! {
+                    <div class="w-[300px] h-[200px] bg-gray-400/20 flex items-center justify-center">
+                        {move || if error.with(Option::is_some) { "Failed to load" } else { "Loading..." }}
+                    </div>
+                }
+            }
+        >
+            <img src=src width="300" height="200"/>
+        </Show>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,97 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_image_capture, use_user_media_with_options, UseImageCaptureReturn, UseUserMediaOptions,
+    UseUserMediaReturn,
+};
+use wasm_bindgen::JsCast;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let video_ref = create_node_ref::<html::Video>();
+
+    let UseUserMediaReturn { stream, .. } =
+        use_user_media_with_options(UseUserMediaOptions::default().enabled(true));
+
+    create_effect(move |_| {
+        if let (Some(video), Some(Ok(stream))) = (video_ref.get(), stream.get()) {
+            video.set_src_object(Some(&stream));
+        }
+    });
+
+    let video_track = Signal::derive(move || {
+        stream
+            .get()
+            .and_then(Result::ok)
+            .and_then(|stream| stream.get_video_tracks().get(0).dyn_into().ok())
+    });
+
+    let UseImageCaptureReturn {
+        is_supported,
+        capabilities,
+        photo,
+        error,
+        take_photo,
+        set_zoom,
+        set_torch,
+        ..
+    } = use_image_capture(video_track);
+
+    let photo_url = create_memo(move |prev: Option<&Option<String>>| {
+        if let Some(Some(prev)) = prev {
+            let _ = web_sys::Url::revoke_object_url(prev);
+        }
+
+        photo
+            .get()
+            .and_then(|blob| web_sys::Url::create_object_url_with_blob(&blob).ok())
+    });
+
+    let zoom = move || capabilities.with(|c| c.as_ref().and_then(|c| c.zoom));
+    let has_torch = move || capabilities.with(|c| c.as_ref().is_some_and(|c| c.torch));
+    let torch_on = move || capabilities.with(|c| c.as_ref().is_some_and(|c| c.current_torch));
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+
+        <video node_ref=video_ref controls=false autoplay=true muted=true class="h-96 w-auto"></video>
+
+        <div>
+            <button on:click=move |_| take_photo()>"Take photo"</button>
+            <button on:click=move |_| set_torch(!torch_on()) disabled=move || !has_torch()>
+                "Toggle torch"
+            </button>
+        </div>
+
+        <div>
+            "Zoom "
+            <input
+                type="range"
+                disabled=move || zoom().is_none()
+                min=move || zoom().map(|z| z.min)
+                max=move || zoom().map(|z| z.max)
+                step=move || zoom().map(|z| z.step)
+                on:input=move |e| {
+                    if let Ok(value) = event_target_value(&e).parse() {
+                        set_zoom(value);
+                    }
+                }
+            />
+        </div>
+
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+
+        <Show when=move || photo_url.with(Option::is_some)>
+            <img src=move || photo_url.get() class="h-48 w-auto"/>
+        </Show>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,52 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::storage::{use_indexed_db, UseStorageAsyncReturn};
+use leptos_use::utils::JsonCodec;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Draft {
+    pub title: String,
+    pub body: String,
+}
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseStorageAsyncReturn {
+        data,
+        set_data,
+        remove,
+        is_loading,
+        is_writing,
+        ..
+    } = use_indexed_db::<Draft, JsonCodec>("leptos-use-demo", "drafts", "current");
+
+    view! {
+        <input
+            class="block"
+            placeholder="Title"
+            prop:value=move || data.get().title
+            on:input=move |e| set_data.update(|draft| draft.title = event_target_value(&e))
+            type="text"
+        />
+        <textarea
+            class="block"
+            placeholder="Write something..."
+            prop:value=move || data.get().body
+            on:input=move |e| set_data.update(|draft| draft.body = event_target_value(&e))
+        ></textarea>
+        <button on:click=move |_| remove()>"Delete from IndexedDB"</button>
+        <p>"Loading: " <BooleanDisplay value=is_loading/></p>
+        <p>"Writing: " <BooleanDisplay value=is_writing/></p>
+        <Note>"Open this page in another tab to see the draft synced"</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,40 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_location_hash_with_options, UseLocationHashOptions};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (tab, set_tab) =
+        use_location_hash_with_options(UseLocationHashOptions::default().strip_hash(true));
+
+    let tabs = ["profile", "settings", "about"];
+
+    view! {
+        <p>
+            {tabs
+                .into_iter()
+                .map(|name| {
+                    view! {
+                        <button
+                            class:font-bold=move || tab.get() == name
+                            on:click=move |_| set_tab.set(name.to_string())
+                        >
+                            {name}
+                        </button>
+                    }
+                })
+                .collect_view()}
+        </p>
+        <p>"Current tab: " <code>{tab}</code></p>
+        <Note>"Click a tab and use the back button of your browser"</Note>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
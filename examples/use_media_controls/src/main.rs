@@ -0,0 +1,133 @@
+use leptos::html::Video;
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_media_controls, UseMediaControlsReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let video = create_node_ref::<Video>();
+
+    let UseMediaControlsReturn {
+        playing,
+        set_playing,
+        current_time,
+        set_current_time,
+        volume,
+        set_volume,
+        muted,
+        set_muted,
+        rate,
+        set_rate,
+        duration,
+        buffered,
+        seeking,
+        waiting,
+        ended,
+        tracks,
+        selected_track,
+        enable_track,
+        disable_track,
+        ..
+    } = use_media_controls(video);
+
+    view! {
+        <video
+            node_ref=video
+            class="w-full max-w-lg"
+            src="https://interactive-examples.mdn.mozilla.net/media/cc0-videos/friday.mp4"
+            crossorigin="anonymous"
+        >
+            <track
+                kind="subtitles"
+                label="English"
+                srclang="en"
+                src="https://interactive-examples.mdn.mozilla.net/media/examples/friday.vtt"
+            />
+        </video>
+
+        <div>
+            <button on:click=move |_| set_playing.set(!playing.get())>
+                {move || if playing.get() { "Pause" } else { "Play" }}
+            </button>
+            <button on:click=move |_| set_current_time.set((current_time.get() - 5.0).max(0.0))>
+                "-5s"
+            </button>
+            <button on:click=move |_| set_current_time.set(current_time.get() + 5.0)>"+5s"</button>
+            <button on:click=move |_| set_muted.set(!muted.get())>
+                {move || if muted.get() { "Unmute" } else { "Mute" }}
+            </button>
+        </div>
+
+        <input
+            class="w-full max-w-lg"
+            type="range"
+            min="0"
+            step="0.1"
+            prop:max=duration
+            prop:value=current_time
+            on:input=move |e| set_current_time.set(event_target_value(&e).parse().unwrap_or(0.0))
+        />
+
+        <p>
+            "Volume "
+            <input
+                type="range"
+                min="0"
+                max="1"
+                step="0.01"
+                prop:value=volume
+                on:input=move |e| set_volume.set(event_target_value(&e).parse().unwrap_or(1.0))
+            />
+        </p>
+
+        <p>
+            "Rate "
+            <select on:change=move |e| set_rate.set(event_target_value(&e).parse().unwrap_or(1.0))>
+                {[0.5, 1.0, 1.5, 2.0]
+                    .map(|r| {
+                        view! {
+                            <option value=r.to_string() selected=move || rate.get() == r>
+                                {r}
+                                "x"
+                            </option>
+                        }
+                    })}
+            </select>
+        </p>
+
+        <p>
+            "Subtitles "
+            <For each=move || tracks.get() key=|track| track.index let:track>
+                <button on:click={
+                    let enable_track = enable_track.clone();
+                    move |_| enable_track(track.index)
+                }>{track.label}</button>
+            </For>
+            <button on:click=move |_| disable_track()>"Off"</button>
+        </p>
+
+        <pre>
+            {move || {
+                format!(
+                    "current_time: {:.1}\nduration: {:.1}\nbuffered: {:?}\nselected_track: {:?}",
+                    current_time.get(),
+                    duration.get(),
+                    buffered.get(),
+                    selected_track.get(),
+                )
+            }}
+        </pre>
+        <p>"Seeking: " <BooleanDisplay value=seeking/></p>
+        <p>"Waiting: " <BooleanDisplay value=waiting/></p>
+        <p>"Ended: " <BooleanDisplay value=ended/></p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
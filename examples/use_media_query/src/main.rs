@@ -1,15 +1,40 @@
 use leptos::*;
 use leptos_use::docs::{demo_or_body, BooleanDisplay};
-use leptos_use::use_media_query;
+use leptos_use::{
+    use_media_query, use_media_query_with_options, UseMediaQueryOptions, UseMediaQueryReturn,
+};
 
 #[component]
 fn Demo() -> impl IntoView {
     let is_large_screen = use_media_query("(min-width: 1024px)");
     let is_dark_preferred = use_media_query("(prefers-color-scheme: dark)");
 
+    let (min_width, set_min_width) = create_signal(600);
+    let UseMediaQueryReturn {
+        matches: is_wider,
+        is_active,
+        pause,
+        resume,
+    } = use_media_query_with_options(
+        Signal::derive(move || format!("(min-width: {}px)", min_width.get())),
+        UseMediaQueryOptions::default(),
+    );
+
     view! {
         <p>"Is large screen: " <BooleanDisplay value=is_large_screen/></p>
         <p>"Is dark preferred: " <BooleanDisplay value=is_dark_preferred/></p>
+
+        <p>
+            "Is wider than "
+            <input
+                type="number"
+                prop:value=min_width
+                on:input=move |e| set_min_width.set(event_target_value(&e).parse().unwrap_or(0))
+            />
+            "px: " <BooleanDisplay value=is_wider/>
+        </p>
+        <button on:click=move |_| pause() disabled=move || !is_active.get()>"Pause"</button>
+        <button on:click=move |_| resume() disabled=is_active>"Resume"</button>
     }
 }
 
@@ -0,0 +1,64 @@
+use leptos::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{
+    use_media_recorder, use_user_media_with_options, UseMediaRecorderReturn, UseUserMediaOptions,
+    UseUserMediaReturn,
+};
+use web_sys::RecordingState;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseUserMediaReturn { stream, .. } =
+        use_user_media_with_options(UseUserMediaOptions::default().enabled(true));
+
+    let UseMediaRecorderReturn {
+        state,
+        data,
+        error,
+        start,
+        pause,
+        resume,
+        stop,
+        ..
+    } = use_media_recorder(Signal::derive(move || stream.get().and_then(Result::ok)));
+
+    let url = create_memo(move |prev: Option<&Option<String>>| {
+        if let Some(Some(prev)) = prev {
+            let _ = web_sys::Url::revoke_object_url(prev);
+        }
+
+        data.get()
+            .and_then(|blob| web_sys::Url::create_object_url_with_blob(&blob).ok())
+    });
+
+    view! {
+        <p>"State: " {move || format!("{:?}", state.get())}</p>
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+
+        <button on:click=move |_| start() disabled=move || state.get() != RecordingState::Inactive>
+            "Record"
+        </button>
+        <button on:click=move |_| pause() disabled=move || state.get() != RecordingState::Recording>
+            "Pause"
+        </button>
+        <button on:click=move |_| resume() disabled=move || state.get() != RecordingState::Paused>
+            "Resume"
+        </button>
+        <button on:click=move |_| stop() disabled=move || state.get() == RecordingState::Inactive>
+            "Stop"
+        </button>
+
+        <Show when=move || url.with(Option::is_some)>
+            <video src=move || url.get() controls=true class="h-96 w-auto"></video>
+        </Show>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,97 @@
+use leptos::html::Audio;
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_media_controls, use_media_session_with_options, MediaArtwork, UseMediaControlsReturn,
+    UseMediaSessionOptions, UseMediaSessionReturn,
+};
+use web_sys::{MediaSessionAction, MediaSessionPlaybackState};
+
+const AUDIO_SRC: &str = "https://interactive-examples.mdn.mozilla.net/media/cc0-audio/t-rex-roar.mp3";
+const TRACKS: [&str; 3] = ["First Track", "Second Track", "Third Track"];
+
+#[component]
+fn Demo() -> impl IntoView {
+    let audio = create_node_ref::<Audio>();
+    let (track, set_track) = create_signal(0_usize);
+    let (last_action, set_last_action) = create_signal(None::<MediaSessionAction>);
+
+    let UseMediaControlsReturn {
+        playing,
+        set_playing,
+        current_time,
+        set_current_time,
+        duration,
+        rate,
+        ..
+    } = use_media_controls(audio);
+
+    let UseMediaSessionReturn { is_supported } = use_media_session_with_options(
+        UseMediaSessionOptions::default()
+            .title(Signal::derive(move || TRACKS[track.get()].to_string()))
+            .artist("Wikimedia Commons")
+            .artwork(vec![MediaArtwork::new("https://placehold.co/512x512.png")
+                .sizes("512x512")
+                .type_("image/png")])
+            .playback_state(Signal::derive(move || {
+                if playing.get() {
+                    MediaSessionPlaybackState::Playing
+                } else {
+                    MediaSessionPlaybackState::Paused
+                }
+            }))
+            .duration(duration)
+            .position(current_time)
+            .playback_rate(rate)
+            .on_action(move |event| {
+                set_last_action.set(Some(event.action));
+
+                match event.action {
+                    MediaSessionAction::Play => set_playing.set(true),
+                    MediaSessionAction::Pause => set_playing.set(false),
+                    MediaSessionAction::Seekbackward => set_current_time
+                        .set(current_time.get_untracked() - event.seek_offset.unwrap_or(10.0)),
+                    MediaSessionAction::Seekforward => set_current_time
+                        .set(current_time.get_untracked() + event.seek_offset.unwrap_or(10.0)),
+                    MediaSessionAction::Seekto => {
+                        if let Some(seek_time) = event.seek_time {
+                            set_current_time.set(seek_time);
+                        }
+                    }
+                    MediaSessionAction::Previoustrack => {
+                        set_track.update(|track| *track = (*track + TRACKS.len() - 1) % TRACKS.len())
+                    }
+                    MediaSessionAction::Nexttrack => {
+                        set_track.update(|track| *track = (*track + 1) % TRACKS.len())
+                    }
+                    _ => {}
+                }
+            }),
+    );
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+
+        <audio node_ref=audio src=AUDIO_SRC></audio>
+
+        <p>{move || TRACKS[track.get()]}</p>
+        <button on:click=move |_| set_playing.set(!playing.get())>
+            {move || if playing.get() { "Pause" } else { "Play" }}
+        </button>
+        <p>{move || format!("{:.0} / {:.0}", current_time.get(), duration.get())}</p>
+
+        <p>
+            "Use the media controls of your operating system. Last action: "
+            {move || last_action.get().map(|action| format!("{action:?}"))}
+        </p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,83 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_midi, UseMidiReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseMidiReturn {
+        is_supported,
+        inputs,
+        outputs,
+        message,
+        error,
+        send,
+        ..
+    } = use_midi();
+
+    let play_note = move |output_id: String| {
+        // note on and note off for middle C on channel 1
+        let _ = send(&output_id, &[0x90, 60, 0x7f]);
+        set_timeout(
+            move || {
+                let _ = send(&output_id, &[0x80, 60, 0x40]);
+            },
+            std::time::Duration::from_millis(500),
+        );
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+
+        <p>"Inputs:"</p>
+        <ul>
+            {move || {
+                inputs
+                    .get()
+                    .into_iter()
+                    .map(|input| view! { <li>{input.name()}</li> })
+                    .collect_view()
+            }}
+        </ul>
+
+        <p>"Outputs:"</p>
+        <ul>
+            {move || {
+                outputs
+                    .get()
+                    .into_iter()
+                    .map(|output| {
+                        let output_id = output.id();
+                        view! {
+                            <li>
+                                {output.name()} " "
+                                <button on:click=move |_| play_note(output_id.clone())>
+                                    "Play C4"
+                                </button>
+                            </li>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </ul>
+
+        <p>
+            "Last message: "
+            {move || {
+                message
+                    .get()
+                    .map(|message| format!("{:02x?} from {}", message.data, message.port_id))
+            }}
+        </p>
+
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,38 @@
+use leptos::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::use_network;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let network = use_network();
+
+    view! {
+        <pre>
+            {move || format!(
+                concat!(
+                    "is_supported: {}\n",
+                    "effective_type: {:?}\n",
+                    "downlink: {:?}\n",
+                    "rtt: {:?}\n",
+                    "save_data: {}\n",
+                    "connection_type: {:?}\n",
+                ),
+                network.is_supported.get(),
+                network.effective_type.get(),
+                network.downlink.get(),
+                network.rtt.get(),
+                network.save_data.get(),
+                network.connection_type.get(),
+            )}
+        </pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,33 @@
+use leptos::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{use_file_dialog_with_options, use_object_url, UseFileDialogOptions, UseFileDialogReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseFileDialogReturn { files, open, .. } = use_file_dialog_with_options(
+        UseFileDialogOptions::default()
+            .multiple(false)
+            .accept("image/*"),
+    );
+
+    let url = use_object_url(Signal::derive(move || {
+        files.get().into_iter().next().map(web_sys::Blob::from)
+    }));
+
+    view! {
+        <button on:click=move |_| open()>"Choose image"</button>
+        <p>"Object URL: " <code>{move || url.get().unwrap_or_else(|| "-".to_string())}</code></p>
+        <Show when=move || url.with(Option::is_some)>
+            <img class="max-w-sm" src=url/>
+        </Show>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,32 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_online_with_options, UseOnlineOptions, UseOnlineReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseOnlineReturn {
+        is_online,
+        last_checked,
+    } = use_online_with_options(
+        UseOnlineOptions::default()
+            .probe_url("index.html")
+            .probe_interval(5_000),
+    );
+
+    view! {
+        <p>"Online: " <BooleanDisplay value=is_online/></p>
+        <p>
+            "Last checked: "
+            {move || last_checked.get().map(|timestamp| format!("{timestamp:.0} ms"))}
+        </p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,22 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::use_page_leave;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let is_left = use_page_leave();
+
+    view! {
+        <Note class="mb-2">"Move your mouse out of the page"</Note>
+        <div>"Has left the page: " <BooleanDisplay value=is_left/></div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
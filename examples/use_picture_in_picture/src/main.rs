@@ -0,0 +1,46 @@
+use leptos::html::Video;
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_picture_in_picture, UsePictureInPictureReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let video = create_node_ref::<Video>();
+
+    let UsePictureInPictureReturn {
+        is_supported,
+        is_active,
+        width,
+        height,
+        error,
+        toggle,
+        ..
+    } = use_picture_in_picture(video);
+
+    view! {
+        <video
+            node_ref=video
+            class="w-full max-w-lg"
+            src="https://interactive-examples.mdn.mozilla.net/media/cc0-videos/flower.mp4"
+            controls
+        ></video>
+
+        <button on:click=move |_| toggle() disabled=move || !is_supported.get()>
+            {move || if is_active.get() { "Exit Picture-in-Picture" } else { "Enter Picture-in-Picture" }}
+        </button>
+
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Active: " <BooleanDisplay value=is_active/></p>
+        <p>"Window size: " {move || format!("{} x {}", width.get(), height.get())}</p>
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
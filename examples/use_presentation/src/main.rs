@@ -0,0 +1,64 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_presentation, UsePresentationReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UsePresentationReturn {
+        is_supported,
+        is_available,
+        is_connected,
+        presentation_id,
+        message,
+        error,
+        start,
+        send,
+        close,
+        terminate,
+        ..
+    } = use_presentation("https://leptos.dev");
+
+    let (text, set_text) = create_signal("Hello".to_string());
+
+    view! {
+        <button on:click=move |_| start() disabled=move || !is_available.get()>
+            "Start presentation"
+        </button>
+        <button on:click=move |_| close() disabled=move || !is_connected.get()>
+            "Close"
+        </button>
+        <button on:click=move |_| terminate() disabled=move || presentation_id.get().is_none()>
+            "Terminate"
+        </button>
+
+        <div>
+            <input
+                type="text"
+                prop:value=text
+                on:input=move |e| set_text.set(event_target_value(&e))
+            />
+            <button
+                on:click=move |_| send(&text.get_untracked())
+                disabled=move || !is_connected.get()
+            >
+                "Send"
+            </button>
+        </div>
+
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Display available: " <BooleanDisplay value=is_available/></p>
+        <p>"Connected: " <BooleanDisplay value=is_connected/></p>
+        <p>"Presentation id: " {move || presentation_id.get().unwrap_or_default()}</p>
+        <p>"Last message: " {move || message.get().unwrap_or_default()}</p>
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,5 @@
+self.addEventListener("push", (event) => {
+  const body = event.data ? event.data.text() : "Empty push message";
+
+  event.waitUntil(self.registration.showNotification("leptos-use", { body }));
+});
@@ -0,0 +1,56 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_push_subscription, use_service_worker, UsePushSubscriptionReturn};
+
+// Replace this with the public key of your own VAPID key pair
+const VAPID_PUBLIC_KEY: &str =
+    "BEl62iUYgUivxIkv69yViEuiBIa-Ib9-SkvMeAtA3LFgDzkrxZJjSgSnfckjBJuBkr3qBUYIHBQFLXYp5Nksh8U";
+
+#[component]
+fn Demo() -> impl IntoView {
+    let _ = use_service_worker("push_worker.js");
+
+    let UsePushSubscriptionReturn {
+        is_supported,
+        permission,
+        info,
+        error,
+        subscribe,
+        unsubscribe,
+        ..
+    } = use_push_subscription(VAPID_PUBLIC_KEY);
+
+    view! {
+        <Show
+            when=move || is_supported.get()
+            fallback=|| view! { <p>"Push API not supported"</p> }
+        >
+            <p>"Permission: " {move || format!("{:?}", permission.get())}</p>
+            <p>
+                "Subscribed: "
+                <BooleanDisplay value=Signal::derive(move || info.with(Option::is_some))/>
+            </p>
+
+            <button on:click={
+                let subscribe = subscribe.clone();
+                move |_| subscribe()
+            }>"Subscribe"</button>
+            <button on:click={
+                let unsubscribe = unsubscribe.clone();
+                move |_| unsubscribe()
+            }>"Unsubscribe"</button>
+
+            <pre>{move || info.get().map(|info| format!("{info:#?}"))}</pre>
+            <p>{move || error.get().map(|err| err.to_string())}</p>
+        </Show>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,51 @@
+use leptos::html::Video;
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_remote_playback, RemotePlaybackState, UseRemotePlaybackReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let video = create_node_ref::<Video>();
+
+    let UseRemotePlaybackReturn {
+        is_supported,
+        is_available,
+        state,
+        error,
+        prompt,
+    } = use_remote_playback(video);
+
+    view! {
+        <video
+            node_ref=video
+            class="w-full max-w-lg"
+            src="https://interactive-examples.mdn.mozilla.net/media/cc0-videos/flower.mp4"
+            controls
+        ></video>
+
+        <button on:click=move |_| prompt() disabled=move || !is_available.get()>
+            "Cast to device"
+        </button>
+
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Device available: " <BooleanDisplay value=is_available/></p>
+        <p>
+            "State: "
+            {move || match state.get() {
+                RemotePlaybackState::Connecting => "connecting",
+                RemotePlaybackState::Connected => "connected",
+                RemotePlaybackState::Disconnected => "disconnected",
+            }}
+        </p>
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
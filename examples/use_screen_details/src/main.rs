@@ -0,0 +1,56 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_screen_details, UseScreenDetailsReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseScreenDetailsReturn {
+        is_supported,
+        screens,
+        current_screen,
+        error,
+        request,
+    } = use_screen_details();
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <button on:click=move |_| request()>"Detect screens"</button>
+
+        <For each=move || screens.get() key=|screen| screen.label.clone() let:screen>
+            {
+                let label = screen.label.clone();
+                let is_current = move || {
+                    current_screen.with(|current| {
+                        current.as_ref().is_some_and(|current| current.label == label)
+                    })
+                };
+
+                view! {
+                    <div class="p-2 my-2 border rounded" class:border-green-500=is_current>
+                        <p><b>{screen.label.clone()}</b></p>
+                        <p>
+                            "Position: " {screen.left} ", " {screen.top} " · Size: "
+                            {screen.width} " x " {screen.height}
+                        </p>
+                        <p>"Pixel ratio: " {screen.device_pixel_ratio}</p>
+                        <p>
+                            "Primary: " <BooleanDisplay value=screen.is_primary/> " · Internal: "
+                            <BooleanDisplay value=screen.is_internal/>
+                        </p>
+                    </div>
+                }
+            }
+        </For>
+
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,59 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_fullscreen, use_screen_orientation, UseFullscreenReturn, UseScreenOrientationReturn,
+};
+use web_sys::{OrientationLockType, OrientationType};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseScreenOrientationReturn {
+        is_supported,
+        orientation,
+        angle,
+        error,
+        lock,
+        unlock,
+    } = use_screen_orientation();
+
+    let UseFullscreenReturn {
+        is_fullscreen,
+        toggle: toggle_fullscreen,
+        ..
+    } = use_fullscreen(document().document_element());
+
+    let orientation_name = move || match orientation.get() {
+        Some(OrientationType::PortraitPrimary) => "portrait-primary",
+        Some(OrientationType::PortraitSecondary) => "portrait-secondary",
+        Some(OrientationType::LandscapePrimary) => "landscape-primary",
+        Some(OrientationType::LandscapeSecondary) => "landscape-secondary",
+        _ => "unknown",
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Orientation: " <code>{orientation_name}</code></p>
+        <p>"Angle: " {angle} "°"</p>
+
+        <button on:click=move |_| toggle_fullscreen()>
+            {move || if is_fullscreen.get() { "Exit fullscreen" } else { "Enter fullscreen" }}
+        </button>
+        <button on:click={
+            let lock = lock.clone();
+            move |_| lock(OrientationLockType::Portrait)
+        }>"Lock portrait"</button>
+        <button on:click=move |_| lock(OrientationLockType::Landscape)>"Lock landscape"</button>
+        <button on:click=move |_| unlock()>"Unlock"</button>
+
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,76 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_serial_with_options, UseSerialOptions, UseSerialReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (lines, set_lines) = create_signal(Vec::<String>::new());
+    let (message, set_message) = create_signal(String::new());
+
+    let UseSerialReturn {
+        is_supported,
+        is_open,
+        line,
+        error,
+        request_port,
+        close,
+        write,
+        ..
+    } = use_serial_with_options(UseSerialOptions::default().baud_rate(115_200));
+
+    let _ = watch(
+        move || line.get(),
+        move |line, _, _| {
+            if let Some(line) = line.clone() {
+                set_lines.update(|lines| lines.push(line));
+            }
+        },
+        false,
+    );
+
+    let send = move |_| {
+        let write = write.clone();
+        let mut bytes = message.get_untracked().into_bytes();
+        bytes.push(b'\n');
+
+        spawn_local(async move {
+            if write(bytes).await.is_ok() {
+                set_message.set(String::new());
+            }
+        });
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Open: " <BooleanDisplay value=is_open/></p>
+
+        <button on:click=move |_| request_port()>"Connect"</button>
+        <button on:click=move |_| close() disabled=move || !is_open.get()>
+            "Close"
+        </button>
+
+        <div>
+            <input
+                type="text"
+                prop:value=message
+                on:input=move |e| set_message.set(event_target_value(&e))
+            />
+            <button on:click=send disabled=move || !is_open.get()>
+                "Send"
+            </button>
+        </div>
+
+        <pre>{move || lines.get().join("\n")}</pre>
+
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -14,9 +14,11 @@ fn Demo() -> impl IntoView {
         installing,
         waiting,
         active,
+        update_available,
         skip_waiting,
+        skip_waiting_and_reload,
         ..
-    } = use_service_worker();
+    } = use_service_worker("service-worker.js");
 
     view! {
         <p>"Current build: " {build}</p>
@@ -27,10 +29,12 @@ fn Demo() -> impl IntoView {
         <p>"installing: " <BooleanDisplay value=installing/></p>
         <p>"waiting: " <BooleanDisplay value=waiting/></p>
         <p>"active: " <BooleanDisplay value=active/></p>
+        <p>"update available: " <BooleanDisplay value=update_available/></p>
 
         <br/>
 
         <button on:click=move |_| { skip_waiting() }>"Send skip_waiting event"</button>
+        <button on:click=move |_| { skip_waiting_and_reload() }>"Update and reload"</button>
     }
 }
 
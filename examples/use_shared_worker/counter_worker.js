@@ -0,0 +1,20 @@
+// One instance of this worker is shared by all tabs that open the demo.
+const ports = [];
+let count = 0;
+
+self.onconnect = (event) => {
+  const port = event.ports[0];
+  ports.push(port);
+
+  port.onmessage = (message) => {
+    if (message.data === "increment") {
+      count += 1;
+    }
+
+    for (const p of ports) {
+      p.postMessage(String(count));
+    }
+  };
+
+  port.postMessage(String(count));
+};
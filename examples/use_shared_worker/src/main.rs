@@ -0,0 +1,38 @@
+use leptos::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::utils::FromToStringCodec;
+use leptos_use::{use_shared_worker, UseSharedWorkerReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseSharedWorkerReturn {
+        is_supported,
+        data,
+        post_message,
+        ..
+    } = use_shared_worker::<String, u32, FromToStringCodec>("counter", "./counter_worker.js");
+
+    view! {
+        <p>"Please open this page in at least two tabs"</p>
+
+        <Show
+            when=move || is_supported.get()
+            fallback=move || view! { <p>"SharedWorker not supported"</p> }
+        >
+            <button on:click={
+                let post_message = post_message.clone();
+                move |_| post_message(&"increment".to_string())
+            }>"Increment"</button>
+            <p>"Shared count: " {move || data.get()}</p>
+        </Show>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,55 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_speech_recognition_with_options, UseSpeechRecognitionOptions, UseSpeechRecognitionReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (lang, set_lang) = create_signal("en-US".to_string());
+
+    let UseSpeechRecognitionReturn {
+        is_supported,
+        is_listening,
+        final_result,
+        interim_result,
+        error,
+        start,
+        stop,
+        ..
+    } = use_speech_recognition_with_options(UseSpeechRecognitionOptions::default().lang(lang));
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Listening: " <BooleanDisplay value=is_listening/></p>
+
+        <select on:change=move |e| set_lang.set(event_target_value(&e))>
+            <option value="en-US">"English"</option>
+            <option value="de-DE">"German"</option>
+            <option value="fr-FR">"French"</option>
+            <option value="es-ES">"Spanish"</option>
+        </select>
+
+        <button on:click=move |_| start() disabled=is_listening>
+            "Start"
+        </button>
+        <button on:click=move |_| stop() disabled=move || !is_listening.get()>
+            "Stop"
+        </button>
+
+        <p>
+            {final_result} <span class="text-gray-500">{interim_result}</span>
+        </p>
+
+        <p>{move || error.get().map(|err| format!("Error: {err:?}"))}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
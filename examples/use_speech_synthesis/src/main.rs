@@ -0,0 +1,107 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_speech_synthesis_with_options, UseSpeechSynthesisOptions, UseSpeechSynthesisReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (text, set_text) = create_signal("Hello, everyone! Good morning!".to_string());
+    let (voice_index, set_voice_index) = create_signal(None::<usize>);
+    let (pitch, set_pitch) = create_signal(1.0_f32);
+    let (rate, set_rate) = create_signal(1.0_f32);
+
+    let (voice, set_voice) = create_signal(None);
+
+    let UseSpeechSynthesisReturn {
+        is_supported,
+        is_playing,
+        status,
+        voices,
+        speak,
+        pause,
+        resume,
+        cancel,
+        ..
+    } = use_speech_synthesis_with_options(
+        text,
+        UseSpeechSynthesisOptions::default()
+            .voice(voice)
+            .pitch(pitch)
+            .rate(rate),
+    );
+
+    create_effect(move |_| {
+        set_voice.set(voice_index.get().and_then(|index| voices.get().get(index).cloned()));
+    });
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Playing: " <BooleanDisplay value=is_playing/></p>
+        <p>"Status: " {move || format!("{:?}", status.get())}</p>
+
+        <textarea
+            prop:value=text
+            on:input=move |e| set_text.set(event_target_value(&e))
+        ></textarea>
+
+        <div>
+            <label>"Voice "</label>
+            <select on:change=move |e| set_voice_index.set(event_target_value(&e).parse().ok())>
+                <option value="">"Default"</option>
+                {move || {
+                    voices
+                        .get()
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, voice)| {
+                            view! {
+                                <option value=index.to_string()>
+                                    {format!("{} ({})", voice.name(), voice.lang())}
+                                </option>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </select>
+        </div>
+
+        <div>
+            <label>"Pitch "</label>
+            <input
+                type="range"
+                min="0.5"
+                max="2"
+                step="0.1"
+                prop:value=move || pitch.get().to_string()
+                on:input=move |e| set_pitch.set(event_target_value(&e).parse().unwrap_or(1.0))
+            />
+        </div>
+
+        <div>
+            <label>"Rate "</label>
+            <input
+                type="range"
+                min="0.5"
+                max="2"
+                step="0.1"
+                prop:value=move || rate.get().to_string()
+                on:input=move |e| set_rate.set(event_target_value(&e).parse().unwrap_or(1.0))
+            />
+        </div>
+
+        <button on:click=move |_| speak()>"Speak"</button>
+        <button on:click=move |_| pause()>"Pause"</button>
+        <button on:click=move |_| resume()>"Resume"</button>
+        <button on:click=move |_| cancel()>"Stop"</button>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,81 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::use_url_search_params;
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (params, set_params) = use_url_search_params();
+
+    let query = move || {
+        params
+            .get()
+            .get("q")
+            .and_then(|values| values.first().cloned())
+            .unwrap_or_default()
+    };
+
+    let has_tag = move |tag: &str| {
+        params
+            .get()
+            .get("tag")
+            .map(|tags| tags.iter().any(|t| t == tag))
+            .unwrap_or_default()
+    };
+
+    let toggle_tag = move |tag: &'static str| {
+        set_params.update(|params| {
+            let tags = params.entry("tag".to_string()).or_default();
+
+            if let Some(index) = tags.iter().position(|t| t == tag) {
+                tags.remove(index);
+            } else {
+                tags.push(tag.to_string());
+            }
+        });
+    };
+
+    view! {
+        <input
+            class="block"
+            placeholder="Search"
+            prop:value=query
+            on:input=move |e| {
+                set_params
+                    .update(|params| {
+                        params.insert("q".to_string(), vec![event_target_value(&e)]);
+                    })
+            }
+
+            type="text"
+        />
+        <p>
+            {["rust", "leptos", "wasm"]
+                .into_iter()
+                .map(|tag| {
+                    view! {
+                        <label class="mr-4">
+                            <input
+                                type="checkbox"
+                                prop:checked=move || has_tag(tag)
+                                on:change=move |_| toggle_tag(tag)
+                            />
+                            " "
+                            {tag}
+                        </label>
+                    }
+                })
+                .collect_view()}
+        </p>
+        <Note>"The params are persisted in the URL. Reload the page to see them restored."</Note>
+        <pre>{move || format!("{:#?}", params.get())}</pre>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
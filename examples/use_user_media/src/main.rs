@@ -0,0 +1,82 @@
+use leptos::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{
+    use_devices_list, use_user_media_with_options, MediaDeviceConstraint, UseUserMediaOptions,
+    UseUserMediaReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let video_ref = create_node_ref::<leptos::html::Video>();
+
+    let (camera, set_camera) = create_signal(MediaDeviceConstraint::Default);
+
+    let devices = use_devices_list();
+
+    let UseUserMediaReturn {
+        stream,
+        enabled,
+        set_enabled,
+        ..
+    } = use_user_media_with_options(
+        UseUserMediaOptions::default()
+            .audio(MediaDeviceConstraint::None)
+            .video(camera),
+    );
+
+    create_effect(move |_| {
+        match stream.get() {
+            Some(Ok(s)) => {
+                video_ref.get().map(|v| v.set_src_object(Some(&s)));
+                return;
+            }
+            Some(Err(e)) => logging::error!("Failed to get media stream: {:?}", e),
+            None => logging::log!("No stream yet"),
+        }
+
+        video_ref.get().map(|v| v.set_src_object(None));
+    });
+
+    view! {
+        <div class="flex flex-col gap-4 text-center">
+            <div>
+                <button on:click=move |_| set_enabled(
+                    !enabled(),
+                )>{move || if enabled() { "Stop" } else { "Start" }} camera</button>
+            </div>
+
+            <div>
+                <select on:change=move |ev| {
+                    set_camera.set(MediaDeviceConstraint::DeviceId(event_target_value(&ev)))
+                }>
+                    <For
+                        each=move || devices.video_inputs.get()
+                        key=|device| device.device_id()
+                        let:device
+                    >
+                        <option value=device.device_id()>{device.label()}</option>
+                    </For>
+                </select>
+            </div>
+
+            <div>
+                <video
+                    node_ref=video_ref
+                    controls=false
+                    autoplay=true
+                    muted=true
+                    class="h-96 w-auto"
+                ></video>
+            </div>
+        </div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
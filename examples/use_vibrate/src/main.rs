@@ -0,0 +1,34 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_vibrate_with_options, UseVibrateOptions, UseVibrateReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseVibrateReturn {
+        is_supported,
+        is_active,
+        vibrate,
+        stop,
+        ..
+    } = use_vibrate_with_options(
+        vec![300, 100, 300],
+        UseVibrateOptions::default().interval(2000),
+    );
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Repeating: " <BooleanDisplay value=is_active/></p>
+
+        <button on:click=move |_| vibrate()>"Vibrate"</button>
+        <button on:click=move |_| stop()>"Stop"</button>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,33 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_wake_lock, UseWakeLockReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseWakeLockReturn {
+        is_supported,
+        is_active,
+        error,
+        request,
+        release,
+    } = use_wake_lock();
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Active: " <BooleanDisplay value=is_active/></p>
+
+        <button on:click=move |_| request() disabled=move || is_active.get()>"Request"</button>
+        <button on:click=move |_| release() disabled=move || !is_active.get()>"Release"</button>
+
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
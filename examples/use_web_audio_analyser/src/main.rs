@@ -0,0 +1,72 @@
+use leptos::html::Audio;
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_web_audio_analyser_with_options, UseWebAudioAnalyserOptions, UseWebAudioAnalyserReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let audio = create_node_ref::<Audio>();
+
+    let UseWebAudioAnalyserReturn {
+        is_supported,
+        frequency_data,
+        volume,
+        error,
+        is_active,
+        pause,
+        resume,
+        ..
+    } = use_web_audio_analyser_with_options(
+        Signal::derive(move || audio.get().map(|audio| (*audio).clone()).into()),
+        UseWebAudioAnalyserOptions::default().fft_size(128),
+    );
+
+    view! {
+        <audio
+            node_ref=audio
+            crossorigin="anonymous"
+            src="https://interactive-examples.mdn.mozilla.net/media/cc0-audio/t-rex-roar.mp3"
+            controls
+        ></audio>
+
+        <div class="flex items-end gap-px h-64">
+            {move || {
+                frequency_data
+                    .get()
+                    .into_iter()
+                    .map(|value| {
+                        view! {
+                            <div
+                                class="w-1 bg-green-500"
+                                style=format!("height: {}px", value)
+                            ></div>
+                        }
+                    })
+                    .collect_view()
+            }}
+        </div>
+
+        <p>"Volume: " <meter min="0" max="1" value=volume></meter></p>
+
+        <button on:click=move |_| pause() disabled=move || !is_active.get()>
+            "Pause"
+        </button>
+        <button on:click=move |_| resume() disabled=is_active>
+            "Resume"
+        </button>
+
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,70 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{
+    use_web_bluetooth_characteristic, use_web_bluetooth_with_options, UseWebBluetoothCharacteristicReturn,
+    UseWebBluetoothOptions, UseWebBluetoothReturn,
+};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseWebBluetoothReturn {
+        is_supported,
+        device,
+        server,
+        is_connected,
+        error,
+        request_device,
+        disconnect,
+        ..
+    } = use_web_bluetooth_with_options(
+        UseWebBluetoothOptions::default()
+            .accept_all_devices(true)
+            .optional_services(vec!["battery_service".to_string()]),
+    );
+
+    let UseWebBluetoothCharacteristicReturn {
+        characteristic,
+        value,
+        read,
+        start_notifications,
+        ..
+    } = use_web_bluetooth_characteristic(server, "battery_service", "battery_level");
+
+    let _ = watch(
+        move || characteristic.get(),
+        move |characteristic, _, _| {
+            if characteristic.is_some() {
+                read();
+                start_notifications();
+            }
+        },
+        false,
+    );
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Connected: " <BooleanDisplay value=is_connected/></p>
+
+        <button on:click=move |_| request_device()>"Pick device"</button>
+        <button on:click=move |_| disconnect() disabled=move || !is_connected.get()>
+            "Disconnect"
+        </button>
+
+        <p>"Device: " {move || device.get().and_then(|device| device.name())}</p>
+        <p>
+            "Battery level: "
+            {move || value.get().and_then(|value| value.first().copied()).map(|level| format!("{level}%"))}
+        </p>
+
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
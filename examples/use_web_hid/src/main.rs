@@ -0,0 +1,48 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_web_hid, UseWebHidReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseWebHidReturn {
+        is_supported,
+        device,
+        is_open,
+        input_report,
+        error,
+        request_device,
+        close,
+        ..
+    } = use_web_hid();
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Open: " <BooleanDisplay value=is_open/></p>
+
+        <button on:click=move |_| request_device()>"Pick device"</button>
+        <button on:click=move |_| close() disabled=move || !is_open.get()>
+            "Close"
+        </button>
+
+        <p>"Device: " {move || device.get().map(|device| device.product_name())}</p>
+        <p>
+            "Last report: "
+            {move || {
+                input_report
+                    .get()
+                    .map(|report| format!("#{} {:02x?}", report.report_id, report.data))
+            }}
+        </p>
+
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,56 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_web_share, ShareData, UseWebShareReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let (text, set_text) = create_signal("Collection of essential Leptos utilities".to_owned());
+    let (result, set_result) = create_signal(None::<String>);
+
+    let UseWebShareReturn {
+        is_supported,
+        share,
+        ..
+    } = use_web_share();
+
+    let on_click = move |_| {
+        let share = share.clone();
+
+        spawn_local(async move {
+            let result = share(
+                ShareData::default()
+                    .title("leptos-use")
+                    .text(text.get_untracked())
+                    .url(window().location().href().unwrap_or_default()),
+            )
+            .await;
+
+            set_result.set(Some(match result {
+                Ok(()) => "Shared!".to_owned(),
+                Err(err) => err.to_string(),
+            }));
+        });
+    };
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+
+        <input
+            value=text
+            on:input=move |e| set_text.set(event_target_value(&e))
+            type="text"
+        />
+        <button on:click=on_click disabled=move || !is_supported.get()>"Share"</button>
+
+        <p>{result}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,65 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, BooleanDisplay};
+use leptos_use::{use_web_usb, UseWebUsbReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseWebUsbReturn {
+        is_supported,
+        devices,
+        device,
+        is_open,
+        error,
+        request_device,
+        close,
+        ..
+    } = use_web_usb();
+
+    view! {
+        <p>"Supported: " <BooleanDisplay value=is_supported/></p>
+        <p>"Open: " <BooleanDisplay value=is_open/></p>
+
+        <button on:click=move |_| request_device()>"Pick device"</button>
+        <button on:click=move |_| close() disabled=move || !is_open.get()>
+            "Close"
+        </button>
+
+        <p>
+            "Device: "
+            {move || {
+                device
+                    .get()
+                    .map(|device| {
+                        format!(
+                            "{} ({:04x}:{:04x})",
+                            device.product_name().unwrap_or_default(),
+                            device.vendor_id(),
+                            device.product_id(),
+                        )
+                    })
+            }}
+        </p>
+
+        <p>"Paired devices:"</p>
+        <ul>
+            {move || {
+                devices
+                    .get()
+                    .into_iter()
+                    .map(|device| view! { <li>{device.product_name()}</li> })
+                    .collect_view()
+            }}
+        </ul>
+
+        <p>{move || error.get().map(|err| err.to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
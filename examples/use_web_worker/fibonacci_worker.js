@@ -0,0 +1,8 @@
+function fibonacci(n) {
+  return n < 2 ? n : fibonacci(n - 1) + fibonacci(n - 2);
+}
+
+self.onmessage = (event) => {
+  const n = parseInt(event.data, 10);
+  self.postMessage(String(fibonacci(n)));
+};
@@ -0,0 +1,44 @@
+use leptos::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::utils::FromToStringCodec;
+use leptos_use::{use_web_worker, UseWebWorkerReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseWebWorkerReturn {
+        data,
+        error,
+        post_message,
+        terminate,
+        is_terminated,
+        ..
+    } = use_web_worker::<u32, u64, FromToStringCodec>("./fibonacci_worker.js");
+
+    let (n, set_n) = create_signal(35_u32);
+
+    view! {
+        <p>"The fibonacci number is computed in a worker so the page stays responsive."</p>
+        <input
+            type="number"
+            prop:value=move || n.get()
+            on:input=move |event| set_n.set(event_target_value(&event).parse().unwrap_or_default())
+        />
+        <button on:click=move |_| post_message(&n.get_untracked()) disabled=is_terminated>
+            "Compute"
+        </button>
+        <button on:click=move |_| terminate() disabled=is_terminated>
+            "Terminate"
+        </button>
+        <p>"Result: " {move || data.get()}</p>
+        <p>"Error: " {move || error.get().map(|e| e.to_string())}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
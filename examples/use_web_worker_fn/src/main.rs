@@ -0,0 +1,72 @@
+use leptos::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::utils::FromToStringCodec;
+use leptos_use::{is_worker, use_web_worker_fn, web_worker_fn, WebWorkerFnStatus};
+
+fn fibonacci(n: u64) -> u64 {
+    if n < 2 {
+        n
+    } else {
+        fibonacci(n - 1) + fibonacci(n - 2)
+    }
+}
+
+web_worker_fn!(FIBONACCI: fn(u64) -> u64 = fibonacci, FromToStringCodec);
+
+#[component]
+fn Demo() -> impl IntoView {
+    let worker_fn = use_web_worker_fn(FIBONACCI);
+    let status = worker_fn.status;
+
+    let (n, set_n) = create_signal(40_u64);
+    let (result, set_result) = create_signal(None::<String>);
+
+    let on_compute = {
+        let worker_fn = worker_fn.clone();
+
+        move |_| {
+            let worker_fn = worker_fn.clone();
+
+            spawn_local(async move {
+                let text = match worker_fn.execute(n.get_untracked()).await {
+                    Ok(value) => value.to_string(),
+                    Err(err) => err.to_string(),
+                };
+                set_result.set(Some(text));
+            });
+        }
+    };
+
+    let running = move || status.get() == WebWorkerFnStatus::Running;
+
+    view! {
+        <p>"While computing in the worker the page stays responsive."</p>
+        <input
+            type="number"
+            prop:value=move || n.get()
+            on:input=move |event| set_n.set(event_target_value(&event).parse().unwrap_or_default())
+        />
+        <button on:click=on_compute disabled=running>
+            "Compute"
+        </button>
+        <button on:click=move |_| worker_fn.terminate() disabled=move || !running()>
+            "Terminate"
+        </button>
+        <p>"Status: " {move || status.get().to_string()}</p>
+        <p>"Result: " {move || result.get()}</p>
+    }
+}
+
+fn main() {
+    // this binary is also loaded by the worker which has no DOM
+    if is_worker() {
+        return;
+    }
+
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,72 @@
+use leptos::*;
+use leptos_use::core::ConnectionReadyState;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{use_webrtc_data_channel, UseWebRtcDataChannelReturn, WebRtcSignal};
+use std::rc::Rc;
+
+#[component]
+fn Demo() -> impl IntoView {
+    // Both peers live on this page so the signaling just forwards the signals directly.
+    // In a real app they would be sent through a server, e.g. with a websocket.
+    let to_bob = store_value(None::<Rc<dyn Fn(WebRtcSignal)>>);
+    let to_alice = store_value(None::<Rc<dyn Fn(WebRtcSignal)>>);
+
+    let UseWebRtcDataChannelReturn {
+        connection_state,
+        ready_state,
+        message: alice_message,
+        connect,
+        close,
+        receive_signal: alice_receive_signal,
+        send: alice_send,
+        ..
+    } = use_webrtc_data_channel("chat", move |signal| {
+        if let Some(to_bob) = to_bob.get_value() {
+            to_bob(signal);
+        }
+    });
+
+    let UseWebRtcDataChannelReturn {
+        message: bob_message,
+        receive_signal: bob_receive_signal,
+        send: bob_send,
+        ..
+    } = use_webrtc_data_channel("chat", move |signal| {
+        if let Some(to_alice) = to_alice.get_value() {
+            to_alice(signal);
+        }
+    });
+
+    to_bob.set_value(Some(Rc::new(bob_receive_signal)));
+    to_alice.set_value(Some(Rc::new(alice_receive_signal)));
+
+    let connected = move || ready_state.get() == ConnectionReadyState::Open;
+
+    view! {
+        <div>
+            <button on:click=move |_| connect() disabled=connected>"Connect"</button>
+            <button on:click=move |_| close() disabled=move || !connected()>"Close"</button>
+        </div>
+        <p>"connection_state: " {move || format!("{:?}", connection_state.get())}</p>
+        <p>"ready_state: " {move || ready_state.get().to_string()}</p>
+        <div>
+            <button on:click=move |_| alice_send("Hi Bob!") disabled=move || !connected()>
+                "Alice: send"
+            </button>
+            <button on:click=move |_| bob_send("Hi Alice!") disabled=move || !connected()>
+                "Bob: send"
+            </button>
+        </div>
+        <p>"Alice received: " {move || alice_message.get()}</p>
+        <p>"Bob received: " {move || bob_message.get()}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
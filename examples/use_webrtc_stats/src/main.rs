@@ -0,0 +1,78 @@
+use leptos::*;
+use leptos_use::core::ConnectionReadyState;
+use leptos_use::docs::demo_or_body;
+use leptos_use::{
+    use_interval_fn, use_webrtc_data_channel, use_webrtc_stats, UseWebRtcDataChannelReturn,
+    UseWebRtcStatsReturn, WebRtcSignal,
+};
+use std::rc::Rc;
+
+#[component]
+fn Demo() -> impl IntoView {
+    // Two peers on the same page that forward their signals directly to each other
+    let to_bob = store_value(None::<Rc<dyn Fn(WebRtcSignal)>>);
+    let to_alice = store_value(None::<Rc<dyn Fn(WebRtcSignal)>>);
+
+    let UseWebRtcDataChannelReturn {
+        peer_connection,
+        ready_state,
+        connect,
+        close,
+        receive_signal: alice_receive_signal,
+        send_bytes,
+        ..
+    } = use_webrtc_data_channel("stats", move |signal| {
+        if let Some(to_bob) = to_bob.get_value() {
+            to_bob(signal);
+        }
+    });
+
+    let UseWebRtcDataChannelReturn {
+        receive_signal: bob_receive_signal,
+        ..
+    } = use_webrtc_data_channel("stats", move |signal| {
+        if let Some(to_alice) = to_alice.get_value() {
+            to_alice(signal);
+        }
+    });
+
+    to_bob.set_value(Some(Rc::new(bob_receive_signal)));
+    to_alice.set_value(Some(Rc::new(alice_receive_signal)));
+
+    // send some data to have something to measure
+    let _ = use_interval_fn(move || send_bytes(vec![0; 16 * 1024]), 100);
+
+    let UseWebRtcStatsReturn {
+        outbound_bitrate,
+        round_trip_time,
+        ..
+    } = use_webrtc_stats(peer_connection, 1000);
+
+    let connected = move || ready_state.get() == ConnectionReadyState::Open;
+
+    view! {
+        <div>
+            <button on:click=move |_| connect() disabled=connected>"Connect"</button>
+            <button on:click=move |_| close() disabled=move || !connected()>"Close"</button>
+        </div>
+        <p>
+            "Outbound bitrate: "
+            {move || {
+                outbound_bitrate.get().map(|b| format!("{:.0} kbit/s", b / 1000.0))
+            }}
+        </p>
+        <p>
+            "Round trip time: "
+            {move || round_trip_time.get().map(|rtt| format!("{:.1} ms", rtt * 1000.0))}
+        </p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -0,0 +1,46 @@
+use leptos::*;
+use leptos_use::docs::demo_or_body;
+use leptos_use::utils::FromToStringCodec;
+use leptos_use::{use_window_message, UseWindowMessageReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseWindowMessageReturn {
+        data,
+        origin,
+        post_to,
+        ..
+    } = use_window_message::<String, FromToStringCodec>();
+
+    let (input_value, set_input_value) = create_signal("Hello".to_string());
+
+    // Post to this same window to keep the demo self-contained.
+    // Usually the target would be an iframe's `content_window()` or `window().parent()`.
+    let on_submit = move |ev: web_sys::SubmitEvent| {
+        ev.prevent_default();
+        let own_origin = window().location().origin().unwrap_or_default();
+        post_to(&window(), &input_value.get_untracked(), &own_origin);
+    };
+
+    view! {
+        <form on:submit=on_submit>
+            <input
+                value=input_value
+                on:input=move |event| set_input_value.set(event_target_value(&event))
+                type="text"
+            />
+            <button type="submit">"Post Message"</button>
+        </form>
+        <p>"Received: " {move || data.get()}</p>
+        <p>"From origin: " {move || origin.get()}</p>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
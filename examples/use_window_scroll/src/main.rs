@@ -1,10 +1,21 @@
 use leptos::*;
-use leptos_use::docs::{demo_or_body, Note};
-use leptos_use::use_window_scroll;
+use leptos_use::docs::{demo_or_body, BooleanDisplay, Note};
+use leptos_use::{
+    use_window_scroll_with_options, ScrollBehavior, UseWindowScrollOptions, UseWindowScrollReturn,
+};
 
 #[component]
 fn Demo() -> impl IntoView {
-    let (x, y) = use_window_scroll();
+    let UseWindowScrollReturn {
+        x,
+        y,
+        set_y,
+        is_scrolling,
+        directions,
+        ..
+    } = use_window_scroll_with_options(
+        UseWindowScrollOptions::default().behavior(ScrollBehavior::Smooth),
+    );
 
     let div = document().create_element("div").unwrap();
     div.set_attribute(
@@ -20,6 +31,11 @@ fn Demo() -> impl IntoView {
         <div class="float m-5 area shadow-lg">
             <Note class="mb-2">Scroll value</Note>
             <div>x: {move || format!("{:.1}", x())} <br/> y: {move || format!("{:.1}", y())}</div>
+            <div class="mt-2">
+                "Scrolling: " <BooleanDisplay value=is_scrolling/> <br/> "Scrolling down: "
+                <BooleanDisplay value=Signal::derive(move || directions.get().bottom)/>
+            </div>
+            <button on:click=move |_| set_y(0.0)>"Back to top"</button>
         </div>
     }
 }
@@ -0,0 +1,28 @@
+use leptos::*;
+use leptos_use::docs::{demo_or_body, Note};
+use leptos_use::{use_window_size, UseWindowSizeReturn};
+
+#[component]
+fn Demo() -> impl IntoView {
+    let UseWindowSizeReturn {
+        width,
+        height,
+        outer_width,
+        outer_height,
+    } = use_window_size();
+
+    view! {
+        <Note class="mb-2">"Resize the window to see the values change"</Note>
+        <div>"Inner: " {width} " x " {height}</div>
+        <div>"Outer: " {outer_width} " x " {outer_height}</div>
+    }
+}
+
+fn main() {
+    _ = console_log::init_with_level(log::Level::Debug);
+    console_error_panic_hook::set_once();
+
+    mount_to(demo_or_body(), || {
+        view! { <Demo/> }
+    })
+}
@@ -366,3 +366,38 @@ impl_from_signal_html_element!(Signal<Option<HtmlElement<HtmlEl>>>, web_sys::Ele
 impl_from_signal_html_element!(ReadSignal<Option<HtmlElement<HtmlEl>>>, web_sys::Element);
 impl_from_signal_html_element!(RwSignal<Option<HtmlElement<HtmlEl>>>, web_sys::Element);
 impl_from_signal_html_element!(Memo<Option<HtmlElement<HtmlEl>>>, web_sys::Element);
+
+// From NodeRef / leptos::html::HtmlElement of media elements ////////////////////
+
+macro_rules! impl_from_media_element {
+    ($ty:ty) => {
+        impl<R> From<NodeRef<R>> for ElementMaybeSignal<$ty, $ty>
+        where
+            R: ElementDescriptor + Deref + Clone + 'static,
+            R::Target: AsRef<$ty>,
+        {
+            fn from(node_ref: NodeRef<R>) -> Self {
+                Self::Dynamic(Signal::derive(move || {
+                    node_ref.get().map(move |el| {
+                        let el: &$ty = el.deref().as_ref();
+                        el.clone()
+                    })
+                }))
+            }
+        }
+
+        impl<R> From<HtmlElement<R>> for ElementMaybeSignal<$ty, $ty>
+        where
+            R: ElementDescriptor + Deref,
+            R::Target: AsRef<$ty>,
+        {
+            fn from(value: HtmlElement<R>) -> Self {
+                let el: &$ty = value.deref().as_ref();
+                Self::Static(Some(el.clone()))
+            }
+        }
+    };
+}
+
+impl_from_media_element!(web_sys::HtmlMediaElement);
+impl_from_media_element!(web_sys::HtmlVideoElement);
@@ -6,6 +6,7 @@ mod elements_maybe_signal;
 mod maybe_rw_signal;
 mod pointer_type;
 mod position;
+mod retry_delay;
 mod size;
 mod ssr_safe_method;
 mod storage;
@@ -20,6 +21,7 @@ pub use elements_maybe_signal::*;
 pub use maybe_rw_signal::*;
 pub use pointer_type::*;
 pub use position::*;
+pub use retry_delay::*;
 pub use size::*;
 pub(crate) use ssr_safe_method::*;
 pub use storage::*;
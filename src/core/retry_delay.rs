@@ -0,0 +1,82 @@
+/// Strategy to compute how long to wait before retrying a failed operation
+/// like a request or a connection.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RetryDelay {
+    /// Always wait the same number of milliseconds.
+    Fixed(u64),
+
+    /// Wait `initial` milliseconds before the first retry and multiply the delay by `factor`
+    /// for every following retry. The delay never exceeds `max` milliseconds.
+    Exponential { initial: u64, factor: f64, max: u64 },
+}
+
+impl RetryDelay {
+    /// Exponential backoff starting at `initial` ms that doubles with every retry
+    /// and is capped at 30 seconds.
+    pub fn exponential(initial: u64) -> Self {
+        Self::Exponential {
+            initial,
+            factor: 2.0,
+            max: 30_000,
+        }
+    }
+
+    /// Returns the delay in ms before the retry number `retry`. The first retry is `1`.
+    pub fn delay(&self, retry: u32) -> u64 {
+        match *self {
+            Self::Fixed(delay) => delay,
+            Self::Exponential {
+                initial,
+                factor,
+                max,
+            } => {
+                let exponent = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+                let delay = initial as f64 * factor.powi(exponent);
+
+                if delay.is_finite() {
+                    (delay as u64).min(max)
+                } else {
+                    max
+                }
+            }
+        }
+    }
+}
+
+impl Default for RetryDelay {
+    fn default() -> Self {
+        Self::Fixed(1000)
+    }
+}
+
+impl From<u64> for RetryDelay {
+    fn from(delay: u64) -> Self {
+        Self::Fixed(delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_delay() {
+        let delay = RetryDelay::Fixed(500);
+        assert_eq!(delay.delay(1), 500);
+        assert_eq!(delay.delay(10), 500);
+    }
+
+    #[test]
+    fn test_exponential_delay() {
+        let delay = RetryDelay::Exponential {
+            initial: 100,
+            factor: 2.0,
+            max: 1000,
+        };
+        assert_eq!(delay.delay(1), 100);
+        assert_eq!(delay.delay(2), 200);
+        assert_eq!(delay.delay(4), 800);
+        assert_eq!(delay.delay(5), 1000);
+        assert_eq!(delay.delay(u32::MAX), 1000);
+    }
+}
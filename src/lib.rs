@@ -10,15 +10,46 @@ pub mod math;
 pub mod storage;
 pub mod utils;
 
-// #[cfg(web_sys_unstable_apis)]
-// mod use_webtransport;
-// #[cfg(web_sys_unstable_apis)]
-// pub use use_webtransport::*;
-
 #[cfg(web_sys_unstable_apis)]
 mod use_clipboard;
 #[cfg(web_sys_unstable_apis)]
+mod use_clipboard_items;
+#[cfg(web_sys_unstable_apis)]
+mod use_file_system_access;
+#[cfg(web_sys_unstable_apis)]
+mod use_image_capture;
+#[cfg(web_sys_unstable_apis)]
+mod use_media_session;
+#[cfg(web_sys_unstable_apis)]
+mod use_serial;
+#[cfg(web_sys_unstable_apis)]
+mod use_web_bluetooth;
+#[cfg(web_sys_unstable_apis)]
+mod use_web_hid;
+#[cfg(web_sys_unstable_apis)]
+mod use_web_usb;
+#[cfg(web_sys_unstable_apis)]
+mod use_webtransport;
+#[cfg(web_sys_unstable_apis)]
 pub use use_clipboard::*;
+#[cfg(web_sys_unstable_apis)]
+pub use use_clipboard_items::*;
+#[cfg(web_sys_unstable_apis)]
+pub use use_file_system_access::*;
+#[cfg(web_sys_unstable_apis)]
+pub use use_image_capture::*;
+#[cfg(web_sys_unstable_apis)]
+pub use use_media_session::*;
+#[cfg(web_sys_unstable_apis)]
+pub use use_serial::*;
+#[cfg(web_sys_unstable_apis)]
+pub use use_web_bluetooth::*;
+#[cfg(web_sys_unstable_apis)]
+pub use use_web_hid::*;
+#[cfg(web_sys_unstable_apis)]
+pub use use_web_usb::*;
+#[cfg(web_sys_unstable_apis)]
+pub use use_webtransport::*;
 
 mod is_err;
 mod is_none;
@@ -29,17 +60,27 @@ mod signal_debounced;
 mod signal_throttled;
 mod sync_signal;
 mod use_active_element;
+mod use_audio_output;
+mod use_barcode_detector;
+mod use_base64;
+mod use_battery;
 mod use_breakpoints;
 mod use_broadcast_channel;
+mod use_browser_location;
 mod use_color_mode;
 mod use_cookie;
 mod use_css_var;
 mod use_cycle_list;
+mod use_dark;
 mod use_debounce_fn;
+mod use_device_motion;
 mod use_device_orientation;
 mod use_device_pixel_ratio;
+mod use_devices_list;
 mod use_display_media;
 mod use_document;
+mod use_document_picture_in_picture;
+mod use_document_title;
 mod use_document_visibility;
 mod use_draggable;
 mod use_drop_zone;
@@ -47,38 +88,78 @@ mod use_element_bounding;
 mod use_element_hover;
 mod use_element_size;
 mod use_element_visibility;
+mod use_event_bus;
 mod use_event_listener;
 mod use_event_source;
+mod use_eye_dropper;
 mod use_favicon;
+mod use_fetch;
+mod use_file_dialog;
+mod use_fullscreen;
+mod use_gamepad;
 mod use_geolocation;
 mod use_idle;
+mod use_idle_detection;
+mod use_image;
 mod use_infinite_scroll;
 mod use_intersection_observer;
 mod use_interval;
 mod use_interval_fn;
 mod use_intl_number_format;
+mod use_location_hash;
+mod use_media_controls;
 mod use_media_query;
+mod use_media_recorder;
+mod use_midi;
 mod use_mouse;
 mod use_mouse_in_element;
 mod use_mutation_observer;
+mod use_network;
+mod use_object_url;
+mod use_online;
+mod use_page_leave;
 mod use_permission;
+mod use_picture_in_picture;
 mod use_preferred_contrast;
 mod use_preferred_dark;
+mod use_preferred_languages;
+mod use_preferred_reduced_data;
+mod use_preferred_reduced_motion;
+mod use_presentation;
+mod use_push_subscription;
 mod use_raf_fn;
+mod use_remote_playback;
 mod use_resize_observer;
+mod use_screen_details;
+mod use_screen_orientation;
 mod use_scroll;
 mod use_service_worker;
+mod use_shared_worker;
 mod use_sorted;
+mod use_speech_recognition;
+mod use_speech_synthesis;
 mod use_supported;
 mod use_throttle_fn;
 mod use_timeout_fn;
 mod use_timestamp;
 mod use_to_string;
+mod use_url_search_params;
+mod use_user_media;
+mod use_vibrate;
+mod use_wake_lock;
+mod use_web_audio_analyser;
 mod use_web_notification;
+mod use_web_share;
+mod use_web_worker;
+mod use_web_worker_fn;
+mod use_webrtc_data_channel;
+mod use_webrtc_stats;
 mod use_websocket;
 mod use_window;
 mod use_window_focus;
+mod use_window_message;
 mod use_window_scroll;
+mod use_window_size;
 mod watch_debounced;
 mod watch_pausable;
 mod watch_throttled;
@@ -94,17 +175,27 @@ pub use signal_debounced::*;
 pub use signal_throttled::*;
 pub use sync_signal::*;
 pub use use_active_element::*;
+pub use use_audio_output::*;
+pub use use_barcode_detector::*;
+pub use use_base64::*;
+pub use use_battery::*;
 pub use use_breakpoints::*;
 pub use use_broadcast_channel::*;
+pub use use_browser_location::*;
 pub use use_color_mode::*;
 pub use use_cookie::*;
 pub use use_css_var::*;
 pub use use_cycle_list::*;
+pub use use_dark::*;
 pub use use_debounce_fn::*;
+pub use use_device_motion::*;
 pub use use_device_orientation::*;
 pub use use_device_pixel_ratio::*;
+pub use use_devices_list::*;
 pub use use_display_media::*;
 pub use use_document::*;
+pub use use_document_picture_in_picture::*;
+pub use use_document_title::*;
 pub use use_document_visibility::*;
 pub use use_draggable::*;
 pub use use_drop_zone::*;
@@ -112,38 +203,78 @@ pub use use_element_bounding::*;
 pub use use_element_hover::*;
 pub use use_element_size::*;
 pub use use_element_visibility::*;
+pub use use_event_bus::*;
 pub use use_event_listener::*;
 pub use use_event_source::*;
+pub use use_eye_dropper::*;
 pub use use_favicon::*;
+pub use use_fetch::*;
+pub use use_file_dialog::*;
+pub use use_fullscreen::*;
+pub use use_gamepad::*;
 pub use use_geolocation::*;
 pub use use_idle::*;
+pub use use_idle_detection::*;
+pub use use_image::*;
 pub use use_infinite_scroll::*;
 pub use use_intersection_observer::*;
 pub use use_interval::*;
 pub use use_interval_fn::*;
 pub use use_intl_number_format::*;
+pub use use_location_hash::*;
+pub use use_media_controls::*;
 pub use use_media_query::*;
+pub use use_media_recorder::*;
+pub use use_midi::*;
 pub use use_mouse::*;
 pub use use_mouse_in_element::*;
 pub use use_mutation_observer::*;
+pub use use_network::*;
+pub use use_object_url::*;
+pub use use_online::*;
+pub use use_page_leave::*;
 pub use use_permission::*;
+pub use use_picture_in_picture::*;
 pub use use_preferred_contrast::*;
 pub use use_preferred_dark::*;
+pub use use_preferred_languages::*;
+pub use use_preferred_reduced_data::*;
+pub use use_preferred_reduced_motion::*;
+pub use use_presentation::*;
+pub use use_push_subscription::*;
 pub use use_raf_fn::*;
+pub use use_remote_playback::*;
 pub use use_resize_observer::*;
+pub use use_screen_details::*;
+pub use use_screen_orientation::*;
 pub use use_scroll::*;
 pub use use_service_worker::*;
+pub use use_shared_worker::*;
 pub use use_sorted::*;
+pub use use_speech_recognition::*;
+pub use use_speech_synthesis::*;
 pub use use_supported::*;
 pub use use_throttle_fn::*;
 pub use use_timeout_fn::*;
 pub use use_timestamp::*;
 pub use use_to_string::*;
+pub use use_url_search_params::*;
+pub use use_user_media::*;
+pub use use_vibrate::*;
+pub use use_wake_lock::*;
+pub use use_web_audio_analyser::*;
 pub use use_web_notification::*;
+pub use use_web_share::*;
+pub use use_web_worker::*;
+pub use use_web_worker_fn::*;
+pub use use_webrtc_data_channel::*;
+pub use use_webrtc_stats::*;
 pub use use_websocket::*;
 pub use use_window::*;
 pub use use_window_focus::*;
+pub use use_window_message::*;
 pub use use_window_scroll::*;
+pub use use_window_size::*;
 pub use watch_debounced::*;
 pub use watch_pausable::*;
 pub use watch_throttled::*;
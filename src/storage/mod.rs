@@ -1,8 +1,12 @@
+mod use_indexed_db;
 mod use_local_storage;
 mod use_session_storage;
 mod use_storage;
+mod use_storage_async;
 
 pub use crate::core::StorageType;
+pub use use_indexed_db::*;
 pub use use_local_storage::*;
 pub use use_session_storage::*;
 pub use use_storage::*;
+pub use use_storage_async::*;
@@ -0,0 +1,481 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use super::{
+    use_storage_async_with_options, StorageAsync, UseStorageAsyncOptions, UseStorageAsyncReturn,
+};
+use crate::js_fut;
+use crate::utils::StringCodec;
+use cfg_if::cfg_if;
+use leptos::*;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use thiserror::Error;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Reactive value that is persisted in an [IndexedDB](https://developer.mozilla.org/en-US/docs/Web/API/IndexedDB_API)
+/// object store.
+///
+/// Unlike `localStorage` IndexedDB isn't limited to a few MB so it is well suited for apps that
+/// should work offline.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_indexed_db)
+///
+/// ## Usage
+///
+/// The database and the object store are created if they don't exist yet. The value is loaded
+/// asynchronously so until then `data` is the initial value and `is_loading` is `true`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::storage::{use_indexed_db, UseStorageAsyncReturn};
+/// # use leptos_use::utils::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseStorageAsyncReturn {
+///     data,
+///     set_data,
+///     is_loading,
+///     ..
+/// } = use_indexed_db::<String, FromToStringCodec>("my-app", "drafts", "current");
+///
+/// view! {
+///     <Show when=move || !is_loading.get() fallback=|| "Loading...">
+///         <textarea prop:value=data on:input=move |e| set_data.set(event_target_value(&e)) />
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// Any [`StringCodec`] can be used, for example the `JsonCodec` to store structs. See
+/// [`use_storage`](crate::storage::use_storage) for the available codecs.
+///
+/// When the value is written or removed, the other calls with the same database, store and key
+/// are refreshed, also in other tabs.
+///
+/// Errors of the database or of the codec are passed to the `on_error` callback of the options.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::storage::{use_indexed_db_with_options, UseStorageAsyncOptions};
+/// # use leptos_use::utils::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let counter = use_indexed_db_with_options::<u32, FromToStringCodec>(
+///     "my-app",
+///     "counters",
+///     "visits",
+///     UseStorageAsyncOptions::default()
+///         .initial_value(1)
+///         .on_error(|err| logging::error!("{err}")),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the returned signals will just read/manipulate the `initial_value` without persistence.
+/// `is_loading` and `is_writing` are always `false`.
+pub fn use_indexed_db<T, C>(
+    db_name: &str,
+    store_name: &str,
+    key: impl AsRef<str>,
+) -> UseStorageAsyncReturn<T, impl Fn() + Clone, impl Fn() + Clone>
+where
+    T: Clone + Default + PartialEq,
+    C: StringCodec<T> + Default,
+{
+    use_indexed_db_with_options::<T, C>(db_name, store_name, key, UseStorageAsyncOptions::default())
+}
+
+/// Version of [`use_indexed_db`] that accepts [`UseStorageAsyncOptions`].
+pub fn use_indexed_db_with_options<T, C>(
+    db_name: &str,
+    store_name: &str,
+    key: impl AsRef<str>,
+    options: UseStorageAsyncOptions<T, C, IndexedDbStorage>,
+) -> UseStorageAsyncReturn<T, impl Fn() + Clone, impl Fn() + Clone>
+where
+    T: Clone + PartialEq,
+    C: StringCodec<T> + Default,
+{
+    let storage = IndexedDbStorage::new(db_name, store_name);
+    let key = key.as_ref().to_owned();
+
+    let storage_return = use_storage_async_with_options(storage.clone(), key.clone(), options);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use crate::utils::FromToStringCodec;
+        use crate::{use_broadcast_channel, UseBroadcastChannelReturn};
+
+        let UseBroadcastChannelReturn { message, .. } =
+            use_broadcast_channel::<String, FromToStringCodec>(&storage.channel_name());
+
+        let refresh = storage_return.refresh.clone();
+        let id = storage.id.clone();
+
+        let _ = watch(
+            move || message.get(),
+            move |message: &Option<String>, _, _| {
+                // Ignore own changes. Reloading them could overwrite newer local changes.
+                let changed_by_other = message
+                    .as_deref()
+                    .and_then(parse_change_message)
+                    .is_some_and(|(sender, changed_key)| sender != id && changed_key == key);
+
+                if changed_by_other {
+                    refresh();
+                }
+            },
+            false,
+        );
+    }
+
+    storage_return
+}
+
+/// [`StorageAsync`] backend that stores the values in an IndexedDB object store.
+///
+/// Every change is announced on a `BroadcastChannel` so [`use_indexed_db`] can refresh the
+/// value in all tabs.
+#[derive(Clone, Debug)]
+pub struct IndexedDbStorage {
+    db_name: String,
+    store_name: String,
+    // Identifies the changes of this storage on the `BroadcastChannel`. Unique across tabs.
+    id: String,
+}
+
+static NEXT_STORAGE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A random nonce combined with a counter. The counter alone would repeat in every tab.
+fn storage_id() -> String {
+    cfg_if! { if #[cfg(feature = "ssr")] {
+        let nonce = 0_u32;
+    } else {
+        let nonce = (js_sys::Math::random() * u32::MAX as f64) as u32;
+    }}
+
+    format!(
+        "{nonce:08x}-{}",
+        NEXT_STORAGE_ID.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+impl IndexedDbStorage {
+    /// Uses the object store `store_name` of the database `db_name`. Both are created when
+    /// they're accessed for the first time.
+    pub fn new(db_name: impl Into<String>, store_name: impl Into<String>) -> Self {
+        Self {
+            db_name: db_name.into(),
+            store_name: store_name.into(),
+            id: storage_id(),
+        }
+    }
+
+    fn channel_name(&self) -> String {
+        format!("leptos-use:indexed-db:{}:{}", self.db_name, self.store_name)
+    }
+
+    /// Opens the database. If the object store doesn't exist yet the database is upgraded to a
+    /// new version that contains it.
+    async fn open(&self) -> Result<Connection, IndexedDbError> {
+        let factory = window()
+            .indexed_db()
+            .ok()
+            .flatten()
+            .ok_or(IndexedDbError::NotAvailable)?;
+
+        let connection = self.open_version(&factory, None).await?;
+
+        if connection
+            .db
+            .object_store_names()
+            .contains(&self.store_name)
+        {
+            return Ok(connection);
+        }
+
+        let version = connection.db.version() as u32 + 1;
+        drop(connection);
+
+        self.open_version(&factory, Some(version)).await
+    }
+
+    async fn open_version(
+        &self,
+        factory: &web_sys::IdbFactory,
+        version: Option<u32>,
+    ) -> Result<Connection, IndexedDbError> {
+        let request = match version {
+            Some(version) => factory.open_with_u32(&self.db_name, version),
+            None => factory.open(&self.db_name),
+        }
+        .map_err(IndexedDbError::OpenFailed)?;
+
+        let on_upgrade_needed = {
+            let request = request.clone();
+            let store_name = self.store_name.clone();
+
+            Closure::<dyn Fn()>::new(move || {
+                if let Ok(db) = request.result() {
+                    let db = db.unchecked_into::<web_sys::IdbDatabase>();
+
+                    if !db.object_store_names().contains(&store_name) {
+                        let _ = db.create_object_store(&store_name);
+                    }
+                }
+            })
+        };
+        request.set_onupgradeneeded(Some(on_upgrade_needed.as_ref().unchecked_ref()));
+
+        let blocked = Rc::new(Cell::new(false));
+        let result = await_request(&request, Some(Rc::clone(&blocked))).await;
+
+        request.set_onupgradeneeded(None);
+
+        if blocked.get() {
+            // The database is still opened once the other connections are closed
+            let close_late = {
+                let request = request.clone();
+
+                Closure::once_into_js(move || {
+                    if let Ok(db) = request.result() {
+                        db.unchecked_into::<web_sys::IdbDatabase>().close();
+                    }
+                })
+            };
+            request.set_onsuccess(Some(close_late.unchecked_ref()));
+            request.set_onerror(Some(close_late.unchecked_ref()));
+        }
+
+        let db = result.map_err(|err| {
+            if blocked.get() {
+                IndexedDbError::Blocked
+            } else {
+                IndexedDbError::OpenFailed(err)
+            }
+        })?;
+
+        Ok(Connection::new(db.unchecked_into()))
+    }
+
+    async fn object_store(
+        &self,
+        mode: web_sys::IdbTransactionMode,
+    ) -> Result<(Connection, web_sys::IdbObjectStore), IndexedDbError> {
+        let connection = self.open().await?;
+
+        let store = connection
+            .db
+            .transaction_with_str_and_mode(&self.store_name, mode)
+            .and_then(|transaction| transaction.object_store(&self.store_name))
+            .map_err(IndexedDbError::TransactionFailed)?;
+
+        Ok((connection, store))
+    }
+
+    fn notify_changed(&self, key: &str) {
+        if let Ok(channel) = web_sys::BroadcastChannel::new(&self.channel_name()) {
+            let _ = channel.post_message(&JsValue::from_str(&format!("{}:{}", self.id, key)));
+            channel.close();
+        }
+    }
+}
+
+impl StorageAsync for IndexedDbStorage {
+    type Error = IndexedDbError;
+
+    async fn get_item(&self, key: &str) -> Result<Option<String>, Self::Error> {
+        let (connection, store) = self
+            .object_store(web_sys::IdbTransactionMode::Readonly)
+            .await?;
+
+        let result = match store.get(&JsValue::from_str(key)) {
+            Ok(request) => await_request(&request, None).await,
+            Err(err) => Err(err),
+        };
+        drop(connection);
+
+        result
+            .map(|value| value.as_string())
+            .map_err(IndexedDbError::RequestFailed)
+    }
+
+    async fn set_item(&self, key: &str, value: &str) -> Result<(), Self::Error> {
+        let (connection, store) = self
+            .object_store(web_sys::IdbTransactionMode::Readwrite)
+            .await?;
+
+        let result = match store.put_with_key(&JsValue::from_str(value), &JsValue::from_str(key)) {
+            Ok(request) => await_request(&request, None).await,
+            Err(err) => Err(err),
+        };
+        drop(connection);
+
+        result.map_err(IndexedDbError::RequestFailed)?;
+        self.notify_changed(key);
+
+        Ok(())
+    }
+
+    async fn remove_item(&self, key: &str) -> Result<(), Self::Error> {
+        let (connection, store) = self
+            .object_store(web_sys::IdbTransactionMode::Readwrite)
+            .await?;
+
+        let result = match store.delete(&JsValue::from_str(key)) {
+            Ok(request) => await_request(&request, None).await,
+            Err(err) => Err(err),
+        };
+        drop(connection);
+
+        result.map_err(IndexedDbError::RequestFailed)?;
+        self.notify_changed(key);
+
+        Ok(())
+    }
+}
+
+/// An open database connection. It is closed when dropped or when another connection wants
+/// to upgrade the database.
+struct Connection {
+    db: web_sys::IdbDatabase,
+    _on_version_change: Closure<dyn Fn()>,
+}
+
+impl Connection {
+    fn new(db: web_sys::IdbDatabase) -> Self {
+        let on_version_change = {
+            let db = db.clone();
+            Closure::<dyn Fn()>::new(move || db.close())
+        };
+        db.set_onversionchange(Some(on_version_change.as_ref().unchecked_ref()));
+
+        Self {
+            db,
+            _on_version_change: on_version_change,
+        }
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.db.set_onversionchange(None);
+        self.db.close();
+    }
+}
+
+/// Waits for the `success` or `error` event of an IndexedDB request.
+///
+/// If `blocked` is given the request is an open request that fails as well when it is blocked
+/// by other connections. In that case `blocked` is set to `true`.
+async fn await_request(
+    request: &web_sys::IdbRequest,
+    blocked: Option<Rc<Cell<bool>>>,
+) -> Result<JsValue, JsValue> {
+    let mut handlers = None;
+
+    let promise = js_sys::Promise::new(&mut |resolve, reject| {
+        let on_success = {
+            let request = request.clone();
+
+            Closure::<dyn Fn()>::new(move || {
+                let _ = resolve.call1(
+                    &JsValue::NULL,
+                    &request.result().unwrap_or(JsValue::UNDEFINED),
+                );
+            })
+        };
+
+        let on_error = {
+            let request = request.clone();
+            let reject = reject.clone();
+
+            Closure::<dyn Fn()>::new(move || {
+                let error = request
+                    .error()
+                    .ok()
+                    .flatten()
+                    .map(JsValue::from)
+                    .unwrap_or(JsValue::UNDEFINED);
+
+                let _ = reject.call1(&JsValue::NULL, &error);
+            })
+        };
+
+        let on_blocked = blocked.clone().map(|blocked| {
+            Closure::<dyn Fn()>::new(move || {
+                blocked.set(true);
+                let _ = reject.call0(&JsValue::NULL);
+            })
+        });
+
+        request.set_onsuccess(Some(on_success.as_ref().unchecked_ref()));
+        request.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+        if let Some(on_blocked) = &on_blocked {
+            request
+                .unchecked_ref::<web_sys::IdbOpenDbRequest>()
+                .set_onblocked(Some(on_blocked.as_ref().unchecked_ref()));
+        }
+
+        handlers = Some((on_success, on_error, on_blocked));
+    });
+
+    let result = js_fut!(promise).await;
+
+    // The request has settled so the handlers can be removed and their closures dropped
+    request.set_onsuccess(None);
+    request.set_onerror(None);
+    if blocked.is_some() {
+        request
+            .unchecked_ref::<web_sys::IdbOpenDbRequest>()
+            .set_onblocked(None);
+    }
+    drop(handlers);
+
+    result
+}
+
+/// Splits a message of the `BroadcastChannel` into the id of the sending storage and the key.
+fn parse_change_message(message: &str) -> Option<(&str, &str)> {
+    message.split_once(':')
+}
+
+/// Errors of [`IndexedDbStorage`].
+#[derive(Error, Debug)]
+pub enum IndexedDbError {
+    #[error("IndexedDB is not available")]
+    NotAvailable,
+    #[error("failed to open database")]
+    OpenFailed(JsValue),
+    #[error("opening the database is blocked by other connections")]
+    Blocked,
+    #[error("failed to start transaction")]
+    TransactionFailed(JsValue),
+    #[error("failed to access item")]
+    RequestFailed(JsValue),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_change_message() {
+        assert_eq!(
+            parse_change_message("0a1b2c3d-3:draft"),
+            Some(("0a1b2c3d-3", "draft"))
+        );
+        assert_eq!(parse_change_message("0-3:a:b"), Some(("0-3", "a:b")));
+        assert_eq!(parse_change_message("draft"), None);
+    }
+}
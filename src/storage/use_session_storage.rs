@@ -1,4 +1,5 @@
 use super::{use_storage_with_options, StorageType, UseStorageOptions};
+use crate::core::MaybeRwSignal;
 use crate::utils::StringCodec;
 use leptos::signal_prelude::*;
 
@@ -10,6 +11,9 @@ use leptos::signal_prelude::*;
 ///
 /// ## Usage
 /// See [`use_storage`] for more details on how to use.
+///
+/// Use [`use_session_storage_with_initial_value`] if the value should be something else than
+/// `T::default()` while the key is not set.
 pub fn use_session_storage<T, C>(
     key: impl AsRef<str>,
 ) -> (Signal<T>, WriteSignal<T>, impl Fn() + Clone)
@@ -35,3 +39,35 @@ where
 {
     use_storage_with_options(StorageType::Session, key, options)
 }
+
+/// Shortcut for [`use_session_storage_with_options`] that only sets the option `initial_value`.
+/// It is used while the key is not set and after the value has been removed.
+///
+/// A stored value replaces the initial value completely. To fill in fields that are missing in
+/// a stored struct see [`use_storage`](super::use_storage).
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::storage::use_session_storage_with_initial_value;
+/// # use leptos_use::utils::FromToStringCodec;
+/// #
+/// # pub fn Demo() -> impl IntoView {
+/// let (volume, set_volume, _) =
+///     use_session_storage_with_initial_value::<u8, FromToStringCodec>("volume", 80);
+/// #    view! { }
+/// # }
+/// ```
+pub fn use_session_storage_with_initial_value<T, C>(
+    key: impl AsRef<str>,
+    initial_value: impl Into<MaybeRwSignal<T>>,
+) -> (Signal<T>, WriteSignal<T>, impl Fn() + Clone)
+where
+    T: Clone + Default + PartialEq,
+    C: StringCodec<T> + Default,
+{
+    use_storage_with_options(
+        StorageType::Session,
+        key,
+        UseStorageOptions::<T, C>::default().initial_value(initial_value),
+    )
+}
@@ -22,7 +22,7 @@ const INTERNAL_STORAGE_EVENT: &str = "leptos-use-storage";
 ///
 /// Pass a [`StorageType`] to determine the kind of key-value browser storage to use.
 /// The specified key is where data is stored. All values are stored as UTF-16 strings which
-/// is then encoded and decoded via the given [`Codec`]. This value is synced with other calls using
+/// is then encoded and decoded via the given [`StringCodec`]. This value is synced with other calls using
 /// the same key on the smae page and across tabs for local storage.
 /// See [`UseStorageOptions`] to see how behaviour can be further customised.
 ///
@@ -30,6 +30,8 @@ const INTERNAL_STORAGE_EVENT: &str = "leptos-use-storage";
 ///
 /// > To use the [`JsonCodec`], you will need to add the `"serde"` feature to your project's `Cargo.toml`.
 /// > To use [`ProstCodec`], add the feature `"prost"`.
+/// > To store binary data with [`Base64Codec`](crate::utils::Base64Codec) wrapping a
+/// > [`BinCodec`](crate::utils::BinCodec), add the feature `"base64"`.
 ///
 /// ## Example
 ///
@@ -68,7 +70,8 @@ const INTERNAL_STORAGE_EVENT: &str = "leptos-use-storage";
 /// }
 ///
 /// // Default can be used to implement initial or deleted values.
-/// // You can also use a signal via UseStorageOptions::default_value`
+/// // You can also use a signal via `UseStorageOptions::initial_value`
+/// // or the shortcuts `use_local_storage_with_initial_value` / `use_session_storage_with_initial_value`
 /// impl Default for MyState {
 ///     fn default() -> Self {
 ///         Self {
@@ -79,6 +82,11 @@ const INTERNAL_STORAGE_EVENT: &str = "leptos-use-storage";
 /// }
 /// ```
 ///
+/// With the [`JsonCodec`] a stored value that misses some fields of the struct, for example
+/// because a field was added in a newer version of your app, can't be decoded and `on_error` is
+/// called. Add `#[serde(default)]` to the struct to fill in the missing fields from its `Default`
+/// implementation and keep the rest of the stored value.
+///
 /// ## Create Your Own Custom Codec
 ///
 /// All you need to do is to implement the [`StringCodec`] trait together with `Default` and `Clone`.
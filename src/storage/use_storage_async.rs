@@ -0,0 +1,385 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::MaybeRwSignal;
+use crate::utils::StringCodec;
+use leptos::*;
+use std::future::Future;
+use std::rc::Rc;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// An asynchronous key-value store that [`use_storage_async`] can persist to like IndexedDB, the
+/// Cache Storage or a remote HTTP endpoint.
+///
+/// It is implemented for [`web_sys::Storage`] so `localStorage` and `sessionStorage` can be used
+/// as well.
+pub trait StorageAsync: Clone + 'static {
+    /// The error type returned when accessing the store fails.
+    type Error;
+
+    /// Returns the value stored for `key` or `None` if the key is not set.
+    fn get_item(&self, key: &str) -> impl Future<Output = Result<Option<String>, Self::Error>>;
+
+    /// Stores `value` for `key`.
+    fn set_item(&self, key: &str, value: &str) -> impl Future<Output = Result<(), Self::Error>>;
+
+    /// Removes the value stored for `key`.
+    fn remove_item(&self, key: &str) -> impl Future<Output = Result<(), Self::Error>>;
+}
+
+impl StorageAsync for web_sys::Storage {
+    type Error = JsValue;
+
+    async fn get_item(&self, key: &str) -> Result<Option<String>, Self::Error> {
+        web_sys::Storage::get_item(self, key)
+    }
+
+    async fn set_item(&self, key: &str, value: &str) -> Result<(), Self::Error> {
+        web_sys::Storage::set_item(self, key, value)
+    }
+
+    async fn remove_item(&self, key: &str) -> Result<(), Self::Error> {
+        web_sys::Storage::remove_item(self, key)
+    }
+}
+
+/// Reactive value that is persisted in an asynchronous store.
+///
+/// Like [`use_storage`](crate::storage::use_storage) but for any [`StorageAsync`] backend. The value is available
+/// synchronously right away and is replaced by the stored value once it has been loaded.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::storage::{use_storage_async, StorageAsync, UseStorageAsyncReturn};
+/// # use leptos_use::utils::FromToStringCodec;
+/// #
+/// /// Stores the values on a server
+/// #[derive(Clone)]
+/// struct RemoteStore;
+///
+/// impl StorageAsync for RemoteStore {
+///     type Error = String;
+///
+///     async fn get_item(&self, key: &str) -> Result<Option<String>, String> {
+///         // fetch from the server
+/// #        Ok(None)
+///     }
+///
+///     async fn set_item(&self, key: &str, value: &str) -> Result<(), String> {
+///         // send to the server
+/// #        Ok(())
+///     }
+///
+///     async fn remove_item(&self, key: &str) -> Result<(), String> {
+///         // delete on the server
+/// #        Ok(())
+///     }
+/// }
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseStorageAsyncReturn {
+///     data,
+///     set_data,
+///     is_loading,
+///     is_writing,
+///     ..
+/// } = use_storage_async::<i32, FromToStringCodec, _>(RemoteStore, "my-count");
+///
+/// view! {
+///     <Show when=move || !is_loading.get() fallback=|| "Loading...">
+///         <button on:click=move |_| set_data.update(|count| *count += 1)>{data}</button>
+///         <Show when=move || is_writing.get()>"Saving..."</Show>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// Changes of `data` are written to the store. Changes made while the value is still loading
+/// win over the stored value and are written as soon as loading has finished.
+///
+/// Unlike [`use_storage`](crate::storage::use_storage) the value is not synced with other calls using the same key.
+/// Call `refresh` to load the stored value again.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the returned signals will just read/manipulate the `initial_value` without persistence.
+/// `is_loading` and `is_writing` are always `false`.
+pub fn use_storage_async<T, C, S>(
+    storage: S,
+    key: impl AsRef<str>,
+) -> UseStorageAsyncReturn<T, impl Fn() + Clone, impl Fn() + Clone>
+where
+    T: Clone + Default + PartialEq,
+    C: StringCodec<T> + Default,
+    S: StorageAsync,
+{
+    use_storage_async_with_options::<T, C, S>(storage, key, UseStorageAsyncOptions::default())
+}
+
+/// Version of [`use_storage_async`] that accepts [`UseStorageAsyncOptions`].
+pub fn use_storage_async_with_options<T, C, S>(
+    storage: S,
+    key: impl AsRef<str>,
+    options: UseStorageAsyncOptions<T, C, S>,
+) -> UseStorageAsyncReturn<T, impl Fn() + Clone, impl Fn() + Clone>
+where
+    T: Clone + PartialEq,
+    C: StringCodec<T> + Default,
+    S: StorageAsync,
+{
+    let UseStorageAsyncOptions {
+        codec,
+        on_error,
+        initial_value,
+    } = options;
+
+    let (data, set_data) = initial_value.into_signal();
+    let default = data.get_untracked();
+
+    let (is_loading, set_loading) = create_signal(false);
+    let (pending_writes, set_pending_writes) = create_signal(0_usize);
+
+    let key = key.as_ref().to_owned();
+
+    // Set while `data` is updated from the store so the change isn't written back
+    let skip_write = store_value(false);
+    // Set when `data` was changed while loading
+    let changed_while_loading = store_value(false);
+
+    let set_data_from_store = move |value: T| {
+        skip_write.set_value(true);
+        set_data.set(value);
+        skip_write.set_value(false);
+    };
+
+    let write = {
+        let storage = storage.clone();
+        let key = key.clone();
+        let on_error = Rc::clone(&on_error);
+        let codec = codec.clone();
+
+        move |value: &T| {
+            let encoded = match codec.encode(value) {
+                Ok(encoded) => encoded,
+                Err(err) => {
+                    on_error(UseStorageAsyncError::ItemCodecError(err));
+                    return;
+                }
+            };
+
+            let storage = storage.clone();
+            let key = key.clone();
+            let on_error = Rc::clone(&on_error);
+
+            set_pending_writes.update(|pending| *pending += 1);
+
+            spawn_local(async move {
+                if let Err(err) = storage.set_item(&key, &encoded).await {
+                    on_error(UseStorageAsyncError::SetItemFailed(err));
+                }
+
+                set_pending_writes.update(|pending| *pending -= 1);
+            });
+        }
+    };
+
+    // Loads the stored value. Changes made in the meantime are written instead.
+    let load = {
+        let storage = storage.clone();
+        let key = key.clone();
+        let on_error = Rc::clone(&on_error);
+        let default = default.clone();
+        let write = write.clone();
+
+        move || {
+            #[cfg(not(feature = "ssr"))]
+            {
+                let storage = storage.clone();
+                let key = key.clone();
+                let on_error = Rc::clone(&on_error);
+                let default = default.clone();
+                let codec = codec.clone();
+                let write = write.clone();
+
+                changed_while_loading.set_value(false);
+                set_loading.set(true);
+
+                spawn_local(async move {
+                    let loaded = storage.get_item(&key).await;
+
+                    if changed_while_loading.get_value() {
+                        data.with_untracked(|value| write(value));
+                    } else {
+                        let value = match loaded {
+                            Ok(Some(encoded)) => codec
+                                .decode(encoded)
+                                .map_err(|err| on_error(UseStorageAsyncError::ItemCodecError(err)))
+                                .ok(),
+                            Ok(None) => Some(default),
+                            Err(err) => {
+                                on_error(UseStorageAsyncError::GetItemFailed(err));
+                                None
+                            }
+                        };
+
+                        if let Some(value) = value {
+                            if data.with_untracked(|data| *data != value) {
+                                set_data_from_store(value);
+                            }
+                        }
+                    }
+
+                    set_loading.set(false);
+                });
+            }
+        }
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        load();
+
+        let _ = watch(
+            move || data.get(),
+            move |value, _, _| {
+                if skip_write.get_value() {
+                    return;
+                }
+
+                if is_loading.get_untracked() {
+                    changed_while_loading.set_value(true);
+                    return;
+                }
+
+                write(value);
+            },
+            false,
+        );
+    }
+
+    let remove = move || {
+        set_data_from_store(default.clone());
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let storage = storage.clone();
+            let key = key.clone();
+            let on_error = Rc::clone(&on_error);
+
+            set_pending_writes.update(|pending| *pending += 1);
+
+            spawn_local(async move {
+                if let Err(err) = storage.remove_item(&key).await {
+                    on_error(UseStorageAsyncError::RemoveItemFailed(err));
+                }
+
+                set_pending_writes.update(|pending| *pending -= 1);
+            });
+        }
+    };
+
+    UseStorageAsyncReturn {
+        data,
+        set_data,
+        remove,
+        refresh: load,
+        is_loading: is_loading.into(),
+        is_writing: Signal::derive(move || pending_writes.get() > 0),
+    }
+}
+
+/// Errors of [`use_storage_async_with_options`].
+#[derive(Error, Debug)]
+pub enum UseStorageAsyncError<StorageErr, CodecErr> {
+    #[error("failed to get item")]
+    GetItemFailed(StorageErr),
+    #[error("failed to set item")]
+    SetItemFailed(StorageErr),
+    #[error("failed to delete item")]
+    RemoveItemFailed(StorageErr),
+    #[error("failed to encode / decode item value")]
+    ItemCodecError(CodecErr),
+}
+
+type OnErrorFn<StorageErr, CodecErr> = Rc<dyn Fn(UseStorageAsyncError<StorageErr, CodecErr>)>;
+
+/// Options for [`use_storage_async_with_options`].
+pub struct UseStorageAsyncOptions<T: 'static, C: StringCodec<T>, S: StorageAsync> {
+    // Translates to and from strings
+    codec: C,
+    // Callback for when an error occurs
+    on_error: OnErrorFn<S::Error, C::Error>,
+    // Value to use until the stored value is loaded or when the key is not set
+    initial_value: MaybeRwSignal<T>,
+}
+
+impl<T: Default, C: StringCodec<T> + Default, S: StorageAsync> Default
+    for UseStorageAsyncOptions<T, C, S>
+{
+    fn default() -> Self {
+        Self {
+            codec: C::default(),
+            on_error: Rc::new(|_err| ()),
+            initial_value: MaybeRwSignal::default(),
+        }
+    }
+}
+
+impl<T: Default, C: StringCodec<T>, S: StorageAsync> UseStorageAsyncOptions<T, C, S> {
+    /// Sets the codec to use for encoding and decoding values to and from strings.
+    pub fn codec(self, codec: impl Into<C>) -> Self {
+        Self {
+            codec: codec.into(),
+            ..self
+        }
+    }
+
+    /// Optional callback whenever an error occurs.
+    pub fn on_error(
+        self,
+        on_error: impl Fn(UseStorageAsyncError<S::Error, C::Error>) + 'static,
+    ) -> Self {
+        Self {
+            on_error: Rc::new(on_error),
+            ..self
+        }
+    }
+
+    /// Value to use until the stored value has been loaded and when the key is not set. Accepts
+    /// a signal and defaults to `T::default()`.
+    pub fn initial_value(self, initial: impl Into<MaybeRwSignal<T>>) -> Self {
+        Self {
+            initial_value: initial.into(),
+            ..self
+        }
+    }
+}
+
+/// Return type of [`use_storage_async`].
+pub struct UseStorageAsyncReturn<T, RemoveFn, RefreshFn>
+where
+    T: 'static,
+    RemoveFn: Fn() + Clone,
+    RefreshFn: Fn() + Clone,
+{
+    /// The current value
+    pub data: Signal<T>,
+
+    /// Sets the value and writes it to the store
+    pub set_data: WriteSignal<T>,
+
+    /// Removes the value from the store and resets `data` to the initial value
+    pub remove: RemoveFn,
+
+    /// Loads the value from the store again, for example after it has been changed somewhere else
+    pub refresh: RefreshFn,
+
+    /// `true` while the stored value is being loaded or refreshed
+    pub is_loading: Signal<bool>,
+
+    /// `true` while a write or remove is in progress
+    pub is_writing: Signal<bool>,
+}
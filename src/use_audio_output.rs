@@ -0,0 +1,209 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::ElementMaybeSignal;
+use crate::{js, js_fut, use_event_listener, use_supported};
+use leptos::ev::Custom;
+use leptos::*;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{HtmlMediaElement, MediaDeviceKind};
+
+/// Reactive audio output device of an `<audio>` or `<video>` element using
+/// [`setSinkId`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/setSinkId).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_audio_output)
+///
+/// ## Usage
+///
+/// Together with [`fn@crate::use_devices_list`] you can let the user pick the speakers or
+/// headphones to play the audio on.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::html::Audio;
+/// # use leptos_use::{use_audio_output, use_devices_list, UseAudioOutputReturn, UseDevicesListReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let audio = create_node_ref::<Audio>();
+///
+/// let UseDevicesListReturn { audio_outputs, .. } = use_devices_list();
+///
+/// let UseAudioOutputReturn {
+///     sink_id,
+///     set_sink_id,
+///     ..
+/// } = use_audio_output(audio);
+///
+/// view! {
+///     <audio node_ref=audio src="https://example.com/song.mp3" controls></audio>
+///
+///     <select on:change=move |e| set_sink_id.set(event_target_value(&e))>
+///         <option value="" selected=move || sink_id.get().is_empty()>"Default"</option>
+///         <For
+///             each=move || audio_outputs.get()
+///             key=|device| device.device_id()
+///             let:device
+///         >
+///             {
+///                 let id = device.device_id();
+///
+///                 view! {
+///                     <option value=id.clone() selected=move || sink_id.get() == id>
+///                         {device.label()}
+///                     </option>
+///                 }
+///             }
+///         </For>
+///     </select>
+/// }
+/// # }
+/// ```
+///
+/// Setting `set_sink_id` to the `device_id` of an audio output switches the element to this
+/// device. An empty string stands for the default device of the system. `sink_id` is only
+/// updated once the browser has switched the device.
+///
+/// If the selected device is disconnected, `error` is set to
+/// [`UseAudioOutputError::DeviceRemoved`] and the element is switched back to the default device.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is always `false`, `sink_id` is always an empty string and
+/// setting `set_sink_id` does nothing.
+pub fn use_audio_output<El, T>(target: El) -> UseAudioOutputReturn
+where
+    El: Into<ElementMaybeSignal<T, HtmlMediaElement>>,
+    T: Into<HtmlMediaElement> + Clone + 'static,
+{
+    let target = target.into();
+    let media = Signal::derive(move || target.get().map(Into::<HtmlMediaElement>::into));
+
+    let is_supported = use_supported(|| {
+        document()
+            .create_element("audio")
+            .map(|audio| js!("setSinkId" in &audio))
+            .unwrap_or_default()
+    });
+
+    let (sink_id, set_current_sink_id) = create_signal(String::new());
+    let (requested_sink_id, set_sink_id) = create_signal(String::new());
+    let (error, set_error) = create_signal(None::<UseAudioOutputError>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let apply_sink_id = move |media: &HtmlMediaElement, id: &str| {
+            let current = js!(media["sinkId"])
+                .ok()
+                .and_then(|sink_id| sink_id.as_string())
+                .unwrap_or_default();
+
+            if current == id {
+                set_current_sink_id.set(current);
+                return;
+            }
+
+            let promise = js_sys::Reflect::get(media, &"setSinkId".into())
+                .and_then(|set_sink_id| set_sink_id.dyn_into::<js_sys::Function>())
+                .and_then(|set_sink_id| set_sink_id.call1(media, &id.into()))
+                .and_then(|promise| promise.dyn_into::<js_sys::Promise>());
+
+            let id = id.to_string();
+
+            match promise {
+                Ok(promise) => spawn_local(async move {
+                    match js_fut!(promise).await {
+                        Ok(_) => set_current_sink_id.set(id),
+                        Err(err) => set_error.set(Some(UseAudioOutputError::SetSinkId(err))),
+                    }
+                }),
+                Err(err) => set_error.set(Some(UseAudioOutputError::SetSinkId(err))),
+            }
+        };
+
+        let _ = watch(
+            move || (media.get(), requested_sink_id.get()),
+            move |(media, id), _, _| {
+                if !is_supported.get_untracked() {
+                    return;
+                }
+
+                if let Some(media) = media {
+                    set_error.set(None);
+                    apply_sink_id(media, id);
+                }
+            },
+            true,
+        );
+
+        if is_supported.get_untracked() {
+            if let Ok(media_devices) = window().navigator().media_devices() {
+                let _ = use_event_listener(
+                    media_devices,
+                    Custom::<ev::Event>::new("devicechange"),
+                    move |_| {
+                        spawn_local(async move {
+                            let current = sink_id.get_untracked();
+
+                            if current.is_empty() {
+                                return;
+                            }
+
+                            let Ok(devices) = crate::use_devices_list::enumerate_devices().await
+                            else {
+                                return;
+                            };
+
+                            let still_connected = devices.iter().any(|device| {
+                                device.kind() == MediaDeviceKind::Audiooutput
+                                    && device.device_id() == current
+                            });
+
+                            if !still_connected {
+                                set_sink_id.set_untracked(String::new());
+
+                                if let Some(media) = media.get_untracked() {
+                                    apply_sink_id(&media, "");
+                                }
+
+                                set_error.set(Some(UseAudioOutputError::DeviceRemoved(current)));
+                            }
+                        });
+                    },
+                );
+            }
+        }
+    }
+
+    UseAudioOutputReturn {
+        is_supported,
+        sink_id: sink_id.into(),
+        set_sink_id,
+        error: error.into(),
+    }
+}
+
+/// Return type of [`use_audio_output`].
+pub struct UseAudioOutputReturn {
+    /// `true` if the browser supports `setSinkId`.
+    pub is_supported: Signal<bool>,
+
+    /// Id of the device the audio is played on. An empty string for the default device.
+    pub sink_id: Signal<String>,
+
+    /// Switches the audio to the device with the given id.
+    pub set_sink_id: WriteSignal<String>,
+
+    /// Latest error that occurred while switching the device.
+    pub error: Signal<Option<UseAudioOutputError>>,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseAudioOutputError {
+    #[error("failed to switch the audio output device")]
+    SetSinkId(JsValue),
+    #[error("the audio output device `{0}` has been disconnected")]
+    DeviceRemoved(String),
+}
@@ -0,0 +1,426 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::ElementMaybeSignal;
+use crate::utils::Pausable;
+use crate::{js, js_fut, use_interval_fn_with_options, use_supported, UseIntervalFnOptions};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Reactive [Barcode Detection API](https://developer.mozilla.org/en-US/docs/Web/API/Barcode_Detection_API).
+///
+/// Scans an image, canvas or video element for barcodes in a regular interval.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_barcode_detector)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::html::Video;
+/// # use leptos_use::{use_barcode_detector, UseBarcodeDetectorReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let video_ref = create_node_ref::<Video>();
+///
+/// let UseBarcodeDetectorReturn { barcodes, .. } = use_barcode_detector(video_ref);
+///
+/// view! {
+///     <video node_ref=video_ref autoplay=true></video>
+///
+///     <ul>
+///         <For each=move || barcodes.get() key=|barcode| barcode.raw_value.clone() let:barcode>
+///             <li>{barcode.raw_value}</li>
+///         </For>
+///     </ul>
+/// }
+/// # }
+/// ```
+///
+/// You can restrict the formats that are detected and change the scan interval.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::html::Video;
+/// # use leptos_use::{use_barcode_detector_with_options, BarcodeFormat, UseBarcodeDetectorOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let video_ref = create_node_ref::<Video>();
+/// let detector = use_barcode_detector_with_options(
+///     video_ref,
+///     UseBarcodeDetectorOptions::default()
+///         .formats(vec![BarcodeFormat::QrCode])
+///         .interval(1000),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// A video element is only scanned once it has data of the current frame available.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is `false`, `barcodes` is always empty and the functions
+/// do nothing.
+pub fn use_barcode_detector<El, T>(
+    target: El,
+) -> UseBarcodeDetectorReturn<impl Fn() + Clone, impl Fn() + Clone>
+where
+    El: Into<ElementMaybeSignal<T, web_sys::Element>>,
+    T: Into<web_sys::Element> + Clone + 'static,
+{
+    use_barcode_detector_with_options(target, UseBarcodeDetectorOptions::default())
+}
+
+/// Version of [`use_barcode_detector`] that takes a `UseBarcodeDetectorOptions`. See [`use_barcode_detector`] for how to use.
+pub fn use_barcode_detector_with_options<El, T>(
+    target: El,
+    options: UseBarcodeDetectorOptions,
+) -> UseBarcodeDetectorReturn<impl Fn() + Clone, impl Fn() + Clone>
+where
+    El: Into<ElementMaybeSignal<T, web_sys::Element>>,
+    T: Into<web_sys::Element> + Clone + 'static,
+{
+    let UseBarcodeDetectorOptions {
+        formats,
+        interval,
+        immediate,
+    } = options;
+
+    let target = target.into();
+
+    let is_supported = use_supported(|| js!("BarcodeDetector" in &window()));
+
+    let (barcodes, set_barcodes) = create_signal(Vec::<DetectedBarcode>::new());
+    let (error, set_error) = create_signal(None::<UseBarcodeDetectorError>);
+
+    let detector = store_value(None::<JsValue>);
+    let is_detecting = store_value(false);
+
+    let detect = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_supported.get_untracked() || is_detecting.get_value() {
+                return;
+            }
+
+            let Some(element) = target.get_untracked().map(Into::<web_sys::Element>::into) else {
+                return;
+            };
+
+            // HAVE_CURRENT_DATA
+            if js!(element["readyState"])
+                .ok()
+                .and_then(|ready_state| ready_state.as_f64())
+                .is_some_and(|ready_state| ready_state < 2.0)
+            {
+                return;
+            }
+
+            let barcode_detector = match detector.get_value() {
+                Some(barcode_detector) => barcode_detector,
+                None => match create_barcode_detector(&formats) {
+                    Ok(barcode_detector) => {
+                        detector.set_value(Some(barcode_detector.clone()));
+                        barcode_detector
+                    }
+                    Err(err) => {
+                        set_error.set(Some(UseBarcodeDetectorError::Create(err)));
+                        return;
+                    }
+                },
+            };
+
+            is_detecting.set_value(true);
+
+            spawn_local(async move {
+                let result = match call_method(&barcode_detector, "detect", &element)
+                    .and_then(|promise| promise.dyn_into::<js_sys::Promise>())
+                {
+                    Ok(promise) => js_fut!(promise).await,
+                    Err(err) => Err(err),
+                };
+
+                match result {
+                    Ok(detected) => {
+                        set_barcodes.set(
+                            js_sys::Array::from(&detected)
+                                .iter()
+                                .filter_map(|barcode| DetectedBarcode::from_js(&barcode))
+                                .collect(),
+                        );
+                        set_error.set(None);
+                    }
+                    Err(err) => set_error.set(Some(UseBarcodeDetectorError::Detect(err))),
+                }
+
+                is_detecting.set_value(false);
+            });
+        }
+    };
+
+    let Pausable {
+        is_active,
+        pause,
+        resume,
+    } = use_interval_fn_with_options(
+        detect,
+        interval,
+        UseIntervalFnOptions::default().immediate(immediate),
+    );
+
+    UseBarcodeDetectorReturn {
+        is_supported,
+        barcodes: barcodes.into(),
+        error: error.into(),
+        is_active,
+        pause,
+        resume,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn create_barcode_detector(formats: &[BarcodeFormat]) -> Result<JsValue, JsValue> {
+    // The Barcode Detection API is not part of web-sys yet
+    let constructor = js_sys::Reflect::get(&window(), &"BarcodeDetector".into())?
+        .dyn_into::<js_sys::Function>()?;
+
+    let args = js_sys::Array::new();
+
+    if !formats.is_empty() {
+        let options = js_sys::Object::new();
+        let formats = formats
+            .iter()
+            .map(|format| JsValue::from(format.as_str()))
+            .collect::<js_sys::Array>();
+        js!(options["formats"] = formats);
+
+        args.push(&options);
+    }
+
+    js_sys::Reflect::construct(&constructor, &args)
+}
+
+#[cfg(not(feature = "ssr"))]
+fn call_method(target: &JsValue, name: &str, arg: &JsValue) -> Result<JsValue, JsValue> {
+    js_sys::Reflect::get(target, &name.into())?
+        .dyn_into::<js_sys::Function>()?
+        .call1(target, arg)
+}
+
+/// Options for [`use_barcode_detector_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseBarcodeDetectorOptions {
+    /// The barcode formats to detect. If empty all formats that the browser supports are
+    /// detected. Defaults to empty.
+    formats: Vec<BarcodeFormat>,
+
+    /// The scan interval in milliseconds. Defaults to `500`.
+    #[builder(into)]
+    interval: MaybeSignal<u64>,
+
+    /// Start scanning immediately. If `false` you have to call `resume` to start.
+    /// Defaults to `true`.
+    immediate: bool,
+}
+
+impl Default for UseBarcodeDetectorOptions {
+    fn default() -> Self {
+        Self {
+            formats: vec![],
+            interval: MaybeSignal::Static(500),
+            immediate: true,
+        }
+    }
+}
+
+/// Return type of [`use_barcode_detector`].
+pub struct UseBarcodeDetectorReturn<PauseFn, ResumeFn>
+where
+    PauseFn: Fn() + Clone,
+    ResumeFn: Fn() + Clone,
+{
+    /// `true` if the browser supports `BarcodeDetector`.
+    pub is_supported: Signal<bool>,
+
+    /// The barcodes found in the latest scan.
+    pub barcodes: Signal<Vec<DetectedBarcode>>,
+
+    /// Latest error that occurred while scanning.
+    pub error: Signal<Option<UseBarcodeDetectorError>>,
+
+    /// Wether the scanning is currently running.
+    pub is_active: Signal<bool>,
+
+    /// Pauses scanning.
+    pub pause: PauseFn,
+
+    /// Resumes scanning.
+    pub resume: ResumeFn,
+}
+
+/// A barcode found by [`use_barcode_detector`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DetectedBarcode {
+    /// The decoded content of the barcode.
+    pub raw_value: String,
+
+    /// The format of the barcode.
+    pub format: BarcodeFormat,
+
+    /// The bounding box of the barcode in the coordinates of the scanned element.
+    pub bounding_box: BarcodeBoundingBox,
+
+    /// The corner points of the barcode, clockwise starting at the top left.
+    pub corner_points: Vec<(f64, f64)>,
+}
+
+#[cfg(not(feature = "ssr"))]
+impl DetectedBarcode {
+    fn from_js(barcode: &JsValue) -> Option<Self> {
+        let raw_value = js!(barcode["rawValue"]).ok()?.as_string()?;
+        let format = BarcodeFormat::parse(&js!(barcode["format"]).ok()?.as_string()?);
+
+        let bounding_box = js!(barcode["boundingBox"]).ok()?;
+        let get_f64 = |target: &JsValue, key: &str| {
+            js_sys::Reflect::get(target, &key.into())
+                .ok()
+                .and_then(|value| value.as_f64())
+        };
+
+        let corner_points = js!(barcode["cornerPoints"])
+            .ok()
+            .filter(JsValue::is_object)
+            .map(|points| {
+                js_sys::Array::from(&points)
+                    .iter()
+                    .filter_map(|point| Some((get_f64(&point, "x")?, get_f64(&point, "y")?)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(Self {
+            raw_value,
+            format,
+            bounding_box: BarcodeBoundingBox {
+                x: get_f64(&bounding_box, "x")?,
+                y: get_f64(&bounding_box, "y")?,
+                width: get_f64(&bounding_box, "width")?,
+                height: get_f64(&bounding_box, "height")?,
+            },
+            corner_points,
+        })
+    }
+}
+
+/// Bounding box of a [`DetectedBarcode`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BarcodeBoundingBox {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Barcode formats supported by the [Barcode Detection API](https://developer.mozilla.org/en-US/docs/Web/API/Barcode_Detection_API#supported_barcode_formats).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BarcodeFormat {
+    Aztec,
+    Code128,
+    Code39,
+    Code93,
+    Codabar,
+    DataMatrix,
+    Ean13,
+    Ean8,
+    Itf,
+    Pdf417,
+    QrCode,
+    UpcA,
+    UpcE,
+    Unknown,
+}
+
+impl BarcodeFormat {
+    /// The name of the format as used by the browser like `"qr_code"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Aztec => "aztec",
+            Self::Code128 => "code_128",
+            Self::Code39 => "code_39",
+            Self::Code93 => "code_93",
+            Self::Codabar => "codabar",
+            Self::DataMatrix => "data_matrix",
+            Self::Ean13 => "ean_13",
+            Self::Ean8 => "ean_8",
+            Self::Itf => "itf",
+            Self::Pdf417 => "pdf417",
+            Self::QrCode => "qr_code",
+            Self::UpcA => "upc_a",
+            Self::UpcE => "upc_e",
+            Self::Unknown => "unknown",
+        }
+    }
+
+    fn parse(value: &str) -> Self {
+        match value {
+            "aztec" => Self::Aztec,
+            "code_128" => Self::Code128,
+            "code_39" => Self::Code39,
+            "code_93" => Self::Code93,
+            "codabar" => Self::Codabar,
+            "data_matrix" => Self::DataMatrix,
+            "ean_13" => Self::Ean13,
+            "ean_8" => Self::Ean8,
+            "itf" => Self::Itf,
+            "pdf417" => Self::Pdf417,
+            "qr_code" => Self::QrCode,
+            "upc_a" => Self::UpcA,
+            "upc_e" => Self::UpcE,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseBarcodeDetectorError {
+    #[error("failed to create barcode detector")]
+    Create(JsValue),
+    #[error("failed to detect barcodes")]
+    Detect(JsValue),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_barcode_format_round_trip() {
+        for format in [
+            BarcodeFormat::Aztec,
+            BarcodeFormat::Code128,
+            BarcodeFormat::Code39,
+            BarcodeFormat::Code93,
+            BarcodeFormat::Codabar,
+            BarcodeFormat::DataMatrix,
+            BarcodeFormat::Ean13,
+            BarcodeFormat::Ean8,
+            BarcodeFormat::Itf,
+            BarcodeFormat::Pdf417,
+            BarcodeFormat::QrCode,
+            BarcodeFormat::UpcA,
+            BarcodeFormat::UpcE,
+            BarcodeFormat::Unknown,
+        ] {
+            assert_eq!(BarcodeFormat::parse(format.as_str()), format);
+        }
+
+        assert_eq!(BarcodeFormat::parse("foo"), BarcodeFormat::Unknown);
+    }
+}
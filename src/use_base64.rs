@@ -0,0 +1,391 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::js_fut;
+use crate::utils::StringCodec;
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use std::future::Future;
+use std::pin::Pin;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Reactive base64 encoding.
+///
+/// Encodes a string, bytes, a `Blob`, a `File` or the content of a canvas to a base64
+/// [data URL](https://developer.mozilla.org/en-US/docs/Web/HTTP/Basics_of_HTTP/Data_URLs)
+/// with the [`FileReader`](https://developer.mozilla.org/en-US/docs/Web/API/FileReader).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_base64)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_base64, UseBase64Return};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (text, set_text) = create_signal("Hello, world!".to_string());
+///
+/// let UseBase64Return { base64, .. } = use_base64(Signal::derive(move || text.get().into()));
+///
+/// view! {
+///     <p>{base64}</p> // "data:text/plain;base64,SGVsbG8sIHdvcmxkIQ=="
+/// }
+/// # }
+/// ```
+///
+/// Every time the source changes it's encoded again. If you're only interested in the
+/// base64 part of the data URL, set the option `data_url` to `false`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::html::Canvas;
+/// # use leptos_use::{use_base64_with_options, UseBase64Options, UseBase64Return};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let canvas = create_node_ref::<Canvas>();
+///
+/// let UseBase64Return { base64, execute, .. } = use_base64_with_options(
+///     Signal::derive(move || canvas.get().map(|canvas| (*canvas).clone()).into()),
+///     UseBase64Options::default()
+///         .data_url(false)
+///         .image_type("image/jpeg")
+///         .image_quality(0.8),
+/// );
+///
+/// let encode = move |_| {
+///     // encode the current content of the canvas
+///     let execute = execute.clone();
+///     spawn_local(async move {
+///         let _ = execute().await;
+///     });
+/// };
+///
+/// view! {
+///     <canvas node_ref=canvas />
+///     <button on:click=encode>"Encode"</button>
+/// }
+/// # }
+/// ```
+///
+/// `execute` encodes the source right away. It updates `base64` and also returns the result.
+/// This is useful for sources that change without a signal update like the content of a canvas.
+///
+/// ### Serialized Values
+///
+/// Any value can be encoded by turning it into a string with one of the
+/// [`StringCodec`](crate::utils::StringCodec)s first. With the feature `serde` enabled, you can
+/// use the [`JsonCodec`](crate::utils::JsonCodec) for any type that implements `Serialize`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_base64, Base64Source, UseBase64Return};
+/// # use leptos_use::utils::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (count, set_count) = create_signal(42);
+///
+/// let UseBase64Return { base64, .. } = use_base64(Signal::derive(move || {
+///     Base64Source::encode(&count.get(), FromToStringCodec).unwrap_or_default()
+/// }));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `base64` is always an empty string and `execute` resolves to an empty string.
+pub fn use_base64<S>(source: S) -> UseBase64Return<impl Fn() -> Base64Future + Clone>
+where
+    S: Into<MaybeSignal<Base64Source>>,
+{
+    use_base64_with_options(source, UseBase64Options::default())
+}
+
+/// Version of [`use_base64`] that takes a `UseBase64Options`. See [`use_base64`] for how to use.
+pub fn use_base64_with_options<S>(
+    source: S,
+    options: UseBase64Options,
+) -> UseBase64Return<impl Fn() -> Base64Future + Clone>
+where
+    S: Into<MaybeSignal<Base64Source>>,
+{
+    let UseBase64Options {
+        data_url,
+        image_type,
+        image_quality,
+    } = options;
+
+    let source = source.into();
+    let source = Signal::derive(move || source.get());
+
+    let (base64, set_base64) = create_signal(String::new());
+    let (error, set_error) = create_signal(None::<UseBase64Error>);
+
+    // Only the result of the latest encoding is kept if several are running at the same time.
+    let run_id = store_value(0_usize);
+    let image_type = store_value(image_type);
+
+    let execute = move || {
+        Box::pin(async move {
+            #[cfg(feature = "ssr")]
+            {
+                Ok(String::new())
+            }
+
+            #[cfg(not(feature = "ssr"))]
+            {
+                run_id.update_value(|id| *id += 1);
+                let id = run_id.get_value();
+
+                let result = encode(
+                    source.get_untracked(),
+                    &image_type.get_value(),
+                    image_quality,
+                )
+                .await
+                .map(|encoded| {
+                    if data_url {
+                        encoded
+                    } else {
+                        strip_data_url(&encoded).to_string()
+                    }
+                });
+
+                if run_id.get_value() == id {
+                    match &result {
+                        Ok(encoded) => {
+                            set_base64.set(encoded.clone());
+                            set_error.set(None);
+                        }
+                        Err(err) => set_error.set(Some(err.clone())),
+                    }
+                }
+
+                result
+            }
+        }) as Base64Future
+    };
+
+    let _ = watch(
+        move || source.track(),
+        move |_, _, _| {
+            spawn_local(async move {
+                let _ = execute().await;
+            });
+        },
+        true,
+    );
+
+    UseBase64Return {
+        base64: base64.into(),
+        error: error.into(),
+        execute,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn encode(
+    source: Base64Source,
+    image_type: &str,
+    image_quality: Option<f64>,
+) -> Result<String, UseBase64Error> {
+    let blob = match source {
+        Base64Source::Text(text) => {
+            let mut blob_options = web_sys::BlobPropertyBag::new();
+            blob_options.type_("text/plain");
+
+            web_sys::Blob::new_with_str_sequence_and_options(
+                &js_sys::Array::of1(&JsValue::from_str(&text)),
+                &blob_options,
+            )
+            .map_err(UseBase64Error::Read)?
+        }
+        Base64Source::Bytes(bytes) => {
+            let mut blob_options = web_sys::BlobPropertyBag::new();
+            blob_options.type_("application/octet-stream");
+
+            web_sys::Blob::new_with_u8_array_sequence_and_options(
+                &js_sys::Array::of1(&js_sys::Uint8Array::from(bytes.as_slice())),
+                &blob_options,
+            )
+            .map_err(UseBase64Error::Read)?
+        }
+        Base64Source::Blob(blob) => blob,
+        Base64Source::Canvas(canvas) => {
+            return canvas
+                .to_data_url_with_type_and_encoder_options(
+                    image_type,
+                    &image_quality.map(JsValue::from_f64).unwrap_or_default(),
+                )
+                .map_err(UseBase64Error::Canvas);
+        }
+        Base64Source::None => return Ok(String::new()),
+    };
+
+    let reader = web_sys::FileReader::new().map_err(UseBase64Error::Read)?;
+
+    let promise = js_sys::Promise::new(&mut |resolve, reject| {
+        reader.set_onload(Some(&resolve));
+        reader.set_onerror(Some(&reject));
+    });
+
+    reader
+        .read_as_data_url(&blob)
+        .map_err(UseBase64Error::Read)?;
+
+    js_fut!(promise).await.map_err(UseBase64Error::Read)?;
+
+    Ok(reader
+        .result()
+        .map_err(UseBase64Error::Read)?
+        .as_string()
+        .unwrap_or_default())
+}
+
+/// Removes the `data:<mime type>;base64,` prefix of a data URL.
+fn strip_data_url(data_url: &str) -> &str {
+    data_url
+        .split_once(";base64,")
+        .map(|(_, base64)| base64)
+        .unwrap_or(data_url)
+}
+
+/// Future returned by the `execute` function of [`use_base64`].
+pub type Base64Future = Pin<Box<dyn Future<Output = Result<String, UseBase64Error>>>>;
+
+/// What [`use_base64`] encodes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Base64Source {
+    /// Nothing to encode. The result is an empty string.
+    #[default]
+    None,
+    /// Text that's encoded as `text/plain`.
+    Text(String),
+    /// Bytes that are encoded as `application/octet-stream`.
+    Bytes(Vec<u8>),
+    /// A `Blob` or `File` that's encoded with its own type.
+    Blob(web_sys::Blob),
+    /// The current content of a canvas that's encoded as an image.
+    Canvas(web_sys::HtmlCanvasElement),
+}
+
+impl Base64Source {
+    /// Turns `value` into text with the given [`StringCodec`].
+    pub fn encode<T, C: StringCodec<T>>(value: &T, codec: C) -> Result<Self, C::Error> {
+        codec.encode(value).map(Self::Text)
+    }
+}
+
+impl From<String> for Base64Source {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Base64Source {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<Vec<u8>> for Base64Source {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Bytes(bytes)
+    }
+}
+
+impl From<web_sys::Blob> for Base64Source {
+    fn from(blob: web_sys::Blob) -> Self {
+        Self::Blob(blob)
+    }
+}
+
+impl From<web_sys::File> for Base64Source {
+    fn from(file: web_sys::File) -> Self {
+        Self::Blob(file.into())
+    }
+}
+
+impl From<web_sys::HtmlCanvasElement> for Base64Source {
+    fn from(canvas: web_sys::HtmlCanvasElement) -> Self {
+        Self::Canvas(canvas)
+    }
+}
+
+impl<T: Into<Base64Source>> From<Option<T>> for Base64Source {
+    fn from(source: Option<T>) -> Self {
+        source.map(Into::into).unwrap_or_default()
+    }
+}
+
+/// Options for [`use_base64_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseBase64Options {
+    /// Return a complete data URL like `"data:text/plain;base64,SGVsbG8="`. If `false` only the
+    /// base64 part is returned. Defaults to `true`.
+    data_url: bool,
+
+    /// Image format of the canvas content like `"image/jpeg"`. Defaults to `"image/png"`.
+    #[builder(into)]
+    image_type: String,
+
+    /// Quality between `0.0` and `1.0` for lossy image formats of the canvas content.
+    /// Defaults to `None` which uses the browser's default.
+    #[builder(into)]
+    image_quality: Option<f64>,
+}
+
+impl Default for UseBase64Options {
+    fn default() -> Self {
+        Self {
+            data_url: true,
+            image_type: "image/png".to_string(),
+            image_quality: None,
+        }
+    }
+}
+
+/// Return type of [`use_base64`].
+pub struct UseBase64Return<ExecuteFn>
+where
+    ExecuteFn: Fn() -> Base64Future + Clone,
+{
+    /// The encoded source.
+    pub base64: Signal<String>,
+
+    /// The latest error that occurred while encoding.
+    pub error: Signal<Option<UseBase64Error>>,
+
+    /// Encodes the source right away.
+    pub execute: ExecuteFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseBase64Error {
+    #[error("failed to read the data")]
+    Read(JsValue),
+    #[error("failed to encode the canvas")]
+    Canvas(JsValue),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_data_url() {
+        assert_eq!(
+            strip_data_url("data:text/plain;base64,SGVsbG8="),
+            "SGVsbG8="
+        );
+        assert_eq!(strip_data_url("SGVsbG8="), "SGVsbG8=");
+        assert_eq!(strip_data_url(""), "");
+    }
+}
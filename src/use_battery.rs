@@ -0,0 +1,118 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{use_event_listener, use_supported};
+use leptos::ev::Custom;
+use leptos::*;
+use wasm_bindgen::JsCast;
+
+/// Reactive [Battery Status API](https://developer.mozilla.org/en-US/docs/Web/API/Battery_Status_API).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_battery)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_battery, UseBatteryReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseBatteryReturn {
+///     is_supported,
+///     charging,
+///     charging_time,
+///     discharging_time,
+///     level,
+/// } = use_battery();
+///
+/// view! {
+///     <p>"Battery level: " {move || (level.get() * 100.0).round()} "%"</p>
+/// }
+/// # }
+/// ```
+///
+/// As long as the battery information hasn't been received or if the API is not supported
+/// by the browser (`is_supported` is `false`) the signals contain the values of a fully charged
+/// battery that is plugged in: `charging` is `true`, `level` is `1.0` and the times are `0.0`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the signals always contain the values of a fully charged battery as described above.
+pub fn use_battery() -> UseBatteryReturn {
+    let is_supported = use_supported(|| crate::js!("getBattery" in &window().navigator()));
+
+    let (charging, set_charging) = create_signal(true);
+    let (charging_time, set_charging_time) = create_signal(0.0);
+    let (discharging_time, set_discharging_time) = create_signal(0.0);
+    let (level, set_level) = create_signal(1.0);
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        let (battery, set_battery) = create_signal(None::<web_sys::BatteryManager>);
+
+        let update = move || {
+            if let Some(battery) = battery.get_untracked() {
+                set_charging.set(battery.charging());
+                set_charging_time.set(battery.charging_time());
+                set_discharging_time.set(battery.discharging_time());
+                set_level.set(battery.level());
+            }
+        };
+
+        for event in [
+            "chargingchange",
+            "chargingtimechange",
+            "dischargingtimechange",
+            "levelchange",
+        ] {
+            let _ = use_event_listener(battery, Custom::<ev::Event>::new(event), move |_| update());
+        }
+
+        spawn_local(async move {
+            let navigator = window().navigator();
+
+            let promise = js_sys::Reflect::get(&navigator, &"getBattery".into())
+                .and_then(|get_battery| get_battery.dyn_into::<js_sys::Function>())
+                .and_then(|get_battery| get_battery.call0(&navigator))
+                .and_then(|promise| promise.dyn_into::<js_sys::Promise>());
+
+            if let Ok(promise) = promise {
+                if let Ok(battery) = crate::js_fut!(promise).await {
+                    set_battery.set(Some(battery.unchecked_into()));
+                    update();
+                }
+            }
+        });
+    }
+
+    UseBatteryReturn {
+        is_supported,
+        charging: charging.into(),
+        charging_time: charging_time.into(),
+        discharging_time: discharging_time.into(),
+        level: level.into(),
+    }
+}
+
+/// Return type of [`use_battery`].
+#[derive(Copy, Clone)]
+pub struct UseBatteryReturn {
+    /// `true` if the browser supports the Battery Status API.
+    pub is_supported: Signal<bool>,
+
+    /// Wether the battery is currently being charged.
+    pub charging: Signal<bool>,
+
+    /// The remaining time in seconds until the battery is fully charged. `0.0` if it is full
+    /// and `f64::INFINITY` if it isn't charging.
+    pub charging_time: Signal<f64>,
+
+    /// The remaining time in seconds until the battery is empty and the system suspends.
+    /// `f64::INFINITY` if it is charging.
+    pub discharging_time: Signal<f64>,
+
+    /// The charge level of the battery between `0.0` and `1.0`.
+    pub level: Signal<f64>,
+}
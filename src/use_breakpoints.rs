@@ -34,6 +34,35 @@ use std::hash::Hash;
 /// # }
 /// ```
 ///
+/// The comparison methods are also available with longer names: `greater`, `greater_or_equal`,
+/// `smaller` and `smaller_or_equal`.
+///
+/// ### Active Breakpoint
+///
+/// `active` returns the largest breakpoint that the screen is at least as wide as. This is
+/// `None` if the screen is smaller than all breakpoints.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_breakpoints, BreakpointsTailwind, breakpoints_tailwind};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let screen_width = use_breakpoints(breakpoints_tailwind());
+///
+/// let active = screen_width.active();
+///
+/// let columns = move || match active.get() {
+///     Some(BreakpointsTailwind::Xl | BreakpointsTailwind::Xxl) => 4,
+///     Some(BreakpointsTailwind::Lg) => 3,
+///     Some(BreakpointsTailwind::Md) => 2,
+///     _ => 1,
+/// };
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## Breakpoints
 ///
 /// There are many predefined breakpoints for major UI frameworks. The following are provided.
@@ -42,8 +71,10 @@ use std::hash::Hash;
 /// * [`breakpoints_material`]
 /// * [`breakpoints_ant_design`]
 /// * [`breakpoints_quasar`]
-/// * [`breakpoints_semantic`]
+/// * [`breakpoints_sematic`]
 /// * [`breakpoints_master_css`]
+/// * [`breakpoints_vuetify_v2`]
+/// * [`breakpoints_vuetify_v3`]
 ///
 /// You can also provide your own breakpoints.
 ///
@@ -218,6 +249,22 @@ impl<K: Eq + Hash + Debug + Clone> UseBreakpointsReturn<K> {
         /// `[screen size]` <= `key`
         le, "max", =
     );
+    impl_cmp_reactively!(
+        /// `[screen size]` > `key`. Same as `gt`.
+        greater, "min", >
+    );
+    impl_cmp_reactively!(
+        /// `[screen size]` >= `key`. Same as `ge`.
+        greater_or_equal, "min", =
+    );
+    impl_cmp_reactively!(
+        /// `[screen size]` < `key`. Same as `lt`.
+        smaller, "max", <
+    );
+    impl_cmp_reactively!(
+        /// `[screen size]` <= `key`. Same as `le`.
+        smaller_or_equal, "max", =
+    );
 
     fn between_media_query(min: &u32, max: &u32) -> String {
         format!("(min-width: {min}px) and (max-width: {}.9px)", max - 1)
@@ -272,6 +319,20 @@ impl<K: Eq + Hash + Debug + Clone> UseBreakpointsReturn<K> {
                 .collect::<Vec<_>>()
         })
     }
+
+    /// Reactive largest breakpoint that fulfills `[screen size]` >= `key`.
+    /// `None` if the screen is smaller than all breakpoints.
+    pub fn active(&self) -> Signal<Option<K>> {
+        let breakpoints = self.breakpoints.clone();
+        let current = self.current();
+
+        Signal::derive(move || largest_breakpoint(&breakpoints, current.get()))
+    }
+}
+
+fn largest_breakpoint<K: Eq + Hash>(breakpoints: &HashMap<K, u32>, keys: Vec<K>) -> Option<K> {
+    keys.into_iter()
+        .max_by_key(|key| breakpoints.get(key).copied().unwrap_or_default())
 }
 
 /// Breakpoint keys for Tailwind V2
@@ -458,3 +519,70 @@ pub fn breakpoints_master_css() -> HashMap<BreakpointsMasterCss, u32> {
         (BreakpointsMasterCss::Xxxxl, 2560),
     ])
 }
+
+/// Breakpoint keys for Vuetify V2
+///
+/// See [https://v2.vuetifyjs.com/en/features/breakpoints/]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BreakpointsVuetifyV2 {
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+/// Breakpoint definitions for Vuetify V2
+///
+/// See [https://v2.vuetifyjs.com/en/features/breakpoints/]
+pub fn breakpoints_vuetify_v2() -> HashMap<BreakpointsVuetifyV2, u32> {
+    HashMap::from([
+        (BreakpointsVuetifyV2::Xs, 1),
+        (BreakpointsVuetifyV2::Sm, 600),
+        (BreakpointsVuetifyV2::Md, 960),
+        (BreakpointsVuetifyV2::Lg, 1264),
+        (BreakpointsVuetifyV2::Xl, 1904),
+    ])
+}
+
+/// Breakpoint keys for Vuetify V3
+///
+/// See [https://vuetifyjs.com/en/features/display-and-platform/]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BreakpointsVuetifyV3 {
+    Xs,
+    Sm,
+    Md,
+    Lg,
+    Xl,
+    Xxl,
+}
+
+/// Breakpoint definitions for Vuetify V3
+///
+/// See [https://vuetifyjs.com/en/features/display-and-platform/]
+pub fn breakpoints_vuetify_v3() -> HashMap<BreakpointsVuetifyV3, u32> {
+    HashMap::from([
+        (BreakpointsVuetifyV3::Xs, 1),
+        (BreakpointsVuetifyV3::Sm, 600),
+        (BreakpointsVuetifyV3::Md, 960),
+        (BreakpointsVuetifyV3::Lg, 1280),
+        (BreakpointsVuetifyV3::Xl, 1920),
+        (BreakpointsVuetifyV3::Xxl, 2560),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_largest_breakpoint() {
+        use BreakpointsTailwind::*;
+
+        let breakpoints = breakpoints_tailwind();
+
+        assert_eq!(largest_breakpoint(&breakpoints, vec![Sm, Lg, Md]), Some(Lg));
+        assert_eq!(largest_breakpoint(&breakpoints, vec![]), None);
+    }
+}
@@ -19,6 +19,9 @@ use wasm_bindgen::JsValue;
 /// The BroadcastChannel interface represents a named channel that any browsing context of a given origin can subscribe to. It allows communication between different documents (in different windows, tabs, frames, or iframes) of the same origin.
 ///
 /// Messages are broadcasted via a message event fired at all BroadcastChannel objects listening to the channel.
+/// The channel that posted a message doesn't receive it itself so `message` only ever contains
+/// messages from other browsing contexts (or other calls of `use_broadcast_channel` with the same name).
+/// After calling `close` posting messages does nothing.
 ///
 /// ```
 /// # use leptos::*;
@@ -45,6 +48,8 @@ use wasm_bindgen::JsValue;
 /// ```
 ///
 /// Just like with [`use_storage`] you can use different codecs for encoding and decoding.
+/// With the feature `serde` enabled you can send any type that implements `Serialize` and
+/// `Deserialize` as JSON with [`JsonCodec`](crate::utils::JsonCodec).
 ///
 /// ```
 /// # use leptos::*;
@@ -88,6 +93,10 @@ where
         let codec = codec.clone();
 
         move |data: &T| {
+            if is_closed.get_untracked() {
+                return;
+            }
+
             if let Some(channel) = channel.get_untracked() {
                 match codec.encode(data) {
                     Ok(msg) => {
@@ -0,0 +1,221 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{use_event_listener, use_window};
+use leptos::ev::{hashchange, popstate};
+use leptos::*;
+use wasm_bindgen::JsValue;
+
+/// Reactive browser [`location`](https://developer.mozilla.org/en-US/docs/Web/API/Location)
+/// and [`history`](https://developer.mozilla.org/en-US/docs/Web/API/History) state.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_browser_location)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_browser_location, UseBrowserLocationReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseBrowserLocationReturn {
+///     pathname,
+///     search,
+///     hash,
+///     ..
+/// } = use_browser_location();
+///
+/// view! {
+///     <p>"You are at " {pathname} {search} {hash}</p>
+/// }
+/// # }
+/// ```
+///
+/// The signals are updated on `popstate` and `hashchange`. `trigger` tells you which of these
+/// events caused the last update.
+///
+/// Browsers don't fire any event when the URL is changed with `history.pushState` or
+/// `history.replaceState`. If you call these yourself call `update` afterwards.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_browser_location, UseBrowserLocationReturn};
+/// # use wasm_bindgen::JsValue;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseBrowserLocationReturn { update, .. } = use_browser_location();
+///
+/// let go_to_settings = move |_| {
+///     let _ = window()
+///         .history()
+///         .unwrap()
+///         .push_state_with_url(&JsValue::NULL, "", Some("/settings"));
+///     update();
+/// };
+///
+/// view! {
+///     <button on:click=go_to_settings>"Settings"</button>
+/// }
+/// # }
+/// ```
+///
+/// This is meant for apps that don't use `leptos_router`. If you do, use its `use_location`
+/// instead.
+///
+/// ## Server-Side Rendering
+///
+/// On the server all the strings are empty, `state` is `null`, `length` is `0` and `update`
+/// does nothing.
+///
+/// ## See also
+///
+/// * [`use_location_hash`]
+/// * [`use_url_search_params`]
+pub fn use_browser_location() -> UseBrowserLocationReturn<impl Fn() + Clone> {
+    let (location, set_location) = create_signal(BrowserLocation::default());
+    let (state, set_state) = create_signal(JsValue::NULL);
+    let (trigger, set_trigger) = create_signal(BrowserLocationTrigger::Load);
+
+    let read = move |event_trigger: BrowserLocationTrigger| {
+        #[cfg(not(feature = "ssr"))]
+        {
+            set_location.set(BrowserLocation::read());
+            set_state.set(
+                window()
+                    .history()
+                    .and_then(|history| history.state())
+                    .unwrap_or(JsValue::NULL),
+            );
+            set_trigger.set(event_trigger);
+        }
+    };
+
+    read(BrowserLocationTrigger::Load);
+
+    let _ = use_event_listener(use_window(), popstate, move |_| {
+        read(BrowserLocationTrigger::PopState)
+    });
+    let _ = use_event_listener(use_window(), hashchange, move |_| {
+        read(BrowserLocationTrigger::HashChange)
+    });
+
+    let field = move |get: fn(&BrowserLocation) -> &String| -> Signal<String> {
+        create_memo(move |_| location.with(|location| get(location).clone())).into()
+    };
+
+    UseBrowserLocationReturn {
+        href: field(|location| &location.href),
+        origin: field(|location| &location.origin),
+        protocol: field(|location| &location.protocol),
+        host: field(|location| &location.host),
+        hostname: field(|location| &location.hostname),
+        port: field(|location| &location.port),
+        pathname: field(|location| &location.pathname),
+        search: field(|location| &location.search),
+        hash: field(|location| &location.hash),
+        length: create_memo(move |_| location.with(|location| location.length)).into(),
+        state: state.into(),
+        trigger: trigger.into(),
+        update: move || read(BrowserLocationTrigger::Update),
+    }
+}
+
+/// Snapshot of `window.location` and `history.length`.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct BrowserLocation {
+    href: String,
+    origin: String,
+    protocol: String,
+    host: String,
+    hostname: String,
+    port: String,
+    pathname: String,
+    search: String,
+    hash: String,
+    length: u32,
+}
+
+impl BrowserLocation {
+    #[cfg(not(feature = "ssr"))]
+    fn read() -> Self {
+        let location = window().location();
+
+        Self {
+            href: location.href().unwrap_or_default(),
+            origin: location.origin().unwrap_or_default(),
+            protocol: location.protocol().unwrap_or_default(),
+            host: location.host().unwrap_or_default(),
+            hostname: location.hostname().unwrap_or_default(),
+            port: location.port().unwrap_or_default(),
+            pathname: location.pathname().unwrap_or_default(),
+            search: location.search().unwrap_or_default(),
+            hash: location.hash().unwrap_or_default(),
+            length: window()
+                .history()
+                .and_then(|history| history.length())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// The reason for the last update of [`use_browser_location`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum BrowserLocationTrigger {
+    /// The initial value when the function was called.
+    #[default]
+    Load,
+    /// A `popstate` event, for example when the user navigated back or forward.
+    PopState,
+    /// A `hashchange` event.
+    HashChange,
+    /// A call of `update`.
+    Update,
+}
+
+/// Return type of [`use_browser_location`].
+pub struct UseBrowserLocationReturn<UpdateFn>
+where
+    UpdateFn: Fn() + Clone,
+{
+    /// The whole URL like `"https://example.com:8080/page?q=1#top"`.
+    pub href: Signal<String>,
+
+    /// The origin like `"https://example.com:8080"`.
+    pub origin: Signal<String>,
+
+    /// The protocol including the `:` like `"https:"`.
+    pub protocol: Signal<String>,
+
+    /// The hostname and the port like `"example.com:8080"`.
+    pub host: Signal<String>,
+
+    /// The hostname like `"example.com"`.
+    pub hostname: Signal<String>,
+
+    /// The port like `"8080"` or empty if it's the default port of the protocol.
+    pub port: Signal<String>,
+
+    /// The path like `"/page"`.
+    pub pathname: Signal<String>,
+
+    /// The query string including the `?` like `"?q=1"` or empty.
+    pub search: Signal<String>,
+
+    /// The hash including the `#` like `"#top"` or empty.
+    pub hash: Signal<String>,
+
+    /// The number of entries in the session history.
+    pub length: Signal<u32>,
+
+    /// The `history.state` of the current history entry.
+    pub state: Signal<JsValue>,
+
+    /// Which event caused the last update.
+    pub trigger: Signal<BrowserLocationTrigger>,
+
+    /// Reads the location again. Call this after `history.pushState` or `history.replaceState`.
+    pub update: UpdateFn,
+}
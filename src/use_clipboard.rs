@@ -1,4 +1,7 @@
-use crate::{js, js_fut, use_event_listener, use_supported, UseTimeoutFnReturn};
+use crate::{
+    js, js_fut, use_event_listener, use_permission, use_supported, PermissionState,
+    UseTimeoutFnReturn,
+};
 use default_struct_builder::DefaultBuilder;
 use leptos::ev::{copy, cut};
 use leptos::*;
@@ -45,6 +48,26 @@ use leptos::*;
 /// # }
 /// ```
 ///
+/// If the browser doesn't support the Clipboard API you can set `legacy` in the options to fall
+/// back to `document.execCommand("copy")`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_clipboard_with_options, UseClipboardOptions, UseClipboardReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseClipboardReturn { is_supported, text, copied, copy } = use_clipboard_with_options(
+///     UseClipboardOptions::default().legacy(true).read(true),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// With `read` enabled, `text` is updated whenever something is copied or cut on the page and
+/// the clipboard is read once the `clipboard-read` permission is granted.
+///
 /// ## Server-Side Rendering
 ///
 /// On the server the returnd `text` signal will always be `None` and `copy` is a no-op.
@@ -59,13 +82,18 @@ pub fn use_clipboard_with_options(
     let UseClipboardOptions {
         copied_reset_delay,
         read,
+        legacy,
     } = options;
 
-    let is_supported = use_supported(|| {
+    let is_clipboard_api_supported = use_supported(|| {
         js!("clipboard" in &window()
             .navigator())
     });
 
+    let is_supported = use_supported(move || {
+        is_clipboard_api_supported.get() || (legacy && js!("execCommand" in &document()))
+    });
+
     let (text, set_text) = create_signal(None);
     let (copied, set_copied) = create_signal(false);
 
@@ -76,8 +104,8 @@ pub fn use_clipboard_with_options(
         copied_reset_delay,
     );
 
-    let update_text = move |_| {
-        if is_supported.get() {
+    let update_text = move || {
+        if is_clipboard_api_supported.get() {
             spawn_local(async move {
                 if let Some(clipboard) = window().navigator().clipboard() {
                     if let Ok(text) = js_fut!(clipboard.read_text()).await {
@@ -88,16 +116,34 @@ pub fn use_clipboard_with_options(
         }
     };
 
-    if is_supported.get() && read {
-        let _ = use_event_listener(window(), copy, update_text.clone());
-        let _ = use_event_listener(window(), cut, update_text);
+    if is_clipboard_api_supported.get() && read {
+        let _ = use_event_listener(window(), copy, move |_| update_text());
+        let _ = use_event_listener(window(), cut, move |_| update_text());
+
+        let permission_read = use_permission("clipboard-read");
+
+        let _ = watch(
+            move || permission_read.get(),
+            move |permission, _, _| {
+                if *permission == PermissionState::Granted {
+                    update_text();
+                }
+            },
+            true,
+        );
     }
 
     let do_copy = {
         let start = start.clone();
 
         move |value: &str| {
-            if is_supported.get() {
+            if !is_clipboard_api_supported.get() {
+                if legacy && legacy_copy(value) {
+                    set_text.set(Some(value.to_owned()));
+                    set_copied.set(true);
+                    start(());
+                }
+            } else {
                 let start = start.clone();
                 let value = value.to_owned();
 
@@ -122,6 +168,40 @@ pub fn use_clipboard_with_options(
     }
 }
 
+/// Copies `value` with a temporary `textarea` and `document.execCommand("copy")`.
+fn legacy_copy(value: &str) -> bool {
+    use wasm_bindgen::JsCast;
+
+    let document = document();
+
+    let Some(textarea) = document
+        .create_element("textarea")
+        .ok()
+        .and_then(|el| el.dyn_into::<web_sys::HtmlTextAreaElement>().ok())
+    else {
+        return false;
+    };
+
+    textarea.set_value(value);
+    let _ = textarea.set_attribute("readonly", "");
+    let _ = textarea.set_attribute("style", "position: absolute; opacity: 0;");
+
+    let Some(body) = document.body() else {
+        return false;
+    };
+    let _ = body.append_child(&textarea);
+
+    textarea.select();
+    let copied = document
+        .unchecked_ref::<web_sys::HtmlDocument>()
+        .exec_command("copy")
+        .unwrap_or_default();
+
+    textarea.remove();
+
+    copied
+}
+
 /// Options for [`use_clipboard_with_options`].
 #[derive(DefaultBuilder)]
 pub struct UseClipboardOptions {
@@ -132,6 +212,10 @@ pub struct UseClipboardOptions {
     /// After how many milliseconds after copying should the returned signal `copied` be set to `false`?
     /// Defaults to 1500.
     copied_reset_delay: f64,
+
+    /// When `true` and the Clipboard API is not supported, `document.execCommand("copy")` is
+    /// used for copying instead. Defaults to `false`.
+    legacy: bool,
 }
 
 impl Default for UseClipboardOptions {
@@ -139,6 +223,7 @@ impl Default for UseClipboardOptions {
         Self {
             read: false,
             copied_reset_delay: 1500.0,
+            legacy: false,
         }
     }
 }
@@ -148,7 +233,8 @@ pub struct UseClipboardReturn<CopyFn>
 where
     CopyFn: Fn(&str) + Clone,
 {
-    /// Whether the Clipboard API is supported.
+    /// Whether the Clipboard API is supported. With `legacy` enabled this is also `true` if
+    /// `document.execCommand` is available.
     pub is_supported: Signal<bool>,
 
     /// The current state of the clipboard.
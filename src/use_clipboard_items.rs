@@ -0,0 +1,331 @@
+use crate::{
+    js, js_fut, use_event_listener, use_permission, use_supported, PermissionState,
+    UseTimeoutFnReturn,
+};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::{copy, cut};
+use leptos::*;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::Blob;
+
+/// Reactive [Clipboard API](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard_API)
+/// for [`ClipboardItem`](https://developer.mozilla.org/en-US/docs/Web/API/ClipboardItem)s.
+/// In contrast to [`use_clipboard`](crate::use_clipboard) this reads and writes arbitrary
+/// content like images or HTML instead of only plain text.
+///
+/// > This function requires `--cfg=web_sys_unstable_apis` to be activated as
+/// > [described in the wasm-bindgen guide](https://rustwasm.github.io/docs/wasm-bindgen/web-sys/unstable-apis.html).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_clipboard_items)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_clipboard_items, ClipboardItemData, UseClipboardItemsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseClipboardItemsReturn {
+///     is_supported,
+///     copied,
+///     copy,
+///     ..
+/// } = use_clipboard_items();
+///
+/// let copy_html = move |_| {
+///     copy(vec![ClipboardItemData::new()
+///         .with_text("text/html", "<b>Hello!</b>")
+///         .with_text("text/plain", "Hello!")]);
+/// };
+///
+/// view! {
+///     <button on:click=copy_html>
+///         {move || if copied.get() { "Copied!" } else { "Copy" }}
+///     </button>
+/// }
+/// # }
+/// ```
+///
+/// ### Reading
+///
+/// With `read` enabled in the options, `content` is updated whenever something is copied or cut
+/// on the page and once the `clipboard-read` permission is granted. You can also call `read`
+/// manually, for example in a paste button handler.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_clipboard_items_with_options, UseClipboardItemsOptions, UseClipboardItemsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseClipboardItemsReturn { content, read, .. } =
+///     use_clipboard_items_with_options(UseClipboardItemsOptions::default().read(true));
+///
+/// let image = move || {
+///     content.with(|items| {
+///         items
+///             .iter()
+///             .find_map(|item| item.get("image/png").cloned())
+///     })
+/// };
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `content` is always empty and `copy` and `read` are no-ops.
+pub fn use_clipboard_items(
+) -> UseClipboardItemsReturn<impl Fn(Vec<ClipboardItemData>) + Clone, impl Fn() + Clone> {
+    use_clipboard_items_with_options(UseClipboardItemsOptions::default())
+}
+
+/// Version of [`use_clipboard_items`] that takes a `UseClipboardItemsOptions`. See [`use_clipboard_items`] for how to use.
+pub fn use_clipboard_items_with_options(
+    options: UseClipboardItemsOptions,
+) -> UseClipboardItemsReturn<impl Fn(Vec<ClipboardItemData>) + Clone, impl Fn() + Clone> {
+    let UseClipboardItemsOptions {
+        read,
+        copied_reset_delay,
+    } = options;
+
+    let is_supported = use_supported(|| {
+        js!("clipboard" in &window().navigator()) && js!("ClipboardItem" in &window())
+    });
+
+    let (content, set_content) = create_signal(Vec::<ClipboardItemData>::new());
+    let (copied, set_copied) = create_signal(false);
+    let (error, set_error) = create_signal(None::<UseClipboardItemsError>);
+
+    let UseTimeoutFnReturn { start, .. } = crate::use_timeout_fn::use_timeout_fn(
+        move |_: ()| {
+            set_copied.set(false);
+        },
+        copied_reset_delay,
+    );
+
+    let update_content = move || {
+        if is_supported.get() {
+            spawn_local(async move {
+                match read_clipboard_items().await {
+                    Ok(items) => {
+                        set_content.set(items);
+                        set_error.set(None);
+                    }
+                    Err(err) => set_error.set(Some(err)),
+                }
+            })
+        }
+    };
+
+    if is_supported.get() && read {
+        let _ = use_event_listener(window(), copy, move |_| update_content());
+        let _ = use_event_listener(window(), cut, move |_| update_content());
+
+        let permission_read = use_permission("clipboard-read");
+
+        let _ = watch(
+            move || permission_read.get(),
+            move |permission, _, _| {
+                if *permission == PermissionState::Granted {
+                    update_content();
+                }
+            },
+            true,
+        );
+    }
+
+    let do_copy = move |items: Vec<ClipboardItemData>| {
+        if is_supported.get() {
+            let start = start.clone();
+
+            spawn_local(async move {
+                match write_clipboard_items(&items).await {
+                    Ok(()) => {
+                        set_content.set(items);
+                        set_copied.set(true);
+                        set_error.set(None);
+                        start(());
+                    }
+                    Err(err) => set_error.set(Some(err)),
+                }
+            });
+        }
+    };
+
+    UseClipboardItemsReturn {
+        is_supported,
+        content: content.into(),
+        copied: copied.into(),
+        error: error.into(),
+        copy: do_copy,
+        read: update_content,
+    }
+}
+
+async fn read_clipboard_items() -> Result<Vec<ClipboardItemData>, UseClipboardItemsError> {
+    let Some(clipboard) = window().navigator().clipboard() else {
+        return Ok(vec![]);
+    };
+
+    let items = js_fut!(clipboard.read())
+        .await
+        .map_err(UseClipboardItemsError::Read)?;
+
+    let mut result = vec![];
+
+    for item in js_sys::Array::from(&items).iter() {
+        let item = item.unchecked_into::<web_sys::ClipboardItem>();
+        let mut data = ClipboardItemData::new();
+
+        for mime_type in item.types().iter().filter_map(|t| t.as_string()) {
+            let blob = js_fut!(item.get_type(&mime_type))
+                .await
+                .map_err(UseClipboardItemsError::Read)?;
+
+            data = data.with_blob(mime_type, blob.unchecked_into());
+        }
+
+        result.push(data);
+    }
+
+    Ok(result)
+}
+
+async fn write_clipboard_items(items: &[ClipboardItemData]) -> Result<(), UseClipboardItemsError> {
+    let Some(clipboard) = window().navigator().clipboard() else {
+        return Ok(());
+    };
+
+    // `ClipboardItem` has no constructor in web-sys yet
+    let constructor = js_sys::Reflect::get(&window(), &"ClipboardItem".into())
+        .and_then(|constructor| constructor.dyn_into::<js_sys::Function>())
+        .map_err(UseClipboardItemsError::Write)?;
+
+    let clipboard_items = js_sys::Array::new();
+
+    for item in items {
+        let record = js_sys::Object::new();
+        for (mime_type, blob) in &item.entries {
+            let _ = js_sys::Reflect::set(&record, &mime_type.into(), blob);
+        }
+
+        let clipboard_item = js_sys::Reflect::construct(&constructor, &js_sys::Array::of1(&record))
+            .map_err(UseClipboardItemsError::Write)?;
+        clipboard_items.push(&clipboard_item);
+    }
+
+    js_fut!(clipboard.write(&clipboard_items))
+        .await
+        .map(|_| ())
+        .map_err(UseClipboardItemsError::Write)
+}
+
+/// Options for [`use_clipboard_items_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseClipboardItemsOptions {
+    /// When `true` event handlers are added so that the returned signal `content` is updated whenever the clipboard changes.
+    /// Defaults to `false`.
+    read: bool,
+
+    /// After how many milliseconds after copying should the returned signal `copied` be set to `false`?
+    /// Defaults to 1500.
+    copied_reset_delay: f64,
+}
+
+impl Default for UseClipboardItemsOptions {
+    fn default() -> Self {
+        Self {
+            read: false,
+            copied_reset_delay: 1500.0,
+        }
+    }
+}
+
+/// Return type of [`use_clipboard_items`].
+pub struct UseClipboardItemsReturn<CopyFn, ReadFn>
+where
+    CopyFn: Fn(Vec<ClipboardItemData>) + Clone,
+    ReadFn: Fn() + Clone,
+{
+    /// Whether the Clipboard API and `ClipboardItem` are supported.
+    pub is_supported: Signal<bool>,
+
+    /// The current content of the clipboard.
+    pub content: Signal<Vec<ClipboardItemData>>,
+
+    /// `true` for [`UseClipboardItemsOptions::copied_reset_delay`] milliseconds after copying.
+    pub copied: Signal<bool>,
+
+    /// Latest error that occurred while reading or writing the clipboard.
+    pub error: Signal<Option<UseClipboardItemsError>>,
+
+    /// Copy the given items to the clipboard.
+    pub copy: CopyFn,
+
+    /// Read the clipboard into `content`.
+    pub read: ReadFn,
+}
+
+/// One item of the clipboard. It can contain the same content in several representations
+/// identified by their mime types like `"text/plain"` and `"text/html"`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClipboardItemData {
+    /// The mime types and their data.
+    pub entries: Vec<(String, Blob)>,
+}
+
+impl ClipboardItemData {
+    /// Creates an empty item.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the data of the given mime type.
+    pub fn with_blob(mut self, mime_type: impl Into<String>, blob: Blob) -> Self {
+        self.entries.push((mime_type.into(), blob));
+        self
+    }
+
+    /// Adds text of the given mime type like `"text/plain"` or `"text/html"`.
+    pub fn with_text(self, mime_type: impl Into<String>, text: &str) -> Self {
+        let mime_type = mime_type.into();
+
+        let mut options = web_sys::BlobPropertyBag::new();
+        options.type_(&mime_type);
+
+        let blob = Blob::new_with_str_sequence_and_options(
+            &js_sys::Array::of1(&JsValue::from_str(text)),
+            &options,
+        )
+        .expect("creating a blob from a string doesn't fail");
+
+        self.with_blob(mime_type, blob)
+    }
+
+    /// Returns the data of the given mime type.
+    pub fn get(&self, mime_type: &str) -> Option<&Blob> {
+        self.entries
+            .iter()
+            .find(|(t, _)| t == mime_type)
+            .map(|(_, blob)| blob)
+    }
+
+    /// Returns the mime types of this item.
+    pub fn mime_types(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(t, _)| t.as_str())
+    }
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseClipboardItemsError {
+    #[error("failed to read from the clipboard")]
+    Read(JsValue),
+    #[error("failed to write to the clipboard")]
+    Write(JsValue),
+}
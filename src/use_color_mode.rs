@@ -6,6 +6,7 @@ use crate::utils::FromToStringCodec;
 use crate::{sync_signal_with_options, use_cookie, use_preferred_dark, SyncSignalOptions};
 use default_struct_builder::DefaultBuilder;
 use leptos::*;
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::marker::PhantomData;
 use std::rc::Rc;
@@ -83,6 +84,29 @@ use wasm_bindgen::JsCast;
 /// # }
 /// ```
 ///
+/// The class name or attribute value of a mode can be changed with `mode_values`. This is
+/// useful if your CSS framework expects different names.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_color_mode_with_options, ColorMode, UseColorModeOptions, UseColorModeReturn};
+/// # use std::collections::HashMap;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseColorModeReturn { mode, set_mode, .. } = use_color_mode_with_options(
+///     UseColorModeOptions::default()
+///         .custom_modes(vec!["cafe".to_string()])
+///         .mode_values(HashMap::from([
+///             (ColorMode::Dark, "theme-dark".to_string()),
+///             (ColorMode::Custom("cafe".to_string()), "theme-cafe".to_string()),
+///         ])),
+/// ); // adds the class `theme-dark` to `<html>` in dark mode
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ### Cookies
 ///
 /// To persist color mode in a cookie, use `use_cookie_with_options` and specify `.cookie_enabled(true)`.
@@ -143,6 +167,7 @@ where
         on_changed,
         storage_signal,
         custom_modes,
+        mode_values,
         storage_key,
         storage,
         storage_enabled,
@@ -156,10 +181,9 @@ where
 
     let modes: Vec<String> = custom_modes
         .into_iter()
-        .chain(vec![
-            ColorMode::Dark.to_string(),
-            ColorMode::Light.to_string(),
-        ])
+        .map(ColorMode::from)
+        .chain(vec![ColorMode::Dark, ColorMode::Light])
+        .map(|mode| mode_value(&mode_values, &mode))
         .collect();
 
     let preferred_dark = use_preferred_dark();
@@ -245,16 +269,18 @@ where
                     }
                 }
 
+                let value = mode_value(&mode_values, &value);
+
                 if attribute == "class" {
                     for mode in &modes {
-                        if &value.to_string() == mode {
+                        if &value == mode {
                             let _ = el.class_list().add_1(mode);
                         } else {
                             let _ = el.class_list().remove_1(mode);
                         }
                     }
                 } else {
-                    let _ = el.set_attribute(&attribute, &value.to_string());
+                    let _ = el.set_attribute(&attribute, &value);
                 }
 
                 if !transition_enabled {
@@ -315,6 +341,14 @@ pub enum ColorMode {
     Custom(String),
 }
 
+/// The class name or attribute value that is written for `mode`.
+fn mode_value(mode_values: &HashMap<ColorMode, String>, mode: &ColorMode) -> String {
+    mode_values
+        .get(mode)
+        .cloned()
+        .unwrap_or_else(|| mode.to_string())
+}
+
 fn get_cookie_signal(
     cookie_name: &str,
     cookie_enabled: bool,
@@ -421,12 +455,16 @@ where
     /// Custom modes that you plan to use as `ColorMode::Custom(x)`. Defaults to `vec![]`.
     custom_modes: Vec<String>,
 
+    /// Overrides the class name or attribute value that is written to the target element for a
+    /// mode. Modes that aren't in this map use their name like `"dark"`. Defaults to an empty map.
+    mode_values: HashMap<ColorMode, String>,
+
     /// Custom handler that is called on updates.
     /// If specified this will override the default behavior.
     /// To get the default behaviour back you can call the provided `default_handler` function.
     /// It takes two parameters:
     /// - `mode: ColorMode`: The color mode to change to.
-    ///  -`default_handler: Rc<dyn Fn(ColorMode)>`: The default handler that would have been called if the `on_changed` handler had not been specified.
+    /// - `default_handler: Rc<dyn Fn(ColorMode)>`: The default handler that would have been called if the `on_changed` handler had not been specified.
     on_changed: OnChangedFn,
 
     /// When provided, `useStorage` will be skipped.
@@ -486,6 +524,7 @@ impl Default for UseColorModeOptions<&'static str, web_sys::Element> {
             initial_value_from_url_param: None,
             initial_value_from_url_param_to_storage: false,
             custom_modes: vec![],
+            mode_values: HashMap::new(),
             on_changed: Rc::new(move |mode, default_handler| (default_handler)(mode)),
             storage_signal: None,
             storage_key: "leptos-use-color-scheme".into(),
@@ -519,3 +558,20 @@ pub struct UseColorModeReturn {
     /// When [`UseColorModeOptions::emit_auto`] is `false` this is the same as `mode`. This will never report `ColorMode::Auto` but always on of the other modes.
     pub state: Signal<ColorMode>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mode_value() {
+        let mode_values = HashMap::from([(ColorMode::Dark, "theme-dark".to_string())]);
+
+        assert_eq!(mode_value(&mode_values, &ColorMode::Dark), "theme-dark");
+        assert_eq!(mode_value(&mode_values, &ColorMode::Light), "light");
+        assert_eq!(
+            mode_value(&mode_values, &ColorMode::Custom("cafe".to_string())),
+            "cafe"
+        );
+    }
+}
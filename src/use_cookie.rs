@@ -12,7 +12,8 @@ use std::rc::Rc;
 ///
 /// You can use this function multiple times in your for the same cookie and they're signals will synchronize
 /// (even across windows/tabs). But there is no way to listen to changes to `document.cookie` directly so in case
-/// something outside of this function changes the cookie, the signal will **not** be updated.
+/// something outside of this function changes the cookie, the signal will **not** be updated unless you set the option
+/// `refresh_interval` (see below).
 ///
 /// When the options `max_age` or `expire` is given then the returned signal will
 /// automatically turn to `None` after that time.
@@ -80,6 +81,29 @@ use std::rc::Rc;
 /// # }
 /// ```
 ///
+/// ## Changes from outside
+///
+/// If the cookie is also changed by other code, for example a JavaScript library or the `Set-Cookie` header of a
+/// `fetch` response, set `refresh_interval` to read `document.cookie` again periodically.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_cookie_with_options, UseCookieOptions};
+/// # use leptos_use::utils::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (session, _) = use_cookie_with_options::<String, FromToStringCodec>(
+///     "session",
+///     UseCookieOptions::default()
+///         .readonly(true)
+///         .refresh_interval(Some(1000)) // every second
+/// );
+/// #
+/// # view! {}
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// This works equally well on the server or the client.
@@ -90,6 +114,30 @@ use std::rc::Rc;
 /// > If you're using `axum` you have to enable the `"axum"` feature in your Cargo.toml.
 /// > In case it's `actix-web` enable the feature `"actix"`, for `spin` enable `"spin"`.
 ///
+/// Because the cookie is read from the request the first render on the server already has the same
+/// value as the client and hydration doesn't flash the default value.
+///
+/// ### Provide the header via context
+///
+/// With any other server integration you can provide the `Cookie` header of the request as an
+/// [`SsrCookiesHeader`] context before rendering. It is used by all calls of `use_cookie` and takes
+/// precedence over the built-in integrations.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::SsrCookiesHeader;
+/// #
+/// # fn cookie_header_of_request() -> String { String::new() }
+/// #
+/// # #[component]
+/// # fn App() -> impl IntoView {
+/// #[cfg(feature = "ssr")]
+/// provide_context(SsrCookiesHeader(cookie_header_of_request()));
+/// #
+/// # view! {}
+/// # }
+/// ```
+///
 /// ### Bring your own header
 ///
 /// In case you're neither using Axum nor Actix, or the default implementation is not to your liking,
@@ -162,6 +210,7 @@ where
         ssr_set_cookie,
         default_value,
         readonly,
+        refresh_interval,
         on_error,
     } = options;
 
@@ -213,7 +262,8 @@ where
     #[cfg(not(feature = "ssr"))]
     {
         use crate::{
-            use_broadcast_channel, watch_pausable, UseBroadcastChannelReturn, WatchPausableReturn,
+            use_broadcast_channel, use_interval_fn, watch_pausable, UseBroadcastChannelReturn,
+            WatchPausableReturn,
         };
 
         let UseBroadcastChannelReturn { message, post, .. } =
@@ -240,9 +290,7 @@ where
                         .and_then(|cookie| codec.encode(cookie).map_err(|err| on_error(err)).ok())
                 });
 
-                if value
-                    == jar.with_value(|jar| jar.get(&cookie_name).map(|c| c.value().to_owned()))
-                {
+                if value == jar.with_value(|jar| cookie_value(jar, &cookie_name)) {
                     return;
                 }
 
@@ -279,6 +327,42 @@ where
             }
         });
 
+        // pick up changes that were made without `use_cookie`
+        if let Some(refresh_interval) = refresh_interval {
+            let cookie_name = cookie_name.to_owned();
+            let ssr_cookies_header_getter = Rc::clone(&ssr_cookies_header_getter);
+            let codec = codec.clone();
+            let on_error = Rc::clone(&on_error);
+            let pause = pause.clone();
+            let resume = resume.clone();
+
+            let _ =
+                use_interval_fn(
+                    move || {
+                        let Some(new_jar) =
+                            load_and_parse_cookie_jar(Rc::clone(&ssr_cookies_header_getter))
+                        else {
+                            return;
+                        };
+
+                        let value = cookie_value(&new_jar, &cookie_name);
+
+                        if value == jar.with_value(|jar| cookie_value(jar, &cookie_name)) {
+                            return;
+                        }
+
+                        jar.set_value(new_jar);
+
+                        pause();
+                        set_cookie.set(value.and_then(|value| {
+                            codec.decode(value).map_err(|err| on_error(err)).ok()
+                        }));
+                        resume();
+                    },
+                    refresh_interval,
+                );
+        }
+
         // listen to cookie changes from the broadcast channel
         create_effect({
             let ssr_cookies_header_getter = Rc::clone(&ssr_cookies_header_getter);
@@ -355,6 +439,8 @@ where
 
     #[cfg(feature = "ssr")]
     {
+        let _ = refresh_interval;
+
         if !readonly {
             let value = cookie
                 .with_untracked(|cookie| {
@@ -454,6 +540,12 @@ pub struct UseCookieOptions<T, Err> {
     /// Default: `false`
     readonly: bool,
 
+    /// Interval in milliseconds in which `document.cookie` is read again to pick up changes that
+    /// were made without `use_cookie`, for example by JavaScript code or by an HTTP response of a
+    /// `fetch` call. Changes made with `use_cookie` are always synced without polling.
+    /// Default: `None`
+    refresh_interval: Option<u64>,
+
     /// Getter function to return the string value of the cookie header.
     /// When you use one of the features `"axum"`, `"actix"` or `"spin"` there's a valid default implementation provided.
     ssr_cookies_header_getter: Rc<dyn Fn() -> Option<String>>,
@@ -466,6 +558,11 @@ pub struct UseCookieOptions<T, Err> {
     on_error: Rc<dyn Fn(Err)>,
 }
 
+/// The `Cookie` header of the current request. Provide it as a context on the server to let
+/// [`use_cookie`] read the cookies without one of the features `"axum"`, `"actix"` or `"spin"`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SsrCookiesHeader(pub String);
+
 impl<T, Err> Default for UseCookieOptions<T, Err> {
     #[allow(dead_code)]
     fn default() -> Self {
@@ -475,6 +572,7 @@ impl<T, Err> Default for UseCookieOptions<T, Err> {
             http_only: false,
             default_value: None,
             readonly: false,
+            refresh_interval: None,
             secure: false,
             domain: None,
             path: None,
@@ -482,6 +580,10 @@ impl<T, Err> Default for UseCookieOptions<T, Err> {
             ssr_cookies_header_getter: Rc::new(move || {
                 #[cfg(feature = "ssr")]
                 {
+                    if let Some(SsrCookiesHeader(header)) = use_context::<SsrCookiesHeader>() {
+                        return Some(header);
+                    }
+
                     #[cfg(all(feature = "actix", feature = "axum"))]
                     compile_error!("You cannot enable only one of features \"actix\" and \"axum\" at the same time");
 
@@ -524,7 +626,7 @@ impl<T, Err> Default for UseCookieOptions<T, Err> {
                         not(feature = "spin")
                     ))]
                     {
-                        leptos::logging::warn!("If you're using use_cookie without the feature `axum`, `actix` or `spin` enabled, you should provide the option `ssr_cookies_header_getter`");
+                        leptos::logging::warn!("If you're using use_cookie without the feature `axum`, `actix` or `spin` enabled, you should provide the context `SsrCookiesHeader` or the option `ssr_cookies_header_getter`");
                         None
                     }
 
@@ -866,6 +968,12 @@ fn write_server_cookie(
     }
 }
 
+/// The value of the cookie `name` in `jar`.
+#[cfg(not(feature = "ssr"))]
+fn cookie_value(jar: &CookieJar, name: &str) -> Option<String> {
+    jar.get(name).map(|cookie| cookie.value().to_owned())
+}
+
 fn load_and_parse_cookie_jar(
     ssr_cookies_header_getter: Rc<dyn Fn() -> Option<String>>,
 ) -> Option<CookieJar> {
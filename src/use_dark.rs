@@ -0,0 +1,152 @@
+use crate::core::ElementMaybeSignal;
+use crate::{use_color_mode_with_options, ColorMode, UseColorModeOptions, UseColorModeReturn};
+use leptos::*;
+
+/// Reactive dark mode with auto data persistence.
+///
+/// This is a shortcut for [`use_color_mode`] if you only need to know and change wether dark mode
+/// is enabled.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_dark)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_dark, UseDarkReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseDarkReturn {
+///     is_dark,
+///     set_dark,
+///     toggle,
+///     ..
+/// } = use_dark();
+///
+/// view! {
+///     <button on:click=move |_| toggle()>
+///         {move || if is_dark.get() { "Switch to light" } else { "Switch to dark" }}
+///     </button>
+/// }
+/// # }
+/// ```
+///
+/// By default `is_dark` follows the browser preference from [`use_preferred_dark`].
+/// When you call `set_dark` or `toggle` the choice is persisted to local storage and the class
+/// `dark` or `light` is added to the `<html>` element, exactly like [`use_color_mode`] does.
+/// Setting the value that the browser prefers anyway stores `ColorMode::Auto` so that `is_dark`
+/// follows the browser preference again.
+///
+/// ## Options
+///
+/// [`use_dark_with_options`] takes the same options as [`use_color_mode_with_options`]. Custom
+/// modes are ignored: `is_dark` is only `true` in `ColorMode::Dark`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_dark_with_options, UseColorModeOptions, UseDarkReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseDarkReturn { is_dark, .. } = use_dark_with_options(
+///     UseColorModeOptions::default()
+///         .attribute("data-theme")
+///         .storage_key("my-dark-mode"),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_dark` is `false` unless a stored mode is taken from a cookie. See
+/// [`use_color_mode`] for how to enable cookies.
+///
+/// ## See also
+///
+/// * [`use_color_mode`]
+/// * [`use_preferred_dark`]
+pub fn use_dark() -> UseDarkReturn<impl Fn(bool) + Clone, impl Fn() + Clone> {
+    use_dark_with_options(UseColorModeOptions::default())
+}
+
+/// Version of [`use_dark`] that takes a `UseColorModeOptions`. See [`use_dark`] for how to use.
+pub fn use_dark_with_options<El, T>(
+    options: UseColorModeOptions<El, T>,
+) -> UseDarkReturn<impl Fn(bool) + Clone, impl Fn() + Clone>
+where
+    El: Clone,
+    El: Into<ElementMaybeSignal<T, web_sys::Element>>,
+    T: Into<web_sys::Element> + Clone + 'static,
+{
+    let UseColorModeReturn {
+        store,
+        set_store,
+        system,
+        state,
+        ..
+    } = use_color_mode_with_options(options.emit_auto(false));
+
+    let is_dark = Signal::derive(move || state.get() == ColorMode::Dark);
+
+    let set_dark = move |dark: bool| {
+        let prefers_dark = system.get_untracked() == ColorMode::Dark;
+        set_store.set(color_mode_for(dark, prefers_dark));
+    };
+
+    let toggle = move || set_dark(!is_dark.get_untracked());
+
+    UseDarkReturn {
+        is_dark,
+        set_dark,
+        toggle,
+        store,
+    }
+}
+
+/// The mode that is stored when dark mode is set to `dark` by the user.
+fn color_mode_for(dark: bool, prefers_dark: bool) -> ColorMode {
+    if dark == prefers_dark {
+        ColorMode::Auto
+    } else if dark {
+        ColorMode::Dark
+    } else {
+        ColorMode::Light
+    }
+}
+
+/// Return type of [`use_dark`].
+pub struct UseDarkReturn<SetDarkFn, ToggleFn>
+where
+    SetDarkFn: Fn(bool) + Clone,
+    ToggleFn: Fn() + Clone,
+{
+    /// Wether dark mode is enabled, either by the user or by the browser preference.
+    pub is_dark: Signal<bool>,
+
+    /// Enables or disables dark mode and persists the choice.
+    pub set_dark: SetDarkFn,
+
+    /// Switches between dark and light mode.
+    pub toggle: ToggleFn,
+
+    /// The persisted mode. `ColorMode::Auto` if the user hasn't overridden the browser preference.
+    pub store: Signal<ColorMode>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_mode_for() {
+        assert_eq!(color_mode_for(true, true), ColorMode::Auto);
+        assert_eq!(color_mode_for(false, false), ColorMode::Auto);
+        assert_eq!(color_mode_for(true, false), ColorMode::Dark);
+        assert_eq!(color_mode_for(false, true), ColorMode::Light);
+    }
+}
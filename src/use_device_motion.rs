@@ -0,0 +1,205 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::filter_builder_methods;
+use crate::utils::{DebounceOptions, FilterOptions, ThrottleOptions};
+use crate::{js, use_event_listener_with_options, use_supported, UseEventListenerOptions};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+
+/// Reactive [DeviceMotionEvent](https://developer.mozilla.org/en-US/docs/Web/API/DeviceMotionEvent).
+/// Provides web developers with information about the speed of changes for the device's
+/// position and orientation.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_device_motion)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_device_motion, UseDeviceMotionReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseDeviceMotionReturn {
+///     acceleration,
+///     acceleration_including_gravity,
+///     rotation_rate,
+///     interval,
+///     ..
+/// } = use_device_motion();
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Motion events are fired very often. To update the signals less frequently you can
+/// throttle or debounce them.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_device_motion_with_options, UseDeviceMotionOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let motion = use_device_motion_with_options(UseDeviceMotionOptions::default().throttle(100.0));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Just like with [`use_device_orientation`](crate::use_device_orientation) Safari on iOS only
+/// reports the motion after `request_permission` has been called in response to a user interaction.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this function returns values as if the motion
+/// capabilities were not supported by the device.
+pub fn use_device_motion() -> UseDeviceMotionReturn<impl Fn() + Clone> {
+    use_device_motion_with_options(UseDeviceMotionOptions::default())
+}
+
+/// Version of [`use_device_motion`] that takes a `UseDeviceMotionOptions`. See [`use_device_motion`] for how to use.
+pub fn use_device_motion_with_options(
+    options: UseDeviceMotionOptions,
+) -> UseDeviceMotionReturn<impl Fn() + Clone> {
+    let UseDeviceMotionOptions { filter } = options;
+
+    let is_supported = use_supported(|| js!("DeviceMotionEvent" in &window()));
+
+    let (acceleration, set_acceleration) = create_signal(DeviceMotionAcceleration::default());
+    let (acceleration_including_gravity, set_acceleration_including_gravity) =
+        create_signal(DeviceMotionAcceleration::default());
+    let (rotation_rate, set_rotation_rate) = create_signal(DeviceMotionRotationRate::default());
+    let (interval, set_interval) = create_signal(0.0);
+    let (permission_granted, set_permission_granted) = create_signal(false);
+
+    let request_permission = move || {
+        #[cfg(not(feature = "ssr"))]
+        spawn_local(async move {
+            set_permission_granted.set(crate::request_sensor_permission("DeviceMotionEvent").await);
+        });
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        use crate::utils::create_filter_wrapper_with_arg;
+
+        set_permission_granted.set(!crate::needs_sensor_permission("DeviceMotionEvent"));
+
+        let on_motion = create_filter_wrapper_with_arg(
+            filter.filter_fn(),
+            move |event: web_sys::DeviceMotionEvent| {
+                set_acceleration.set(event.acceleration().as_ref().into());
+                set_acceleration_including_gravity
+                    .set(event.acceleration_including_gravity().as_ref().into());
+                set_rotation_rate.set(event.rotation_rate().as_ref().into());
+                set_interval.set(event.interval().unwrap_or_default());
+            },
+        );
+
+        let _ = use_event_listener_with_options(
+            window(),
+            ev::devicemotion,
+            move |event| {
+                on_motion(event);
+            },
+            UseEventListenerOptions::default().passive(true),
+        );
+    }
+
+    UseDeviceMotionReturn {
+        is_supported,
+        acceleration: acceleration.into(),
+        acceleration_including_gravity: acceleration_including_gravity.into(),
+        rotation_rate: rotation_rate.into(),
+        interval: interval.into(),
+        permission_granted: permission_granted.into(),
+        request_permission,
+    }
+}
+
+/// Options for [`use_device_motion_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseDeviceMotionOptions {
+    /// Allows to debounce or throttle the updates of the signals. Defaults to no filter.
+    filter: FilterOptions,
+}
+
+#[allow(clippy::needless_update)]
+impl UseDeviceMotionOptions {
+    filter_builder_methods!(
+        /// the updates of the signals
+        filter
+    );
+}
+
+/// Acceleration of the device in m/s² along the three axes.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DeviceMotionAcceleration {
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub z: Option<f64>,
+}
+
+impl From<Option<&web_sys::DeviceAcceleration>> for DeviceMotionAcceleration {
+    fn from(acceleration: Option<&web_sys::DeviceAcceleration>) -> Self {
+        acceleration
+            .map(|acceleration| Self {
+                x: acceleration.x(),
+                y: acceleration.y(),
+                z: acceleration.z(),
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Rate of change of the device's orientation in degrees per second around the three axes.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DeviceMotionRotationRate {
+    pub alpha: Option<f64>,
+    pub beta: Option<f64>,
+    pub gamma: Option<f64>,
+}
+
+impl From<Option<&web_sys::DeviceRotationRate>> for DeviceMotionRotationRate {
+    fn from(rotation_rate: Option<&web_sys::DeviceRotationRate>) -> Self {
+        rotation_rate
+            .map(|rotation_rate| Self {
+                alpha: rotation_rate.alpha(),
+                beta: rotation_rate.beta(),
+                gamma: rotation_rate.gamma(),
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Return type of [`use_device_motion`].
+#[derive(Clone)]
+pub struct UseDeviceMotionReturn<RequestPermissionFn>
+where
+    RequestPermissionFn: Fn() + Clone,
+{
+    /// `true` if the browser supports `DeviceMotionEvent`.
+    pub is_supported: Signal<bool>,
+
+    /// Acceleration of the device without the effect of gravity.
+    pub acceleration: Signal<DeviceMotionAcceleration>,
+
+    /// Acceleration of the device including the effect of gravity.
+    pub acceleration_including_gravity: Signal<DeviceMotionAcceleration>,
+
+    /// Rate of change of the device's orientation.
+    pub rotation_rate: Signal<DeviceMotionRotationRate>,
+
+    /// Interval in milliseconds in which the device provides new data.
+    pub interval: Signal<f64>,
+
+    /// Whether the permission to receive the motion has been granted.
+    pub permission_granted: Signal<bool>,
+
+    /// Requests the permission to receive the motion. Has to be called in response to a
+    /// user interaction.
+    pub request_permission: RequestPermissionFn,
+}
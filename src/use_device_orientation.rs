@@ -23,23 +23,55 @@ use leptos::*;
 ///     alpha,
 ///     beta,
 ///     gamma,
+///     ..
 /// } = use_device_orientation();
 /// #
 /// # view! { }
 /// # }
 /// ```
 ///
+/// ### Permission
+///
+/// Safari on iOS only reports the orientation after the user has granted the permission.
+/// It can only be requested in response to a user interaction like a click.
+/// In other browsers `permission_granted` is `true` right away and `request_permission` does nothing.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_device_orientation, UseDeviceOrientationReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseDeviceOrientationReturn {
+///     permission_granted,
+///     request_permission,
+///     ..
+/// } = use_device_orientation();
+///
+/// view! {
+///     <Show when=move || !permission_granted.get()>
+///         <button on:click={
+///             let request_permission = request_permission.clone();
+///             move |_| request_permission()
+///         }>"Enable orientation"</button>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this function returns values as if the orientation
 /// capabilties were not supported by the device.
-pub fn use_device_orientation() -> UseDeviceOrientationReturn {
+pub fn use_device_orientation() -> UseDeviceOrientationReturn<impl Fn() + Clone> {
     cfg_if! { if #[cfg(feature = "ssr")] {
         let is_supported = Signal::derive(|| false);
         let absolute = || false;
         let alpha = || None;
         let beta = || None;
         let gamma = || None;
+        let permission_granted = || false;
+        let request_permission = || ();
     } else {
         use crate::{use_event_listener_with_options, UseEventListenerOptions, use_supported, js};
         use leptos::ev::deviceorientation;
@@ -49,6 +81,15 @@ pub fn use_device_orientation() -> UseDeviceOrientationReturn {
         let (alpha, set_alpha) = create_signal(None);
         let (beta, set_beta) = create_signal(None);
         let (gamma, set_gamma) = create_signal(None);
+        let (permission_granted, set_permission_granted) =
+            create_signal(!needs_sensor_permission("DeviceOrientationEvent"));
+
+        let request_permission = move || {
+            spawn_local(async move {
+                set_permission_granted
+                    .set(request_sensor_permission("DeviceOrientationEvent").await);
+            });
+        };
 
         if is_supported.get_untracked() {
             let cleanup = use_event_listener_with_options(
@@ -76,15 +117,68 @@ pub fn use_device_orientation() -> UseDeviceOrientationReturn {
         alpha: alpha.into(),
         beta: beta.into(),
         gamma: gamma.into(),
+        permission_granted: permission_granted.into(),
+        request_permission,
+    }
+}
+
+/// Wether the sensor event class has a `requestPermission` function which is the case in
+/// Safari on iOS.
+#[cfg(not(feature = "ssr"))]
+pub(crate) fn needs_sensor_permission(class: &str) -> bool {
+    let window = window();
+
+    js_sys::Reflect::get(&window, &class.into())
+        .map(|class| crate::js!("requestPermission" in &class))
+        .unwrap_or_default()
+}
+
+/// Requests the permission to use the sensor of the given event class. Returns `true` if it
+/// has been granted or no permission is needed.
+#[cfg(not(feature = "ssr"))]
+pub(crate) async fn request_sensor_permission(class: &str) -> bool {
+    use wasm_bindgen::JsCast;
+
+    let window = window();
+
+    let Ok(class) = js_sys::Reflect::get(&window, &class.into()) else {
+        return false;
+    };
+
+    let Ok(request_permission) =
+        crate::js!(class["requestPermission"]).and_then(|f| f.dyn_into::<js_sys::Function>())
+    else {
+        return true;
+    };
+
+    match request_permission
+        .call0(&class)
+        .and_then(|promise| promise.dyn_into::<js_sys::Promise>())
+    {
+        Ok(promise) => crate::js_fut!(promise)
+            .await
+            .map(|permission| permission.as_string().as_deref() == Some("granted"))
+            .unwrap_or_default(),
+        Err(_) => false,
     }
 }
 
 /// Return type of [`use_device_orientation`].
 #[derive(Clone)]
-pub struct UseDeviceOrientationReturn {
+pub struct UseDeviceOrientationReturn<RequestPermissionFn>
+where
+    RequestPermissionFn: Fn() + Clone,
+{
     pub is_supported: Signal<bool>,
     pub absolute: Signal<bool>,
     pub alpha: Signal<Option<f64>>,
     pub beta: Signal<Option<f64>>,
     pub gamma: Signal<Option<f64>>,
+
+    /// Wether the permission to receive the orientation has been granted.
+    pub permission_granted: Signal<bool>,
+
+    /// Requests the permission to receive the orientation. Has to be called in response to a
+    /// user interaction.
+    pub request_permission: RequestPermissionFn,
 }
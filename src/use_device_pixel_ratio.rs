@@ -39,9 +39,11 @@ pub fn use_device_pixel_ratio() -> Signal<f64> {
         let (pixel_ratio, set_pixel_ratio) = create_signal(initial_pixel_ratio);
 
         create_effect(move |_| {
+            // Listen for the moment the current ratio stops matching. The effect then runs again
+            // with the new ratio and registers a new listener.
             let media = window().match_media(
                 &format!("(resolution: {}dppx)", pixel_ratio.get())
-            ).unwrap();
+            ).ok().flatten();
 
             _ = use_event_listener_with_options(
                 media,
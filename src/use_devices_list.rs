@@ -0,0 +1,277 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut, use_event_listener, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::Custom;
+use leptos::*;
+use std::rc::Rc;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{MediaDeviceInfo, MediaDeviceKind};
+
+/// Reactive [`mediaDevices.enumerateDevices`](https://developer.mozilla.org/en-US/docs/Web/API/MediaDevices/enumerateDevices)
+/// listing available input/output devices.
+///
+/// The list is refreshed whenever a device is connected or disconnected.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_devices_list)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_devices_list, UseDevicesListReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseDevicesListReturn {
+///     video_inputs,
+///     ..
+/// } = use_devices_list();
+///
+/// view! {
+///     <For
+///         each=move || video_inputs.get()
+///         key=|device| device.device_id()
+///         let:device
+///     >
+///         <p>{device.label()}</p>
+///     </For>
+/// }
+/// # }
+/// ```
+///
+/// ### Permissions
+///
+/// Browsers only reveal the labels of the devices after the user has granted the permission to
+/// access a camera or microphone. Call `ensure_permissions` or set `request_permissions` in the
+/// options to trigger the permission prompt. `permission_granted` tells you if the labels are
+/// available.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_devices_list_with_options, UseDevicesListOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let devices = use_devices_list_with_options(
+///     UseDevicesListOptions::default()
+///         .request_permissions(true)
+///         .video(false),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server all lists are empty and `ensure_permissions` does nothing.
+pub fn use_devices_list() -> UseDevicesListReturn<impl Fn() + Clone> {
+    use_devices_list_with_options(UseDevicesListOptions::default())
+}
+
+/// Version of [`use_devices_list`] that takes a `UseDevicesListOptions`. See [`use_devices_list`] for how to use.
+pub fn use_devices_list_with_options(
+    options: UseDevicesListOptions,
+) -> UseDevicesListReturn<impl Fn() + Clone> {
+    let UseDevicesListOptions {
+        request_permissions,
+        audio,
+        video,
+        on_updated,
+    } = options;
+
+    let is_supported = use_supported(|| {
+        js!("mediaDevices" in &window().navigator())
+            && window()
+                .navigator()
+                .media_devices()
+                .map(|media_devices| js!("enumerateDevices" in &media_devices))
+                .unwrap_or_default()
+    });
+
+    let (devices, set_devices) = create_signal(Vec::<MediaDeviceInfo>::new());
+    let (permission_granted, set_permission_granted) = create_signal(false);
+
+    let update = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let on_updated = Rc::clone(&on_updated);
+
+            spawn_local(async move {
+                if let Ok(new_devices) = enumerate_devices().await {
+                    if new_devices.iter().any(|device| !device.label().is_empty()) {
+                        set_permission_granted.set(true);
+                    }
+
+                    #[cfg(debug_assertions)]
+                    let prev = SpecialNonReactiveZone::enter();
+
+                    on_updated(&new_devices);
+
+                    #[cfg(debug_assertions)]
+                    SpecialNonReactiveZone::exit(prev);
+
+                    set_devices.set(new_devices);
+                }
+            });
+        }
+    };
+
+    let ensure_permissions = {
+        #[cfg(not(feature = "ssr"))]
+        let update = update.clone();
+
+        move || {
+            #[cfg(not(feature = "ssr"))]
+            {
+                if !is_supported.get_untracked() || permission_granted.get_untracked() {
+                    return;
+                }
+
+                let update = update.clone();
+
+                spawn_local(async move {
+                    if request_media_access(audio, video).await.is_ok() {
+                        set_permission_granted.set(true);
+                        update();
+                    }
+                });
+            }
+        }
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        if let Ok(media_devices) = window().navigator().media_devices() {
+            let update = update.clone();
+            let _ = use_event_listener(
+                media_devices,
+                Custom::<ev::Event>::new("devicechange"),
+                move |_| update(),
+            );
+        }
+
+        update();
+
+        if request_permissions {
+            ensure_permissions();
+        }
+    }
+
+    let of_kind = move |kind: MediaDeviceKind| {
+        Signal::derive(move || {
+            devices.with(|devices| {
+                devices
+                    .iter()
+                    .filter(|device| device.kind() == kind)
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+        })
+    };
+
+    UseDevicesListReturn {
+        is_supported,
+        devices: devices.into(),
+        audio_inputs: of_kind(MediaDeviceKind::Audioinput),
+        audio_outputs: of_kind(MediaDeviceKind::Audiooutput),
+        video_inputs: of_kind(MediaDeviceKind::Videoinput),
+        permission_granted: permission_granted.into(),
+        ensure_permissions,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+pub(crate) async fn enumerate_devices() -> Result<Vec<MediaDeviceInfo>, JsValue> {
+    let promise = window().navigator().media_devices()?.enumerate_devices()?;
+
+    let devices = js_fut!(promise).await?;
+
+    Ok(js_sys::Array::from(&devices)
+        .iter()
+        .map(|device| device.unchecked_into::<MediaDeviceInfo>())
+        .collect())
+}
+
+/// Asks for access to camera and/or microphone and stops the received stream right away.
+#[cfg(not(feature = "ssr"))]
+async fn request_media_access(audio: bool, video: bool) -> Result<(), JsValue> {
+    let mut constraints = web_sys::MediaStreamConstraints::new();
+    constraints
+        .audio(&JsValue::from(audio))
+        .video(&JsValue::from(video));
+
+    let promise = window()
+        .navigator()
+        .media_devices()?
+        .get_user_media_with_constraints(&constraints)?;
+
+    let stream = js_fut!(promise)
+        .await?
+        .unchecked_into::<web_sys::MediaStream>();
+
+    for track in stream.get_tracks() {
+        track.unchecked_ref::<web_sys::MediaStreamTrack>().stop();
+    }
+
+    Ok(())
+}
+
+/// Options for [`use_devices_list_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseDevicesListOptions {
+    /// If `true` the permission to access the devices is requested when this function is called.
+    /// Defaults to `false`.
+    request_permissions: bool,
+
+    /// Wether to request the permission for the microphone. Defaults to `true`.
+    audio: bool,
+
+    /// Wether to request the permission for the camera. Defaults to `true`.
+    video: bool,
+
+    /// Called with the new list of devices whenever it has been updated.
+    #[allow(clippy::type_complexity)]
+    on_updated: Rc<dyn Fn(&[MediaDeviceInfo])>,
+}
+
+impl Default for UseDevicesListOptions {
+    fn default() -> Self {
+        Self {
+            request_permissions: false,
+            audio: true,
+            video: true,
+            on_updated: Rc::new(|_| {}),
+        }
+    }
+}
+
+/// Return type of [`use_devices_list`].
+pub struct UseDevicesListReturn<EnsureFn>
+where
+    EnsureFn: Fn() + Clone,
+{
+    /// `true` if the browser supports `navigator.mediaDevices.enumerateDevices`.
+    pub is_supported: Signal<bool>,
+
+    /// All available media devices.
+    pub devices: Signal<Vec<MediaDeviceInfo>>,
+
+    /// Available microphones.
+    pub audio_inputs: Signal<Vec<MediaDeviceInfo>>,
+
+    /// Available speakers and headphones.
+    pub audio_outputs: Signal<Vec<MediaDeviceInfo>>,
+
+    /// Available cameras.
+    pub video_inputs: Signal<Vec<MediaDeviceInfo>>,
+
+    /// Wether the permission to access the devices has been granted and thus the labels are available.
+    pub permission_granted: Signal<bool>,
+
+    /// Requests the permission to access the devices if it hasn't been granted yet.
+    pub ensure_permissions: EnsureFn,
+}
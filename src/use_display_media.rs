@@ -2,6 +2,7 @@ use crate::core::MaybeRwSignal;
 use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
 use leptos::*;
+use std::rc::Rc;
 use wasm_bindgen::{JsCast, JsValue};
 
 /// Reactive [`mediaDevices.getDisplayMedia`](https://developer.mozilla.org/en-US/docs/Web/API/MediaDevices/getDisplayMedia) streaming.
@@ -38,6 +39,23 @@ use wasm_bindgen::{JsCast, JsValue};
 /// # }
 /// ```
 ///
+/// When the user stops sharing through the UI of the browser, the stream is stopped, `enabled`
+/// becomes `false` and the `on_ended` callback of the options is called.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_display_media_with_options, UseDisplayMediaOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let media = use_display_media_with_options(
+///     UseDisplayMediaOptions::default().on_ended(|| logging::log!("Stopped sharing")),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server calls to `start` or any other way to enable the stream will be ignored
@@ -50,7 +68,11 @@ pub fn use_display_media() -> UseDisplayMediaReturn<impl Fn() + Clone, impl Fn()
 pub fn use_display_media_with_options(
     options: UseDisplayMediaOptions,
 ) -> UseDisplayMediaReturn<impl Fn() + Clone, impl Fn() + Clone> {
-    let UseDisplayMediaOptions { enabled, audio } = options;
+    let UseDisplayMediaOptions {
+        enabled,
+        audio,
+        on_ended,
+    } = options;
 
     let (enabled, set_enabled) = enabled.into_signal();
 
@@ -64,9 +86,43 @@ pub fn use_display_media_with_options(
 
             let stream = create_media(audio).await;
 
+            if let Ok(stream) = &stream {
+                let tracks = stream.get_tracks();
+
+                let on_track_ended = wasm_bindgen::closure::Closure::<dyn Fn()>::new({
+                    let tracks = tracks.clone();
+
+                    move || {
+                        // also stop the remaining tracks like the audio track
+                        for track in tracks.iter() {
+                            track.unchecked_ref::<web_sys::MediaStreamTrack>().stop();
+                        }
+
+                        set_stream.set(None);
+                        set_enabled.set(false);
+
+                        #[cfg(debug_assertions)]
+                        let prev = SpecialNonReactiveZone::enter();
+
+                        on_ended();
+
+                        #[cfg(debug_assertions)]
+                        SpecialNonReactiveZone::exit(prev);
+                    }
+                })
+                .into_js_value();
+
+                for track in tracks.iter() {
+                    track
+                        .unchecked_ref::<web_sys::MediaStreamTrack>()
+                        .set_onended(Some(on_track_ended.unchecked_ref()));
+                }
+            }
+
             set_stream.update(|s| *s = Some(stream));
         } else {
             let _ = audio;
+            let _ = on_ended;
         }}
     };
 
@@ -80,17 +136,24 @@ pub fn use_display_media_with_options(
         set_stream.set(None);
     };
 
-    let start = move || {
-        cfg_if! { if #[cfg(not(feature = "ssr"))] {
-            spawn_local(async move {
-                _start().await;
-                stream.with_untracked(move |stream| {
-                    if let Some(Ok(_)) = stream {
-                        set_enabled.set(true);
-                    }
+    let start = {
+        #[cfg(not(feature = "ssr"))]
+        let _start = _start.clone();
+
+        move || {
+            cfg_if! { if #[cfg(not(feature = "ssr"))] {
+                let _start = _start.clone();
+
+                spawn_local(async move {
+                    _start().await;
+                    stream.with_untracked(move |stream| {
+                        if let Some(Ok(_)) = stream {
+                            set_enabled.set(true);
+                        }
+                    });
                 });
-            });
-        }}
+            }}
+        }
     };
 
     let stop = move || {
@@ -102,6 +165,10 @@ pub fn use_display_media_with_options(
         move || enabled.get(),
         move |enabled, _, _| {
             if *enabled {
+                // under `ssr` the closure is `Copy`
+                #[cfg(not(feature = "ssr"))]
+                let _start = _start.clone();
+
                 spawn_local(async move {
                     _start().await;
                 });
@@ -144,7 +211,7 @@ async fn create_media(audio: bool) -> Result<web_sys::MediaStream, JsValue> {
 
 // NOTE: there's no video value because it has to be `true`. Otherwise the stream would always resolve to an Error.
 /// Options for [`use_display_media`].
-#[derive(DefaultBuilder, Clone, Copy, Debug)]
+#[derive(DefaultBuilder, Clone)]
 pub struct UseDisplayMediaOptions {
     /// If the stream is enabled. Defaults to `false`.
     enabled: MaybeRwSignal<bool>,
@@ -153,6 +220,9 @@ pub struct UseDisplayMediaOptions {
     /// will contain an audio track, if audio is supported and available for the display surface chosen by the user.
     /// The default value is `false`.
     audio: bool,
+
+    /// Called when the stream has ended because the user stopped sharing through the UI of the browser.
+    on_ended: Rc<dyn Fn()>,
 }
 
 impl Default for UseDisplayMediaOptions {
@@ -160,6 +230,7 @@ impl Default for UseDisplayMediaOptions {
         Self {
             enabled: false.into(),
             audio: false,
+            on_ended: Rc::new(|| {}),
         }
     }
 }
@@ -0,0 +1,257 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut, use_event_listener, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::Custom;
+use leptos::*;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Reactive [Document Picture-in-Picture API](https://developer.mozilla.org/en-US/docs/Web/API/Document_Picture-in-Picture_API).
+///
+/// Opens an always-on-top window that can contain any content, not just a video.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_document_picture_in_picture)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_document_picture_in_picture_with_options, UseDocumentPictureInPictureOptions, UseDocumentPictureInPictureReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (count, set_count) = create_signal(0);
+///
+/// let UseDocumentPictureInPictureReturn {
+///     is_open,
+///     body,
+///     open,
+///     close,
+///     ..
+/// } = use_document_picture_in_picture_with_options(
+///     UseDocumentPictureInPictureOptions::default()
+///         .width(300u32)
+///         .height(200u32),
+/// );
+///
+/// view! {
+///     <button on:click=move |_| open()>"Open"</button>
+///     <button on:click=move |_| close()>"Close"</button>
+///
+///     {move || body.get().map(|body| view! {
+///         <Portal mount=web_sys::Element::from(body)>
+///             <p>"Count: " {count}</p>
+///             <button on:click=move |_| set_count.update(|count| *count += 1)>"+1"</button>
+///         </Portal>
+///     })}
+/// }
+/// # }
+/// ```
+///
+/// `open` has to be called in response to a user action like a click. Once the window is open
+/// you can render into its `body` with a [`Portal`](leptos::Portal). The content stays part of
+/// your component, so it can use the same signals.
+///
+/// By default the style sheets of the page are copied into the new window so the content looks
+/// the same. Set the option `copy_style_sheets` to `false` to prevent this.
+///
+/// The window closes when the component is cleaned up.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_open` are always `false`, `window` and `body` are
+/// always `None` and the functions do nothing.
+pub fn use_document_picture_in_picture(
+) -> UseDocumentPictureInPictureReturn<impl Fn() + Clone, impl Fn() + Clone> {
+    use_document_picture_in_picture_with_options(UseDocumentPictureInPictureOptions::default())
+}
+
+/// Version of [`use_document_picture_in_picture`] that takes a `UseDocumentPictureInPictureOptions`. See [`use_document_picture_in_picture`] for how to use.
+pub fn use_document_picture_in_picture_with_options(
+    options: UseDocumentPictureInPictureOptions,
+) -> UseDocumentPictureInPictureReturn<impl Fn() + Clone, impl Fn() + Clone> {
+    let UseDocumentPictureInPictureOptions {
+        width,
+        height,
+        disallow_return_to_opener,
+        copy_style_sheets,
+    } = options;
+
+    let is_supported = use_supported(|| js!("documentPictureInPicture" in &window()));
+
+    let (pip_window, set_pip_window) = create_signal(None::<web_sys::Window>);
+    let (error, set_error) = create_signal(None::<UseDocumentPictureInPictureError>);
+
+    let _ = use_event_listener(
+        pip_window,
+        Custom::<ev::Event>::new("pagehide"),
+        move |_| {
+            set_pip_window.set(None);
+        },
+    );
+
+    let open = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_supported.get_untracked() || pip_window.with_untracked(Option::is_some) {
+                return;
+            }
+
+            let window = window();
+
+            let request_options = js_sys::Object::new();
+            if let Some(width) = width {
+                let _ = js_sys::Reflect::set(&request_options, &"width".into(), &width.into());
+            }
+            if let Some(height) = height {
+                let _ = js_sys::Reflect::set(&request_options, &"height".into(), &height.into());
+            }
+            let _ = js_sys::Reflect::set(
+                &request_options,
+                &"disallowReturnToOpener".into(),
+                &disallow_return_to_opener.into(),
+            );
+
+            let promise = js!(window["documentPictureInPicture"]).and_then(|pip| {
+                js_sys::Reflect::get(&pip, &"requestWindow".into())?
+                    .dyn_into::<js_sys::Function>()?
+                    .call1(&pip, &request_options)?
+                    .dyn_into::<js_sys::Promise>()
+            });
+
+            let promise = match promise {
+                Ok(promise) => promise,
+                Err(err) => {
+                    set_error.set(Some(UseDocumentPictureInPictureError::Open(err)));
+                    return;
+                }
+            };
+
+            spawn_local(async move {
+                match js_fut!(promise).await {
+                    Ok(new_window) => {
+                        let new_window = new_window.unchecked_into::<web_sys::Window>();
+
+                        if copy_style_sheets {
+                            copy_style_sheets_to(&new_window);
+                        }
+
+                        set_pip_window.set(Some(new_window));
+                        set_error.set(None);
+                    }
+                    Err(err) => set_error.set(Some(UseDocumentPictureInPictureError::Open(err))),
+                }
+            });
+        }
+    };
+
+    let close = move || {
+        if let Some(pip_window) = pip_window.get_untracked() {
+            let _ = pip_window.close();
+            set_pip_window.set(None);
+        }
+    };
+
+    on_cleanup(close);
+
+    UseDocumentPictureInPictureReturn {
+        is_supported,
+        is_open: Signal::derive(move || pip_window.with(Option::is_some)),
+        window: pip_window.into(),
+        body: Signal::derive(move || {
+            pip_window
+                .get()
+                .and_then(|pip_window| pip_window.document())
+                .and_then(|document| document.body())
+        }),
+        error: error.into(),
+        open,
+        close,
+    }
+}
+
+/// Copies the `<link rel="stylesheet">` and `<style>` elements of the page into `target`.
+#[cfg(not(feature = "ssr"))]
+fn copy_style_sheets_to(target: &web_sys::Window) {
+    let Some(head) = target.document().and_then(|document| document.head()) else {
+        return;
+    };
+
+    if let Ok(style_sheets) = document().query_selector_all("link[rel=\"stylesheet\"], style") {
+        for index in 0..style_sheets.length() {
+            if let Some(copy) = style_sheets
+                .item(index)
+                .and_then(|node| node.clone_node_with_deep(true).ok())
+            {
+                let _ = head.append_child(&copy);
+            }
+        }
+    }
+}
+
+/// Options for [`use_document_picture_in_picture_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseDocumentPictureInPictureOptions {
+    /// Initial width of the window in pixels. Defaults to `None` which lets the browser decide.
+    #[builder(into)]
+    width: Option<u32>,
+
+    /// Initial height of the window in pixels. Defaults to `None` which lets the browser decide.
+    #[builder(into)]
+    height: Option<u32>,
+
+    /// Hide the button in the window that goes back to the page. Defaults to `false`.
+    disallow_return_to_opener: bool,
+
+    /// Copy the style sheets of the page into the window when it's opened. Defaults to `true`.
+    copy_style_sheets: bool,
+}
+
+impl Default for UseDocumentPictureInPictureOptions {
+    fn default() -> Self {
+        Self {
+            width: None,
+            height: None,
+            disallow_return_to_opener: false,
+            copy_style_sheets: true,
+        }
+    }
+}
+
+/// Return type of [`use_document_picture_in_picture`].
+pub struct UseDocumentPictureInPictureReturn<OpenFn, CloseFn>
+where
+    OpenFn: Fn() + Clone,
+    CloseFn: Fn() + Clone,
+{
+    /// `true` if the browser supports the Document Picture-in-Picture API.
+    pub is_supported: Signal<bool>,
+
+    /// Wether the Picture-in-Picture window is open.
+    pub is_open: Signal<bool>,
+
+    /// The Picture-in-Picture window while it's open.
+    pub window: Signal<Option<web_sys::Window>>,
+
+    /// The `<body>` of the Picture-in-Picture window while it's open. Use it as the `mount` of a
+    /// [`Portal`](leptos::Portal) to render into the window.
+    pub body: Signal<Option<web_sys::HtmlElement>>,
+
+    /// Latest error that occurred while opening the window.
+    pub error: Signal<Option<UseDocumentPictureInPictureError>>,
+
+    /// Opens the Picture-in-Picture window.
+    pub open: OpenFn,
+
+    /// Closes the Picture-in-Picture window.
+    pub close: CloseFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseDocumentPictureInPictureError {
+    #[error("failed to open the Picture-in-Picture window")]
+    Open(JsValue),
+}
@@ -0,0 +1,203 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::MaybeRwSignal;
+use crate::{use_mutation_observer_with_options, UseMutationObserverOptions};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use std::rc::Rc;
+
+/// Reactive document title.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_document_title)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::use_document_title;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (title, set_title) = use_document_title();
+///
+/// set_title.set(Some("New title".to_string())); // change the title of the document
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// When the title is `None` it is initialized with the current `document.title`. Setting it to
+/// `None` later leaves the title of the document unchanged.
+///
+/// ## Passing a Source Signal
+///
+/// You can pass a `Signal` to [`use_document_title_with_options`]. Changes of the source signal
+/// are reflected in the document title automatically.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_document_title_with_options, UseDocumentTitleOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (unread, set_unread) = create_signal(0);
+///
+/// let _ = use_document_title_with_options(
+///     UseDocumentTitleOptions::default()
+///         .title(Signal::derive(move || Some(format!("Inbox ({})", unread.get())))),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Template
+///
+/// With the option `title_template` every title is formatted before it is written to the
+/// document. The signals still contain the title without the template.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_document_title_with_options, UseDocumentTitleOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (title, set_title) = use_document_title_with_options(
+///     UseDocumentTitleOptions::default()
+///         .title_template(|title: &str| format!("{title} | My App")),
+/// );
+///
+/// set_title.set(Some("Settings".to_string())); // document.title is now "Settings | My App"
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Observing External Changes
+///
+/// If the title is changed by other code, for example a third-party script, you can enable the
+/// option `observe`. The `<title>` element is then watched with a
+/// [`MutationObserver`](https://developer.mozilla.org/en-US/docs/Web/API/MutationObserver)
+/// and the signals are updated with the new title.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_document_title_with_options, UseDocumentTitleOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (title, _) =
+///     use_document_title_with_options(UseDocumentTitleOptions::default().observe(true));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server only the signals work but the title isn't rendered. If you need the title in
+/// the server rendered HTML use the `<Title>` component of `leptos_meta`.
+///
+/// ## See also
+///
+/// * [`use_favicon`]
+pub fn use_document_title() -> (Signal<Option<String>>, WriteSignal<Option<String>>) {
+    use_document_title_with_options(UseDocumentTitleOptions::default())
+}
+
+/// Version of [`use_document_title`] that takes a `UseDocumentTitleOptions`. See [`use_document_title`] for how to use.
+pub fn use_document_title_with_options(
+    options: UseDocumentTitleOptions,
+) -> (Signal<Option<String>>, WriteSignal<Option<String>>) {
+    let UseDocumentTitleOptions {
+        title,
+        title_template,
+        observe,
+    } = options;
+
+    let (title, set_title) = title.into_signal();
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        if title.get_untracked().is_none() {
+            set_title.set(Some(document().title()));
+        }
+
+        // set when the title was changed externally so it isn't written back to the document
+        let is_external_change = store_value(false);
+
+        let _ = watch(
+            move || title.get(),
+            {
+                let title_template = Rc::clone(&title_template);
+
+                move |title: &Option<String>, _, _| {
+                    if is_external_change.get_value() {
+                        is_external_change.set_value(false);
+                        return;
+                    }
+
+                    if let Some(title) = title {
+                        let title = title_template(title);
+
+                        if document().title() != title {
+                            document().set_title(&title);
+                        }
+                    }
+                }
+            },
+            true,
+        );
+
+        if observe {
+            let (title_element, set_title_element) = create_signal(None::<web_sys::Element>);
+
+            let _ = use_mutation_observer_with_options(
+                title_element,
+                move |_, _| {
+                    let document_title = document().title();
+
+                    let current_title = title.get_untracked().map(|title| title_template(&title));
+
+                    if current_title.as_ref() != Some(&document_title) {
+                        is_external_change.set_value(true);
+                        set_title.set(Some(document_title));
+                    }
+                },
+                UseMutationObserverOptions::default().child_list(true),
+            );
+
+            // Querying the element after the title has been written above makes sure it exists.
+            set_title_element.set(document().query_selector("title").ok().flatten());
+        }
+    }
+
+    (title, set_title)
+}
+
+/// Options for [`use_document_title_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseDocumentTitleOptions {
+    /// The title of the document. Can be a `RwSignal` in which case updates will change the title.
+    /// Defaults to `None` which keeps the current title.
+    #[builder(into)]
+    title: MaybeRwSignal<Option<String>>,
+
+    /// Formats the title before it is written to the document. Defaults to the title itself.
+    title_template: Rc<dyn Fn(&str) -> String>,
+
+    /// Update the title when the `<title>` element is changed by other code. Defaults to `false`.
+    observe: bool,
+}
+
+impl Default for UseDocumentTitleOptions {
+    fn default() -> Self {
+        Self {
+            title: Default::default(),
+            title_template: Rc::new(|title| title.to_string()),
+            observe: false,
+        }
+    }
+}
@@ -25,17 +25,50 @@ use leptos::*;
 /// # }
 /// ```
 ///
+/// The signal is updated whenever the user switches to another tab or minimizes the window.
+/// You can use that to pause work that isn't visible anyway, like polling or animations.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_document_visibility, use_interval_fn};
+/// # use leptos_use::utils::Pausable;
+/// # use web_sys::VisibilityState;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let visibility = use_document_visibility();
+///
+/// let Pausable { pause, resume, .. } = use_interval_fn(
+///     || {
+///         // poll the server
+///     },
+///     5000,
+/// );
+///
+/// let _ = watch(
+///     move || visibility.get(),
+///     move |visibility, _, _| match visibility {
+///         VisibilityState::Hidden => pause(),
+///         _ => resume(),
+///     },
+///     false,
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this returns a `Signal` that always contains the value `web_sys::VisibilityState::Hidden`.
 pub fn use_document_visibility() -> Signal<web_sys::VisibilityState> {
     cfg_if! { if #[cfg(feature = "ssr")] {
-        let inital_visibility = web_sys::VisibilityState::Hidden;
+        let initial_visibility = web_sys::VisibilityState::Hidden;
     } else {
-        let inital_visibility = document().visibility_state();
+        let initial_visibility = document().visibility_state();
     }}
 
-    let (visibility, set_visibility) = create_signal(inital_visibility);
+    let (visibility, set_visibility) = create_signal(initial_visibility);
 
     cfg_if! { if #[cfg(not(feature = "ssr"))] {
         let _ = use_event_listener(document(), visibilitychange, move |_| {
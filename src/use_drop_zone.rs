@@ -8,6 +8,8 @@ use std::rc::Rc;
 cfg_if! { if #[cfg(not(feature = "ssr"))] {
     use crate::use_event_listener;
     use leptos::ev::{dragenter, dragleave, dragover, drop};
+    use std::collections::VecDeque;
+    use wasm_bindgen::JsValue;
 }}
 
 /// Create a zone where files can be dropped.
@@ -47,6 +49,40 @@ cfg_if! { if #[cfg(not(feature = "ssr"))] {
 /// # }
 /// ```
 ///
+/// ### Allowed Types
+///
+/// With `data_types` you can restrict the mime types of the files that can be dropped.
+/// Wildcards like `"image/*"` are supported. Files of other types are ignored and while only
+/// such files are dragged over the zone, `is_over_drop_zone` stays `false` and the browser shows
+/// that they can't be dropped.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::html::Div;
+/// # use leptos_use::{use_drop_zone_with_options, UseDropZoneOptions, UseDropZoneReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let drop_zone_el = create_node_ref::<Div>();
+///
+/// let UseDropZoneReturn { files, .. } = use_drop_zone_with_options(
+///     drop_zone_el,
+///     UseDropZoneOptions::default()
+///         .data_types(vec!["image/*".to_string(), "application/pdf".to_string()])
+///         .directory(true),
+/// );
+/// #
+/// # view! { <div node_ref=drop_zone_el></div> }
+/// # }
+/// ```
+///
+/// ### Directories
+///
+/// If `directory` is `true`, dropped directories are traversed recursively with
+/// [`webkitGetAsEntry`](https://developer.mozilla.org/en-US/docs/Web/API/DataTransferItem/webkitGetAsEntry)
+/// and `files` contains all the files inside of them. Because reading the directories is
+/// asynchronous, `files` is updated and `on_drop` is called once all of them have been read.
+///
 /// ## Server-Side Rendering
 ///
 /// On the server the returned `file` signal always contains an empty `Vec` and
@@ -81,8 +117,12 @@ where
             on_enter,
             on_leave,
             on_over,
+            data_types,
+            directory,
         } = options;
 
+        let data_types = Signal::derive(move || data_types.get());
+
         let counter = store_value(0_usize);
 
         let update_files = move |event: &web_sys::DragEvent| {
@@ -93,16 +133,46 @@ where
                     .unwrap_or_default()
                     .into_iter()
                     .map(web_sys::File::from)
+                    .filter(|file| {
+                        data_types.with_untracked(|types| is_type_allowed(&file.type_(), types))
+                    })
                     .collect();
 
                 set_files.update(move |f| *f = files);
             }
         };
 
+        // While dragging only the types of the items are available, not the files themselves.
+        let is_drag_allowed = move |event: &web_sys::DragEvent| {
+            let Some(data_transfer) = event.data_transfer() else {
+                return true;
+            };
+
+            let items = data_transfer.items();
+            let allowed = data_types.with_untracked(|types| {
+                types.is_empty()
+                    || (0..items.length())
+                        .filter_map(|i| items.get(i))
+                        .filter(|item| item.kind() == "file")
+                        .any(|item| {
+                            // directories have no type
+                            let item_type = item.type_();
+                            (directory && item_type.is_empty())
+                                || is_type_allowed(&item_type, types)
+                        })
+            });
+
+            if !allowed {
+                data_transfer.set_drop_effect("none");
+            }
+
+            allowed
+        };
+
         let _ = use_event_listener(target.clone(), dragenter, move |event| {
             event.prevent_default();
             counter.update_value(|counter| *counter += 1);
-            set_over_drop_zone.set(true);
+            set_over_drop_zone.set(is_drag_allowed(&event));
 
             update_files(&event);
 
@@ -120,6 +190,7 @@ where
 
         let _ = use_event_listener(target.clone(), dragover, move |event| {
             event.prevent_default();
+            is_drag_allowed(&event);
             update_files(&event);
 
             #[cfg(debug_assertions)]
@@ -160,18 +231,54 @@ where
             counter.update_value(|counter| *counter = 0);
             set_over_drop_zone.set(false);
 
-            update_files(&event);
+            let call_on_drop = {
+                let on_drop = Rc::clone(&on_drop);
 
-            #[cfg(debug_assertions)]
-            let prev = SpecialNonReactiveZone::enter();
+                move |event| {
+                    #[cfg(debug_assertions)]
+                    let prev = SpecialNonReactiveZone::enter();
 
-            on_drop(UseDropZoneEvent {
-                files: files.get_untracked(),
-                event,
-            });
+                    on_drop(UseDropZoneEvent {
+                        files: files.get_untracked(),
+                        event,
+                    });
 
-            #[cfg(debug_assertions)]
-            SpecialNonReactiveZone::exit(prev);
+                    #[cfg(debug_assertions)]
+                    SpecialNonReactiveZone::exit(prev);
+                }
+            };
+
+            if !directory {
+                update_files(&event);
+                call_on_drop(event);
+                return;
+            }
+
+            // The entries are only accessible during the event so they are collected right away.
+            let entries = event
+                .data_transfer()
+                .map(|data_transfer| {
+                    let items = data_transfer.items();
+
+                    (0..items.length())
+                        .filter_map(|i| items.get(i))
+                        .filter_map(|item| item.webkit_get_as_entry().ok().flatten())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+
+            spawn_local(async move {
+                let dropped = read_entries(entries)
+                    .await
+                    .into_iter()
+                    .filter(|file| {
+                        data_types.with_untracked(|types| is_type_allowed(&file.type_(), types))
+                    })
+                    .collect::<Vec<_>>();
+
+                set_files.set(dropped);
+                call_on_drop(event);
+            });
         });
     }
 
@@ -181,6 +288,70 @@ where
     }
 }
 
+/// Reads the files of the given entries and of all the directories among them recursively.
+#[cfg(not(feature = "ssr"))]
+async fn read_entries(entries: Vec<web_sys::FileSystemEntry>) -> Vec<web_sys::File> {
+    use crate::js_fut;
+    use wasm_bindgen::JsCast;
+
+    let mut queue = VecDeque::from(entries);
+    let mut files = vec![];
+
+    while let Some(entry) = queue.pop_front() {
+        if entry.is_file() {
+            let entry = entry.unchecked_into::<web_sys::FileSystemFileEntry>();
+            let promise = js_sys::Promise::new(&mut |resolve, reject| {
+                entry.file_with_callback_and_callback(&resolve, &reject);
+            });
+
+            if let Ok(file) = js_fut!(promise).await {
+                files.push(file.unchecked_into());
+            }
+        } else if entry.is_directory() {
+            let reader = entry
+                .unchecked_into::<web_sys::FileSystemDirectoryEntry>()
+                .create_reader();
+
+            // `readEntries` only returns a part of the entries per call
+            loop {
+                let promise = js_sys::Promise::new(&mut |resolve, reject| {
+                    if let Err(err) =
+                        reader.read_entries_with_callback_and_callback(&resolve, &reject)
+                    {
+                        let _ = reject.call1(&JsValue::NULL, &err);
+                    }
+                });
+
+                let Ok(batch) = js_fut!(promise).await else {
+                    break;
+                };
+                let batch = batch.unchecked_into::<js_sys::Array>();
+
+                if batch.length() == 0 {
+                    break;
+                }
+
+                queue.extend(batch.iter().map(|entry| entry.unchecked_into()));
+            }
+        }
+    }
+
+    files
+}
+
+/// Checks if `mime_type` matches one of `allowed`. Entries can end in a wildcard like `image/*`.
+/// If `allowed` is empty every type matches.
+#[cfg_attr(feature = "ssr", allow(dead_code))]
+fn is_type_allowed(mime_type: &str, allowed: &[String]) -> bool {
+    allowed.is_empty()
+        || allowed
+            .iter()
+            .any(|allowed| match allowed.strip_suffix('*') {
+                Some(prefix) => mime_type.starts_with(prefix),
+                None => mime_type == allowed,
+            })
+}
+
 /// Options for [`use_drop_zone_with_options`].
 #[derive(DefaultBuilder, Clone)]
 #[cfg_attr(feature = "ssr", allow(dead_code))]
@@ -193,6 +364,13 @@ pub struct UseDropZoneOptions {
     on_leave: Rc<dyn Fn(UseDropZoneEvent)>,
     /// Event handler for the [`dragover`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/dragover_event) event
     on_over: Rc<dyn Fn(UseDropZoneEvent)>,
+    /// Mime types of the files that can be dropped like `"image/png"` or `"image/*"`.
+    /// Defaults to an empty `Vec` which allows all types.
+    #[builder(into)]
+    data_types: MaybeSignal<Vec<String>>,
+    /// Traverse dropped directories recursively and provide all the files inside of them.
+    /// Defaults to `false`.
+    directory: bool,
 }
 
 impl Default for UseDropZoneOptions {
@@ -202,6 +380,8 @@ impl Default for UseDropZoneOptions {
             on_enter: Rc::new(|_| {}),
             on_leave: Rc::new(|_| {}),
             on_over: Rc::new(|_| {}),
+            data_types: MaybeSignal::default(),
+            directory: false,
         }
     }
 }
@@ -228,3 +408,19 @@ pub struct UseDropZoneReturn {
     /// Whether the files (dragged by the pointer) are over the drop zone
     pub is_over_drop_zone: Signal<bool>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_type_allowed() {
+        let allowed = vec!["image/*".to_string(), "application/pdf".to_string()];
+
+        assert!(is_type_allowed("image/png", &allowed));
+        assert!(is_type_allowed("application/pdf", &allowed));
+        assert!(!is_type_allowed("application/json", &allowed));
+        assert!(!is_type_allowed("", &allowed));
+        assert!(is_type_allowed("text/plain", &[]));
+    }
+}
@@ -0,0 +1,282 @@
+use leptos::*;
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Typed event bus to send fire-and-forget events between components that don't share signals.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_event_bus)
+///
+/// ## Usage
+///
+/// All calls with the same `key` and the same event type `T` share one bus.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::use_event_bus;
+/// #
+/// #[derive(Clone, Debug)]
+/// enum CartEvent {
+///     Added(u32),
+///     Cleared,
+/// }
+///
+/// #[component]
+/// fn AddButton() -> impl IntoView {
+///     let bus = use_event_bus::<CartEvent>("cart");
+///
+///     view! {
+///         <button on:click=move |_| bus.emit(CartEvent::Added(42))>"Add to cart"</button>
+///     }
+/// }
+///
+/// #[component]
+/// fn CartBadge() -> impl IntoView {
+///     let (count, set_count) = create_signal(0);
+///
+///     let bus = use_event_bus::<CartEvent>("cart");
+///
+///     // Stops listening automatically when this component is disposed.
+///     bus.on(move |event: &CartEvent| match event {
+///         CartEvent::Added(_) => set_count.update(|count| *count += 1),
+///         CartEvent::Cleared => set_count.set(0),
+///     });
+///
+///     view! { <span>{count}</span> }
+/// }
+/// ```
+///
+/// `on` and `once` return a function that stops listening early. `reset` removes all the
+/// listeners of the bus.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::use_event_bus;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let bus = use_event_bus::<String>("notifications");
+///
+/// let stop = bus.on(|message: &String| logging::log!("{message}"));
+///
+/// bus.once(|message: &String| logging::log!("First message: {message}"));
+///
+/// bus.emit("Hello".to_string()); // logs twice
+/// bus.emit("World".to_string()); // logs once
+///
+/// stop();
+///
+/// bus.emit("Nobody listens".to_string());
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Scoping
+///
+/// By default the buses live in a global registry. Call [`provide_event_bus`] in a component to
+/// give it and its children their own registry. Buses used outside of it are not affected.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::provide_event_bus;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// provide_event_bus();
+///
+/// // every `use_event_bus` in here and in its children only sees events emitted in here
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the global registry is shared by all requests that are handled by the same
+/// thread. Call [`provide_event_bus`] in your root component to have a registry per request.
+pub fn use_event_bus<T: 'static>(key: impl Into<String>) -> UseEventBusReturn<T> {
+    let registry = use_context::<EventBusRegistry>()
+        .unwrap_or_else(|| GLOBAL_EVENT_BUS.with(|registry| registry.clone()));
+
+    UseEventBusReturn {
+        listeners: registry.listeners(key.into()),
+    }
+}
+
+/// Provides a new event bus registry to this component and its children.
+/// See [`use_event_bus`] for details.
+pub fn provide_event_bus() {
+    provide_context(EventBusRegistry::default());
+}
+
+thread_local! {
+    static GLOBAL_EVENT_BUS: EventBusRegistry = EventBusRegistry::default();
+}
+
+type BusKey = (String, TypeId);
+type Callback<T> = Rc<dyn Fn(&T)>;
+
+/// Registry of the event buses, keyed by the name and the event type.
+#[derive(Clone, Default)]
+struct EventBusRegistry {
+    buses: Rc<RefCell<HashMap<BusKey, Rc<dyn Any>>>>,
+}
+
+impl EventBusRegistry {
+    fn listeners<T: 'static>(&self, key: String) -> Rc<RefCell<Listeners<T>>> {
+        let bus = Rc::clone(
+            self.buses
+                .borrow_mut()
+                .entry((key, TypeId::of::<T>()))
+                .or_insert_with(|| Rc::new(RefCell::new(Listeners::<T>::default()))),
+        );
+
+        bus.downcast::<RefCell<Listeners<T>>>()
+            .expect("buses are keyed by their event type")
+    }
+}
+
+struct Listener<T> {
+    id: usize,
+    callback: Callback<T>,
+    once: bool,
+}
+
+struct Listeners<T> {
+    next_id: usize,
+    listeners: Vec<Listener<T>>,
+}
+
+impl<T> Default for Listeners<T> {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            listeners: Vec::new(),
+        }
+    }
+}
+
+impl<T> Listeners<T> {
+    fn add(&mut self, callback: Callback<T>, once: bool) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.listeners.push(Listener { id, callback, once });
+
+        id
+    }
+
+    fn remove(&mut self, id: usize) {
+        self.listeners.retain(|listener| listener.id != id);
+    }
+
+    /// Returns the callbacks to call for an emitted event and removes the `once` listeners.
+    fn take_for_emit(&mut self) -> Vec<Callback<T>> {
+        let callbacks = self
+            .listeners
+            .iter()
+            .map(|listener| Rc::clone(&listener.callback))
+            .collect();
+
+        self.listeners.retain(|listener| !listener.once);
+
+        callbacks
+    }
+}
+
+/// Return type of [`use_event_bus`].
+pub struct UseEventBusReturn<T: 'static> {
+    listeners: Rc<RefCell<Listeners<T>>>,
+}
+
+impl<T: 'static> Clone for UseEventBusReturn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            listeners: Rc::clone(&self.listeners),
+        }
+    }
+}
+
+impl<T: 'static> UseEventBusReturn<T> {
+    /// Calls all the listeners of the bus with `event`.
+    pub fn emit(&self, event: T) {
+        // release the borrow first so listeners can emit or subscribe themselves
+        let callbacks = self.listeners.borrow_mut().take_for_emit();
+
+        for callback in callbacks {
+            callback(&event);
+        }
+    }
+
+    /// Calls `listener` for every emitted event until the current scope is disposed or the
+    /// returned function is called.
+    pub fn on(&self, listener: impl Fn(&T) + 'static) -> impl Fn() + Clone {
+        self.subscribe(Rc::new(listener), false)
+    }
+
+    /// Like [`UseEventBusReturn::on`] but stops listening after the first event.
+    pub fn once(&self, listener: impl Fn(&T) + 'static) -> impl Fn() + Clone {
+        self.subscribe(Rc::new(listener), true)
+    }
+
+    /// Removes all the listeners of the bus.
+    pub fn reset(&self) {
+        self.listeners.borrow_mut().listeners.clear();
+    }
+
+    fn subscribe(&self, listener: Callback<T>, once: bool) -> impl Fn() + Clone {
+        let id = self.listeners.borrow_mut().add(listener, once);
+
+        let listeners = Rc::downgrade(&self.listeners);
+        let stop = move || {
+            if let Some(listeners) = listeners.upgrade() {
+                listeners.borrow_mut().remove(id);
+            }
+        };
+
+        on_cleanup(stop.clone());
+
+        stop
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_listeners() {
+        let mut listeners = Listeners::<u32>::default();
+
+        let always = listeners.add(Rc::new(|_| {}), false);
+        listeners.add(Rc::new(|_| {}), true);
+
+        assert_eq!(listeners.take_for_emit().len(), 2);
+        assert_eq!(listeners.take_for_emit().len(), 1);
+
+        listeners.remove(always);
+
+        assert_eq!(listeners.take_for_emit().len(), 0);
+    }
+
+    #[test]
+    fn test_registry_is_keyed_by_name_and_type() {
+        let registry = EventBusRegistry::default();
+
+        let a = registry.listeners::<u32>("a".to_string());
+        let b = registry.listeners::<u32>("b".to_string());
+        let a_string = registry.listeners::<String>("a".to_string());
+
+        assert!(Rc::ptr_eq(&a, &registry.listeners::<u32>("a".to_string())));
+        assert!(!Rc::ptr_eq(&a, &b));
+
+        a.borrow_mut().add(Rc::new(|_| {}), false);
+
+        assert_eq!(b.borrow().listeners.len(), 0);
+        assert_eq!(a_string.borrow().listeners.len(), 0);
+    }
+}
@@ -1,9 +1,12 @@
-use crate::core::ConnectionReadyState;
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::{ConnectionReadyState, RetryDelay};
 use crate::utils::StringCodec;
 use crate::{js, use_event_listener};
 use default_struct_builder::DefaultBuilder;
 use leptos::*;
 use std::cell::Cell;
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::rc::Rc;
 use std::time::Duration;
@@ -16,6 +19,10 @@ use thiserror::Error;
 /// instance opens a persistent connection to an HTTP server,
 /// which sends events in text/event-stream format.
 ///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_event_source)
+///
 /// ## Usage
 ///
 /// Values are decoded via the given [`Codec`].
@@ -70,6 +77,28 @@ use thiserror::Error;
 /// # }
 /// ```
 ///
+/// The latest data of every named event is also available separately in `named_data` while
+/// `data` always contains the latest data of any event.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_event_source_with_options, UseEventSourceReturn, UseEventSourceOptions, utils::FromToStringCodec};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseEventSourceReturn { named_data, .. } = use_event_source_with_options::<String, FromToStringCodec>(
+///     "https://event-source-url",
+///     UseEventSourceOptions::default()
+///         .named_events(["notice".to_string(), "update".to_string()])
+/// );
+///
+/// let notice = named_data["notice"];
+/// let update = named_data["update"];
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ### Immediate
 ///
 /// Auto-connect (enabled by default).
@@ -104,13 +133,53 @@ use thiserror::Error;
 ///
 /// To disable auto-reconnection, set `reconnect_limit` to `0`.
 ///
+/// Instead of a fixed interval you can also use an exponential backoff like
+/// `.reconnect_interval(RetryDelay::exponential(1000))`.
+///
+/// ### Last Event ID, Pause and Resume
+///
+/// The id of the last received event is available as `last_event_id`. While the browser sends it
+/// automatically as `Last-Event-ID` header when it reconnects by itself, this is not possible
+/// for new connections. Set `last_event_id_param` to have it appended to the url as query
+/// parameter when reconnecting, so your server can continue after the last received event.
+///
+/// With `pause` and `resume` you can temporarily close the connection and continue later.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_event_source_with_options, UseEventSourceReturn, UseEventSourceOptions, utils::FromToStringCodec};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseEventSourceReturn {
+///     last_event_id, pause, resume, ..
+/// } = use_event_source_with_options::<String, FromToStringCodec>(
+///     "https://event-source-url",
+///     UseEventSourceOptions::default().last_event_id_param("lastEventId"),
+/// );
+///
+/// // resuming connects to `https://event-source-url?lastEventId=<id>`
+/// pause();
+/// resume();
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server-side, `use_event_source` will always return `ready_state` as `ConnectionReadyState::Closed`,
 /// `data`, `event` and `error` will always be `None`, and `open` and `close` will do nothing.
 pub fn use_event_source<T, C>(
     url: &str,
-) -> UseEventSourceReturn<T, C::Error, impl Fn() + Clone + 'static, impl Fn() + Clone + 'static>
+) -> UseEventSourceReturn<
+    T,
+    C::Error,
+    impl Fn() + Clone + 'static,
+    impl Fn() + Clone + 'static,
+    impl Fn() + Clone + 'static,
+    impl Fn() + Clone + 'static,
+>
 where
     T: Clone + PartialEq + 'static,
     C: StringCodec<T> + Default,
@@ -122,7 +191,14 @@ where
 pub fn use_event_source_with_options<T, C>(
     url: &str,
     options: UseEventSourceOptions<T, C>,
-) -> UseEventSourceReturn<T, C::Error, impl Fn() + Clone + 'static, impl Fn() + Clone + 'static>
+) -> UseEventSourceReturn<
+    T,
+    C::Error,
+    impl Fn() + Clone + 'static,
+    impl Fn() + Clone + 'static,
+    impl Fn() + Clone + 'static,
+    impl Fn() + Clone + 'static,
+>
 where
     T: Clone + PartialEq + 'static,
     C: StringCodec<T> + Default,
@@ -135,6 +211,7 @@ where
         immediate,
         named_events,
         with_credentials,
+        last_event_id_param,
         _marker,
     } = options;
 
@@ -145,17 +222,32 @@ where
     let (ready_state, set_ready_state) = create_signal(ConnectionReadyState::Closed);
     let (event_source, set_event_source) = create_signal(None::<web_sys::EventSource>);
     let (error, set_error) = create_signal(None::<UseEventSourceError<C::Error>>);
+    let (last_event_id, set_last_event_id) = create_signal(None::<String>);
+    let (is_paused, set_paused) = create_signal(false);
 
     let explicitly_closed = Rc::new(Cell::new(false));
     let retried = Rc::new(Cell::new(0));
 
+    let mut named_data = HashMap::<String, Signal<Option<T>>>::new();
+    let mut set_named_data = HashMap::new();
+    for name in &named_events {
+        let (data, set_data) = create_signal(None::<T>);
+        named_data.insert(name.clone(), data.into());
+        set_named_data.insert(name.clone(), set_data);
+    }
+
     let set_data_from_string = move |data_string: Option<String>| {
         if let Some(data_string) = data_string {
             match codec.decode(data_string) {
-                Ok(data) => set_data.set(Some(data)),
+                Ok(data) => {
+                    set_data.set(Some(data.clone()));
+                    return Some(data);
+                }
                 Err(err) => set_error.set(Some(UseEventSourceError::Deserialize(err))),
             }
         }
+
+        None
     };
 
     let close = {
@@ -171,13 +263,20 @@ where
         }
     };
 
-    let init = store_value(None::<Rc<dyn Fn()>>);
+    let update_last_event_id = move |id: Option<String>| {
+        if let Some(id) = id.filter(|id| !id.is_empty()) {
+            set_last_event_id.set(Some(id));
+        }
+    };
+
+    // The parameter is `true` if the connection should continue after the last received event
+    let init = store_value(None::<Rc<dyn Fn(bool)>>);
 
     init.set_value(Some(Rc::new({
         let explicitly_closed = Rc::clone(&explicitly_closed);
         let retried = Rc::clone(&retried);
 
-        move || {
+        move |resume: bool| {
             use wasm_bindgen::prelude::*;
 
             if explicitly_closed.get() {
@@ -187,6 +286,15 @@ where
             let mut event_src_opts = web_sys::EventSourceInit::new();
             event_src_opts.with_credentials(with_credentials);
 
+            let url = match (&last_event_id_param, last_event_id.get_untracked()) {
+                (Some(param), Some(id)) if resume => append_query_param(
+                    &url,
+                    &String::from(js_sys::encode_uri_component(param)),
+                    &String::from(js_sys::encode_uri_component(&id)),
+                ),
+                _ => url.clone(),
+            };
+
             let es = web_sys::EventSource::new_with_event_source_init_dict(&url, &event_src_opts)
                 .unwrap_throw();
 
@@ -194,9 +302,14 @@ where
 
             set_event_source.set(Some(es.clone()));
 
-            let on_open = Closure::wrap(Box::new(move |_: web_sys::Event| {
-                set_ready_state.set(ConnectionReadyState::Open);
-                set_error.set(None);
+            let on_open = Closure::wrap(Box::new({
+                let retried = Rc::clone(&retried);
+
+                move |_: web_sys::Event| {
+                    retried.set(0);
+                    set_ready_state.set(ConnectionReadyState::Open);
+                    set_error.set(None);
+                }
             }) as Box<dyn FnMut(web_sys::Event)>);
             es.set_onopen(Some(on_open.as_ref().unchecked_ref()));
             on_open.forget();
@@ -222,10 +335,13 @@ where
                             set_timeout(
                                 move || {
                                     if let Some(init) = init.get_value() {
-                                        init();
+                                        init(true);
                                     }
                                 },
-                                Duration::from_millis(reconnect_interval),
+                                Duration::from_millis(
+                                    reconnect_interval
+                                        .delay(retried.get().min(u32::MAX as u64) as u32),
+                                ),
                             );
                         } else {
                             #[cfg(debug_assertions)]
@@ -246,6 +362,7 @@ where
                 let set_data_from_string = set_data_from_string.clone();
 
                 move |e: web_sys::MessageEvent| {
+                    update_last_event_id(Some(e.last_event_id()));
                     set_data_from_string(e.data().as_string());
                 }
             }) as Box<dyn FnMut(web_sys::MessageEvent)>);
@@ -254,14 +371,22 @@ where
 
             for event_name in named_events.clone() {
                 let set_data_from_string = set_data_from_string.clone();
+                let set_named_data = set_named_data.get(&event_name).copied();
 
                 let _ = use_event_listener(
                     es.clone(),
                     ev::Custom::<ev::Event>::new(event_name),
                     move |e| {
                         set_event.set(Some(e.clone()));
+                        update_last_event_id(
+                            js!(e["lastEventId"]).ok().and_then(|id| id.as_string()),
+                        );
                         let data_string = js!(e["data"]).ok().and_then(|d| d.as_string());
-                        set_data_from_string(data_string);
+                        let data = set_data_from_string(data_string);
+
+                        if let (Some(data), Some(set_named_data)) = (data, set_named_data) {
+                            set_named_data.set(Some(data));
+                        }
                     },
                 );
             }
@@ -269,6 +394,8 @@ where
     })));
 
     let open;
+    let pause;
+    let resume;
 
     #[cfg(not(feature = "ssr"))]
     {
@@ -279,10 +406,41 @@ where
 
             move || {
                 close();
+                set_paused.set(false);
+                explicitly_closed.set(false);
+                retried.set(0);
+                if let Some(init) = init.get_value() {
+                    init(false);
+                }
+            }
+        };
+
+        pause = {
+            let close = close.clone();
+            let explicitly_closed = Rc::clone(&explicitly_closed);
+
+            move || {
+                close();
+                // also stops a pending reconnect
+                explicitly_closed.set(true);
+                set_paused.set(true);
+            }
+        };
+
+        resume = {
+            let explicitly_closed = Rc::clone(&explicitly_closed);
+            let retried = Rc::clone(&retried);
+
+            move || {
+                if !is_paused.get_untracked() {
+                    return;
+                }
+
+                set_paused.set(false);
                 explicitly_closed.set(false);
                 retried.set(0);
                 if let Some(init) = init.get_value() {
-                    init();
+                    init(true);
                 }
             }
         };
@@ -291,6 +449,8 @@ where
     #[cfg(feature = "ssr")]
     {
         open = move || {};
+        pause = move || {};
+        resume = move || {};
     }
 
     if immediate {
@@ -303,10 +463,15 @@ where
         event_source: event_source.into(),
         event: event.into(),
         data: data.into(),
+        named_data,
         ready_state: ready_state.into(),
         error: error.into(),
+        last_event_id: last_event_id.into(),
+        is_paused: is_paused.into(),
         open,
         close,
+        pause,
+        resume,
     }
 }
 
@@ -323,8 +488,10 @@ where
     /// Retry times. Defaults to 3.
     reconnect_limit: u64,
 
-    /// Retry interval in ms. Defaults to 3000.
-    reconnect_interval: u64,
+    /// Delay before a reconnect attempt. Can be a fixed interval in ms or an exponential
+    /// backoff. Defaults to a fixed interval of 3000 ms.
+    #[builder(into)]
+    reconnect_interval: RetryDelay,
 
     /// On maximum retry times reached.
     on_failed: Rc<dyn Fn()>,
@@ -341,6 +508,12 @@ where
     /// If CORS should be set to `include` credentials. Defaults to `false`.
     with_credentials: bool,
 
+    /// `EventSource` doesn't allow to set the `Last-Event-ID` header when a new connection is
+    /// created. If set, the id of the last received event is appended to the url as a query
+    /// parameter with this name when reconnecting or resuming. Defaults to `None`.
+    #[builder(into)]
+    last_event_id_param: Option<String>,
+
     _marker: PhantomData<T>,
 }
 
@@ -349,27 +522,33 @@ impl<T, C: StringCodec<T> + Default> Default for UseEventSourceOptions<T, C> {
         Self {
             codec: C::default(),
             reconnect_limit: 3,
-            reconnect_interval: 3000,
+            reconnect_interval: RetryDelay::Fixed(3000),
             on_failed: Rc::new(|| {}),
             immediate: true,
             named_events: vec![],
             with_credentials: false,
+            last_event_id_param: None,
             _marker: PhantomData,
         }
     }
 }
 
 /// Return type of [`use_event_source`].
-pub struct UseEventSourceReturn<T, Err, OpenFn, CloseFn>
+pub struct UseEventSourceReturn<T, Err, OpenFn, CloseFn, PauseFn, ResumeFn>
 where
     Err: 'static,
     T: Clone + 'static,
     OpenFn: Fn() + Clone + 'static,
     CloseFn: Fn() + Clone + 'static,
+    PauseFn: Fn() + Clone + 'static,
+    ResumeFn: Fn() + Clone + 'static,
 {
     /// Latest data received via the `EventSource`
     pub data: Signal<Option<T>>,
 
+    /// Latest data received per named event. Contains an entry for every name in `named_events`.
+    pub named_data: HashMap<String, Signal<Option<T>>>,
+
     /// The current state of the connection,
     pub ready_state: Signal<ConnectionReadyState>,
 
@@ -386,10 +565,28 @@ where
     /// Closes the `EventSource` connection
     pub close: CloseFn,
 
+    /// The id of the last received event that had an id
+    pub last_event_id: Signal<Option<String>>,
+
+    /// `true` while the connection is paused
+    pub is_paused: Signal<bool>,
+
+    /// Closes the connection but keeps the last event id so the stream can be resumed
+    pub pause: PauseFn,
+
+    /// Reopens a paused connection continuing after the last received event.
+    /// See the option `last_event_id_param`.
+    pub resume: ResumeFn,
+
     /// The `EventSource` instance
     pub event_source: Signal<Option<web_sys::EventSource>>,
 }
 
+fn append_query_param(url: &str, name: &str, value: &str) -> String {
+    let separator = if url.contains('?') { '&' } else { '?' };
+    format!("{url}{separator}{name}={value}")
+}
+
 #[derive(Error, Debug)]
 pub enum UseEventSourceError<Err> {
     #[error("Error event: {0:?}")]
@@ -398,3 +595,20 @@ pub enum UseEventSourceError<Err> {
     #[error("Error decoding value")]
     Deserialize(Err),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_query_param() {
+        assert_eq!(
+            append_query_param("https://a.com/sse", "lastEventId", "42"),
+            "https://a.com/sse?lastEventId=42"
+        );
+        assert_eq!(
+            append_query_param("https://a.com/sse?topic=news", "lastEventId", "42"),
+            "https://a.com/sse?topic=news&lastEventId=42"
+        );
+    }
+}
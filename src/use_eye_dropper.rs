@@ -0,0 +1,118 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use wasm_bindgen::JsCast;
+
+/// Reactive [EyeDropper API](https://developer.mozilla.org/en-US/docs/Web/API/EyeDropper_API).
+///
+/// Lets the user pick a color from anywhere on the screen.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_eye_dropper)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_eye_dropper, UseEyeDropperReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseEyeDropperReturn {
+///     is_supported,
+///     srgb_hex,
+///     open,
+/// } = use_eye_dropper();
+///
+/// view! {
+///     <button on:click=move |_| open() disabled=move || !is_supported.get()>"Pick color"</button>
+///     <div style:background-color=srgb_hex>{srgb_hex}</div>
+/// }
+/// # }
+/// ```
+///
+/// `open` has to be called in response to a user action like a click. If the user cancels
+/// the selection `srgb_hex` keeps its previous value.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is always `false`, `srgb_hex` keeps its initial value and
+/// `open` does nothing.
+pub fn use_eye_dropper() -> UseEyeDropperReturn<impl Fn() + Clone> {
+    use_eye_dropper_with_options(UseEyeDropperOptions::default())
+}
+
+/// Version of [`use_eye_dropper`] that takes a `UseEyeDropperOptions`. See [`use_eye_dropper`] for how to use.
+pub fn use_eye_dropper_with_options(
+    options: UseEyeDropperOptions,
+) -> UseEyeDropperReturn<impl Fn() + Clone> {
+    let UseEyeDropperOptions { initial_value } = options;
+
+    let is_supported = use_supported(|| js!("EyeDropper" in &window()));
+
+    let (srgb_hex, set_srgb_hex) = create_signal(initial_value);
+
+    let open = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_supported.get_untracked() {
+                return;
+            }
+
+            spawn_local(async move {
+                if let Ok(hex) = open_eye_dropper().await {
+                    set_srgb_hex.set(hex);
+                }
+            });
+        }
+    };
+
+    UseEyeDropperReturn {
+        is_supported,
+        srgb_hex: srgb_hex.into(),
+        open,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn open_eye_dropper() -> Result<String, wasm_bindgen::JsValue> {
+    // The EyeDropper API is not part of web-sys yet
+    let constructor =
+        js_sys::Reflect::get(&window(), &"EyeDropper".into())?.dyn_into::<js_sys::Function>()?;
+    let eye_dropper = js_sys::Reflect::construct(&constructor, &js_sys::Array::new())?;
+
+    let promise = js_sys::Reflect::get(&eye_dropper, &"open".into())?
+        .dyn_into::<js_sys::Function>()?
+        .call0(&eye_dropper)?
+        .dyn_into::<js_sys::Promise>()?;
+
+    let result = js_fut!(promise).await?;
+
+    Ok(js!(result["sRGBHex"])?.as_string().unwrap_or_default())
+}
+
+/// Options for [`use_eye_dropper_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseEyeDropperOptions {
+    /// The value of `srgb_hex` before a color has been picked. Defaults to `""`.
+    #[builder(into)]
+    initial_value: String,
+}
+
+/// Return type of [`use_eye_dropper`].
+pub struct UseEyeDropperReturn<OpenFn>
+where
+    OpenFn: Fn() + Clone,
+{
+    /// `true` if the browser supports the EyeDropper API.
+    pub is_supported: Signal<bool>,
+
+    /// The picked color as hex string like `"#ff0000"`.
+    pub srgb_hex: Signal<String>,
+
+    /// Opens the eye dropper.
+    pub open: OpenFn,
+}
@@ -55,6 +55,33 @@ use wasm_bindgen::JsCast;
 /// # }
 /// ```
 ///
+/// ## Text and Emojis
+///
+/// With [`text_favicon`] you can use a text or an emoji as the favicon. This is handy to show
+/// for example the number of unread messages in the tab.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{text_favicon, use_favicon_with_options, UseFaviconOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (unread, set_unread) = create_signal(3);
+///
+/// let _ = use_favicon_with_options(UseFaviconOptions::default().new_icon(Signal::derive(
+///     move || match unread.get() {
+///         0 => Some("favicon.png".to_string()),
+///         count => Some(text_favicon(&count.to_string())),
+///     },
+/// )));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// If the page doesn't have a `<link rel="icon">` one is added. When the reactive scope is
+/// disposed the original favicon is restored.
+///
 /// ## Server-Side Rendering
 ///
 /// On the server only the signals work but no favicon will be changed obviously.
@@ -77,22 +104,68 @@ pub fn use_favicon_with_options(
     cfg_if! { if #[cfg(not(feature = "ssr"))] {
         let link_selector = format!("link[rel*=\"{rel}\"]");
 
+        // the links and their `href` before the first change so they can be restored on cleanup
+        let original_links = store_value(None::<Vec<(web_sys::HtmlLinkElement, Option<String>)>>);
+        let created_links = store_value(Vec::<web_sys::HtmlLinkElement>::new());
+
         let apply_icon = move |icon: &String| {
             if let Some(head) = document().head() {
                 if let Ok(links) = head.query_selector_all(&link_selector) {
-                    let href = format!("{base_url}{icon}");
+                    let href = if icon.starts_with("data:") {
+                        icon.clone()
+                    } else {
+                        format!("{base_url}{icon}")
+                    };
+
+                    let mut links = (0..links.length())
+                        .filter_map(|i| links.get(i))
+                        .map(|node| node.unchecked_into::<web_sys::HtmlLinkElement>())
+                        .collect::<Vec<_>>();
+
+                    if original_links.with_value(Option::is_none) {
+                        original_links.set_value(Some(
+                            links
+                                .iter()
+                                .map(|link| (link.clone(), link.get_attribute("href")))
+                                .collect(),
+                        ));
+                    }
+
+                    if links.is_empty() {
+                        if let Ok(link) = document().create_element("link") {
+                            let link: web_sys::HtmlLinkElement = link.unchecked_into();
+                            link.set_rel(&rel);
+                            let _ = head.append_child(&link);
 
-                    for i in 0..links.length() {
-                        let node = links.get(i).expect("checked length");
-                        let link: web_sys::HtmlLinkElement = node.unchecked_into();
+                            created_links.update_value(|created| created.push(link.clone()));
+                            links.push(link);
+                        }
+                    }
+
+                    for link in links {
                         link.set_href(&href);
                     }
                 }
             }
         };
 
+        on_cleanup(move || {
+            for (link, href) in original_links.get_value().unwrap_or_default() {
+                match href {
+                    Some(href) => link.set_href(&href),
+                    None => {
+                        let _ = link.remove_attribute("href");
+                    }
+                }
+            }
+
+            for link in created_links.get_value() {
+                link.remove();
+            }
+        });
+
         let _ = watch(
-                        move || favicon.get(),
+            move || favicon.get(),
             move |new_icon, prev_icon, _| {
                 if Some(new_icon) != prev_icon {
                     if let Some(new_icon) = new_icon {
@@ -107,6 +180,29 @@ pub fn use_favicon_with_options(
     (favicon, set_favicon)
 }
 
+/// Renders `text` to an SVG [data URL](https://developer.mozilla.org/en-US/docs/Web/HTTP/Basics_of_HTTP/Data_URLs)
+/// that can be used as a favicon with [`use_favicon`]. Works best with a single emoji or a few
+/// characters. The `base_url` option isn't added to data URLs.
+pub fn text_favicon(text: &str) -> String {
+    let text = text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+
+    let svg = format!(
+        "<svg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 100 100'><text x='50' y='.9em' font-size='90' text-anchor='middle'>{text}</text></svg>"
+    );
+
+    let svg = svg
+        .replace('%', "%25")
+        .replace('#', "%23")
+        .replace('<', "%3C")
+        .replace('>', "%3E")
+        .replace('"', "%22");
+
+    format!("data:image/svg+xml,{svg}")
+}
+
 /// Options for [`use_favicon_with_options`].
 #[derive(DefaultBuilder)]
 pub struct UseFaviconOptions {
@@ -114,7 +210,7 @@ pub struct UseFaviconOptions {
     #[builder(into)]
     new_icon: MaybeRwSignal<Option<String>>,
 
-    /// Base URL of the favicon. Defaults to "".
+    /// Base URL of the favicon. Isn't added to `data:` URLs. Defaults to "".
     #[builder(into)]
     base_url: String,
     /// Rel attribute of the <link> tag. Defaults to "icon".
@@ -131,3 +227,17 @@ impl Default for UseFaviconOptions {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_favicon() {
+        assert_eq!(
+            text_favicon("<3"),
+            "data:image/svg+xml,%3Csvg xmlns='http://www.w3.org/2000/svg' viewBox='0 0 100 100'%3E%3Ctext x='50' y='.9em' font-size='90' text-anchor='middle'%3E&lt;3%3C/text%3E%3C/svg%3E"
+        );
+        assert!(text_favicon("#1").ends_with("%231%3C/text%3E%3C/svg%3E"));
+    }
+}
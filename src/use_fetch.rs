@@ -0,0 +1,1294 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::{now, RetryDelay};
+use crate::utils::StringCodec;
+use crate::{js, js_fut};
+use default_struct_builder::DefaultBuilder;
+use gloo_timers::future::sleep;
+use leptos::*;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::time::Duration;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Reactive [Fetch API](https://developer.mozilla.org/en-US/docs/Web/API/Fetch_API).
+///
+/// Sends an HTTP request and exposes the response body, status code, error and loading state
+/// as signals.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_fetch)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_fetch, UseFetchReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFetchReturn {
+///     data,
+///     error,
+///     is_fetching,
+///     status_code,
+///     execute,
+///     abort,
+///     ..
+/// } = use_fetch("https://httpbin.org/get");
+///
+/// view! {
+///     <p>"Status: " {move || format!("{:?}", status_code.get())}</p>
+///     <p>"Response: " {move || data.get()}</p>
+///     <button on:click=move |_| execute() disabled=is_fetching>"Refetch"</button>
+///     <button on:click=move |_| abort() disabled=move || !is_fetching.get()>"Abort"</button>
+/// }
+/// # }
+/// ```
+///
+/// ### Options
+///
+/// You can set the HTTP method, headers and body with [`UseFetchOptions`].
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_fetch_with_options, UseFetchOptions, UseFetchReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFetchReturn { data, .. } = use_fetch_with_options(
+///     "https://httpbin.org/post",
+///     UseFetchOptions::default()
+///         .method("POST")
+///         .headers(vec![("Content-Type".to_string(), "application/json".to_string())])
+///         .body(r#"{"hello":"world"}"#.to_string()),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Immediate and Refetch
+///
+/// By default the request is sent immediately when calling this function. Set `immediate` to
+/// `false` to only send it when you call `execute`.
+///
+/// The `url` can be a signal. If `refetch` is set to `true` the request is sent again every time
+/// the `url` changes.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_fetch_with_options, UseFetchOptions, UseFetchReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (id, set_id) = create_signal(1);
+///
+/// let UseFetchReturn { data, .. } = use_fetch_with_options(
+///     Signal::derive(move || format!("https://jsonplaceholder.typicode.com/todos/{}", id.get())),
+///     UseFetchOptions::default().refetch(true),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Abort and Timeout
+///
+/// A request that is in flight can be cancelled by calling `abort`. This happens automatically
+/// when the component that called `use_fetch` is unmounted, so no response is ever written into
+/// signals that have already been disposed.
+///
+/// With the `timeout` option the request is aborted after the given number of milliseconds and
+/// `error` is set to [`UseFetchError::Timeout`].
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_fetch_with_options, UseFetchOptions, UseFetchReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFetchReturn { data, error, .. } = use_fetch_with_options(
+///     "https://httpbin.org/delay/10",
+///     UseFetchOptions::default().timeout(3000),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Retry
+///
+/// Failed requests can be retried automatically with the `retry` option. By default there is a
+/// fixed delay of one second between attempts. Use [`RetryDelay::Exponential`] for an
+/// exponential backoff. The current attempt is available as the `attempt` signal.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_fetch_with_options, UseFetchOptions, UseFetchReturn};
+/// # use leptos_use::core::RetryDelay;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFetchReturn { data, attempt, .. } = use_fetch_with_options(
+///     "https://httpbin.org/status/503",
+///     UseFetchOptions::default()
+///         .retry(3)
+///         .retry_delay(RetryDelay::exponential(500)), // 500ms, 1s, 2s
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Interceptors
+///
+/// With the async hooks `before_fetch` and `after_fetch` you can modify a request before it is
+/// sent and the response before it is written into `data`. To apply them to many requests
+/// together with a base url, have a look at [`create_fetch`].
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_fetch_with_options, UseFetchOptions, UseFetchReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # async fn refresh_token() {}
+/// let UseFetchReturn { data, .. } = use_fetch_with_options(
+///     "https://my-api.com/me",
+///     UseFetchOptions::default()
+///         .before_fetch(|mut context| async move {
+///             context
+///                 .headers
+///                 .push(("Authorization".to_string(), "Bearer my-token".to_string()));
+///             context
+///         })
+///         .after_fetch(|mut context| async move {
+///             if context.response.status() == 401 {
+///                 refresh_token().await;
+///                 context.refetch = true;
+///             }
+///             context
+///         }),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Caching
+///
+/// Set `cache` to `true` to get stale-while-revalidate behaviour. Successful responses are
+/// cached by url and method. Requests that hit the cache return the cached data immediately and
+/// are revalidated in the background. Cached responses that are younger than `cache_ttl`
+/// milliseconds are returned without revalidation. If the revalidation fails `error` is set
+/// and `data` keeps the cached data.
+///
+/// Use [`invalidate_fetch_cache`] or [`clear_fetch_cache`] to remove cached responses manually.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{invalidate_fetch_cache, use_fetch_with_options, UseFetchOptions, UseFetchReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFetchReturn { data, .. } = use_fetch_with_options(
+///     "https://my-api.com/users",
+///     UseFetchOptions::default().cache(true).cache_ttl(60_000),
+/// );
+///
+/// // later, for example after creating a user
+/// invalidate_fetch_cache("https://my-api.com/users");
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Progress
+///
+/// The signals `download_progress` and `upload_progress` report the bytes transferred of the
+/// response and request bodies. Downloads are read as a stream and the total is taken from the
+/// `Content-Length` header.
+///
+/// By default the upload is reported as complete once the response headers arrive. Set
+/// `stream_upload` to `true` to stream the request body in chunks and get the real upload
+/// progress. Please note that streaming request bodies is only supported by some browsers
+/// and requires HTTP/2.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_fetch_with_options, UseFetchOptions, UseFetchReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let file_content = String::new();
+/// let UseFetchReturn { upload_progress, .. } = use_fetch_with_options(
+///     "https://my-api.com/upload",
+///     UseFetchOptions::default()
+///         .method("POST")
+///         .body(file_content)
+///         .stream_upload(true),
+/// );
+///
+/// view! {
+///     <progress max="1" value=move || upload_progress.get().and_then(|p| p.ratio())></progress>
+/// }
+/// # }
+/// ```
+///
+/// ### Typed Responses
+///
+/// The response body can be decoded into a typed signal by calling [`UseFetchReturn::decode`]
+/// with any [`StringCodec`]. For JSON there is the shortcut [`UseFetchReturn::json`].
+/// Decoding errors are reported separately from request errors in `parse_error`.
+///
+/// > To use [`UseFetchReturn::json`], you will need to add the `"serde"` feature to your project's `Cargo.toml`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_fetch, UseFetchDecodeReturn};
+/// # use serde::Deserialize;
+/// #
+/// #[derive(Deserialize, Clone)]
+/// pub struct Todo {
+///     pub title: String,
+///     pub completed: bool,
+/// }
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFetchDecodeReturn { data, parse_error } =
+///     use_fetch("https://jsonplaceholder.typicode.com/todos/1").json::<Todo>();
+///
+/// view! {
+///     <p>{move || data.get().map(|todo| todo.title)}</p>
+///     <p>{move || parse_error.with(|err| err.as_ref().map(|err| err.to_string()))}</p>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server no request is sent. `data`, `error` and `status_code` are always `None`,
+/// `is_fetching` is always `false` and `execute` and `abort` do nothing.
+pub fn use_fetch(
+    url: impl Into<MaybeSignal<String>>,
+) -> UseFetchReturn<impl Fn() + Clone + 'static, impl Fn() + Clone + 'static> {
+    use_fetch_with_options(url, UseFetchOptions::default())
+}
+
+/// Version of [`use_fetch`] that takes a `UseFetchOptions`. See [`use_fetch`] for how to use.
+pub fn use_fetch_with_options(
+    url: impl Into<MaybeSignal<String>>,
+    options: UseFetchOptions,
+) -> UseFetchReturn<impl Fn() + Clone + 'static, impl Fn() + Clone + 'static> {
+    let UseFetchOptions {
+        method,
+        headers,
+        body,
+        immediate,
+        refetch,
+        timeout,
+        retry,
+        retry_delay,
+        before_fetch,
+        after_fetch,
+        cache,
+        cache_ttl,
+        stream_upload,
+    } = options;
+
+    let url = url.into();
+
+    let (data, set_data) = create_signal(None::<String>);
+    let (error, set_error) = create_signal(None::<UseFetchError>);
+    let (is_fetching, set_fetching) = create_signal(false);
+    let (is_finished, set_finished) = create_signal(false);
+    let (status_code, set_status_code) = create_signal(None::<u16>);
+    let (response, set_response) = create_signal(None::<web_sys::Response>);
+    let (attempt, set_attempt) = create_signal(0_u32);
+    let (download_progress, set_download_progress) = create_signal(None::<UseFetchProgress>);
+    let (upload_progress, set_upload_progress) = create_signal(None::<UseFetchProgress>);
+
+    let controller = store_value(None::<web_sys::AbortController>);
+    // Incremented on every `execute` and on cleanup so that outdated requests don't write into
+    // the signals. Not a `StoredValue` because it has to outlive the reactive scope.
+    let request_id = Rc::new(Cell::new(0_u64));
+
+    let abort = move || {
+        if let Some(controller) = controller.get_value() {
+            controller.abort();
+        }
+    };
+
+    let execute = {
+        let url = url.clone();
+        let request_id = Rc::clone(&request_id);
+
+        move || {
+            #[cfg(not(feature = "ssr"))]
+            {
+                abort();
+
+                request_id.set(request_id.get() + 1);
+                let current_id = request_id.get();
+
+                let abort_controller = web_sys::AbortController::new().ok();
+                controller.set_value(abort_controller.clone());
+
+                let url = url.get_untracked();
+                let cache_key = format!("{method} {url}");
+
+                // `true` if the cached data is shown while the request revalidates it
+                let mut revalidating = false;
+
+                if cache {
+                    if let Some(entry) = FETCH_CACHE.with(|c| c.borrow().get(&cache_key).cloned()) {
+                        set_status_code.set(Some(entry.status));
+                        set_data.set(Some(entry.data.clone()));
+                        set_error.set(None);
+
+                        if entry.is_fresh(now(), cache_ttl) {
+                            // also ends a request that was in flight and has just been aborted
+                            finish_request(controller, set_fetching, set_finished);
+                            return;
+                        }
+
+                        revalidating = true;
+                    }
+                }
+
+                set_fetching.set(true);
+                set_finished.set(false);
+                set_error.set(None);
+                set_download_progress.set(None);
+                set_upload_progress.set(None);
+
+                let request = BeforeFetchContext {
+                    url,
+                    method: method.clone(),
+                    headers: headers.clone(),
+                    body: body.clone(),
+                    cancel: false,
+                };
+
+                let progress = FetchProgress {
+                    download: {
+                        let request_id = Rc::clone(&request_id);
+                        Rc::new(move |progress| {
+                            if request_id.get() == current_id {
+                                set_download_progress.set(Some(progress));
+                            }
+                        })
+                    },
+                    upload: {
+                        let request_id = Rc::clone(&request_id);
+                        Rc::new(move |progress| {
+                            if request_id.get() == current_id {
+                                set_upload_progress.set(Some(progress));
+                            }
+                        })
+                    },
+                    stream_upload,
+                };
+
+                let request_id = Rc::clone(&request_id);
+                let before_fetch = Rc::clone(&before_fetch);
+                let after_fetch = Rc::clone(&after_fetch);
+
+                spawn_local(async move {
+                    let mut abort_controller = abort_controller;
+                    let mut attempt = 1;
+                    let mut refetched_by_hook = false;
+
+                    let result = loop {
+                        set_attempt.set(attempt);
+
+                        let request = before_fetch(request.clone()).await;
+
+                        if request_id.get() != current_id {
+                            return;
+                        }
+
+                        if request.cancel {
+                            finish_request(controller, set_fetching, set_finished);
+                            return;
+                        }
+
+                        let result = send_request_with_timeout(
+                            &request,
+                            abort_controller.as_ref(),
+                            timeout,
+                            &progress,
+                        )
+                        .await;
+
+                        if request_id.get() != current_id {
+                            return;
+                        }
+
+                        let result = match result {
+                            Ok((response, data)) => {
+                                let context = after_fetch(AfterFetchContext {
+                                    response,
+                                    data,
+                                    refetch: false,
+                                })
+                                .await;
+
+                                if request_id.get() != current_id {
+                                    return;
+                                }
+
+                                if context.refetch && !refetched_by_hook {
+                                    refetched_by_hook = true;
+                                    abort_controller = web_sys::AbortController::new().ok();
+                                    controller.set_value(abort_controller.clone());
+                                    continue;
+                                }
+
+                                Ok((context.response, context.data))
+                            }
+                            Err(err) => Err(err),
+                        };
+
+                        let should_retry = match &result {
+                            Ok((resp, _)) => !resp.ok() && is_retryable_status(resp.status()),
+                            Err(err) => err.is_retryable(),
+                        };
+
+                        if !should_retry || attempt > retry {
+                            break result;
+                        }
+
+                        sleep(Duration::from_millis(retry_delay.delay(attempt))).await;
+
+                        if request_id.get() != current_id {
+                            return;
+                        }
+
+                        // `abort` has been called while waiting for the next attempt
+                        if abort_controller
+                            .as_ref()
+                            .is_some_and(|c| c.signal().aborted())
+                        {
+                            break Err(UseFetchError::Aborted);
+                        }
+
+                        abort_controller = web_sys::AbortController::new().ok();
+                        controller.set_value(abort_controller.clone());
+
+                        attempt += 1;
+                    };
+
+                    match result {
+                        Ok((resp, text)) => {
+                            let status = resp.status();
+                            let ok = resp.ok();
+                            set_status_code.set(Some(status));
+                            set_response.set(Some(resp));
+
+                            if ok {
+                                if cache {
+                                    FETCH_CACHE.with(|c| {
+                                        c.borrow_mut().insert(
+                                            cache_key,
+                                            FetchCacheEntry {
+                                                data: text.clone(),
+                                                status,
+                                                fetched_at: now(),
+                                            },
+                                        )
+                                    });
+                                }
+
+                                set_data.set(Some(text));
+                            } else {
+                                // keep showing the cached data if revalidation fails
+                                if !revalidating {
+                                    set_data.set(None);
+                                }
+                                set_error.set(Some(UseFetchError::Status(status)));
+                            }
+                        }
+                        Err(err) => {
+                            if !revalidating {
+                                set_data.set(None);
+                            }
+                            set_error.set(Some(err));
+                        }
+                    }
+
+                    finish_request(controller, set_fetching, set_finished);
+                });
+            }
+        }
+    };
+
+    on_cleanup(move || {
+        request_id.set(request_id.get() + 1);
+        abort();
+    });
+
+    if refetch {
+        let _ = watch(
+            move || url.get(),
+            {
+                // under `ssr` the closure is `Copy`
+                #[cfg(not(feature = "ssr"))]
+                let execute = execute.clone();
+                move |_, _, _| execute()
+            },
+            false,
+        );
+    }
+
+    if immediate {
+        execute();
+    }
+
+    UseFetchReturn {
+        data: data.into(),
+        error: error.into(),
+        is_fetching: is_fetching.into(),
+        is_finished: is_finished.into(),
+        status_code: status_code.into(),
+        response: response.into(),
+        attempt: attempt.into(),
+        download_progress: download_progress.into(),
+        upload_progress: upload_progress.into(),
+        execute,
+        abort,
+    }
+}
+
+/// Options for [`use_fetch_with_options`].
+#[derive(DefaultBuilder, Clone)]
+pub struct UseFetchOptions {
+    /// The HTTP method of the request. Defaults to `"GET"`.
+    #[builder(into)]
+    method: String,
+
+    /// Headers that are sent with the request as `(name, value)` pairs.
+    #[builder(into)]
+    headers: Vec<(String, String)>,
+
+    /// The body of the request. Defaults to `None`.
+    #[builder(into)]
+    body: Option<String>,
+
+    /// If `true` the request is sent immediately when calling this function.
+    /// If `false` you have to manually call the `execute` function. Defaults to `true`.
+    immediate: bool,
+
+    /// If `true` the request is sent again whenever the `url` changes. Defaults to `false`.
+    refetch: bool,
+
+    /// Time in ms after which the request is aborted with [`UseFetchError::Timeout`].
+    /// Defaults to `0` which means no timeout.
+    timeout: u64,
+
+    /// How many times a failed request is retried. Requests are retried on network errors,
+    /// timeouts and the status codes 408, 425, 429 and 5xx. Defaults to `0`.
+    retry: u32,
+
+    /// How long to wait before each retry. Either a fixed delay in ms or an exponential backoff.
+    /// Defaults to `RetryDelay::Fixed(1000)`.
+    #[builder(into)]
+    retry_delay: RetryDelay,
+
+    /// Async hook that is called before every request is sent. It can modify the request,
+    /// for example to add an auth header, or cancel it. Defaults to a no-op.
+    #[builder(skip)]
+    before_fetch: BeforeFetchFn,
+
+    /// Async hook that is called after a response has been received and before it is written into
+    /// `data`. It can transform the response body or request to send the request again.
+    /// Defaults to a no-op.
+    #[builder(skip)]
+    after_fetch: AfterFetchFn,
+
+    /// If `true` successful responses are cached by url and method. When a request with the
+    /// same url and method is executed again, the cached data is returned immediately and
+    /// revalidated in the background. Defaults to `false`.
+    cache: bool,
+
+    /// Time in ms a cached response is considered fresh. Fresh responses are not revalidated.
+    /// Defaults to `0` which means cached responses are always revalidated.
+    cache_ttl: u64,
+
+    /// If `true` the request body is sent as a stream which reports the real progress in
+    /// `upload_progress`. Streaming request bodies are not supported by every browser and
+    /// require HTTP/2. If `false` the upload is reported as complete as soon as the response
+    /// headers arrive. Defaults to `false`.
+    stream_upload: bool,
+}
+
+type BeforeFetchFn =
+    Rc<dyn Fn(BeforeFetchContext) -> Pin<Box<dyn Future<Output = BeforeFetchContext>>>>;
+type AfterFetchFn =
+    Rc<dyn Fn(AfterFetchContext) -> Pin<Box<dyn Future<Output = AfterFetchContext>>>>;
+
+impl UseFetchOptions {
+    /// Async hook that is called before every request is sent. It can modify the request,
+    /// for example to add an auth header, or cancel it. Defaults to a no-op.
+    pub fn before_fetch<F, Fut>(self, before_fetch: F) -> Self
+    where
+        F: Fn(BeforeFetchContext) -> Fut + 'static,
+        Fut: Future<Output = BeforeFetchContext> + 'static,
+    {
+        Self {
+            before_fetch: Rc::new(move |context| Box::pin(before_fetch(context))),
+            ..self
+        }
+    }
+
+    /// Async hook that is called after a response has been received and before it is written into
+    /// `data`. It can transform the response body or request to send the request again.
+    /// Defaults to a no-op.
+    pub fn after_fetch<F, Fut>(self, after_fetch: F) -> Self
+    where
+        F: Fn(AfterFetchContext) -> Fut + 'static,
+        Fut: Future<Output = AfterFetchContext> + 'static,
+    {
+        Self {
+            after_fetch: Rc::new(move |context| Box::pin(after_fetch(context))),
+            ..self
+        }
+    }
+}
+
+impl Default for UseFetchOptions {
+    fn default() -> Self {
+        Self {
+            method: "GET".to_string(),
+            headers: vec![],
+            body: None,
+            immediate: true,
+            refetch: false,
+            timeout: 0,
+            retry: 0,
+            retry_delay: RetryDelay::default(),
+            before_fetch: Rc::new(|context| Box::pin(async move { context })),
+            after_fetch: Rc::new(|context| Box::pin(async move { context })),
+            cache: false,
+            cache_ttl: 0,
+            stream_upload: false,
+        }
+    }
+}
+
+/// The request that is passed to [`UseFetchOptions::before_fetch`].
+#[derive(Clone, Debug)]
+pub struct BeforeFetchContext {
+    /// The url the request is sent to.
+    pub url: String,
+
+    /// The HTTP method of the request.
+    pub method: String,
+
+    /// The headers of the request as `(name, value)` pairs.
+    pub headers: Vec<(String, String)>,
+
+    /// The body of the request.
+    pub body: Option<String>,
+
+    /// Set this to `true` to not send the request at all.
+    pub cancel: bool,
+}
+
+/// The response that is passed to [`UseFetchOptions::after_fetch`].
+pub struct AfterFetchContext {
+    /// The raw `Response`.
+    pub response: web_sys::Response,
+
+    /// The body of the response as text. Changing it changes what will be written into `data`.
+    pub data: String,
+
+    /// Set this to `true` to send the request again, for example after refreshing an expired
+    /// token. The request is only sent again once per `execute` to avoid loops.
+    pub refetch: bool,
+}
+
+/// Creates a preconfigured version of [`use_fetch`] with a base url and default options
+/// like headers or the `before_fetch` and `after_fetch` hooks.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{create_fetch, UseFetchOptions, UseFetchReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let api = create_fetch(
+///     "https://my-api.com/api",
+///     UseFetchOptions::default().before_fetch(|mut context| async move {
+///         context
+///             .headers
+///             .push(("Authorization".to_string(), "Bearer my-token".to_string()));
+///         context
+///     }),
+/// );
+///
+/// // sends the request to `https://my-api.com/api/users`
+/// let UseFetchReturn { data, .. } = api.use_fetch("users");
+///
+/// // start from the base options to change them for a single request
+/// let UseFetchReturn { data, .. } = api.use_fetch_with_options(
+///     "users",
+///     api.options().method("POST").body(r#"{"name":"Ferris"}"#.to_string()),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+pub fn create_fetch(base_url: impl Into<String>, options: UseFetchOptions) -> CreateFetchReturn {
+    CreateFetchReturn {
+        base_url: base_url.into(),
+        options,
+    }
+}
+
+/// Return type of [`create_fetch`].
+#[derive(Clone)]
+pub struct CreateFetchReturn {
+    base_url: String,
+    options: UseFetchOptions,
+}
+
+impl CreateFetchReturn {
+    /// Calls [`use_fetch_with_options`] with the url joined to the base url and the base options.
+    pub fn use_fetch(
+        &self,
+        url: impl Into<MaybeSignal<String>>,
+    ) -> UseFetchReturn<impl Fn() + Clone + 'static, impl Fn() + Clone + 'static> {
+        self.use_fetch_with_options(url, self.options())
+    }
+
+    /// Calls [`use_fetch_with_options`] with the url joined to the base url and the given options.
+    pub fn use_fetch_with_options(
+        &self,
+        url: impl Into<MaybeSignal<String>>,
+        options: UseFetchOptions,
+    ) -> UseFetchReturn<impl Fn() + Clone + 'static, impl Fn() + Clone + 'static> {
+        let url = match url.into() {
+            MaybeSignal::Static(url) => MaybeSignal::Static(join_url(&self.base_url, &url)),
+            MaybeSignal::Dynamic(url) => {
+                let base_url = self.base_url.clone();
+                MaybeSignal::Dynamic(Signal::derive(move || join_url(&base_url, &url.get())))
+            }
+        };
+
+        use_fetch_with_options(url, options)
+    }
+
+    /// Returns a copy of the base options to build upon for a single request.
+    pub fn options(&self) -> UseFetchOptions {
+        self.options.clone()
+    }
+}
+
+thread_local! {
+    static FETCH_CACHE: RefCell<HashMap<String, FetchCacheEntry>> = RefCell::new(HashMap::new());
+}
+
+#[derive(Clone)]
+struct FetchCacheEntry {
+    data: String,
+    status: u16,
+    fetched_at: f64,
+}
+
+impl FetchCacheEntry {
+    fn is_fresh(&self, now: f64, ttl: u64) -> bool {
+        now - self.fetched_at < ttl as f64
+    }
+}
+
+/// Removes all cached responses of [`use_fetch`] for the given url regardless of the method.
+/// The next request to this url will not return stale data.
+pub fn invalidate_fetch_cache(url: &str) {
+    FETCH_CACHE.with(|c| {
+        c.borrow_mut()
+            .retain(|key, _| key.split_once(' ').map(|(_, u)| u) != Some(url))
+    });
+}
+
+/// Removes all cached responses of [`use_fetch`].
+pub fn clear_fetch_cache() {
+    FETCH_CACHE.with(|c| c.borrow_mut().clear());
+}
+
+fn join_url(base_url: &str, url: &str) -> String {
+    if base_url.is_empty() || url.contains("://") || url.starts_with("//") {
+        return url.to_string();
+    }
+
+    format!(
+        "{}/{}",
+        base_url.trim_end_matches('/'),
+        url.trim_start_matches('/')
+    )
+}
+
+/// Return type of [`use_fetch`].
+pub struct UseFetchReturn<ExecuteFn, AbortFn>
+where
+    ExecuteFn: Fn() + Clone + 'static,
+    AbortFn: Fn() + Clone + 'static,
+{
+    /// The body of the latest successful response as text.
+    pub data: Signal<Option<String>>,
+
+    /// The latest error. Reset when a new request is sent.
+    pub error: Signal<Option<UseFetchError>>,
+
+    /// `true` while a request is in flight.
+    pub is_fetching: Signal<bool>,
+
+    /// `true` once the latest request has completed (successfully or not).
+    pub is_finished: Signal<bool>,
+
+    /// The HTTP status code of the latest response.
+    pub status_code: Signal<Option<u16>>,
+
+    /// The raw `Response` of the latest request.
+    pub response: Signal<Option<web_sys::Response>>,
+
+    /// The number of the current attempt of the latest request. `1` for the initial request
+    /// and incremented with every retry. `0` before the first request has been sent.
+    pub attempt: Signal<u32>,
+
+    /// The progress of downloading the response body of the latest request.
+    /// `None` before the response headers have arrived.
+    pub download_progress: Signal<Option<UseFetchProgress>>,
+
+    /// The progress of uploading the request body of the latest request.
+    /// `None` if the request has no body or hasn't been sent yet.
+    pub upload_progress: Signal<Option<UseFetchProgress>>,
+
+    /// Sends the request (again). Aborts a request that is still in flight.
+    pub execute: ExecuteFn,
+
+    /// Aborts the request that is currently in flight. This is also called automatically
+    /// when the reactive scope is disposed.
+    pub abort: AbortFn,
+}
+
+impl<ExecuteFn, AbortFn> UseFetchReturn<ExecuteFn, AbortFn>
+where
+    ExecuteFn: Fn() + Clone + 'static,
+    AbortFn: Fn() + Clone + 'static,
+{
+    /// Decodes the response body with the codec `C` every time a new response arrives.
+    /// See [`use_fetch`] for how to use.
+    pub fn decode<T, C>(&self) -> UseFetchDecodeReturn<T, C::Error>
+    where
+        T: Clone + 'static,
+        C: StringCodec<T> + Default,
+    {
+        let codec = C::default();
+        decode_response(self.data, move |text| codec.decode(text))
+    }
+
+    /// Deserializes the response body as JSON every time a new response arrives.
+    /// See [`use_fetch`] for how to use.
+    #[cfg(feature = "serde_json")]
+    pub fn json<T>(&self) -> UseFetchDecodeReturn<T, serde_json::Error>
+    where
+        T: serde::de::DeserializeOwned + Clone + 'static,
+    {
+        decode_response(self.data, |text| serde_json::from_str(&text))
+    }
+}
+
+fn decode_response<T, Err>(
+    text: Signal<Option<String>>,
+    decode: impl Fn(String) -> Result<T, Err> + Clone + 'static,
+) -> UseFetchDecodeReturn<T, Err>
+where
+    T: Clone + 'static,
+    Err: 'static,
+{
+    let (data, set_data) = create_signal(None::<T>);
+    let (parse_error, set_parse_error) = create_signal(None::<Err>);
+
+    let _ = watch(
+        move || text.get(),
+        move |text, _, _| match text.clone().map(&decode) {
+            Some(Ok(value)) => {
+                set_data.set(Some(value));
+                set_parse_error.set(None);
+            }
+            Some(Err(err)) => {
+                set_data.set(None);
+                set_parse_error.set(Some(err));
+            }
+            None => set_data.set(None),
+        },
+        true,
+    );
+
+    UseFetchDecodeReturn {
+        data: data.into(),
+        parse_error: parse_error.into(),
+    }
+}
+
+/// Return type of [`UseFetchReturn::decode`] and [`UseFetchReturn::json`].
+pub struct UseFetchDecodeReturn<T, Err>
+where
+    T: 'static,
+    Err: 'static,
+{
+    /// The decoded body of the latest successful response.
+    pub data: Signal<Option<T>>,
+
+    /// The error of decoding the latest response body. Reset when decoding succeeds.
+    pub parse_error: Signal<Option<Err>>,
+}
+
+/// Progress of a download or upload of [`use_fetch`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UseFetchProgress {
+    /// The number of bytes transferred so far.
+    pub loaded: u64,
+
+    /// The total number of bytes if known. For downloads this is taken from the
+    /// `Content-Length` header.
+    pub total: Option<u64>,
+}
+
+impl UseFetchProgress {
+    /// The transferred fraction between `0.0` and `1.0` or `None` if the total is unknown.
+    pub fn ratio(&self) -> Option<f64> {
+        match self.total {
+            Some(0) => Some(1.0),
+            Some(total) => Some((self.loaded as f64 / total as f64).min(1.0)),
+            None => None,
+        }
+    }
+}
+
+/// Error type of [`use_fetch`].
+#[derive(Error, Debug, Clone)]
+pub enum UseFetchError {
+    #[error("failed to send request: {0:?}")]
+    Request(JsValue),
+
+    #[error("server responded with status {0}")]
+    Status(u16),
+
+    #[error("failed to read response body: {0:?}")]
+    Body(JsValue),
+
+    #[error("request was aborted")]
+    Aborted,
+
+    #[error("request timed out")]
+    Timeout,
+}
+
+impl UseFetchError {
+    /// Whether a request that failed with this error should be retried.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Request(_) | Self::Timeout => true,
+            Self::Status(status) => is_retryable_status(*status),
+            Self::Body(_) | Self::Aborted => false,
+        }
+    }
+}
+
+fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 408 | 425 | 429 | 500..=599)
+}
+
+/// Progress reporting of a single request.
+struct FetchProgress {
+    download: Rc<dyn Fn(UseFetchProgress)>,
+    upload: Rc<dyn Fn(UseFetchProgress)>,
+    stream_upload: bool,
+}
+
+/// Chunk size of streamed request bodies.
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+#[cfg(not(feature = "ssr"))]
+async fn send_request_with_timeout(
+    request: &BeforeFetchContext,
+    abort_controller: Option<&web_sys::AbortController>,
+    timeout: u64,
+    progress: &FetchProgress,
+) -> Result<(web_sys::Response, String), UseFetchError> {
+    let timed_out = Rc::new(Cell::new(false));
+
+    let timeout_handle = match abort_controller {
+        Some(abort_controller) if timeout > 0 => {
+            let abort_controller = abort_controller.clone();
+            let timed_out = Rc::clone(&timed_out);
+
+            set_timeout_with_handle(
+                move || {
+                    timed_out.set(true);
+                    abort_controller.abort();
+                },
+                Duration::from_millis(timeout),
+            )
+            .ok()
+        }
+        _ => None,
+    };
+
+    let result = send_request(request, abort_controller.map(|c| c.signal()), progress).await;
+
+    if let Some(timeout_handle) = timeout_handle {
+        timeout_handle.clear();
+    }
+
+    match result {
+        Err(UseFetchError::Aborted) if timed_out.get() => Err(UseFetchError::Timeout),
+        result => result,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn send_request(
+    request: &BeforeFetchContext,
+    signal: Option<web_sys::AbortSignal>,
+    progress: &FetchProgress,
+) -> Result<(web_sys::Response, String), UseFetchError> {
+    let to_request_error = |err: JsValue| {
+        let is_abort = js!(err["name"])
+            .ok()
+            .and_then(|name| name.as_string())
+            .is_some_and(|name| name == "AbortError");
+
+        if is_abort {
+            UseFetchError::Aborted
+        } else {
+            UseFetchError::Request(err)
+        }
+    };
+
+    let fetch_headers = web_sys::Headers::new().map_err(UseFetchError::Request)?;
+    for (name, value) in &request.headers {
+        fetch_headers
+            .set(name, value)
+            .map_err(UseFetchError::Request)?;
+    }
+
+    let to_body_error = |err: JsValue| match to_request_error(err) {
+        UseFetchError::Request(err) => UseFetchError::Body(err),
+        err => err,
+    };
+
+    let mut init = web_sys::RequestInit::new();
+    init.method(&request.method);
+    init.headers(&fetch_headers);
+    init.signal(signal.as_ref());
+
+    let upload_total = request.body.as_ref().map(|body| body.len() as u64);
+    // Has to be kept alive until the body has been streamed completely
+    let mut _pull_body = None;
+
+    if let Some(body) = &request.body {
+        if progress.stream_upload {
+            let (stream, pull_body) = upload_stream(body, Rc::clone(&progress.upload))?;
+            init.body(Some(&stream));
+            js!(init["duplex"] = "half");
+            _pull_body = Some(pull_body);
+        } else {
+            init.body(Some(&JsValue::from_str(body)));
+        }
+
+        (progress.upload)(UseFetchProgress {
+            loaded: 0,
+            total: upload_total,
+        });
+    }
+
+    let fetch_request = web_sys::Request::new_with_str_and_init(&request.url, &init)
+        .map_err(UseFetchError::Request)?;
+
+    let response: web_sys::Response = js_fut!(window().fetch_with_request(&fetch_request))
+        .await
+        .map_err(to_request_error)?
+        .unchecked_into();
+
+    if let Some(total) = upload_total {
+        (progress.upload)(UseFetchProgress {
+            loaded: total,
+            total: Some(total),
+        });
+    }
+
+    let download_total = response
+        .headers()
+        .get("content-length")
+        .ok()
+        .flatten()
+        .and_then(|length| length.parse::<u64>().ok());
+
+    (progress.download)(UseFetchProgress {
+        loaded: 0,
+        total: download_total,
+    });
+
+    let text = match response.body() {
+        Some(stream) => {
+            let reader: web_sys::ReadableStreamDefaultReader = stream.get_reader().unchecked_into();
+            let mut bytes = Vec::new();
+
+            loop {
+                let chunk = js_fut!(reader.read()).await.map_err(to_body_error)?;
+
+                if js!(chunk["done"])
+                    .ok()
+                    .and_then(|done| done.as_bool())
+                    .unwrap_or(true)
+                {
+                    break;
+                }
+
+                let value = js!(chunk["value"]).map_err(UseFetchError::Body)?;
+                bytes.extend(value.unchecked_into::<js_sys::Uint8Array>().to_vec());
+
+                (progress.download)(UseFetchProgress {
+                    loaded: bytes.len() as u64,
+                    total: download_total,
+                });
+            }
+
+            String::from_utf8_lossy(&bytes).into_owned()
+        }
+        None => js_fut!(response.text().map_err(UseFetchError::Body)?)
+            .await
+            .map_err(to_body_error)?
+            .as_string()
+            .unwrap_or_default(),
+    };
+
+    Ok((response, text))
+}
+
+#[cfg(not(feature = "ssr"))]
+type PullBodyClosure =
+    wasm_bindgen::closure::Closure<dyn FnMut(web_sys::ReadableStreamDefaultController)>;
+
+/// Creates a `ReadableStream` that emits `body` in chunks of [`UPLOAD_CHUNK_SIZE`] and reports
+/// every chunk to `on_upload`. The returned closure has to be kept alive while the stream is read.
+#[cfg(not(feature = "ssr"))]
+fn upload_stream(
+    body: &str,
+    on_upload: Rc<dyn Fn(UseFetchProgress)>,
+) -> Result<(web_sys::ReadableStream, PullBodyClosure), UseFetchError> {
+    let bytes = body.as_bytes().to_vec();
+    let total = bytes.len() as u64;
+    let mut offset = 0;
+
+    let pull =
+        wasm_bindgen::closure::Closure::<dyn FnMut(web_sys::ReadableStreamDefaultController)>::new(
+            move |controller: web_sys::ReadableStreamDefaultController| {
+                if offset >= bytes.len() {
+                    let _ = controller.close();
+                    return;
+                }
+
+                let end = (offset + UPLOAD_CHUNK_SIZE).min(bytes.len());
+                let chunk = js_sys::Uint8Array::from(&bytes[offset..end]);
+                let _ = controller.enqueue_with_chunk(&chunk);
+                offset = end;
+
+                on_upload(UseFetchProgress {
+                    loaded: offset as u64,
+                    total: Some(total),
+                });
+            },
+        );
+
+    let source = js_sys::Object::new();
+    let _ = js_sys::Reflect::set(&source, &"pull".into(), pull.as_ref());
+
+    let stream = web_sys::ReadableStream::new_with_underlying_source(&source)
+        .map_err(UseFetchError::Request)?;
+
+    Ok((stream, pull))
+}
+
+/// Resets the state once a request has finished, was cancelled or was answered from the cache.
+fn finish_request(
+    controller: StoredValue<Option<web_sys::AbortController>>,
+    set_fetching: WriteSignal<bool>,
+    set_finished: WriteSignal<bool>,
+) {
+    controller.set_value(None);
+    set_fetching.set(false);
+    set_finished.set(true);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_ratio() {
+        let progress = |loaded, total| UseFetchProgress { loaded, total };
+        assert_eq!(progress(50, Some(200)).ratio(), Some(0.25));
+        assert_eq!(progress(300, Some(200)).ratio(), Some(1.0));
+        assert_eq!(progress(0, Some(0)).ratio(), Some(1.0));
+        assert_eq!(progress(50, None).ratio(), None);
+    }
+
+    #[test]
+    fn test_cache_entry_is_fresh() {
+        let entry = FetchCacheEntry {
+            data: String::new(),
+            status: 200,
+            fetched_at: 1000.0,
+        };
+        assert!(entry.is_fresh(1500.0, 1000));
+        assert!(!entry.is_fresh(2000.0, 1000));
+        assert!(!entry.is_fresh(1000.0, 0));
+    }
+
+    #[test]
+    fn test_finish_request() {
+        let runtime = create_runtime();
+
+        // a request is in flight when the next one is answered from the cache
+        let controller = store_value(None::<web_sys::AbortController>);
+        let (is_fetching, set_fetching) = create_signal(true);
+        let (is_finished, set_finished) = create_signal(false);
+
+        finish_request(controller, set_fetching, set_finished);
+
+        assert!(!is_fetching.get_untracked());
+        assert!(is_finished.get_untracked());
+
+        runtime.dispose();
+    }
+
+    #[test]
+    fn test_join_url() {
+        assert_eq!(
+            join_url("https://a.com/api", "users"),
+            "https://a.com/api/users"
+        );
+        assert_eq!(
+            join_url("https://a.com/api/", "/users"),
+            "https://a.com/api/users"
+        );
+        assert_eq!(
+            join_url("https://a.com/api", "https://b.com"),
+            "https://b.com"
+        );
+        assert_eq!(join_url("", "users"), "users");
+    }
+}
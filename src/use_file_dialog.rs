@@ -0,0 +1,201 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::use_event_listener;
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::change;
+use leptos::*;
+use std::rc::Rc;
+use web_sys::HtmlInputElement;
+
+/// Open a file dialog without having to put an `<input type="file">` into the page.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_file_dialog)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_file_dialog_with_options, UseFileDialogOptions, UseFileDialogReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFileDialogReturn { files, open, reset } = use_file_dialog_with_options(
+///     UseFileDialogOptions::default().accept("image/*"),
+/// );
+///
+/// view! {
+///     <button on:click=move |_| open()>"Choose images"</button>
+///     <button on:click=move |_| reset()>"Reset"</button>
+///     <ul>
+///         {move || files.get().into_iter().map(|file| view! { <li>{file.name()}</li> }).collect_view()}
+///     </ul>
+/// }
+/// # }
+/// ```
+///
+/// `open` has to be called in response to a user action like a click. The options are read
+/// every time `open` is called so you can pass signals to change them in between.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `files` is always empty and `open` and `reset` do nothing.
+pub fn use_file_dialog() -> UseFileDialogReturn<impl Fn() + Clone, impl Fn() + Clone> {
+    use_file_dialog_with_options(UseFileDialogOptions::default())
+}
+
+/// Version of [`use_file_dialog`] that takes a `UseFileDialogOptions`. See [`use_file_dialog`] for how to use.
+pub fn use_file_dialog_with_options(
+    options: UseFileDialogOptions,
+) -> UseFileDialogReturn<impl Fn() + Clone, impl Fn() + Clone> {
+    let UseFileDialogOptions {
+        multiple,
+        accept,
+        capture,
+        directory,
+        reset_on_open,
+        on_change,
+    } = options;
+
+    let (files, set_files) = create_signal(Vec::<web_sys::File>::new());
+    let (input, set_input) = create_signal(None::<HtmlInputElement>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use wasm_bindgen::JsCast;
+
+        if let Ok(new_input) = document().create_element("input") {
+            let new_input = new_input.unchecked_into::<HtmlInputElement>();
+            new_input.set_type("file");
+
+            set_input.set(Some(new_input));
+        }
+    }
+
+    let _ = use_event_listener(input, change, move |_| {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(input) = input.get_untracked() {
+            let selected = input
+                .files()
+                .map(|file_list| {
+                    (0..file_list.length())
+                        .filter_map(|i| file_list.get(i))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+
+            set_files.set(selected.clone());
+
+            #[cfg(debug_assertions)]
+            let prev = SpecialNonReactiveZone::enter();
+
+            on_change(selected);
+
+            #[cfg(debug_assertions)]
+            SpecialNonReactiveZone::exit(prev);
+        }
+    });
+
+    let reset = move || {
+        set_files.set(vec![]);
+
+        if let Some(input) = input.get_untracked() {
+            input.set_value("");
+        }
+    };
+
+    let open = move || {
+        let Some(input) = input.get_untracked() else {
+            return;
+        };
+
+        if reset_on_open {
+            reset();
+        }
+
+        input.set_multiple(multiple.get_untracked());
+        input.set_accept(&accept.get_untracked());
+
+        match capture.get_untracked() {
+            Some(capture) => {
+                let _ = input.set_attribute("capture", &capture);
+            }
+            None => {
+                let _ = input.remove_attribute("capture");
+            }
+        }
+
+        if directory.get_untracked() {
+            let _ = input.set_attribute("webkitdirectory", "");
+        } else {
+            let _ = input.remove_attribute("webkitdirectory");
+        }
+
+        input.click();
+    };
+
+    UseFileDialogReturn {
+        files: files.into(),
+        open,
+        reset,
+    }
+}
+
+/// Options for [`use_file_dialog_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseFileDialogOptions {
+    /// Allow to select more than one file. Defaults to `true`.
+    #[builder(into)]
+    multiple: MaybeSignal<bool>,
+
+    /// Comma separated list of file types that can be selected like `"image/*,.pdf"`.
+    /// Defaults to `"*"`.
+    #[builder(into)]
+    accept: MaybeSignal<String>,
+
+    /// Which camera to use on mobile devices to capture a new photo or video instead of
+    /// choosing a file. Either `"user"` or `"environment"`. Defaults to `None`.
+    #[builder(into)]
+    capture: MaybeSignal<Option<String>>,
+
+    /// Select a directory instead of files. `files` then contains all files in the directory.
+    /// Defaults to `false`.
+    #[builder(into)]
+    directory: MaybeSignal<bool>,
+
+    /// Clear `files` when the dialog is opened. Defaults to `false`.
+    reset_on_open: bool,
+
+    /// Called with the selected files after the user has chosen them.
+    on_change: Rc<dyn Fn(Vec<web_sys::File>)>,
+}
+
+impl Default for UseFileDialogOptions {
+    fn default() -> Self {
+        Self {
+            multiple: true.into(),
+            accept: "*".to_string().into(),
+            capture: MaybeSignal::default(),
+            directory: false.into(),
+            reset_on_open: false,
+            on_change: Rc::new(|_| {}),
+        }
+    }
+}
+
+/// Return type of [`use_file_dialog`].
+pub struct UseFileDialogReturn<OpenFn, ResetFn>
+where
+    OpenFn: Fn() + Clone,
+    ResetFn: Fn() + Clone,
+{
+    /// The files that have been selected in the dialog.
+    pub files: Signal<Vec<web_sys::File>>,
+
+    /// Opens the file dialog.
+    pub open: OpenFn,
+
+    /// Clears the selected files.
+    pub reset: ResetFn,
+}
@@ -0,0 +1,416 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::FileSystemFileHandle;
+
+/// Reactive [File System Access API](https://developer.mozilla.org/en-US/docs/Web/API/File_System_Access_API).
+///
+/// Opens a file from the user's disk and saves changes to the same file again.
+///
+/// > This function requires `--cfg=web_sys_unstable_apis` to be activated as
+/// > [described in the wasm-bindgen guide](https://rustwasm.github.io/docs/wasm-bindgen/web-sys/unstable-apis.html).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_file_system_access)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_file_system_access_with_options, FilePickerType, FileSystemAccessData, UseFileSystemAccessOptions, UseFileSystemAccessReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFileSystemAccessReturn {
+///     data,
+///     set_data,
+///     file_name,
+///     is_dirty,
+///     open_file,
+///     save,
+///     save_as,
+///     ..
+/// } = use_file_system_access_with_options(
+///     UseFileSystemAccessOptions::default().types(vec![
+///         FilePickerType::new("Text files").accept("text/plain", vec![".txt".to_string()])
+///     ]),
+/// );
+///
+/// let text = move || match data.get() {
+///     Some(FileSystemAccessData::Text(text)) => text,
+///     _ => String::new(),
+/// };
+///
+/// view! {
+///     <button on:click=move |_| open_file()>"Open"</button>
+///     <button on:click=move |_| save() disabled=move || !is_dirty.get()>"Save"</button>
+///     <button on:click=move |_| save_as()>"Save as"</button>
+///     <p>{file_name}</p>
+///     <textarea
+///         prop:value=text
+///         on:input=move |e| set_data.set(Some(FileSystemAccessData::Text(event_target_value(&e))))
+///     />
+/// }
+/// # }
+/// ```
+///
+/// `open_file` and `save_as` have to be called in response to a user action like a click.
+/// `save` writes to the file that has been opened or saved last. If there is none it behaves
+/// like `save_as`.
+///
+/// `is_dirty` is `true` whenever `data` differs from what has been read from or written to
+/// the file last.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_dirty` are always `false`, `data` is always `None`
+/// and the functions do nothing.
+#[allow(clippy::type_complexity)]
+pub fn use_file_system_access(
+) -> UseFileSystemAccessReturn<impl Fn() + Clone, impl Fn() + Clone, impl Fn() + Clone> {
+    use_file_system_access_with_options(UseFileSystemAccessOptions::default())
+}
+
+/// Version of [`use_file_system_access`] that takes a `UseFileSystemAccessOptions`. See [`use_file_system_access`] for how to use.
+#[allow(clippy::type_complexity)]
+pub fn use_file_system_access_with_options(
+    options: UseFileSystemAccessOptions,
+) -> UseFileSystemAccessReturn<impl Fn() + Clone, impl Fn() + Clone, impl Fn() + Clone> {
+    let UseFileSystemAccessOptions {
+        types,
+        exclude_accept_all_option,
+        data_type,
+    } = options;
+
+    let is_supported = use_supported(|| js!("showOpenFilePicker" in &window()));
+
+    let (file_handle, set_file_handle) = create_signal(None::<FileSystemFileHandle>);
+    let (file, set_file) = create_signal(None::<web_sys::File>);
+    let (data, set_data) = create_signal(None::<FileSystemAccessData>);
+    let (saved_data, set_saved_data) = create_signal(None::<FileSystemAccessData>);
+    let (error, set_error) = create_signal(None::<UseFileSystemAccessError>);
+
+    let types = store_value(types);
+
+    let update_file = move |handle: FileSystemFileHandle, read: bool| async move {
+        let new_file: web_sys::File = js_fut!(handle.get_file())
+            .await
+            .map_err(UseFileSystemAccessError::Read)?
+            .unchecked_into();
+
+        if read {
+            let new_data = match data_type {
+                FileSystemAccessDataType::Text => FileSystemAccessData::Text(
+                    js_fut!(new_file.text())
+                        .await
+                        .map_err(UseFileSystemAccessError::Read)?
+                        .as_string()
+                        .unwrap_or_default(),
+                ),
+                FileSystemAccessDataType::Binary => FileSystemAccessData::Binary(
+                    js_sys::Uint8Array::new(
+                        &js_fut!(new_file.array_buffer())
+                            .await
+                            .map_err(UseFileSystemAccessError::Read)?,
+                    )
+                    .to_vec(),
+                ),
+            };
+
+            set_data.set(Some(new_data.clone()));
+            set_saved_data.set(Some(new_data));
+        }
+
+        set_file.set(Some(new_file));
+
+        Ok::<(), UseFileSystemAccessError>(())
+    };
+
+    let write = move |handle: FileSystemFileHandle| async move {
+        let current = data.get_untracked();
+
+        write_file(&handle, current.as_ref())
+            .await
+            .map_err(UseFileSystemAccessError::Write)?;
+
+        set_file_handle.set(Some(handle.clone()));
+        set_saved_data.set(current);
+
+        update_file(handle, false).await
+    };
+
+    let open_file = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_supported.get_untracked() {
+                return;
+            }
+
+            let mut picker_options = web_sys::OpenFilePickerOptions::new();
+            picker_options
+                .exclude_accept_all_option(exclude_accept_all_option)
+                .types(&types.with_value(|types| to_js_types(types)));
+
+            let promise = match window().show_open_file_picker_with_options(&picker_options) {
+                Ok(promise) => promise,
+                Err(err) => {
+                    set_error.set(Some(UseFileSystemAccessError::Picker(err)));
+                    return;
+                }
+            };
+
+            spawn_local(async move {
+                let handle = match js_fut!(promise).await {
+                    Ok(handles) => handles.unchecked_into::<js_sys::Array>().get(0),
+                    Err(err) => {
+                        set_error.set(Some(UseFileSystemAccessError::Picker(err)));
+                        return;
+                    }
+                };
+
+                let handle: FileSystemFileHandle = handle.unchecked_into();
+                set_file_handle.set(Some(handle.clone()));
+
+                match update_file(handle, true).await {
+                    Ok(()) => set_error.set(None),
+                    Err(err) => set_error.set(Some(err)),
+                }
+            });
+        }
+    };
+
+    let save_as = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_supported.get_untracked() {
+                return;
+            }
+
+            let mut picker_options = web_sys::SaveFilePickerOptions::new();
+            picker_options
+                .exclude_accept_all_option(exclude_accept_all_option)
+                .types(&types.with_value(|types| to_js_types(types)))
+                .suggested_name(file.get_untracked().map(|file| file.name()).as_deref());
+
+            let promise = match window().show_save_file_picker_with_options(&picker_options) {
+                Ok(promise) => promise,
+                Err(err) => {
+                    set_error.set(Some(UseFileSystemAccessError::Picker(err)));
+                    return;
+                }
+            };
+
+            spawn_local(async move {
+                let handle = match js_fut!(promise).await {
+                    Ok(handle) => handle.unchecked_into::<FileSystemFileHandle>(),
+                    Err(err) => {
+                        set_error.set(Some(UseFileSystemAccessError::Picker(err)));
+                        return;
+                    }
+                };
+
+                match write(handle).await {
+                    Ok(()) => set_error.set(None),
+                    Err(err) => set_error.set(Some(err)),
+                }
+            });
+        }
+    };
+
+    let save = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(handle) = file_handle.get_untracked() else {
+                save_as();
+                return;
+            };
+
+            spawn_local(async move {
+                match write(handle).await {
+                    Ok(()) => set_error.set(None),
+                    Err(err) => set_error.set(Some(err)),
+                }
+            });
+        }
+    };
+
+    UseFileSystemAccessReturn {
+        is_supported,
+        file_handle: file_handle.into(),
+        file: file.into(),
+        file_name: Signal::derive(move || file.get().map(|file| file.name())),
+        data: data.into(),
+        set_data,
+        is_dirty: Signal::derive(move || data.with(|data| saved_data.with(|saved| data != saved))),
+        error: error.into(),
+        open_file,
+        save,
+        save_as,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn write_file(
+    handle: &FileSystemFileHandle,
+    data: Option<&FileSystemAccessData>,
+) -> Result<(), JsValue> {
+    let writable: web_sys::FileSystemWritableFileStream =
+        js_fut!(handle.create_writable()).await?.unchecked_into();
+
+    let promise = match data {
+        Some(FileSystemAccessData::Text(text)) => writable.write_with_str(text)?,
+        Some(FileSystemAccessData::Binary(bytes)) => writable.write_with_u8_array(bytes)?,
+        None => writable.write_with_str("")?,
+    };
+    js_fut!(promise).await?;
+
+    js_fut!(writable.close()).await?;
+
+    Ok(())
+}
+
+#[cfg(feature = "ssr")]
+async fn write_file(
+    _handle: &FileSystemFileHandle,
+    _data: Option<&FileSystemAccessData>,
+) -> Result<(), JsValue> {
+    Ok(())
+}
+
+#[cfg(not(feature = "ssr"))]
+fn to_js_types(types: &[FilePickerType]) -> js_sys::Array {
+    types
+        .iter()
+        .map(|picker_type| {
+            let mut accept_type = web_sys::FilePickerAcceptType::new();
+            accept_type.description(&picker_type.description);
+
+            let accept = js_sys::Object::new();
+            for (mime_type, extensions) in &picker_type.accept {
+                let _ = js_sys::Reflect::set(
+                    &accept,
+                    &JsValue::from_str(mime_type),
+                    &extensions
+                        .iter()
+                        .map(|extension| JsValue::from_str(extension))
+                        .collect::<js_sys::Array>(),
+                );
+            }
+            let _ = js_sys::Reflect::set(&accept_type, &"accept".into(), &accept);
+
+            accept_type
+        })
+        .collect()
+}
+
+/// Options for [`use_file_system_access_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseFileSystemAccessOptions {
+    /// The file types that can be picked.
+    types: Vec<FilePickerType>,
+
+    /// Don't offer the option to pick any file in the file picker. Defaults to `false`.
+    exclude_accept_all_option: bool,
+
+    /// How the content of the file is read. Defaults to [`FileSystemAccessDataType::Text`].
+    data_type: FileSystemAccessDataType,
+}
+
+/// A file type that can be picked with [`use_file_system_access`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FilePickerType {
+    /// Description of the file type that is shown in the picker.
+    pub description: String,
+
+    /// Mime types and their file extensions like `("text/plain", vec![".txt"])`.
+    pub accept: Vec<(String, Vec<String>)>,
+}
+
+impl FilePickerType {
+    /// Creates a new file type with the given `description`.
+    pub fn new(description: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            accept: vec![],
+        }
+    }
+
+    /// Adds a mime type and its file extensions.
+    pub fn accept(mut self, mime_type: impl Into<String>, extensions: Vec<String>) -> Self {
+        self.accept.push((mime_type.into(), extensions));
+        self
+    }
+}
+
+/// How the content of a file is read by [`use_file_system_access`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FileSystemAccessDataType {
+    /// Read the content as UTF-8 text.
+    #[default]
+    Text,
+    /// Read the content as bytes.
+    Binary,
+}
+
+/// Content of a file opened with [`use_file_system_access`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FileSystemAccessData {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+/// Return type of [`use_file_system_access`].
+pub struct UseFileSystemAccessReturn<OpenFileFn, SaveFn, SaveAsFn>
+where
+    OpenFileFn: Fn() + Clone,
+    SaveFn: Fn() + Clone,
+    SaveAsFn: Fn() + Clone,
+{
+    /// `true` if the browser supports the File System Access API.
+    pub is_supported: Signal<bool>,
+
+    /// Handle of the file that has been opened or saved last.
+    pub file_handle: Signal<Option<FileSystemFileHandle>>,
+
+    /// The file that has been opened or saved last.
+    pub file: Signal<Option<web_sys::File>>,
+
+    /// Name of the file that has been opened or saved last.
+    pub file_name: Signal<Option<String>>,
+
+    /// Content of the file.
+    pub data: Signal<Option<FileSystemAccessData>>,
+
+    /// Changes the content. It's written to the file on `save`.
+    pub set_data: WriteSignal<Option<FileSystemAccessData>>,
+
+    /// Wether `data` has been changed since it has been read or saved.
+    pub is_dirty: Signal<bool>,
+
+    /// Latest error that occurred.
+    pub error: Signal<Option<UseFileSystemAccessError>>,
+
+    /// Shows the file picker and reads the picked file.
+    pub open_file: OpenFileFn,
+
+    /// Writes `data` to the current file.
+    pub save: SaveFn,
+
+    /// Shows the save file picker and writes `data` to the picked file.
+    pub save_as: SaveAsFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseFileSystemAccessError {
+    #[error("failed to pick file")]
+    Picker(JsValue),
+    #[error("failed to read file")]
+    Read(JsValue),
+    #[error("failed to write file")]
+    Write(JsValue),
+}
@@ -0,0 +1,249 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::ElementMaybeSignal;
+use crate::{use_event_listener, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::Custom;
+use leptos::*;
+use wasm_bindgen::{JsCast, JsValue};
+
+const REQUEST_METHODS: [&str; 5] = [
+    "requestFullscreen",
+    "webkitRequestFullscreen",
+    "webkitEnterFullscreen",
+    "mozRequestFullScreen",
+    "msRequestFullscreen",
+];
+
+const EXIT_METHODS: [&str; 5] = [
+    "exitFullscreen",
+    "webkitExitFullscreen",
+    "webkitCancelFullScreen",
+    "mozCancelFullScreen",
+    "msExitFullscreen",
+];
+
+const ELEMENT_PROPERTIES: [&str; 4] = [
+    "fullscreenElement",
+    "webkitFullscreenElement",
+    "mozFullScreenElement",
+    "msFullscreenElement",
+];
+
+const CHANGE_EVENTS: [&str; 4] = [
+    "fullscreenchange",
+    "webkitfullscreenchange",
+    "mozfullscreenchange",
+    "MSFullscreenChange",
+];
+
+/// Reactive [Fullscreen API](https://developer.mozilla.org/en-US/docs/Web/API/Fullscreen_API).
+///
+/// Vendor prefixed versions of the API are used if the standard one is not available, for
+/// example in Safari.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_fullscreen)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::html::Video;
+/// # use leptos_use::{use_fullscreen, UseFullscreenReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let video_ref = create_node_ref::<Video>();
+///
+/// let UseFullscreenReturn {
+///     is_fullscreen,
+///     enter,
+///     exit,
+///     toggle,
+///     ..
+/// } = use_fullscreen(video_ref);
+///
+/// view! {
+///     <video node_ref=video_ref></video>
+///     <button on:click=move |_| toggle()>"Toggle fullscreen"</button>
+/// }
+/// # }
+/// ```
+///
+/// To put the whole page into fullscreen you can pass `document().document_element()`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_fullscreen` are always `false` and the functions
+/// do nothing.
+pub fn use_fullscreen<El, T>(
+    target: El,
+) -> UseFullscreenReturn<impl Fn() + Clone, impl Fn() + Clone, impl Fn() + Clone>
+where
+    El: Into<ElementMaybeSignal<T, web_sys::Element>>,
+    T: Into<web_sys::Element> + Clone + 'static,
+{
+    use_fullscreen_with_options(target, UseFullscreenOptions::default())
+}
+
+/// Version of [`use_fullscreen`] that takes a `UseFullscreenOptions`. See [`use_fullscreen`] for how to use.
+pub fn use_fullscreen_with_options<El, T>(
+    target: El,
+    options: UseFullscreenOptions,
+) -> UseFullscreenReturn<impl Fn() + Clone, impl Fn() + Clone, impl Fn() + Clone>
+where
+    El: Into<ElementMaybeSignal<T, web_sys::Element>>,
+    T: Into<web_sys::Element> + Clone + 'static,
+{
+    let UseFullscreenOptions { auto_exit } = options;
+
+    let target = target.into();
+    let target = Signal::derive(move || target.get().map(|target| target.into()));
+
+    let is_supported = use_supported(|| {
+        document()
+            .document_element()
+            .is_some_and(|el| find_method(&el, &REQUEST_METHODS).is_some())
+    });
+
+    let (is_fullscreen, set_fullscreen) = create_signal(false);
+
+    let update = move || {
+        let Some(el) = target.get_untracked() else {
+            set_fullscreen.set(false);
+            return;
+        };
+
+        let document = document();
+
+        let fullscreen_element = ELEMENT_PROPERTIES.iter().find_map(|property| {
+            js_sys::Reflect::get(&document, &JsValue::from_str(property))
+                .ok()
+                .filter(|value| !value.is_undefined())
+        });
+
+        let is_current = match fullscreen_element {
+            Some(fullscreen_element) => fullscreen_element == JsValue::from(el),
+            // iOS Safari only supports fullscreen for video elements
+            None => js_sys::Reflect::get(&el, &"webkitDisplayingFullscreen".into())
+                .map(|value| value.is_truthy())
+                .unwrap_or_default(),
+        };
+
+        set_fullscreen.set(is_current);
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        for event in CHANGE_EVENTS {
+            let _ = use_event_listener(document(), Custom::<ev::Event>::new(event), move |_| {
+                update()
+            });
+        }
+
+        // iOS Safari fires these on the video element
+        let _ = use_event_listener(
+            target,
+            Custom::<ev::Event>::new("webkitbeginfullscreen"),
+            move |_| update(),
+        );
+        let _ = use_event_listener(
+            target,
+            Custom::<ev::Event>::new("webkitendfullscreen"),
+            move |_| update(),
+        );
+    }
+
+    let exit = move || {
+        if !is_supported.get_untracked() || !is_fullscreen.get_untracked() {
+            return;
+        }
+
+        let document = document();
+        if let Some(method) = find_method(&document, &EXIT_METHODS) {
+            let _ = method.call0(&document);
+        } else if let Some(el) = target.get_untracked() {
+            // video elements on iOS Safari
+            if let Some(method) = find_method(&el, &["webkitExitFullscreen"]) {
+                let _ = method.call0(&el);
+            }
+        }
+
+        set_fullscreen.set(false);
+    };
+
+    let enter = move || {
+        if !is_supported.get_untracked() || is_fullscreen.get_untracked() {
+            return;
+        }
+
+        if let Some(el) = target.get_untracked() {
+            if let Some(method) = find_method(&el, &REQUEST_METHODS) {
+                if method.call0(&el).is_ok() {
+                    set_fullscreen.set(true);
+                }
+            }
+        }
+    };
+
+    let toggle = move || {
+        if is_fullscreen.get_untracked() {
+            exit();
+        } else {
+            enter();
+        }
+    };
+
+    if auto_exit {
+        on_cleanup(exit);
+    }
+
+    UseFullscreenReturn {
+        is_supported,
+        is_fullscreen: is_fullscreen.into(),
+        enter,
+        exit,
+        toggle,
+    }
+}
+
+/// Returns the first of the given methods that exists on `target`.
+fn find_method(target: &JsValue, methods: &[&str]) -> Option<js_sys::Function> {
+    methods.iter().find_map(|method| {
+        js_sys::Reflect::get(target, &JsValue::from_str(method))
+            .ok()
+            .and_then(|method| method.dyn_into::<js_sys::Function>().ok())
+    })
+}
+
+/// Options for [`use_fullscreen_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseFullscreenOptions {
+    /// Exit fullscreen automatically when the component is cleaned up. Defaults to `false`.
+    auto_exit: bool,
+}
+
+/// Return type of [`use_fullscreen`].
+pub struct UseFullscreenReturn<EnterFn, ExitFn, ToggleFn>
+where
+    EnterFn: Fn() + Clone,
+    ExitFn: Fn() + Clone,
+    ToggleFn: Fn() + Clone,
+{
+    /// `true` if the browser supports the Fullscreen API.
+    pub is_supported: Signal<bool>,
+
+    /// Wether the target element is currently displayed in fullscreen.
+    pub is_fullscreen: Signal<bool>,
+
+    /// Displays the target element in fullscreen.
+    pub enter: EnterFn,
+
+    /// Exits fullscreen.
+    pub exit: ExitFn,
+
+    /// Enters or exits fullscreen depending on `is_fullscreen`.
+    pub toggle: ToggleFn,
+}
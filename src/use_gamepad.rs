@@ -0,0 +1,297 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::utils::Pausable;
+use crate::{js, use_event_listener, use_raf_fn_with_options, use_supported, UseRafFnOptions};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use wasm_bindgen::JsCast;
+
+/// Reactive [Gamepad API](https://developer.mozilla.org/en-US/docs/Web/API/Gamepad_API).
+///
+/// The browser doesn't notify about changes of the buttons and axes. That's why the state of
+/// the gamepads is polled every animation frame as long as at least one gamepad is connected.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_gamepad)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_gamepad, UseGamepadReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseGamepadReturn { gamepads, .. } = use_gamepad();
+///
+/// view! {
+///     <For each=move || gamepads.get() key=|gamepad| gamepad.index let:gamepad>
+///         <p>{gamepad.id} ": " {gamepad.axes.len()} " axes"</p>
+///     </For>
+/// }
+/// # }
+/// ```
+///
+/// To only react to the changes of a single gamepad use `gamepad`. Browsers report small values
+/// for the axes of sticks at rest. Values within the `dead_zone` are reported as `0.0`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_gamepad_with_options, UseGamepadOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let gamepad_return = use_gamepad_with_options(UseGamepadOptions::default().dead_zone(0.2));
+///
+/// let first_gamepad = gamepad_return.gamepad(0);
+///
+/// let left_stick_x = Signal::derive(move || {
+///     first_gamepad.with(|gamepad| gamepad.as_ref().and_then(|g| g.axes.first().copied()))
+/// });
+/// let a_pressed = Signal::derive(move || {
+///     first_gamepad.with(|gamepad| gamepad.as_ref().is_some_and(|g| g.is_pressed(0)))
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is `false` and `gamepads` is always empty.
+pub fn use_gamepad() -> UseGamepadReturn<impl Fn() + Clone, impl Fn() + Clone> {
+    use_gamepad_with_options(UseGamepadOptions::default())
+}
+
+/// Version of [`use_gamepad`] that takes a `UseGamepadOptions`. See [`use_gamepad`] for how to use.
+pub fn use_gamepad_with_options(
+    options: UseGamepadOptions,
+) -> UseGamepadReturn<impl Fn() + Clone, impl Fn() + Clone> {
+    let UseGamepadOptions { dead_zone } = options;
+
+    let is_supported = use_supported(|| js!("getGamepads" in &window().navigator()));
+
+    let (gamepads, set_gamepads) = create_signal(Vec::<GamepadState>::new());
+
+    let update = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Ok(raw_gamepads) = window().navigator().get_gamepads() else {
+                return;
+            };
+
+            let new_gamepads = raw_gamepads
+                .iter()
+                .filter_map(|gamepad| gamepad.dyn_into::<web_sys::Gamepad>().ok())
+                .filter(web_sys::Gamepad::connected)
+                .map(|gamepad| GamepadState::new(&gamepad, dead_zone))
+                .collect::<Vec<_>>();
+
+            // only notify subscribers if something actually changed
+            if gamepads.with_untracked(|gamepads| *gamepads != new_gamepads) {
+                set_gamepads.set(new_gamepads);
+            }
+        }
+    };
+
+    let Pausable {
+        is_active,
+        pause,
+        resume,
+    } = use_raf_fn_with_options(
+        move |_| update(),
+        UseRafFnOptions::default().immediate(false),
+    );
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        let _ = use_event_listener(window(), ev::gamepadconnected, {
+            let resume = resume.clone();
+
+            move |_| {
+                update();
+                resume();
+            }
+        });
+
+        let _ = use_event_listener(window(), ev::gamepaddisconnected, {
+            let pause = pause.clone();
+
+            move |_| {
+                update();
+
+                if gamepads.with_untracked(Vec::is_empty) {
+                    pause();
+                }
+            }
+        });
+
+        // gamepads that were connected before
+        update();
+        if gamepads.with_untracked(|gamepads| !gamepads.is_empty()) {
+            resume();
+        }
+    }
+
+    UseGamepadReturn {
+        is_supported,
+        gamepads: gamepads.into(),
+        is_active,
+        pause,
+        resume,
+    }
+}
+
+/// Sets `value` to `0.0` if it lies within `dead_zone`.
+fn apply_dead_zone(value: f64, dead_zone: f64) -> f64 {
+    if value.abs() < dead_zone {
+        0.0
+    } else {
+        value
+    }
+}
+
+/// Options for [`use_gamepad_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseGamepadOptions {
+    /// Axis values with an absolute value below this are reported as `0.0`. Defaults to `0.1`.
+    dead_zone: f64,
+}
+
+impl Default for UseGamepadOptions {
+    fn default() -> Self {
+        Self { dead_zone: 0.1 }
+    }
+}
+
+/// Return type of [`use_gamepad`].
+pub struct UseGamepadReturn<PauseFn, ResumeFn>
+where
+    PauseFn: Fn() + Clone,
+    ResumeFn: Fn() + Clone,
+{
+    /// `true` if the browser supports the Gamepad API.
+    pub is_supported: Signal<bool>,
+
+    /// The connected gamepads.
+    pub gamepads: Signal<Vec<GamepadState>>,
+
+    /// Wether the gamepads are currently polled.
+    pub is_active: Signal<bool>,
+
+    /// Stops polling the gamepads.
+    pub pause: PauseFn,
+
+    /// Resumes polling the gamepads.
+    pub resume: ResumeFn,
+}
+
+impl<PauseFn, ResumeFn> UseGamepadReturn<PauseFn, ResumeFn>
+where
+    PauseFn: Fn() + Clone,
+    ResumeFn: Fn() + Clone,
+{
+    /// Returns a signal of the gamepad with the given `index`. It only changes when the state of
+    /// this gamepad changes.
+    pub fn gamepad(&self, index: u32) -> Signal<Option<GamepadState>> {
+        let gamepads = self.gamepads;
+
+        create_memo(move |_| {
+            gamepads.with(|gamepads| {
+                gamepads
+                    .iter()
+                    .find(|gamepad| gamepad.index == index)
+                    .cloned()
+            })
+        })
+        .into()
+    }
+}
+
+/// State of a gamepad at the time it was polled.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GamepadState {
+    /// The index of the gamepad. It stays the same as long as the gamepad is connected.
+    pub index: u32,
+
+    /// Identifies the model of the gamepad.
+    pub id: String,
+
+    /// The button and axis layout. With `GamepadMappingType::Standard` the buttons and axes
+    /// follow the [standard gamepad](https://w3c.github.io/gamepad/#remapping) layout.
+    pub mapping: web_sys::GamepadMappingType,
+
+    /// When the state of the gamepad was last updated.
+    pub timestamp: f64,
+
+    /// The buttons of the gamepad.
+    pub buttons: Vec<GamepadButtonState>,
+
+    /// The values of the axes between `-1.0` and `1.0` with the dead zone applied.
+    pub axes: Vec<f64>,
+}
+
+impl GamepadState {
+    #[cfg(not(feature = "ssr"))]
+    fn new(gamepad: &web_sys::Gamepad, dead_zone: f64) -> Self {
+        Self {
+            index: gamepad.index(),
+            id: gamepad.id(),
+            mapping: gamepad.mapping(),
+            timestamp: gamepad.timestamp(),
+            buttons: gamepad
+                .buttons()
+                .iter()
+                .map(|button| {
+                    let button = button.unchecked_into::<web_sys::GamepadButton>();
+
+                    GamepadButtonState {
+                        pressed: button.pressed(),
+                        touched: button.touched(),
+                        value: button.value(),
+                    }
+                })
+                .collect(),
+            axes: gamepad
+                .axes()
+                .iter()
+                .map(|axis| apply_dead_zone(axis.as_f64().unwrap_or_default(), dead_zone))
+                .collect(),
+        }
+    }
+
+    /// Wether the button with the given index is pressed.
+    pub fn is_pressed(&self, button: usize) -> bool {
+        self.buttons
+            .get(button)
+            .is_some_and(|button| button.pressed)
+    }
+}
+
+/// State of a button of a [`GamepadState`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GamepadButtonState {
+    /// Wether the button is pressed.
+    pub pressed: bool,
+
+    /// Wether the button is touched. Only supported by some gamepads.
+    pub touched: bool,
+
+    /// How far the button is pressed between `0.0` and `1.0`. Useful for triggers.
+    pub value: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_dead_zone() {
+        assert_eq!(apply_dead_zone(0.05, 0.1), 0.0);
+        assert_eq!(apply_dead_zone(-0.05, 0.1), 0.0);
+        assert_eq!(apply_dead_zone(0.5, 0.1), 0.5);
+        assert_eq!(apply_dead_zone(-1.0, 0.1), -1.0);
+        assert_eq!(apply_dead_zone(0.05, 0.0), 0.05);
+    }
+}
@@ -76,6 +76,11 @@ pub fn use_geolocation_with_options(
             let position_options = options.as_position_options();
 
             move || {
+                // already watching
+                if watch_handle.get().is_some() {
+                    return;
+                }
+
                 let navigator = use_window().navigator();
                 if let Some(navigator) = navigator {
                     if let Ok(geolocation) = navigator.geolocation() {
@@ -211,7 +216,7 @@ where
     /// The last error received from `navigator.geolocation`.
     pub error: Signal<Option<web_sys::PositionError>>,
 
-    /// Resume the geolocation watch.
+    /// Resume the geolocation watch. Does nothing if the watch is already running.
     pub resume: ResumeFn,
 
     /// Pause the geolocation watch.
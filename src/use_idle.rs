@@ -5,7 +5,10 @@ use cfg_if::cfg_if;
 use default_struct_builder::DefaultBuilder;
 use leptos::*;
 
+/// Tracks whether the user is being inactive.
 ///
+/// The user is considered active on mouse, keyboard, touch and wheel events as well as when the
+/// page becomes visible again. The event listener is throttled by 50ms by default.
 ///
 /// ## Demo
 ///
@@ -149,6 +152,12 @@ pub fn use_idle_with_options(
         }
 
         reset.clone()();
+
+        on_cleanup(move || {
+            if let Some(timer) = timer.take() {
+                timer.clear();
+            }
+        });
     }}
 
     UseIdleReturn {
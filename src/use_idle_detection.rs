@@ -0,0 +1,315 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut, use_event_listener, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Reactive [Idle Detection API](https://developer.mozilla.org/en-US/docs/Web/API/Idle_Detection_API).
+///
+/// In contrast to [`use_idle`](crate::use_idle) which only detects inactivity inside of the page,
+/// this detects if the user is idle on the whole device and if the screen is locked.
+///
+/// > This is an experimental API that is currently only available in Chromium based browsers.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_idle_detection)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_idle_detection, IdleUserState, UseIdleDetectionReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseIdleDetectionReturn {
+///     user_state,
+///     screen_state,
+///     start,
+///     ..
+/// } = use_idle_detection();
+///
+/// view! {
+///     <button on:click=move |_| start()>"Start detection"</button>
+///     <p>"User state: " {move || format!("{:?}", user_state.get())}</p>
+///     <p>"Screen state: " {move || format!("{:?}", screen_state.get())}</p>
+/// }
+/// # }
+/// ```
+///
+/// `start` requests the permission to detect idleness first. Browsers only allow to request it
+/// in response to a user interaction like a click. Once the permission has been granted, you can
+/// set `immediate` in the options to start the detection right away.
+///
+/// The user is considered idle after not interacting with the device for `threshold` milliseconds
+/// which is one minute by default. This is also the minimum value that is allowed.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_active` are `false`, the states are `None` and the
+/// functions do nothing.
+pub fn use_idle_detection() -> UseIdleDetectionReturn<impl Fn() + Clone, impl Fn() + Clone> {
+    use_idle_detection_with_options(UseIdleDetectionOptions::default())
+}
+
+/// Version of [`use_idle_detection`] that takes a `UseIdleDetectionOptions`. See [`use_idle_detection`] for how to use.
+pub fn use_idle_detection_with_options(
+    options: UseIdleDetectionOptions,
+) -> UseIdleDetectionReturn<impl Fn() + Clone, impl Fn() + Clone> {
+    let UseIdleDetectionOptions {
+        threshold,
+        immediate,
+    } = options;
+
+    let is_supported = use_supported(|| js!("IdleDetector" in &window()));
+
+    let (detector, set_detector) = create_signal(None::<web_sys::EventTarget>);
+    let (user_state, set_user_state) = create_signal(None::<IdleUserState>);
+    let (screen_state, set_screen_state) = create_signal(None::<IdleScreenState>);
+    let (error, set_error) = create_signal(None::<UseIdleDetectionError>);
+    let (is_active, set_active) = create_signal(false);
+
+    let abort_controller = store_value(None::<web_sys::AbortController>);
+
+    let update = move || {
+        if let Some(detector) = detector.get_untracked() {
+            let state = |key: &str| {
+                js_sys::Reflect::get(&detector, &key.into())
+                    .ok()
+                    .and_then(|state| state.as_string())
+            };
+
+            set_user_state.set(state("userState").and_then(|state| IdleUserState::parse(&state)));
+            set_screen_state
+                .set(state("screenState").and_then(|state| IdleScreenState::parse(&state)));
+        }
+    };
+
+    let _ = use_event_listener(detector, ev::change, move |_| update());
+
+    let stop = move || {
+        if let Some(controller) = abort_controller.try_update_value(Option::take).flatten() {
+            controller.abort();
+        }
+
+        set_detector.set(None);
+        set_user_state.set(None);
+        set_screen_state.set(None);
+        set_active.set(false);
+    };
+
+    let start = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_supported.get_untracked() || is_active.get_untracked() {
+                return;
+            }
+
+            let Ok(controller) = web_sys::AbortController::new() else {
+                return;
+            };
+            let signal = controller.signal();
+
+            abort_controller.set_value(Some(controller));
+            set_active.set(true);
+
+            spawn_local(async move {
+                match start_idle_detector(threshold, signal.clone()).await {
+                    // `stop` might have been called in the meantime
+                    Ok(_) if signal.aborted() => {}
+                    Ok(new_detector) => {
+                        set_detector.set(Some(new_detector));
+                        set_error.set(None);
+                        update();
+                    }
+                    Err(err) => {
+                        set_error.set(Some(err));
+                        stop();
+                    }
+                }
+            });
+        }
+    };
+
+    if immediate {
+        start();
+    }
+
+    on_cleanup(stop);
+
+    UseIdleDetectionReturn {
+        is_supported,
+        user_state: user_state.into(),
+        screen_state: screen_state.into(),
+        error: error.into(),
+        is_active: is_active.into(),
+        start,
+        stop,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn start_idle_detector(
+    threshold: u32,
+    signal: web_sys::AbortSignal,
+) -> Result<web_sys::EventTarget, UseIdleDetectionError> {
+    let window = window();
+
+    let class = js!(window["IdleDetector"])
+        .and_then(|class| class.dyn_into::<js_sys::Function>())
+        .map_err(UseIdleDetectionError::Start)?;
+
+    let permission = call_method(&class, "requestPermission", &[])?;
+    let permission = js_fut!(permission)
+        .await
+        .map_err(UseIdleDetectionError::Start)?;
+
+    if permission.as_string().as_deref() != Some("granted") {
+        return Err(UseIdleDetectionError::PermissionDenied);
+    }
+
+    let detector = js_sys::Reflect::construct(&class, &js_sys::Array::new())
+        .map_err(UseIdleDetectionError::Start)?;
+
+    let options = js_sys::Object::new();
+    js!(options["threshold"] = threshold);
+    js!(options["signal"] = signal);
+
+    let started = call_method(&detector, "start", &[options.into()])?;
+    js_fut!(started)
+        .await
+        .map_err(UseIdleDetectionError::Start)?;
+
+    Ok(detector.unchecked_into())
+}
+
+#[cfg(not(feature = "ssr"))]
+fn call_method(
+    target: &JsValue,
+    name: &str,
+    args: &[JsValue],
+) -> Result<js_sys::Promise, UseIdleDetectionError> {
+    js_sys::Reflect::get(target, &name.into())
+        .and_then(|method| method.dyn_into::<js_sys::Function>())
+        .and_then(|method| {
+            js_sys::Reflect::apply(&method, target, &args.iter().collect::<js_sys::Array>())
+        })
+        .and_then(|promise| promise.dyn_into::<js_sys::Promise>())
+        .map_err(UseIdleDetectionError::Start)
+}
+
+/// Options for [`use_idle_detection_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseIdleDetectionOptions {
+    /// Milliseconds of inactivity after which the user is considered idle.
+    /// Must be at least `60000`. Defaults to `60000`.
+    threshold: u32,
+
+    /// If `true` the detection is started when this function is called. This only works if the
+    /// permission has been granted before. Defaults to `false`.
+    immediate: bool,
+}
+
+impl Default for UseIdleDetectionOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 60_000,
+            immediate: false,
+        }
+    }
+}
+
+/// Return type of [`use_idle_detection`].
+pub struct UseIdleDetectionReturn<StartFn, StopFn>
+where
+    StartFn: Fn() + Clone,
+    StopFn: Fn() + Clone,
+{
+    /// `true` if the browser supports the Idle Detection API.
+    pub is_supported: Signal<bool>,
+
+    /// Wether the user is interacting with the device. `None` while the detection isn't running.
+    pub user_state: Signal<Option<IdleUserState>>,
+
+    /// Wether the screen is locked. `None` while the detection isn't running.
+    pub screen_state: Signal<Option<IdleScreenState>>,
+
+    /// Latest error that occurred while starting the detection.
+    pub error: Signal<Option<UseIdleDetectionError>>,
+
+    /// Wether the detection is running or being started.
+    pub is_active: Signal<bool>,
+
+    /// Requests the permission and starts the detection.
+    pub start: StartFn,
+
+    /// Stops the detection.
+    pub stop: StopFn,
+}
+
+/// State of the user as reported by the Idle Detection API.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IdleUserState {
+    /// The user has interacted with the device within the threshold.
+    Active,
+    /// The user hasn't interacted with the device for at least the threshold.
+    Idle,
+}
+
+impl IdleUserState {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "active" => Some(Self::Active),
+            "idle" => Some(Self::Idle),
+            _ => None,
+        }
+    }
+}
+
+/// State of the screen as reported by the Idle Detection API.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IdleScreenState {
+    /// The screen is locked, for example by a screen saver.
+    Locked,
+    /// The screen is unlocked.
+    Unlocked,
+}
+
+impl IdleScreenState {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "locked" => Some(Self::Locked),
+            "unlocked" => Some(Self::Unlocked),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseIdleDetectionError {
+    #[error("permission to detect idle state was not granted")]
+    PermissionDenied,
+    #[error("failed to start idle detection")]
+    Start(JsValue),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_states() {
+        assert_eq!(IdleUserState::parse("idle"), Some(IdleUserState::Idle));
+        assert_eq!(IdleUserState::parse("active"), Some(IdleUserState::Active));
+        assert_eq!(IdleUserState::parse("locked"), None);
+
+        assert_eq!(
+            IdleScreenState::parse("locked"),
+            Some(IdleScreenState::Locked)
+        );
+        assert_eq!(IdleScreenState::parse("idle"), None);
+    }
+}
@@ -0,0 +1,193 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use crate::use_event_listener;
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::Custom;
+use leptos::*;
+use web_sys::HtmlImageElement;
+
+/// Reactive image loading.
+///
+/// Loads an image in the background and tells you when it's ready to be displayed. That way
+/// you can show a placeholder until the image has been loaded.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_image)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_image, UseImageReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let src = "https://place.dog/300/200";
+///
+/// let UseImageReturn { is_loading, error, .. } = use_image(src);
+///
+/// view! {
+///     <Show
+///         when=move || !is_loading.get()
+///         fallback=|| view! { <p>"Loading..."</p> }
+///     >
+///         <Show
+///             when=move || error.with(Option::is_none)
+///             fallback=|| view! { <p>"Failed to load the image"</p> }
+///         >
+///             <img src=src />
+///         </Show>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// The source as well as `srcset` and `sizes` can be signals. Whenever one of them changes,
+/// the image is loaded again. Use the same values for your `<img>` so the browser picks the
+/// image that has already been loaded.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_image_with_options, UseImageOptions, UseImageReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (size, set_size) = create_signal(300);
+///
+/// let src = Signal::derive(move || format!("https://place.dog/{}/200", size.get()));
+/// let srcset = Signal::derive(move || {
+///     Some(format!("https://place.dog/{}/400 2x", size.get() * 2))
+/// });
+///
+/// let UseImageReturn { is_ready, .. } = use_image_with_options(
+///     src,
+///     UseImageOptions::default().srcset(srcset),
+/// );
+///
+/// view! {
+///     <Show when=move || is_ready.get()>
+///         <img src=src srcset=srcset />
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_loading` is always `true`, `is_ready` is always `false` and `error` and
+/// `image` are always `None`.
+pub fn use_image<S>(src: S) -> UseImageReturn
+where
+    S: Into<MaybeSignal<String>>,
+{
+    use_image_with_options(src, UseImageOptions::default())
+}
+
+/// Version of [`use_image`] that takes a `UseImageOptions`. See [`use_image`] for how to use.
+pub fn use_image_with_options<S>(src: S, options: UseImageOptions) -> UseImageReturn
+where
+    S: Into<MaybeSignal<String>>,
+{
+    let UseImageOptions {
+        srcset,
+        sizes,
+        cross_origin,
+        referrer_policy,
+    } = options;
+
+    let src = src.into();
+
+    let (image, set_image) = create_signal(None::<HtmlImageElement>);
+    let (is_loading, set_loading) = create_signal(true);
+    let (is_ready, set_ready) = create_signal(false);
+    let (error, set_error) = create_signal(None::<web_sys::Event>);
+
+    #[cfg(not(feature = "ssr"))]
+    if let Ok(new_image) = HtmlImageElement::new() {
+        new_image.set_cross_origin(cross_origin.as_deref());
+
+        if let Some(referrer_policy) = &referrer_policy {
+            new_image.set_referrer_policy(referrer_policy);
+        }
+
+        set_image.set(Some(new_image));
+    }
+
+    let _ = use_event_listener(image, Custom::<ev::Event>::new("load"), move |_| {
+        set_loading.set(false);
+        set_ready.set(true);
+    });
+
+    let _ = use_event_listener(image, Custom::<ev::Event>::new("error"), move |event| {
+        set_loading.set(false);
+        set_error.set(Some(event));
+    });
+
+    let _ = watch(
+        move || (image.get(), src.get(), srcset.get(), sizes.get()),
+        move |(image, src, srcset, sizes), _, _| {
+            let Some(image) = image else {
+                return;
+            };
+
+            set_loading.set(true);
+            set_ready.set(false);
+            set_error.set(None);
+
+            // `srcset` and `sizes` have to be set before `src` or the image might be loaded twice
+            image.set_srcset(srcset.as_deref().unwrap_or_default());
+            image.set_sizes(sizes.as_deref().unwrap_or_default());
+            image.set_src(src);
+        },
+        true,
+    );
+
+    UseImageReturn {
+        is_loading: is_loading.into(),
+        is_ready: is_ready.into(),
+        error: error.into(),
+        image: image.into(),
+    }
+}
+
+/// Options for [`use_image_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseImageOptions {
+    /// Candidate sources for different screen sizes or pixel densities like
+    /// `"image-2x.png 2x"`. See [`srcset`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLImageElement/srcset).
+    /// Defaults to `None`.
+    #[builder(into)]
+    srcset: MaybeSignal<Option<String>>,
+
+    /// Sizes that are used to pick one of the `srcset` sources like `"(max-width: 600px) 480px, 800px"`.
+    /// See [`sizes`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLImageElement/sizes).
+    /// Defaults to `None`.
+    #[builder(into)]
+    sizes: MaybeSignal<Option<String>>,
+
+    /// CORS setting of the request. Either `"anonymous"` or `"use-credentials"`.
+    /// Defaults to `None`.
+    #[builder(into)]
+    cross_origin: Option<String>,
+
+    /// Referrer policy of the request like `"no-referrer"`. Defaults to `None` which uses the
+    /// browser's default.
+    #[builder(into)]
+    referrer_policy: Option<String>,
+}
+
+/// Return type of [`use_image`].
+pub struct UseImageReturn {
+    /// `true` while the image is being loaded.
+    pub is_loading: Signal<bool>,
+
+    /// `true` once the image has been loaded successfully.
+    pub is_ready: Signal<bool>,
+
+    /// The error event if the image failed to load.
+    pub error: Signal<Option<web_sys::Event>>,
+
+    /// The image element that loads the image in the background.
+    pub image: Signal<Option<HtmlImageElement>>,
+}
@@ -0,0 +1,381 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut, use_supported};
+use leptos::*;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, ImageBitmap, MediaStreamTrack};
+
+/// Reactive [ImageCapture API](https://developer.mozilla.org/en-US/docs/Web/API/ImageCapture).
+///
+/// Takes photos and grabs frames from a video track, for example from a stream returned by
+/// [`use_user_media`](crate::use_user_media). The capabilities of the camera like zoom and
+/// torch are available reactively and can be changed with `set_zoom` and `set_torch`.
+///
+/// > This function requires `--cfg=web_sys_unstable_apis` to be activated as
+/// > [described in the wasm-bindgen guide](https://rustwasm.github.io/docs/wasm-bindgen/web-sys/unstable-apis.html).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_image_capture)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_image_capture, use_user_media, UseImageCaptureReturn, UseUserMediaReturn};
+/// # use wasm_bindgen::JsCast;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseUserMediaReturn { stream, .. } = use_user_media();
+///
+/// let video_track = Signal::derive(move || {
+///     stream
+///         .get()
+///         .and_then(Result::ok)
+///         .and_then(|stream| stream.get_video_tracks().get(0).dyn_into().ok())
+/// });
+///
+/// let UseImageCaptureReturn {
+///     photo,
+///     capabilities,
+///     take_photo,
+///     set_zoom,
+///     ..
+/// } = use_image_capture(video_track);
+///
+/// let url = move || {
+///     photo
+///         .get()
+///         .and_then(|blob| web_sys::Url::create_object_url_with_blob(&blob).ok())
+/// };
+///
+/// view! {
+///     <button on:click=move |_| take_photo()>"Take photo"</button>
+///     <button
+///         on:click=move |_| set_zoom(2.0)
+///         disabled=move || capabilities.with(|c| c.as_ref().map_or(true, |c| c.zoom.is_none()))
+///     >
+///         "Zoom"
+///     </button>
+///     <img src=url/>
+/// }
+/// # }
+/// ```
+///
+/// `take_photo` uses the full resolution of the camera and might trigger the flash while
+/// `grab_frame` is faster and returns the current frame of the video as an `ImageBitmap`
+/// in `frame`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is `false`, all other signals stay `None` and the functions
+/// do nothing.
+pub fn use_image_capture<T>(
+    video_track: T,
+) -> UseImageCaptureReturn<
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn(f64) + Clone,
+    impl Fn(bool) + Clone,
+>
+where
+    T: Into<MaybeSignal<Option<MediaStreamTrack>>>,
+{
+    let video_track: MaybeSignal<Option<MediaStreamTrack>> = video_track.into();
+    let video_track = Signal::derive(move || video_track.get());
+
+    let is_supported = use_supported(|| js!("ImageCapture" in &window()));
+
+    let (image_capture, set_image_capture) = create_signal(None::<web_sys::ImageCapture>);
+    let (capabilities, set_capabilities) = create_signal(None::<ImageCaptureCapabilities>);
+    let (photo, set_photo) = create_signal(None::<Blob>);
+    let (frame, set_frame) = create_signal(None::<ImageBitmap>);
+    let (error, set_error) = create_signal(None::<UseImageCaptureError>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let _ = watch(
+            move || video_track.get(),
+            move |track, _, _| {
+                set_capabilities.set(None);
+
+                if !is_supported.get_untracked() {
+                    return;
+                }
+
+                let Some(track) = track.clone() else {
+                    set_image_capture.set(None);
+                    return;
+                };
+
+                match web_sys::ImageCapture::new(&track) {
+                    Ok(new_image_capture) => {
+                        set_image_capture.set(Some(new_image_capture.clone()));
+                        set_error.set(None);
+
+                        spawn_local(async move {
+                            match js_fut!(new_image_capture.get_photo_capabilities()).await {
+                                Ok(photo_capabilities) => {
+                                    set_capabilities.set(Some(ImageCaptureCapabilities::new(
+                                        &photo_capabilities,
+                                        &track_capabilities(&track),
+                                    )))
+                                }
+                                Err(err) => {
+                                    set_error.set(Some(UseImageCaptureError::Capabilities(err)))
+                                }
+                            }
+                        });
+                    }
+                    Err(err) => {
+                        set_image_capture.set(None);
+                        set_error.set(Some(UseImageCaptureError::Create(err)));
+                    }
+                }
+            },
+            true,
+        );
+    }
+
+    let take_photo = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(image_capture) = image_capture.get_untracked() else {
+                set_error.set(Some(UseImageCaptureError::NoTrack));
+                return;
+            };
+
+            spawn_local(async move {
+                match js_fut!(image_capture.take_photo()).await {
+                    Ok(blob) => set_photo.set(Some(blob.unchecked_into())),
+                    Err(err) => set_error.set(Some(UseImageCaptureError::TakePhoto(err))),
+                }
+            });
+        }
+    };
+
+    let grab_frame = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(image_capture) = image_capture.get_untracked() else {
+                set_error.set(Some(UseImageCaptureError::NoTrack));
+                return;
+            };
+
+            spawn_local(async move {
+                match js_fut!(image_capture.grab_frame()).await {
+                    Ok(bitmap) => set_frame.set(Some(bitmap.unchecked_into())),
+                    Err(err) => set_error.set(Some(UseImageCaptureError::GrabFrame(err))),
+                }
+            });
+        }
+    };
+
+    let apply_constraint = move |key: &'static str, value: JsValue| {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(track) = video_track.get_untracked() else {
+                set_error.set(Some(UseImageCaptureError::NoTrack));
+                return;
+            };
+
+            let constraint = js_sys::Object::new();
+            let _ = js_sys::Reflect::set(&constraint, &key.into(), &value);
+
+            let constraints = js_sys::Object::new();
+            let advanced = js_sys::Array::of1(&constraint);
+            js!(constraints["advanced"] = advanced);
+
+            spawn_local(async move {
+                let result = match track.apply_constraints_with_constraints(
+                    constraints.unchecked_ref::<web_sys::MediaTrackConstraints>(),
+                ) {
+                    Ok(promise) => js_fut!(promise).await,
+                    Err(err) => Err(err),
+                };
+
+                match result {
+                    Ok(_) => set_capabilities.update(|capabilities| {
+                        if let Some(capabilities) = capabilities {
+                            capabilities.update_setting(key, &value);
+                        }
+                    }),
+                    Err(err) => set_error.set(Some(UseImageCaptureError::ApplyConstraints(err))),
+                }
+            });
+        }
+    };
+
+    let set_zoom = move |zoom: f64| apply_constraint("zoom", zoom.into());
+
+    let set_torch = move |torch: bool| apply_constraint("torch", torch.into());
+
+    UseImageCaptureReturn {
+        is_supported,
+        image_capture: image_capture.into(),
+        capabilities: capabilities.into(),
+        photo: photo.into(),
+        frame: frame.into(),
+        error: error.into(),
+        take_photo,
+        grab_frame,
+        set_zoom,
+        set_torch,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn track_capabilities(track: &MediaStreamTrack) -> JsValue {
+    // `getCapabilities` is not part of web-sys yet
+    js_sys::Reflect::get(track, &"getCapabilities".into())
+        .and_then(|get_capabilities| get_capabilities.dyn_into::<js_sys::Function>())
+        .and_then(|get_capabilities| get_capabilities.call0(track))
+        .unwrap_or(JsValue::UNDEFINED)
+}
+
+/// Return type of [`use_image_capture`].
+pub struct UseImageCaptureReturn<TakePhotoFn, GrabFrameFn, SetZoomFn, SetTorchFn>
+where
+    TakePhotoFn: Fn() + Clone,
+    GrabFrameFn: Fn() + Clone,
+    SetZoomFn: Fn(f64) + Clone,
+    SetTorchFn: Fn(bool) + Clone,
+{
+    /// `true` if the browser supports the ImageCapture API.
+    pub is_supported: Signal<bool>,
+
+    /// The `ImageCapture` of the current video track.
+    pub image_capture: Signal<Option<web_sys::ImageCapture>>,
+
+    /// The capabilities of the camera. `None` as long as they haven't been received.
+    pub capabilities: Signal<Option<ImageCaptureCapabilities>>,
+
+    /// The latest photo taken with `take_photo`.
+    pub photo: Signal<Option<Blob>>,
+
+    /// The latest frame grabbed with `grab_frame`.
+    pub frame: Signal<Option<ImageBitmap>>,
+
+    /// Latest error that occurred.
+    pub error: Signal<Option<UseImageCaptureError>>,
+
+    /// Takes a photo with the full resolution of the camera and puts it into `photo`.
+    pub take_photo: TakePhotoFn,
+
+    /// Grabs the current frame of the video track and puts it into `frame`.
+    pub grab_frame: GrabFrameFn,
+
+    /// Sets the zoom of the camera if it is supported.
+    pub set_zoom: SetZoomFn,
+
+    /// Turns the torch of the camera on or off if it has one.
+    pub set_torch: SetTorchFn,
+}
+
+/// Capabilities of a camera as reported by the browser.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImageCaptureCapabilities {
+    /// Supported zoom range. `None` if the camera can't zoom.
+    pub zoom: Option<MediaSettingsRange>,
+
+    /// The current zoom.
+    pub current_zoom: Option<f64>,
+
+    /// `true` if the camera has a torch.
+    pub torch: bool,
+
+    /// Wether the torch is currently turned on.
+    pub current_torch: bool,
+
+    /// Supported photo widths.
+    pub image_width: Option<MediaSettingsRange>,
+
+    /// Supported photo heights.
+    pub image_height: Option<MediaSettingsRange>,
+
+    /// Supported flash modes like `"auto"`, `"off"` or `"flash"`.
+    pub fill_light_mode: Vec<String>,
+}
+
+#[cfg(not(feature = "ssr"))]
+impl ImageCaptureCapabilities {
+    fn new(photo_capabilities: &JsValue, track_capabilities: &JsValue) -> Self {
+        let torch = js_sys::Reflect::get(track_capabilities, &"torch".into())
+            .map(|torch| torch.is_truthy())
+            .unwrap_or_default();
+
+        let fill_light_mode = js_sys::Reflect::get(photo_capabilities, &"fillLightMode".into())
+            .ok()
+            .filter(JsValue::is_object)
+            .map(|modes| {
+                js_sys::Array::from(&modes)
+                    .iter()
+                    .filter_map(|mode| mode.as_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            zoom: MediaSettingsRange::from_js(track_capabilities, "zoom"),
+            current_zoom: None,
+            torch,
+            current_torch: false,
+            image_width: MediaSettingsRange::from_js(photo_capabilities, "imageWidth"),
+            image_height: MediaSettingsRange::from_js(photo_capabilities, "imageHeight"),
+            fill_light_mode,
+        }
+    }
+
+    fn update_setting(&mut self, key: &str, value: &JsValue) {
+        match key {
+            "zoom" => self.current_zoom = value.as_f64(),
+            "torch" => self.current_torch = value.is_truthy(),
+            _ => {}
+        }
+    }
+}
+
+/// Range of values supported by a camera setting.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MediaSettingsRange {
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+}
+
+#[cfg(not(feature = "ssr"))]
+impl MediaSettingsRange {
+    fn from_js(target: &JsValue, key: &str) -> Option<Self> {
+        let range = js_sys::Reflect::get(target, &key.into()).ok()?;
+
+        let get = |key: &str| {
+            js_sys::Reflect::get(&range, &key.into())
+                .ok()
+                .and_then(|value| value.as_f64())
+        };
+
+        Some(Self {
+            min: get("min")?,
+            max: get("max")?,
+            step: get("step").unwrap_or(1.0),
+        })
+    }
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseImageCaptureError {
+    #[error("there is no video track")]
+    NoTrack,
+    #[error("failed to create image capture")]
+    Create(JsValue),
+    #[error("failed to get photo capabilities")]
+    Capabilities(JsValue),
+    #[error("failed to take photo")]
+    TakePhoto(JsValue),
+    #[error("failed to grab frame")]
+    GrabFrame(JsValue),
+    #[error("failed to apply constraints")]
+    ApplyConstraints(JsValue),
+}
@@ -0,0 +1,132 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::use_event_listener;
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::hashchange;
+use leptos::*;
+
+/// Reactive [`location.hash`](https://developer.mozilla.org/en-US/docs/Web/API/Location/hash).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_location_hash)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::use_location_hash;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (hash, set_hash) = use_location_hash();
+///
+/// set_hash.set("#settings".to_string()); // navigates to `#settings`
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// The signal is updated on `hashchange`, for example when the user clicks on a link to an
+/// anchor or navigates back. Setting it changes the hash of the URL which creates a new history
+/// entry.
+///
+/// ### Without `#`
+///
+/// For hash driven tabs it's easier to work without the leading `#`. Set the option
+/// `strip_hash` to remove it from the signal. When setting the signal you can also omit it.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_location_hash_with_options, UseLocationHashOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (tab, set_tab) =
+///     use_location_hash_with_options(UseLocationHashOptions::default().strip_hash(true));
+///
+/// view! {
+///     <button on:click=move |_| set_tab.set("profile".to_string())>"Profile"</button>
+///     <button on:click=move |_| set_tab.set("settings".to_string())>"Settings"</button>
+///
+///     <Show when=move || tab.get() == "settings" fallback=|| view! { <p>"Profile"</p> }>
+///         <p>"Settings"</p>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the hash is always empty because browsers don't send it to the server.
+///
+/// ## See also
+///
+/// * [`use_url_search_params`]
+pub fn use_location_hash() -> (Signal<String>, WriteSignal<String>) {
+    use_location_hash_with_options(UseLocationHashOptions::default())
+}
+
+/// Version of [`use_location_hash`] that takes a `UseLocationHashOptions`. See [`use_location_hash`] for how to use.
+pub fn use_location_hash_with_options(
+    options: UseLocationHashOptions,
+) -> (Signal<String>, WriteSignal<String>) {
+    let UseLocationHashOptions { strip_hash } = options;
+
+    let (hash, set_hash) = create_signal(String::new());
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let read = move || {
+            let hash = window().location().hash().unwrap_or_default();
+
+            if strip_hash {
+                hash.trim_start_matches('#').to_string()
+            } else {
+                hash
+            }
+        };
+
+        set_hash.set(read());
+
+        let _ = use_event_listener(window(), hashchange, move |_| set_hash.set(read()));
+
+        let _ = watch(
+            move || hash.get(),
+            move |hash, _, _| {
+                let location = window().location();
+
+                if normalize_hash(hash) != normalize_hash(&location.hash().unwrap_or_default()) {
+                    let _ = location.set_hash(hash);
+                }
+            },
+            false,
+        );
+    }
+
+    (hash.into(), set_hash)
+}
+
+/// The hash without the leading `#` so values with and without it can be compared.
+fn normalize_hash(hash: &str) -> &str {
+    hash.strip_prefix('#').unwrap_or(hash)
+}
+
+/// Options for [`use_location_hash_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseLocationHashOptions {
+    /// Remove the leading `#` from the hash. Defaults to `false`.
+    strip_hash: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_hash() {
+        assert_eq!(normalize_hash("#tab"), "tab");
+        assert_eq!(normalize_hash("tab"), "tab");
+        assert_eq!(normalize_hash(""), "");
+    }
+}
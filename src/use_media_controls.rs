@@ -0,0 +1,473 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::ElementMaybeSignal;
+use crate::{js_fut, use_event_listener};
+use leptos::ev::Custom;
+use leptos::*;
+use web_sys::{HtmlMediaElement, TextTrackKind, TextTrackList, TextTrackMode};
+
+/// Reactive controls for `<audio>` and `<video>` elements.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_media_controls)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::html::Video;
+/// # use leptos_use::{use_media_controls, UseMediaControlsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let video = create_node_ref::<Video>();
+///
+/// let UseMediaControlsReturn {
+///     playing,
+///     set_playing,
+///     current_time,
+///     set_current_time,
+///     duration,
+///     volume,
+///     set_volume,
+///     ..
+/// } = use_media_controls(video);
+///
+/// view! {
+///     <video node_ref=video src="https://example.com/video.mp4"></video>
+///
+///     <button on:click=move |_| set_playing.set(!playing.get())>
+///         {move || if playing.get() { "Pause" } else { "Play" }}
+///     </button>
+///     <button on:click=move |_| set_current_time.set(current_time.get() + 10.0)>"+10s"</button>
+///     <input
+///         type="range" min="0" max="1" step="0.01"
+///         prop:value=volume
+///         on:input=move |e| set_volume.set(event_target_value(&e).parse().unwrap_or(1.0))
+///     />
+///     <p>{move || format!("{:.0} / {:.0}", current_time.get(), duration.get())}</p>
+/// }
+/// # }
+/// ```
+///
+/// `playing`, `current_time`, `volume`, `muted` and `rate` are updated whenever the state of the
+/// element changes and setting them changes the element. All the other signals are read only.
+///
+/// ### Text Tracks
+///
+/// `tracks` contains the text tracks like subtitles or captions of the element. Show one of
+/// them with `enable_track` and hide it again with `disable_track`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::html::Video;
+/// # use leptos_use::{use_media_controls, UseMediaControlsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let video = create_node_ref::<Video>();
+///
+/// let UseMediaControlsReturn {
+///     tracks,
+///     selected_track,
+///     enable_track,
+///     disable_track,
+///     ..
+/// } = use_media_controls(video);
+///
+/// view! {
+///     <video node_ref=video src="https://example.com/video.mp4">
+///         <track kind="subtitles" label="English" srclang="en" src="https://example.com/en.vtt" />
+///     </video>
+///
+///     <For each=move || tracks.get() key=|track| track.index let:track>
+///         <button on:click={
+///             let enable_track = enable_track.clone();
+///             move |_| enable_track(track.index)
+///         }>
+///             {track.label}
+///         </button>
+///     </For>
+///     <button on:click=move |_| disable_track()>"Off"</button>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the signals always contain the state of a media element that hasn't been
+/// loaded yet and the functions do nothing.
+pub fn use_media_controls<El, T>(
+    target: El,
+) -> UseMediaControlsReturn<impl Fn(usize) + Clone, impl Fn() + Clone>
+where
+    El: Into<ElementMaybeSignal<T, HtmlMediaElement>>,
+    T: Into<HtmlMediaElement> + Clone + 'static,
+{
+    let target = target.into();
+    let media = Signal::derive(move || target.get().map(Into::<HtmlMediaElement>::into));
+
+    let (playing, set_playing) = create_signal(false);
+    let (current_time, set_current_time) = create_signal(0.0);
+    let (volume, set_volume) = create_signal(1.0);
+    let (muted, set_muted) = create_signal(false);
+    let (rate, set_rate) = create_signal(1.0);
+    let (duration, set_duration) = create_signal(0.0);
+    let (buffered, set_buffered) = create_signal(Vec::<(f64, f64)>::new());
+    let (seeking, set_seeking) = create_signal(false);
+    let (waiting, set_waiting) = create_signal(false);
+    let (ended, set_ended) = create_signal(false);
+    let (stalled, set_stalled) = create_signal(false);
+    let (tracks, set_tracks) = create_signal(Vec::<MediaTextTrack>::new());
+
+    let text_tracks = Signal::derive(move || media.get().and_then(|media| media.text_tracks()));
+
+    let update_tracks = move || {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(text_tracks) = text_tracks.get_untracked() {
+            set_tracks.set(
+                (0..text_tracks.length())
+                    .filter_map(|index| {
+                        text_tracks.get(index).map(|track| MediaTextTrack {
+                            index: index as usize,
+                            id: track.id(),
+                            label: track.label(),
+                            language: track.language(),
+                            kind: track.kind(),
+                            mode: track.mode(),
+                        })
+                    })
+                    .collect(),
+            );
+        }
+    };
+
+    let update_buffered = move || {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(media) = media.get_untracked() {
+            let ranges = media.buffered();
+
+            set_buffered.set(
+                (0..ranges.length())
+                    .filter_map(|index| Some((ranges.start(index).ok()?, ranges.end(index).ok()?)))
+                    .collect(),
+            );
+        }
+    };
+
+    // Read the whole state whenever the element changes
+    let _ = watch(
+        move || media.get(),
+        move |media, _, _| {
+            let Some(media) = media else {
+                return;
+            };
+
+            set_playing.set(!media.paused());
+            set_current_time.set(media.current_time());
+            set_volume.set(media.volume());
+            set_muted.set(media.muted());
+            set_rate.set(media.playback_rate());
+            set_duration.set(media.duration());
+            set_seeking.set(media.seeking());
+            set_ended.set(media.ended());
+            update_buffered();
+            update_tracks();
+        },
+        true,
+    );
+
+    // From the signals to the element. The values are compared so updates that come from the
+    // element itself don't cause e.g. a seek.
+
+    let _ = watch(
+        move || playing.get(),
+        move |playing, _, _| {
+            let Some(media) = media.get_untracked() else {
+                return;
+            };
+
+            if *playing && media.paused() {
+                #[cfg(not(feature = "ssr"))]
+                if let Ok(promise) = media.play() {
+                    spawn_local(async move {
+                        // playing can be prevented by the browser's autoplay policy
+                        if js_fut!(promise).await.is_err() {
+                            set_playing.set(false);
+                        }
+                    });
+                }
+            } else if !*playing && !media.paused() {
+                let _ = media.pause();
+            }
+        },
+        false,
+    );
+
+    let _ = watch(
+        move || current_time.get(),
+        move |current_time, _, _| {
+            if let Some(media) = media.get_untracked() {
+                if media.current_time() != *current_time {
+                    media.set_current_time(*current_time);
+                }
+            }
+        },
+        false,
+    );
+
+    let _ = watch(
+        move || volume.get(),
+        move |volume, _, _| {
+            if let Some(media) = media.get_untracked() {
+                if media.volume() != *volume {
+                    media.set_volume(*volume);
+                }
+            }
+        },
+        false,
+    );
+
+    let _ = watch(
+        move || muted.get(),
+        move |muted, _, _| {
+            if let Some(media) = media.get_untracked() {
+                if media.muted() != *muted {
+                    media.set_muted(*muted);
+                }
+            }
+        },
+        false,
+    );
+
+    let _ = watch(
+        move || rate.get(),
+        move |rate, _, _| {
+            if let Some(media) = media.get_untracked() {
+                if media.playback_rate() != *rate {
+                    media.set_playback_rate(*rate);
+                }
+            }
+        },
+        false,
+    );
+
+    // From the element to the signals
+
+    let _ = use_event_listener(media, Custom::<ev::Event>::new("timeupdate"), move |_| {
+        if let Some(media) = media.get_untracked() {
+            set_current_time.set(media.current_time());
+        }
+    });
+
+    for event in ["durationchange", "loadedmetadata"] {
+        let _ = use_event_listener(media, Custom::<ev::Event>::new(event), move |_| {
+            if let Some(media) = media.get_untracked() {
+                set_duration.set(media.duration());
+            }
+        });
+    }
+
+    for event in ["progress", "loadeddata", "emptied"] {
+        let _ = use_event_listener(media, Custom::<ev::Event>::new(event), move |_| {
+            update_buffered();
+        });
+    }
+
+    let _ = use_event_listener(media, Custom::<ev::Event>::new("volumechange"), move |_| {
+        if let Some(media) = media.get_untracked() {
+            set_volume.set(media.volume());
+            set_muted.set(media.muted());
+        }
+    });
+
+    let _ = use_event_listener(media, Custom::<ev::Event>::new("ratechange"), move |_| {
+        if let Some(media) = media.get_untracked() {
+            set_rate.set(media.playback_rate());
+        }
+    });
+
+    let _ = use_event_listener(media, Custom::<ev::Event>::new("play"), move |_| {
+        set_playing.set(true);
+        set_ended.set(false);
+    });
+
+    let _ = use_event_listener(media, Custom::<ev::Event>::new("pause"), move |_| {
+        set_playing.set(false);
+    });
+
+    let _ = use_event_listener(media, Custom::<ev::Event>::new("ended"), move |_| {
+        set_playing.set(false);
+        set_ended.set(true);
+    });
+
+    let _ = use_event_listener(media, Custom::<ev::Event>::new("seeking"), move |_| {
+        set_seeking.set(true);
+        set_ended.set(false);
+    });
+
+    let _ = use_event_listener(media, Custom::<ev::Event>::new("seeked"), move |_| {
+        set_seeking.set(false);
+    });
+
+    let _ = use_event_listener(media, Custom::<ev::Event>::new("waiting"), move |_| {
+        set_waiting.set(true);
+    });
+
+    let _ = use_event_listener(media, Custom::<ev::Event>::new("stalled"), move |_| {
+        set_stalled.set(true);
+    });
+
+    for event in ["playing", "canplay", "loadeddata"] {
+        let _ = use_event_listener(media, Custom::<ev::Event>::new(event), move |_| {
+            set_waiting.set(false);
+            set_stalled.set(false);
+        });
+    }
+
+    for event in ["addtrack", "removetrack", "change"] {
+        let _ = use_event_listener(text_tracks, Custom::<ev::Event>::new(event), move |_| {
+            update_tracks();
+        });
+    }
+
+    let enable_track = move |index: usize| {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(text_tracks) = text_tracks.get_untracked() {
+            set_modes(&text_tracks, Some(index));
+            update_tracks();
+        }
+    };
+
+    let disable_track = move || {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(text_tracks) = text_tracks.get_untracked() {
+            set_modes(&text_tracks, None);
+            update_tracks();
+        }
+    };
+
+    UseMediaControlsReturn {
+        playing: playing.into(),
+        set_playing,
+        current_time: current_time.into(),
+        set_current_time,
+        volume: volume.into(),
+        set_volume,
+        muted: muted.into(),
+        set_muted,
+        rate: rate.into(),
+        set_rate,
+        duration: duration.into(),
+        buffered: buffered.into(),
+        seeking: seeking.into(),
+        waiting: waiting.into(),
+        ended: ended.into(),
+        stalled: stalled.into(),
+        tracks: tracks.into(),
+        selected_track: Signal::derive(move || {
+            tracks.with(|tracks| {
+                tracks
+                    .iter()
+                    .find(|track| track.mode == TextTrackMode::Showing)
+                    .map(|track| track.index)
+            })
+        }),
+        enable_track,
+        disable_track,
+    }
+}
+
+/// Shows the track at `selected` and disables all the others.
+#[cfg(not(feature = "ssr"))]
+fn set_modes(text_tracks: &TextTrackList, selected: Option<usize>) {
+    for index in 0..text_tracks.length() {
+        if let Some(track) = text_tracks.get(index) {
+            track.set_mode(if Some(index as usize) == selected {
+                TextTrackMode::Showing
+            } else {
+                TextTrackMode::Disabled
+            });
+        }
+    }
+}
+
+/// A text track of the element controlled by [`use_media_controls`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MediaTextTrack {
+    /// Position of the track in the list of text tracks. Pass it to `enable_track` to show this track.
+    pub index: usize,
+    /// The `id` attribute of the `<track>`.
+    pub id: String,
+    /// Label of the track like `"English"`.
+    pub label: String,
+    /// Language of the track like `"en"`.
+    pub language: String,
+    /// Kind of the track like subtitles or captions.
+    pub kind: TextTrackKind,
+    /// If the track is currently shown.
+    pub mode: TextTrackMode,
+}
+
+/// Return type of [`use_media_controls`].
+pub struct UseMediaControlsReturn<EnableTrackFn, DisableTrackFn>
+where
+    EnableTrackFn: Fn(usize) + Clone,
+    DisableTrackFn: Fn() + Clone,
+{
+    /// Wether the media is playing.
+    pub playing: Signal<bool>,
+    /// Plays or pauses the media.
+    pub set_playing: WriteSignal<bool>,
+
+    /// The current playback position in seconds.
+    pub current_time: Signal<f64>,
+    /// Seeks to the given position in seconds.
+    pub set_current_time: WriteSignal<f64>,
+
+    /// Volume between `0.0` and `1.0`.
+    pub volume: Signal<f64>,
+    /// Changes the volume.
+    pub set_volume: WriteSignal<f64>,
+
+    /// Wether the media is muted.
+    pub muted: Signal<bool>,
+    /// Mutes or unmutes the media.
+    pub set_muted: WriteSignal<bool>,
+
+    /// Playback rate where `1.0` is the normal speed.
+    pub rate: Signal<f64>,
+    /// Changes the playback rate.
+    pub set_rate: WriteSignal<f64>,
+
+    /// Length of the media in seconds. `NaN` as long as it's unknown.
+    pub duration: Signal<f64>,
+
+    /// The time ranges `(start, end)` in seconds that have been buffered.
+    pub buffered: Signal<Vec<(f64, f64)>>,
+
+    /// Wether the media is seeking to a new position.
+    pub seeking: Signal<bool>,
+
+    /// Wether playback has stopped because the next frame isn't available yet.
+    pub waiting: Signal<bool>,
+
+    /// Wether the end of the media has been reached.
+    pub ended: Signal<bool>,
+
+    /// Wether the browser is trying to load data that isn't arriving.
+    pub stalled: Signal<bool>,
+
+    /// The text tracks of the element.
+    pub tracks: Signal<Vec<MediaTextTrack>>,
+
+    /// Index of the track that is currently shown.
+    pub selected_track: Signal<Option<usize>>,
+
+    /// Shows the track with the given index and hides all the others.
+    pub enable_track: EnableTrackFn,
+
+    /// Hides all the tracks.
+    pub disable_track: DisableTrackFn,
+}
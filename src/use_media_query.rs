@@ -1,11 +1,9 @@
 #![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
 
 use crate::use_event_listener;
-use cfg_if::cfg_if;
+use default_struct_builder::DefaultBuilder;
 use leptos::ev::change;
 use leptos::*;
-use std::cell::RefCell;
-use std::rc::Rc;
 
 /// Reactive [Media Query](https://developer.mozilla.org/en-US/docs/Web/CSS/Media_Queries/Testing_media_queries).
 ///
@@ -30,72 +28,147 @@ use std::rc::Rc;
 /// # }
 /// ```
 ///
+/// The query can also be a signal. The result is updated whenever the query changes.
+///
+/// ### Pause
+///
+/// With [`use_media_query_with_options`] you get a `pause` and `resume` handle. While paused
+/// `matches` keeps its last value and isn't updated. On `resume` the query is evaluated again.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_media_query_with_options, UseMediaQueryOptions, UseMediaQueryReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseMediaQueryReturn {
+///     matches,
+///     pause,
+///     resume,
+///     ..
+/// } = use_media_query_with_options("(orientation: portrait)", UseMediaQueryOptions::default());
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
-/// On the server this functions returns a Signal that is always `false`.
+/// On the server this functions returns a Signal that is always `false`. You can change this
+/// value with the option `initial_value`. It is also used in the browser until the query has been
+/// evaluated which avoids a mismatch when hydrating.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_media_query_with_options, UseMediaQueryOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let is_large_screen = use_media_query_with_options(
+///     "(min-width: 1024px)",
+///     UseMediaQueryOptions::default().initial_value(true),
+/// )
+/// .matches;
+/// #
+/// # view! { }
+/// # }
+/// ```
 ///
 /// ## See also
 ///
 /// * [`use_preferred_dark`]
 /// * [`use_preferred_contrast`]
 pub fn use_media_query(query: impl Into<MaybeSignal<String>>) -> Signal<bool> {
-    let query = query.into();
+    use_media_query_with_options(query, UseMediaQueryOptions::default()).matches
+}
 
-    let (matches, set_matches) = create_signal(false);
+/// Version of [`use_media_query`] that takes a `UseMediaQueryOptions`. See [`use_media_query`] for how to use.
+pub fn use_media_query_with_options(
+    query: impl Into<MaybeSignal<String>>,
+    options: UseMediaQueryOptions,
+) -> UseMediaQueryReturn<impl Fn() + Clone, impl Fn() + Clone> {
+    let UseMediaQueryOptions {
+        initial_value,
+        immediate,
+    } = options;
 
-    cfg_if! { if #[cfg(not(feature = "ssr"))] {
-        let media_query: Rc<RefCell<Option<web_sys::MediaQueryList>>> = Rc::new(RefCell::new(None));
-        let remove_listener: RemoveListener = Rc::new(RefCell::new(None));
+    let query = query.into();
 
-        let listener = Rc::new(RefCell::new(Rc::new(|_| {}) as Rc<dyn Fn(web_sys::Event)>));
+    let (matches, set_matches) = create_signal(initial_value);
+    let (is_active, set_active) = create_signal(immediate);
+    let (media_query, set_media_query) = create_signal(None::<web_sys::MediaQueryList>);
 
-        let cleanup = {
-            let remove_listener = Rc::clone(&remove_listener);
+    #[cfg(not(feature = "ssr"))]
+    {
+        create_effect(move |_| {
+            let query = query.get();
 
-            move || {
-                if let Some(remove_listener) = remove_listener.take().as_ref() {
-                    remove_listener();
-                }
+            if !is_active.get() {
+                set_media_query.set(None);
+                return;
             }
-        };
 
-        let update = {
-            let cleanup = cleanup.clone();
-            let listener = Rc::clone(&listener);
+            let new_media_query = window().match_media(&query).unwrap_or(None);
 
-            Rc::new(move || {
-                cleanup();
+            set_matches.set(
+                new_media_query
+                    .as_ref()
+                    .map(|media_query| media_query.matches())
+                    .unwrap_or_default(),
+            );
+            set_media_query.set(new_media_query);
+        });
 
-                let mut media_query = media_query.borrow_mut();
-                *media_query = window().match_media(&query.get()).unwrap_or(None);
-
-                if let Some(media_query) = media_query.as_ref() {
-                    set_matches.set(media_query.matches());
+        let _ = use_event_listener(media_query, change, move |_| {
+            if let Some(media_query) = media_query.get_untracked() {
+                set_matches.set(media_query.matches());
+            }
+        });
+    }
+
+    UseMediaQueryReturn {
+        matches: matches.into(),
+        is_active: is_active.into(),
+        pause: move || set_active.set(false),
+        resume: move || set_active.set(true),
+    }
+}
 
-                    let listener = Rc::clone(&*listener.borrow());
+/// Options for [`use_media_query_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseMediaQueryOptions {
+    /// The value of `matches` on the server and before the query has been evaluated in the
+    /// browser. Defaults to `false`.
+    initial_value: bool,
 
-                    remove_listener.replace(Some(Box::new(use_event_listener(
-                        media_query.clone(),
-                        change,
-                        move |e| listener(e),
-                    ))));
-                } else {
-                    set_matches.set(false);
-                }
-            })
-        };
+    /// If `false` the query isn't evaluated until `resume` is called. Defaults to `true`.
+    immediate: bool,
+}
 
-        {
-            let update = Rc::clone(&update);
-            listener.replace(Rc::new(move |_| update()) as Rc<dyn Fn(web_sys::Event)>);
+impl Default for UseMediaQueryOptions {
+    fn default() -> Self {
+        Self {
+            initial_value: false,
+            immediate: true,
         }
+    }
+}
 
-        create_effect(move |_| update());
+/// Return type of [`use_media_query_with_options`].
+pub struct UseMediaQueryReturn<PauseFn, ResumeFn>
+where
+    PauseFn: Fn() + Clone,
+    ResumeFn: Fn() + Clone,
+{
+    /// Wether the media query matches.
+    pub matches: Signal<bool>,
 
-        on_cleanup(cleanup);
-    }}
+    /// Wether `matches` is updated. `false` when paused.
+    pub is_active: Signal<bool>,
 
-    matches.into()
-}
+    /// Stops updating `matches`.
+    pub pause: PauseFn,
 
-type RemoveListener = Rc<RefCell<Option<Box<dyn Fn()>>>>;
+    /// Evaluates the query again and resumes updating `matches`.
+    pub resume: ResumeFn,
+}
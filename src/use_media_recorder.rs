@@ -0,0 +1,361 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, use_event_listener, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::Custom;
+use leptos::*;
+use std::rc::Rc;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+use web_sys::{Blob, BlobEvent, MediaStream, RecordingState};
+
+/// Reactive [MediaRecorder API](https://developer.mozilla.org/en-US/docs/Web/API/MediaRecorder).
+///
+/// Records a `MediaStream`, for example from [`use_user_media`](crate::use_user_media) or
+/// [`use_display_media`](crate::use_display_media). The recorded chunks are collected and
+/// put together into one `Blob` when the recording is stopped.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_media_recorder)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_media_recorder, use_user_media, UseMediaRecorderReturn, UseUserMediaReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseUserMediaReturn { stream, .. } = use_user_media();
+///
+/// let UseMediaRecorderReturn {
+///     state,
+///     data,
+///     start,
+///     stop,
+///     ..
+/// } = use_media_recorder(Signal::derive(move || stream.get().and_then(Result::ok)));
+///
+/// let url = move || {
+///     data.get()
+///         .and_then(|blob| web_sys::Url::create_object_url_with_blob(&blob).ok())
+/// };
+///
+/// view! {
+///     <button on:click=move |_| start()>"Record"</button>
+///     <button on:click=move |_| stop()>"Stop"</button>
+///     <video src=url controls=true></video>
+/// }
+/// # }
+/// ```
+///
+/// With `timeslice` in the options the recorder emits a chunk every `timeslice` milliseconds
+/// instead of one chunk at the end. Each chunk is passed to `on_data_available` which is useful
+/// to upload a long recording while it is still running.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_media_recorder_with_options, UseMediaRecorderOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let stream = Signal::derive(|| None);
+/// let recorder = use_media_recorder_with_options(
+///     stream,
+///     UseMediaRecorderOptions::default()
+///         .mime_type("audio/webm")
+///         .timeslice(1000)
+///         .on_data_available(|chunk: web_sys::Blob| {
+///             // upload chunk
+///         }),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `state` is always `RecordingState::Inactive`, `data` is `None` and the
+/// functions do nothing.
+pub fn use_media_recorder<S>(
+    stream: S,
+) -> UseMediaRecorderReturn<
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+>
+where
+    S: Into<MaybeSignal<Option<MediaStream>>>,
+{
+    use_media_recorder_with_options(stream, UseMediaRecorderOptions::default())
+}
+
+/// Version of [`use_media_recorder`] that takes a `UseMediaRecorderOptions`. See [`use_media_recorder`] for how to use.
+pub fn use_media_recorder_with_options<S>(
+    stream: S,
+    options: UseMediaRecorderOptions,
+) -> UseMediaRecorderReturn<
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+>
+where
+    S: Into<MaybeSignal<Option<MediaStream>>>,
+{
+    let UseMediaRecorderOptions {
+        mime_type,
+        timeslice,
+        audio_bits_per_second,
+        video_bits_per_second,
+        on_data_available,
+    } = options;
+
+    let stream = stream.into();
+
+    let is_supported = use_supported(|| js!("MediaRecorder" in &window()));
+
+    let (recorder, set_recorder) = create_signal(None::<web_sys::MediaRecorder>);
+    let (state, set_state) = create_signal(RecordingState::Inactive);
+    let (data, set_data) = create_signal(None::<Blob>);
+    let (error, set_error) = create_signal(None::<UseMediaRecorderError>);
+
+    let chunks = store_value(Vec::<Blob>::new());
+
+    let update_state = move || {
+        if let Some(recorder) = recorder.get_untracked() {
+            set_state.set(recorder.state());
+        }
+    };
+
+    let _ = use_event_listener(
+        recorder,
+        Custom::<BlobEvent>::new("dataavailable"),
+        move |event| {
+            if let Some(chunk) = event.data() {
+                #[cfg(debug_assertions)]
+                let prev = SpecialNonReactiveZone::enter();
+
+                on_data_available(chunk.clone());
+
+                #[cfg(debug_assertions)]
+                SpecialNonReactiveZone::exit(prev);
+
+                chunks.update_value(|chunks| chunks.push(chunk));
+            }
+        },
+    );
+
+    let _ = use_event_listener(recorder, Custom::<ev::Event>::new("stop"), move |_| {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let mime_type = recorder
+                .get_untracked()
+                .map(|recorder| recorder.mime_type())
+                .unwrap_or_default();
+
+            let parts = chunks.with_value(|chunks| chunks.iter().collect::<js_sys::Array>());
+            chunks.set_value(vec![]);
+
+            let mut blob_options = web_sys::BlobPropertyBag::new();
+            blob_options.type_(&mime_type);
+
+            match Blob::new_with_blob_sequence_and_options(&parts, &blob_options) {
+                Ok(blob) => set_data.set(Some(blob)),
+                Err(err) => set_error.set(Some(UseMediaRecorderError::Blob(err))),
+            }
+        }
+
+        update_state();
+    });
+
+    let _ = use_event_listener(recorder, Custom::<ev::Event>::new("error"), move |event| {
+        set_error.set(Some(UseMediaRecorderError::Recorder(event.into())));
+        update_state();
+    });
+
+    let start = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_supported.get_untracked() || state.get_untracked() != RecordingState::Inactive {
+                return;
+            }
+
+            let Some(stream) = stream.get_untracked() else {
+                set_error.set(Some(UseMediaRecorderError::NoStream));
+                return;
+            };
+
+            let mut recorder_options = web_sys::MediaRecorderOptions::new();
+            if let Some(mime_type) = &mime_type {
+                recorder_options.mime_type(mime_type);
+            }
+            if let Some(audio_bits_per_second) = audio_bits_per_second {
+                recorder_options.audio_bits_per_second(audio_bits_per_second);
+            }
+            if let Some(video_bits_per_second) = video_bits_per_second {
+                recorder_options.video_bits_per_second(video_bits_per_second);
+            }
+
+            let new_recorder =
+                match web_sys::MediaRecorder::new_with_media_stream_and_media_recorder_options(
+                    &stream,
+                    &recorder_options,
+                ) {
+                    Ok(new_recorder) => new_recorder,
+                    Err(err) => {
+                        set_error.set(Some(UseMediaRecorderError::Create(err)));
+                        return;
+                    }
+                };
+
+            // register the event listeners before starting
+            set_recorder.set(Some(new_recorder.clone()));
+
+            chunks.set_value(vec![]);
+            set_data.set(None);
+            set_error.set(None);
+
+            let result = match timeslice {
+                Some(timeslice) => new_recorder.start_with_time_slice(timeslice),
+                None => new_recorder.start(),
+            };
+
+            if let Err(err) = result {
+                set_error.set(Some(UseMediaRecorderError::Recorder(err)));
+            }
+
+            update_state();
+        }
+    };
+
+    let call = move |f: fn(&web_sys::MediaRecorder) -> Result<(), JsValue>| {
+        if let Some(recorder) = recorder.get_untracked() {
+            if let Err(err) = f(&recorder) {
+                set_error.set(Some(UseMediaRecorderError::Recorder(err)));
+            }
+
+            update_state();
+        }
+    };
+
+    let pause = move || {
+        if state.get_untracked() == RecordingState::Recording {
+            call(web_sys::MediaRecorder::pause);
+        }
+    };
+
+    let resume = move || {
+        if state.get_untracked() == RecordingState::Paused {
+            call(web_sys::MediaRecorder::resume);
+        }
+    };
+
+    let stop = move || {
+        if state.get_untracked() != RecordingState::Inactive {
+            call(web_sys::MediaRecorder::stop);
+        }
+    };
+
+    on_cleanup(stop);
+
+    UseMediaRecorderReturn {
+        is_supported,
+        recorder: recorder.into(),
+        state: state.into(),
+        data: data.into(),
+        error: error.into(),
+        start,
+        pause,
+        resume,
+        stop,
+    }
+}
+
+/// Options for [`use_media_recorder_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseMediaRecorderOptions {
+    /// The container format and codecs of the recording like `"video/webm;codecs=vp9"`.
+    /// Defaults to `None` which lets the browser choose.
+    #[builder(into)]
+    mime_type: Option<String>,
+
+    /// If set, a chunk is emitted every `timeslice` milliseconds. Otherwise the whole recording
+    /// is emitted as one chunk when it is stopped. Defaults to `None`.
+    #[builder(into)]
+    timeslice: Option<i32>,
+
+    /// Bit rate of the audio track. Defaults to `None` which lets the browser choose.
+    #[builder(into)]
+    audio_bits_per_second: Option<u32>,
+
+    /// Bit rate of the video track. Defaults to `None` which lets the browser choose.
+    #[builder(into)]
+    video_bits_per_second: Option<u32>,
+
+    /// Called with every recorded chunk.
+    on_data_available: Rc<dyn Fn(Blob)>,
+}
+
+impl Default for UseMediaRecorderOptions {
+    fn default() -> Self {
+        Self {
+            mime_type: None,
+            timeslice: None,
+            audio_bits_per_second: None,
+            video_bits_per_second: None,
+            on_data_available: Rc::new(|_| {}),
+        }
+    }
+}
+
+/// Return type of [`use_media_recorder`].
+pub struct UseMediaRecorderReturn<StartFn, PauseFn, ResumeFn, StopFn>
+where
+    StartFn: Fn() + Clone,
+    PauseFn: Fn() + Clone,
+    ResumeFn: Fn() + Clone,
+    StopFn: Fn() + Clone,
+{
+    /// `true` if the browser supports `MediaRecorder`.
+    pub is_supported: Signal<bool>,
+
+    /// The recorder of the current or last recording.
+    pub recorder: Signal<Option<web_sys::MediaRecorder>>,
+
+    /// The state of the recording.
+    pub state: Signal<RecordingState>,
+
+    /// The whole recording. Available after the recording has been stopped.
+    pub data: Signal<Option<Blob>>,
+
+    /// Latest error that occurred while recording.
+    pub error: Signal<Option<UseMediaRecorderError>>,
+
+    /// Starts a new recording of the current stream.
+    pub start: StartFn,
+
+    /// Pauses the recording.
+    pub pause: PauseFn,
+
+    /// Resumes a paused recording.
+    pub resume: ResumeFn,
+
+    /// Stops the recording. `data` is updated afterwards.
+    pub stop: StopFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseMediaRecorderError {
+    #[error("there is no stream to record")]
+    NoStream,
+    #[error("failed to create media recorder")]
+    Create(JsValue),
+    #[error("media recorder error")]
+    Recorder(JsValue),
+    #[error("failed to create blob from recorded data")]
+    Blob(JsValue),
+}
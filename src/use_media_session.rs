@@ -0,0 +1,385 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{MediaSessionAction, MediaSessionPlaybackState};
+
+/// Reactive [Media Session API](https://developer.mozilla.org/en-US/docs/Web/API/Media_Session_API).
+///
+/// Shows what is playing in the media controls of the operating system, for example on the
+/// lock screen or in the notification area, and handles the buttons of these controls.
+///
+/// > This function requires `--cfg=web_sys_unstable_apis` to be activated as
+/// > [described in the wasm-bindgen guide](https://rustwasm.github.io/docs/wasm-bindgen/web-sys/unstable-apis.html).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_media_session)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::html::Audio;
+/// # use leptos_use::{use_media_controls, use_media_session_with_options, MediaArtwork, UseMediaControlsReturn, UseMediaSessionOptions};
+/// # use web_sys::{MediaSessionAction, MediaSessionPlaybackState};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let audio = create_node_ref::<Audio>();
+///
+/// let UseMediaControlsReturn {
+///     playing,
+///     set_playing,
+///     current_time,
+///     set_current_time,
+///     duration,
+///     rate,
+///     ..
+/// } = use_media_controls(audio);
+///
+/// use_media_session_with_options(
+///     UseMediaSessionOptions::default()
+///         .title("Never Gonna Give You Up")
+///         .artist("Rick Astley")
+///         .artwork(vec![MediaArtwork::new("https://example.com/cover.png").sizes("512x512")])
+///         .playback_state(Signal::derive(move || {
+///             if playing.get() {
+///                 MediaSessionPlaybackState::Playing
+///             } else {
+///                 MediaSessionPlaybackState::Paused
+///             }
+///         }))
+///         .duration(duration)
+///         .position(current_time)
+///         .playback_rate(rate)
+///         .on_action(move |event| match event.action {
+///             MediaSessionAction::Play => set_playing.set(true),
+///             MediaSessionAction::Pause => set_playing.set(false),
+///             MediaSessionAction::Seekto => {
+///                 set_current_time.set(event.seek_time.unwrap_or_default())
+///             }
+///             _ => {}
+///         }),
+/// );
+///
+/// view! {
+///     <audio node_ref=audio src="https://example.com/song.mp3"></audio>
+/// }
+/// # }
+/// ```
+///
+/// The metadata and the position are updated whenever the signals change. The position is only
+/// shown if `duration` is a finite number.
+///
+/// Only the `actions` that are handled are shown in the controls of the operating system. By
+/// default these are play, pause, seek backward, seek forward, seek to, previous track and next
+/// track. Actions that aren't supported by the browser are skipped. The handlers are removed
+/// again when the component is cleaned up.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is always `false` and nothing happens.
+pub fn use_media_session() -> UseMediaSessionReturn {
+    use_media_session_with_options(UseMediaSessionOptions::default())
+}
+
+/// Version of [`use_media_session`] that takes a `UseMediaSessionOptions`. See [`use_media_session`] for how to use.
+pub fn use_media_session_with_options(options: UseMediaSessionOptions) -> UseMediaSessionReturn {
+    let UseMediaSessionOptions {
+        title,
+        artist,
+        album,
+        artwork,
+        playback_state,
+        duration,
+        position,
+        playback_rate,
+        actions,
+        on_action,
+    } = options;
+
+    let is_supported = use_supported(|| js!("mediaSession" in &window().navigator()));
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        let session = window().navigator().media_session();
+
+        let _ = watch(
+            move || (title.get(), artist.get(), album.get(), artwork.get()),
+            {
+                let session = session.clone();
+
+                move |(title, artist, album, artwork): &(
+                    String,
+                    String,
+                    String,
+                    Vec<MediaArtwork>,
+                ),
+                      _,
+                      _| {
+                    let mut init = web_sys::MediaMetadataInit::new();
+                    init.title(title).artist(artist).album(album).artwork(
+                        &artwork
+                            .iter()
+                            .map(MediaArtwork::to_media_image)
+                            .collect::<js_sys::Array>(),
+                    );
+
+                    if let Ok(metadata) = web_sys::MediaMetadata::new_with_init(&init) {
+                        session.set_metadata(Some(&metadata));
+                    }
+                }
+            },
+            true,
+        );
+
+        let _ = watch(
+            move || playback_state.get(),
+            {
+                let session = session.clone();
+
+                move |playback_state, _, _| {
+                    session.set_playback_state(*playback_state);
+                }
+            },
+            true,
+        );
+
+        let _ = watch(
+            move || (duration.get(), position.get(), playback_rate.get()),
+            {
+                let session = session.clone();
+
+                move |(duration, position, playback_rate), _, _| match position_state(
+                    *duration,
+                    *position,
+                    *playback_rate,
+                ) {
+                    Some((duration, position, playback_rate)) => {
+                        let mut state = web_sys::MediaPositionState::new();
+                        state
+                            .duration(duration)
+                            .position(position)
+                            .playback_rate(playback_rate);
+
+                        session.set_position_state_with_state(&state);
+                    }
+                    None => session.set_position_state(),
+                }
+            },
+            true,
+        );
+
+        let handlers = actions
+            .into_iter()
+            .filter_map(|action| {
+                let on_action = Rc::clone(&on_action);
+
+                let handler = Closure::wrap(Box::new(move |details: JsValue| {
+                    let event = MediaSessionActionEvent {
+                        action,
+                        seek_offset: js!(details["seekOffset"]).ok().and_then(|v| v.as_f64()),
+                        seek_time: js!(details["seekTime"]).ok().and_then(|v| v.as_f64()),
+                        fast_seek: js!(details["fastSeek"]).ok().and_then(|v| v.as_bool()),
+                    };
+
+                    #[cfg(debug_assertions)]
+                    let prev = SpecialNonReactiveZone::enter();
+
+                    on_action(event);
+
+                    #[cfg(debug_assertions)]
+                    SpecialNonReactiveZone::exit(prev);
+                }) as Box<dyn FnMut(JsValue)>);
+
+                set_action_handler(&session, action, handler.as_ref())
+                    .ok()
+                    .map(|_| (action, handler))
+            })
+            .collect::<Vec<_>>();
+
+        on_cleanup(move || {
+            for (action, _) in &handlers {
+                let _ = set_action_handler(&session, *action, &JsValue::NULL);
+            }
+
+            session.set_metadata(None);
+            session.set_playback_state(MediaSessionPlaybackState::None);
+        });
+    }
+
+    UseMediaSessionReturn { is_supported }
+}
+
+/// `setActionHandler` throws for actions that the browser doesn't know which the
+/// `web_sys` binding doesn't catch.
+#[cfg(not(feature = "ssr"))]
+fn set_action_handler(
+    session: &web_sys::MediaSession,
+    action: MediaSessionAction,
+    handler: &JsValue,
+) -> Result<JsValue, JsValue> {
+    js_sys::Reflect::get(session, &"setActionHandler".into())?
+        .unchecked_into::<js_sys::Function>()
+        .call2(session, &JsValue::from(action), handler)
+}
+
+/// Returns the values for the position state if they are valid. Otherwise the browser throws.
+fn position_state(duration: f64, position: f64, playback_rate: f64) -> Option<(f64, f64, f64)> {
+    if !duration.is_finite() || duration < 0.0 || playback_rate == 0.0 || !position.is_finite() {
+        return None;
+    }
+
+    Some((duration, position.clamp(0.0, duration), playback_rate))
+}
+
+/// Options for [`use_media_session_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseMediaSessionOptions {
+    /// Title of what is playing. Defaults to an empty string.
+    #[builder(into)]
+    title: MaybeSignal<String>,
+
+    /// Artist of what is playing. Defaults to an empty string.
+    #[builder(into)]
+    artist: MaybeSignal<String>,
+
+    /// Album of what is playing. Defaults to an empty string.
+    #[builder(into)]
+    album: MaybeSignal<String>,
+
+    /// Images like the album cover in different sizes. Defaults to an empty `Vec`.
+    #[builder(into)]
+    artwork: MaybeSignal<Vec<MediaArtwork>>,
+
+    /// If the media is playing or paused. Defaults to `MediaSessionPlaybackState::None`
+    /// which lets the browser figure it out.
+    #[builder(into)]
+    playback_state: MaybeSignal<MediaSessionPlaybackState>,
+
+    /// Length of the media in seconds. Defaults to `NaN` which means no position is shown.
+    #[builder(into)]
+    duration: MaybeSignal<f64>,
+
+    /// Current playback position in seconds. Defaults to `0.0`.
+    #[builder(into)]
+    position: MaybeSignal<f64>,
+
+    /// Playback rate where `1.0` is the normal speed. Defaults to `1.0`.
+    #[builder(into)]
+    playback_rate: MaybeSignal<f64>,
+
+    /// The actions that are handled by `on_action`. See the defaults above.
+    actions: Vec<MediaSessionAction>,
+
+    /// Called when the user presses one of the media controls.
+    on_action: Rc<dyn Fn(MediaSessionActionEvent)>,
+}
+
+impl Default for UseMediaSessionOptions {
+    fn default() -> Self {
+        Self {
+            title: MaybeSignal::default(),
+            artist: MaybeSignal::default(),
+            album: MaybeSignal::default(),
+            artwork: MaybeSignal::default(),
+            playback_state: MediaSessionPlaybackState::None.into(),
+            duration: f64::NAN.into(),
+            position: 0.0.into(),
+            playback_rate: 1.0.into(),
+            actions: vec![
+                MediaSessionAction::Play,
+                MediaSessionAction::Pause,
+                MediaSessionAction::Seekbackward,
+                MediaSessionAction::Seekforward,
+                MediaSessionAction::Seekto,
+                MediaSessionAction::Previoustrack,
+                MediaSessionAction::Nexttrack,
+            ],
+            on_action: Rc::new(|_| {}),
+        }
+    }
+}
+
+/// An image shown in the media controls.
+#[derive(DefaultBuilder, Clone, Debug, PartialEq, Eq)]
+pub struct MediaArtwork {
+    /// URL of the image.
+    #[builder(into)]
+    src: String,
+
+    /// Size of the image like `"512x512"`.
+    #[builder(into)]
+    sizes: Option<String>,
+
+    /// Mime type of the image like `"image/png"`.
+    #[builder(into)]
+    type_: Option<String>,
+}
+
+impl MediaArtwork {
+    /// Creates an artwork with the given image URL.
+    pub fn new(src: impl Into<String>) -> Self {
+        Self {
+            src: src.into(),
+            sizes: None,
+            type_: None,
+        }
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    fn to_media_image(&self) -> web_sys::MediaImage {
+        let mut image = web_sys::MediaImage::new(&self.src);
+
+        if let Some(sizes) = &self.sizes {
+            image.sizes(sizes);
+        }
+        if let Some(type_) = &self.type_ {
+            image.type_(type_);
+        }
+
+        image
+    }
+}
+
+/// Passed to the `on_action` handler of [`use_media_session_with_options`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MediaSessionActionEvent {
+    /// The action the user has triggered.
+    pub action: MediaSessionAction,
+
+    /// Seconds to move for `Seekbackward` and `Seekforward`. If `None` use your own default.
+    pub seek_offset: Option<f64>,
+
+    /// The position to seek to in seconds for `Seekto`.
+    pub seek_time: Option<f64>,
+
+    /// For `Seekto`, `true` if the seek is part of a sequence and you should seek fast.
+    pub fast_seek: Option<bool>,
+}
+
+/// Return type of [`use_media_session`].
+pub struct UseMediaSessionReturn {
+    /// `true` if the browser supports the Media Session API.
+    pub is_supported: Signal<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_state() {
+        assert_eq!(position_state(120.0, 30.0, 1.0), Some((120.0, 30.0, 1.0)));
+        assert_eq!(position_state(120.0, 150.0, 2.0), Some((120.0, 120.0, 2.0)));
+        assert_eq!(position_state(120.0, -1.0, 1.0), Some((120.0, 0.0, 1.0)));
+        assert_eq!(position_state(f64::NAN, 0.0, 1.0), None);
+        assert_eq!(position_state(f64::INFINITY, 0.0, 1.0), None);
+        assert_eq!(position_state(120.0, 0.0, 0.0), None);
+    }
+}
@@ -0,0 +1,336 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut, use_event_listener, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::Custom;
+use leptos::*;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{MidiAccess, MidiInput, MidiOutput};
+
+/// Reactive [Web MIDI API](https://developer.mozilla.org/en-US/docs/Web/API/Web_MIDI_API).
+///
+/// Requests access to the MIDI devices and keeps the lists of input and output ports up to date
+/// when devices are plugged in or out.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_midi)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_midi, UseMidiReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseMidiReturn {
+///     inputs,
+///     outputs,
+///     message,
+///     send,
+///     ..
+/// } = use_midi();
+///
+/// let play_note = move |_| {
+///     if let Some(output) = outputs.get_untracked().first() {
+///         // note on, middle C, full velocity
+///         let _ = send(&output.id(), &[0x90, 60, 0x7f]);
+///     }
+/// };
+///
+/// view! {
+///     <button on:click=play_note>"Play"</button>
+///     <p>{move || message.get().map(|message| format!("{:?}", message.data))}</p>
+/// }
+/// # }
+/// ```
+///
+/// Every message received by any of the input ports is available in `message`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is always `false`, the port lists are always empty and
+/// `send` always fails with [`UseMidiError::NoOutput`].
+#[allow(clippy::type_complexity)]
+pub fn use_midi(
+) -> UseMidiReturn<impl Fn() + Clone, impl Fn(&str, &[u8]) -> Result<(), UseMidiError> + Clone> {
+    use_midi_with_options(UseMidiOptions::default())
+}
+
+/// Version of [`use_midi`] that takes a `UseMidiOptions`. See [`use_midi`] for how to use.
+#[allow(clippy::type_complexity)]
+pub fn use_midi_with_options(
+    options: UseMidiOptions,
+) -> UseMidiReturn<impl Fn() + Clone, impl Fn(&str, &[u8]) -> Result<(), UseMidiError> + Clone> {
+    let UseMidiOptions {
+        immediate,
+        sysex,
+        software,
+    } = options;
+
+    let is_supported = use_supported(|| js!("requestMIDIAccess" in &window().navigator()));
+
+    let (access, set_access) = create_signal(None::<MidiAccess>);
+    let (inputs, set_inputs) = create_signal(Vec::<MidiInput>::new());
+    let (outputs, set_outputs) = create_signal(Vec::<MidiOutput>::new());
+    let (message, set_message) = create_signal(None::<MidiMessage>);
+    let (error, set_error) = create_signal(None::<UseMidiError>);
+
+    let on_message = store_value(None::<js_sys::Function>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use wasm_bindgen::closure::Closure;
+
+        let closure = Closure::wrap(Box::new(move |event: web_sys::MidiMessageEvent| {
+            let port_id = event
+                .target()
+                .map(|target| target.unchecked_into::<web_sys::MidiPort>().id())
+                .unwrap_or_default();
+
+            set_message.set(Some(MidiMessage {
+                port_id,
+                data: event.data().unwrap_or_default(),
+                timestamp: event.time_stamp(),
+            }));
+        }) as Box<dyn FnMut(web_sys::MidiMessageEvent)>);
+
+        on_message.set_value(Some(
+            closure.as_ref().unchecked_ref::<js_sys::Function>().clone(),
+        ));
+        closure.forget();
+    }
+
+    let update_ports = move || {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(access) = access.get_untracked() {
+            let new_inputs = access
+                .inputs()
+                .values()
+                .into_iter()
+                .filter_map(|input| input.ok())
+                .map(|input| input.unchecked_into::<MidiInput>())
+                .collect::<Vec<_>>();
+
+            // setting the handler also opens the port
+            for input in &new_inputs {
+                input.set_onmidimessage(on_message.get_value().as_ref());
+            }
+
+            set_inputs.set(new_inputs);
+            set_outputs.set(
+                access
+                    .outputs()
+                    .values()
+                    .into_iter()
+                    .filter_map(|output| output.ok())
+                    .map(|output| output.unchecked_into())
+                    .collect(),
+            );
+        }
+    };
+
+    let _ = use_event_listener(access, Custom::<ev::Event>::new("statechange"), move |_| {
+        update_ports();
+    });
+
+    let request_access = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_supported.get_untracked() || access.get_untracked().is_some() {
+                return;
+            }
+
+            let mut midi_options = web_sys::MidiOptions::new();
+            midi_options.sysex(sysex).software(software);
+
+            let promise = match window()
+                .navigator()
+                .request_midi_access_with_options(&midi_options)
+            {
+                Ok(promise) => promise,
+                Err(err) => {
+                    set_error.set(Some(UseMidiError::Access(err)));
+                    return;
+                }
+            };
+
+            spawn_local(async move {
+                match js_fut!(promise).await {
+                    Ok(new_access) => {
+                        set_access.set(Some(new_access.unchecked_into()));
+                        set_error.set(None);
+                        update_ports();
+                    }
+                    Err(err) => set_error.set(Some(UseMidiError::Access(err))),
+                }
+            });
+        }
+    };
+
+    if immediate {
+        request_access();
+    }
+
+    let send = move |output_id: &str, data: &[u8]| {
+        #[cfg(feature = "ssr")]
+        {
+            Err(UseMidiError::NoOutput)
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            let output = access
+                .get_untracked()
+                .and_then(|access| access.outputs().get(output_id))
+                .ok_or(UseMidiError::NoOutput)?;
+
+            output
+                .send(&js_sys::Uint8Array::from(data))
+                .map_err(UseMidiError::Send)
+        }
+    };
+
+    on_cleanup(move || {
+        for input in inputs.get_untracked() {
+            input.set_onmidimessage(None);
+        }
+    });
+
+    UseMidiReturn {
+        is_supported,
+        access: access.into(),
+        inputs: inputs.into(),
+        outputs: outputs.into(),
+        message: message.into(),
+        error: error.into(),
+        request_access,
+        send,
+    }
+}
+
+/// Options for [`use_midi_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseMidiOptions {
+    /// Request access to the MIDI devices immediately. Defaults to `true`.
+    /// If `false` you have to call `request_access` yourself.
+    immediate: bool,
+
+    /// Request the permission to send and receive system exclusive messages. Defaults to `false`.
+    sysex: bool,
+
+    /// Request access to software synthesizers as well. Defaults to `false`.
+    software: bool,
+}
+
+impl Default for UseMidiOptions {
+    fn default() -> Self {
+        Self {
+            immediate: true,
+            sysex: false,
+            software: false,
+        }
+    }
+}
+
+/// A message received by one of the input ports of [`use_midi`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MidiMessage {
+    /// Id of the input port that received the message.
+    pub port_id: String,
+
+    /// The bytes of the message. The first one is the status byte.
+    pub data: Vec<u8>,
+
+    /// Time the message has been received in milliseconds since the page was loaded.
+    pub timestamp: f64,
+}
+
+impl MidiMessage {
+    /// The command of the message, like `0x9` for "note on" or `0x8` for "note off".
+    /// `None` for system messages (`0xF0` to `0xFF`).
+    pub fn command(&self) -> Option<u8> {
+        self.data
+            .first()
+            .map(|status| status >> 4)
+            .filter(|command| *command != 0xf)
+    }
+
+    /// The channel (`0` to `15`) of the message. `None` for system messages.
+    pub fn channel(&self) -> Option<u8> {
+        self.command()
+            .and_then(|_| self.data.first())
+            .map(|status| status & 0x0f)
+    }
+}
+
+/// Return type of [`use_midi`].
+pub struct UseMidiReturn<RequestAccessFn, SendFn>
+where
+    RequestAccessFn: Fn() + Clone,
+    SendFn: Fn(&str, &[u8]) -> Result<(), UseMidiError> + Clone,
+{
+    /// `true` if the browser supports the Web MIDI API.
+    pub is_supported: Signal<bool>,
+
+    /// The MIDI access object once access has been granted.
+    pub access: Signal<Option<MidiAccess>>,
+
+    /// All available input ports.
+    pub inputs: Signal<Vec<MidiInput>>,
+
+    /// All available output ports.
+    pub outputs: Signal<Vec<MidiOutput>>,
+
+    /// The latest message received by any of the input ports.
+    pub message: Signal<Option<MidiMessage>>,
+
+    /// Latest error that occurred while requesting access.
+    pub error: Signal<Option<UseMidiError>>,
+
+    /// Requests access to the MIDI devices if it hasn't been granted yet.
+    pub request_access: RequestAccessFn,
+
+    /// Sends the given bytes to the output port with the given id.
+    pub send: SendFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseMidiError {
+    #[error("failed to get access to MIDI devices")]
+    Access(JsValue),
+    #[error("no output port with this id")]
+    NoOutput,
+    #[error("failed to send message")]
+    Send(JsValue),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(data: Vec<u8>) -> MidiMessage {
+        MidiMessage {
+            port_id: "input".to_string(),
+            data,
+            timestamp: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_command_and_channel() {
+        let note_on = message(vec![0x93, 60, 0x7f]);
+        assert_eq!(note_on.command(), Some(0x9));
+        assert_eq!(note_on.channel(), Some(3));
+
+        let clock = message(vec![0xf8]);
+        assert_eq!(clock.command(), None);
+        assert_eq!(clock.channel(), None);
+
+        let empty = message(vec![]);
+        assert_eq!(empty.command(), None);
+        assert_eq!(empty.channel(), None);
+    }
+}
@@ -0,0 +1,158 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{use_event_listener, use_supported};
+use leptos::*;
+use wasm_bindgen::JsValue;
+
+/// Reactive [Network Information API](https://developer.mozilla.org/en-US/docs/Web/API/Network_Information_API).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_network)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_network, NetworkEffectiveType, UseNetworkReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseNetworkReturn {
+///     effective_type,
+///     save_data,
+///     ..
+/// } = use_network();
+///
+/// let load_hd_images = move || {
+///     !save_data.get() && effective_type.get() == Some(NetworkEffectiveType::FourG)
+/// };
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// The values are updated whenever the browser fires a `change` event on `navigator.connection`.
+/// Which values are available depends on the browser. Values that are not available are `None`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `save_data` are `false` and all other signals are `None`.
+pub fn use_network() -> UseNetworkReturn {
+    let is_supported = use_supported(|| crate::js!("connection" in &window().navigator()));
+
+    let (effective_type, set_effective_type) = create_signal(None::<NetworkEffectiveType>);
+    let (downlink, set_downlink) = create_signal(None::<f64>);
+    let (rtt, set_rtt) = create_signal(None::<f64>);
+    let (save_data, set_save_data) = create_signal(false);
+    let (connection_type, set_connection_type) = create_signal(None::<web_sys::ConnectionType>);
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        if let Ok(connection) = window().navigator().connection() {
+            let update = {
+                let connection = connection.clone();
+
+                move || {
+                    set_effective_type.set(
+                        get_property(&connection, "effectiveType")
+                            .as_string()
+                            .and_then(|effective_type| {
+                                NetworkEffectiveType::parse(&effective_type)
+                            }),
+                    );
+                    set_downlink.set(get_property(&connection, "downlink").as_f64());
+                    set_rtt.set(get_property(&connection, "rtt").as_f64());
+                    set_save_data.set(get_property(&connection, "saveData").is_truthy());
+                    set_connection_type.set(match connection.type_() {
+                        web_sys::ConnectionType::__Nonexhaustive => None,
+                        connection_type => Some(connection_type),
+                    });
+                }
+            };
+
+            update();
+
+            let _ = use_event_listener(connection, ev::change, move |_| update());
+        }
+    }
+
+    UseNetworkReturn {
+        is_supported,
+        effective_type: effective_type.into(),
+        downlink: downlink.into(),
+        rtt: rtt.into(),
+        save_data: save_data.into(),
+        connection_type: connection_type.into(),
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn get_property(connection: &web_sys::NetworkInformation, key: &str) -> JsValue {
+    js_sys::Reflect::get(connection, &key.into()).unwrap_or(JsValue::UNDEFINED)
+}
+
+/// Effective type of the connection as reported by `navigator.connection.effectiveType`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NetworkEffectiveType {
+    /// `"slow-2g"`
+    Slow2G,
+    /// `"2g"`
+    TwoG,
+    /// `"3g"`
+    ThreeG,
+    /// `"4g"`
+    FourG,
+}
+
+impl NetworkEffectiveType {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "slow-2g" => Some(Self::Slow2G),
+            "2g" => Some(Self::TwoG),
+            "3g" => Some(Self::ThreeG),
+            "4g" => Some(Self::FourG),
+            _ => None,
+        }
+    }
+}
+
+/// Return type of [`use_network`].
+#[derive(Copy, Clone)]
+pub struct UseNetworkReturn {
+    /// `true` if the browser supports `navigator.connection`.
+    pub is_supported: Signal<bool>,
+
+    /// The effective type of the connection based on the measured `rtt` and `downlink`.
+    pub effective_type: Signal<Option<NetworkEffectiveType>>,
+
+    /// The estimated bandwidth in megabits per second.
+    pub downlink: Signal<Option<f64>>,
+
+    /// The estimated round-trip time in milliseconds.
+    pub rtt: Signal<Option<f64>>,
+
+    /// Wether the user has requested a reduced data usage.
+    pub save_data: Signal<bool>,
+
+    /// The type of connection the device uses to communicate with the network like `Wifi` or `Cellular`.
+    pub connection_type: Signal<Option<web_sys::ConnectionType>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_effective_type() {
+        assert_eq!(
+            NetworkEffectiveType::parse("slow-2g"),
+            Some(NetworkEffectiveType::Slow2G)
+        );
+        assert_eq!(
+            NetworkEffectiveType::parse("4g"),
+            Some(NetworkEffectiveType::FourG)
+        );
+        assert_eq!(NetworkEffectiveType::parse("5g"), None);
+    }
+}
@@ -0,0 +1,75 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+
+use leptos::*;
+
+/// Reactive URL representing an object.
+///
+/// Creates an URL for the provided `Blob` or `File` with
+/// [`URL.createObjectURL()`](https://developer.mozilla.org/en-US/docs/Web/API/URL/createObjectURL_static).
+/// The previous URL is released with
+/// [`URL.revokeObjectURL()`](https://developer.mozilla.org/en-US/docs/Web/API/URL/revokeObjectURL_static)
+/// whenever the object changes and when the component is cleaned up so the browser can free
+/// the memory of the object.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_object_url)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_file_dialog, use_object_url, UseFileDialogReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseFileDialogReturn { files, open, .. } = use_file_dialog();
+///
+/// let url = use_object_url(Signal::derive(move || {
+///     files.get().into_iter().next().map(web_sys::Blob::from)
+/// }));
+///
+/// view! {
+///     <button on:click=move |_| open()>"Choose image"</button>
+///     <img src=url/>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the returned signal always contains `None`.
+pub fn use_object_url<B>(object: B) -> Signal<Option<String>>
+where
+    B: Into<MaybeSignal<Option<web_sys::Blob>>>,
+{
+    let object = object.into();
+
+    let (url, set_url) = create_signal(None::<String>);
+
+    let release = move || {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(url) = url.get_untracked() {
+            let _ = web_sys::Url::revoke_object_url(&url);
+        }
+
+        set_url.set(None);
+    };
+
+    let _ = watch(
+        move || object.get(),
+        move |object, _, _| {
+            release();
+
+            #[cfg(not(feature = "ssr"))]
+            if let Some(object) = object {
+                set_url.set(web_sys::Url::create_object_url_with_blob(object).ok());
+            }
+        },
+        true,
+    );
+
+    on_cleanup(release);
+
+    url.into()
+}
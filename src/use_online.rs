@@ -0,0 +1,165 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::now;
+use crate::{use_event_listener, use_interval_fn, use_window};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+
+/// Reactive online state.
+///
+/// Combines [`navigator.onLine`](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/onLine)
+/// with the `online` and `offline` events of `window`.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_online)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_online, UseOnlineReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseOnlineReturn { is_online, .. } = use_online();
+///
+/// view! {
+///     <Show when=move || !is_online.get()>
+///         <p>"You are offline"</p>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// ### Probing
+///
+/// `navigator.onLine` being `false` reliably means that there is no network connection but
+/// `true` only means that the device is connected to some network. It might still be unable
+/// to reach the internet. Set `probe_url` to additionally send a `HEAD` request to that URL
+/// every `probe_interval` milliseconds and whenever the browser reports to be online.
+/// The state is only considered online if that request succeeds. Any response counts as success,
+/// so the URL has to be on the same origin or allow CORS.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_online_with_options, UseOnlineOptions, UseOnlineReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseOnlineReturn {
+///     is_online,
+///     last_checked,
+/// } = use_online_with_options(
+///     UseOnlineOptions::default()
+///         .probe_url("/health")
+///         .probe_interval(10_000),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_online` is always `true` and `last_checked` is `None`.
+pub fn use_online() -> UseOnlineReturn {
+    use_online_with_options(UseOnlineOptions::default())
+}
+
+/// Version of [`use_online`] that takes a `UseOnlineOptions`. See [`use_online`] for how to use.
+pub fn use_online_with_options(options: UseOnlineOptions) -> UseOnlineReturn {
+    let UseOnlineOptions {
+        probe_url,
+        probe_interval,
+    } = options;
+
+    let (is_online, set_online) = create_signal(true);
+    let (last_checked, set_last_checked) = create_signal(None::<f64>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let has_probe = probe_url.is_some();
+
+        let check = move || {
+            let navigator_online = window().navigator().on_line();
+
+            match (&probe_url, navigator_online) {
+                (Some(probe_url), true) => {
+                    let probe_url = probe_url.clone();
+
+                    spawn_local(async move {
+                        let reachable = probe(&probe_url).await;
+                        set_online.set(reachable);
+                        set_last_checked.set(Some(now()));
+                    });
+                }
+                _ => {
+                    set_online.set(navigator_online);
+                    set_last_checked.set(Some(now()));
+                }
+            }
+        };
+
+        check();
+
+        let _ = use_event_listener(use_window(), ev::online, {
+            let check = check.clone();
+            move |_| check()
+        });
+        let _ = use_event_listener(use_window(), ev::offline, {
+            let check = check.clone();
+            move |_| check()
+        });
+
+        if has_probe {
+            let _ = use_interval_fn(check, probe_interval);
+        }
+    }
+
+    UseOnlineReturn {
+        is_online: is_online.into(),
+        last_checked: last_checked.into(),
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn probe(url: &str) -> bool {
+    let mut init = web_sys::RequestInit::new();
+    init.method("HEAD").cache(web_sys::RequestCache::NoStore);
+
+    crate::js_fut!(window().fetch_with_str_and_init(url, &init))
+        .await
+        .is_ok()
+}
+
+/// Options for [`use_online_with_options`].
+#[derive(DefaultBuilder, Clone)]
+pub struct UseOnlineOptions {
+    /// URL that is requested to check if the internet is actually reachable.
+    /// Defaults to `None` which means that only `navigator.onLine` is used.
+    #[builder(into)]
+    probe_url: Option<String>,
+
+    /// Interval in milliseconds in which the `probe_url` is requested. Defaults to `30000`.
+    probe_interval: u64,
+}
+
+impl Default for UseOnlineOptions {
+    fn default() -> Self {
+        Self {
+            probe_url: None,
+            probe_interval: 30_000,
+        }
+    }
+}
+
+/// Return type of [`use_online`].
+#[derive(Copy, Clone)]
+pub struct UseOnlineReturn {
+    /// Wether the browser is online.
+    pub is_online: Signal<bool>,
+
+    /// Timestamp in milliseconds of the last time the online state was checked.
+    pub last_checked: Signal<Option<f64>>,
+}
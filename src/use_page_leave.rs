@@ -0,0 +1,53 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{use_document, use_event_listener_with_options, use_window, UseEventListenerOptions};
+use leptos::ev::{mouseenter, mouseleave, mouseout};
+use leptos::*;
+
+/// Reactive state to show whether the mouse leaves the page.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_page_leave)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::use_page_leave;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let is_left = use_page_leave();
+///
+/// view! {
+///     <Show when=move || is_left.get()>
+///         <div class="modal">"Wait! Don't forget to save your changes."</div>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// The value is `true` as soon as the pointer leaves the page, for example when the user moves
+/// it towards the tab bar or the close button. This is commonly used for exit-intent dialogs.
+/// Moving the pointer from one element to another inside of the page doesn't change the value.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this returns a `Signal` that is always `false`.
+pub fn use_page_leave() -> Signal<bool> {
+    let (is_left, set_is_left) = create_signal(false);
+
+    let handler = move |event: web_sys::MouseEvent| {
+        // `related_target` is the element the pointer moved to which is `None` outside of the page
+        set_is_left.set(event.related_target().is_none());
+    };
+
+    let options = UseEventListenerOptions::default().passive(true);
+
+    let _ = use_event_listener_with_options(use_window(), mouseout, handler, options);
+    let _ = use_event_listener_with_options(use_document(), mouseleave, handler, options);
+    let _ = use_event_listener_with_options(use_document(), mouseenter, handler, options);
+
+    is_left.into()
+}
@@ -30,31 +30,25 @@ pub fn use_permission(permission_name: &str) -> Signal<PermissionState> {
     #[cfg(not(feature = "ssr"))]
     {
         use crate::use_event_listener;
-        use std::cell::RefCell;
-        use std::rc::Rc;
 
-        let permission_status = Rc::new(RefCell::new(None::<web_sys::PermissionStatus>));
+        let (permission_status, set_permission_status) =
+            create_signal(None::<web_sys::PermissionStatus>);
 
-        let on_change = {
-            let permission_status = Rc::clone(&permission_status);
-
-            move || {
-                if let Some(permission_status) = permission_status.borrow().as_ref() {
-                    set_state.set(PermissionState::from(permission_status.state()));
-                }
+        let on_change = move || {
+            if let Some(permission_status) = permission_status.get_untracked() {
+                set_state.set(PermissionState::from(permission_status.state()));
             }
         };
 
+        // registered here instead of after the query so the listener is removed on cleanup
+        let _ = use_event_listener(permission_status, ev::change, move |_| on_change());
+
         spawn_local({
             let permission_name = permission_name.to_owned();
 
             async move {
                 if let Ok(status) = query_permission(permission_name).await {
-                    let _ = use_event_listener(status.clone(), ev::change, {
-                        let on_change = on_change.clone();
-                        move |_| on_change()
-                    });
-                    permission_status.replace(Some(status));
+                    set_permission_status.set(Some(status));
                     on_change();
                 } else {
                     set_state.set(PermissionState::Prompt);
@@ -73,7 +67,7 @@ pub fn use_permission(permission_name: &str) -> Signal<PermissionState> {
 }
 
 /// Return type of [`use_permission`].
-#[derive(Copy, Clone, Default, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
 pub enum PermissionState {
     /// State hasn't been requested yet. This is the initial value.
     #[default]
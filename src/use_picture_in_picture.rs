@@ -0,0 +1,249 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::ElementMaybeSignal;
+use crate::{js, js_fut, use_event_listener, use_supported};
+use leptos::ev::Custom;
+use leptos::*;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::HtmlVideoElement;
+
+/// Reactive [Picture-in-Picture API](https://developer.mozilla.org/en-US/docs/Web/API/Picture-in-Picture_API).
+///
+/// Plays a video in a small always-on-top window.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_picture_in_picture)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::html::Video;
+/// # use leptos_use::{use_picture_in_picture, UsePictureInPictureReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let video = create_node_ref::<Video>();
+///
+/// let UsePictureInPictureReturn {
+///     is_supported,
+///     is_active,
+///     toggle,
+///     width,
+///     height,
+///     ..
+/// } = use_picture_in_picture(video);
+///
+/// view! {
+///     <video node_ref=video src="https://example.com/video.mp4" controls></video>
+///     <button on:click=move |_| toggle() disabled=move || !is_supported.get()>
+///         {move || if is_active.get() { "Exit Picture-in-Picture" } else { "Enter Picture-in-Picture" }}
+///     </button>
+///     <p>{move || format!("{} x {}", width.get(), height.get())}</p>
+/// }
+/// # }
+/// ```
+///
+/// `enter` has to be called in response to a user action like a click. `width` and `height`
+/// contain the size of the Picture-in-Picture window while it's open and are `0` otherwise.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_active` are always `false`, `width` and `height` are
+/// always `0` and the functions do nothing.
+pub fn use_picture_in_picture<El, T>(
+    target: El,
+) -> UsePictureInPictureReturn<impl Fn() + Clone, impl Fn() + Clone, impl Fn() + Clone>
+where
+    El: Into<ElementMaybeSignal<T, HtmlVideoElement>>,
+    T: Into<HtmlVideoElement> + Clone + 'static,
+{
+    let target = target.into();
+    let target = Signal::derive(move || target.get().map(Into::<HtmlVideoElement>::into));
+
+    let is_supported = use_supported(|| {
+        let document = document();
+        js!(document["pictureInPictureEnabled"]).is_ok_and(|enabled| enabled.is_truthy())
+    });
+
+    let (is_active, set_active) = create_signal(false);
+    let (pip_window, set_pip_window) = create_signal(None::<web_sys::EventTarget>);
+    let (width, set_width) = create_signal(0);
+    let (height, set_height) = create_signal(0);
+    let (error, set_error) = create_signal(None::<UsePictureInPictureError>);
+
+    let update_size = move || match pip_window.get_untracked() {
+        Some(pip_window) => {
+            let size = |key: &str| {
+                js_sys::Reflect::get(&pip_window, &key.into())
+                    .ok()
+                    .and_then(|value| value.as_f64())
+                    .unwrap_or_default() as u32
+            };
+
+            set_width.set(size("width"));
+            set_height.set(size("height"));
+        }
+        None => {
+            set_width.set(0);
+            set_height.set(0);
+        }
+    };
+
+    let _ = use_event_listener(
+        target,
+        Custom::<ev::Event>::new("enterpictureinpicture"),
+        move |event| {
+            set_active.set(true);
+            set_pip_window.set(
+                js!(event["pictureInPictureWindow"])
+                    .ok()
+                    .filter(|pip_window| !pip_window.is_undefined())
+                    .map(JsValue::unchecked_into),
+            );
+            update_size();
+        },
+    );
+
+    let _ = use_event_listener(
+        target,
+        Custom::<ev::Event>::new("leavepictureinpicture"),
+        move |_| {
+            set_active.set(false);
+            set_pip_window.set(None);
+            update_size();
+        },
+    );
+
+    let _ = use_event_listener(pip_window, Custom::<ev::Event>::new("resize"), move |_| {
+        update_size();
+    });
+
+    // the video might already be in Picture-in-Picture
+    let _ = watch(
+        move || target.get(),
+        move |video, _, _| {
+            let document = document();
+            set_active.set(
+                video.is_some()
+                    && js!(document["pictureInPictureElement"])
+                        .is_ok_and(|el| el == JsValue::from(video.clone())),
+            );
+        },
+        true,
+    );
+
+    let enter = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_supported.get_untracked() || is_active.get_untracked() {
+                return;
+            }
+
+            let Some(video) = target.get_untracked() else {
+                return;
+            };
+
+            let promise = js_sys::Reflect::get(&video, &"requestPictureInPicture".into())
+                .and_then(|request| request.dyn_into::<js_sys::Function>())
+                .and_then(|request| request.call0(&video))
+                .and_then(|promise| promise.dyn_into::<js_sys::Promise>());
+
+            match promise {
+                Ok(promise) => spawn_local(async move {
+                    match js_fut!(promise).await {
+                        Ok(_) => set_error.set(None),
+                        Err(err) => set_error.set(Some(UsePictureInPictureError::Enter(err))),
+                    }
+                }),
+                Err(err) => set_error.set(Some(UsePictureInPictureError::Enter(err))),
+            }
+        }
+    };
+
+    let exit = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_active.get_untracked() {
+                return;
+            }
+
+            let document = document();
+
+            let promise = js_sys::Reflect::get(&document, &"exitPictureInPicture".into())
+                .and_then(|exit| exit.dyn_into::<js_sys::Function>())
+                .and_then(|exit| exit.call0(&document))
+                .and_then(|promise| promise.dyn_into::<js_sys::Promise>());
+
+            match promise {
+                Ok(promise) => spawn_local(async move {
+                    if let Err(err) = js_fut!(promise).await {
+                        set_error.set(Some(UsePictureInPictureError::Exit(err)));
+                    }
+                }),
+                Err(err) => set_error.set(Some(UsePictureInPictureError::Exit(err))),
+            }
+        }
+    };
+
+    let toggle = move || {
+        if is_active.get_untracked() {
+            exit();
+        } else {
+            enter();
+        }
+    };
+
+    UsePictureInPictureReturn {
+        is_supported,
+        is_active: is_active.into(),
+        width: width.into(),
+        height: height.into(),
+        error: error.into(),
+        enter,
+        exit,
+        toggle,
+    }
+}
+
+/// Return type of [`use_picture_in_picture`].
+pub struct UsePictureInPictureReturn<EnterFn, ExitFn, ToggleFn>
+where
+    EnterFn: Fn() + Clone,
+    ExitFn: Fn() + Clone,
+    ToggleFn: Fn() + Clone,
+{
+    /// `true` if the browser supports Picture-in-Picture and it isn't disabled.
+    pub is_supported: Signal<bool>,
+
+    /// Wether the video is shown in the Picture-in-Picture window.
+    pub is_active: Signal<bool>,
+
+    /// Width of the Picture-in-Picture window in pixels.
+    pub width: Signal<u32>,
+
+    /// Height of the Picture-in-Picture window in pixels.
+    pub height: Signal<u32>,
+
+    /// Latest error that occurred while entering or exiting Picture-in-Picture.
+    pub error: Signal<Option<UsePictureInPictureError>>,
+
+    /// Shows the video in the Picture-in-Picture window.
+    pub enter: EnterFn,
+
+    /// Closes the Picture-in-Picture window.
+    pub exit: ExitFn,
+
+    /// Calls `enter` or `exit` depending on `is_active`.
+    pub toggle: ToggleFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UsePictureInPictureError {
+    #[error("failed to enter Picture-in-Picture")]
+    Enter(JsValue),
+    #[error("failed to exit Picture-in-Picture")]
+    Exit(JsValue),
+}
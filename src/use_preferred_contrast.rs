@@ -8,17 +8,25 @@ use std::fmt::Display;
 ///
 /// ```
 /// # use leptos::*;
-/// # use leptos_use::use_preferred_contrast;
+/// # use leptos_use::{use_preferred_contrast, PreferredContrast};
 /// #
 /// # #[component]
 /// # fn Demo() -> impl IntoView {
 /// #
 /// let preferred_contrast = use_preferred_contrast();
+///
+/// let border_color = move || match preferred_contrast.get() {
+///     PreferredContrast::More => "black",
+///     PreferredContrast::Less => "lightgray",
+///     PreferredContrast::Custom | PreferredContrast::NoPreference => "gray",
+/// };
 /// #
 /// #    view! { }
 /// # }
 /// ```
 ///
+/// The signal is updated whenever the user changes the contrast settings of the system.
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this returns a `Signal` that always contains the value `PreferredContrast::NoPreference`.
@@ -48,9 +56,13 @@ pub fn use_preferred_contrast() -> Signal<PreferredContrast> {
 /// Return value for [`use_preferred_contrast`]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PreferredContrast {
+    /// The user prefers more contrast than usual.
     More,
+    /// The user prefers less contrast than usual.
     Less,
+    /// The user has set specific colors like with the forced colors mode of Windows.
     Custom,
+    /// The user hasn't expressed a preference.
     #[default]
     NoPreference,
 }
@@ -1,4 +1,5 @@
-use crate::use_media_query;
+use crate::{use_media_query_with_options, UseMediaQueryOptions};
+use default_struct_builder::DefaultBuilder;
 use leptos::*;
 
 /// Reactive [dark theme preference](https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-color-scheme).
@@ -20,12 +21,48 @@ use leptos::*;
 ///
 /// ## Server-Side Rendering
 ///
-/// On the server this functions returns a Signal that is always `false`.
+/// On the server this functions returns a Signal that is always `false`. To change this use
+/// [`use_preferred_dark_with_options`] and set `ssr_fallback`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_preferred_dark_with_options, UsePreferredDarkOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let is_dark_preferred = use_preferred_dark_with_options(
+///     UsePreferredDarkOptions::default().ssr_fallback(true),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// In the browser this value is used until the media query has been evaluated.
 ///
 /// ## See also
 ///
-/// * [`use_media_query`]
+/// * [`use_media_query`](crate::use_media_query)
 /// * [`use_preferred_contrast`]
 pub fn use_preferred_dark() -> Signal<bool> {
-    use_media_query("(prefers-color-scheme: dark)")
+    use_preferred_dark_with_options(UsePreferredDarkOptions::default())
+}
+
+/// Version of [`use_preferred_dark`] that takes a `UsePreferredDarkOptions`. See [`use_preferred_dark`] for how to use.
+pub fn use_preferred_dark_with_options(options: UsePreferredDarkOptions) -> Signal<bool> {
+    let UsePreferredDarkOptions { ssr_fallback } = options;
+
+    use_media_query_with_options(
+        "(prefers-color-scheme: dark)",
+        UseMediaQueryOptions::default().initial_value(ssr_fallback),
+    )
+    .matches
+}
+
+/// Options for [`use_preferred_dark_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UsePreferredDarkOptions {
+    /// The value that is returned on the server and in the browser until the media query has
+    /// been evaluated. Defaults to `false`.
+    ssr_fallback: bool,
 }
@@ -0,0 +1,63 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::use_event_listener;
+use leptos::ev::Custom;
+use leptos::*;
+
+/// Reactive [`navigator.languages`](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/languages).
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::use_preferred_languages;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let languages = use_preferred_languages();
+///
+/// let language = move || languages.get().first().cloned().unwrap_or("en".to_string());
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// The languages are ordered by preference with the most preferred language first. The signal
+/// is updated when the user changes the languages in the browser settings.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this function returns a Signal that always contains an empty `Vec`.
+///
+/// ## See also
+///
+/// * [`use_preferred_dark`]
+/// * [`use_preferred_contrast`]
+pub fn use_preferred_languages() -> Signal<Vec<String>> {
+    #[cfg(feature = "ssr")]
+    {
+        Signal::derive(Vec::new)
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let read_languages = || {
+            window()
+                .navigator()
+                .languages()
+                .iter()
+                .filter_map(|language| language.as_string())
+                .collect::<Vec<_>>()
+        };
+
+        let (languages, set_languages) = create_signal(read_languages());
+
+        let _ = use_event_listener(
+            window(),
+            Custom::<ev::Event>::new("languagechange"),
+            move |_| set_languages.set(read_languages()),
+        );
+
+        languages.into()
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{use_media_query, use_supported};
+use leptos::*;
+use std::fmt::Display;
+
+/// Reactive [prefers-reduced-data](https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-reduced-data) media query.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_preferred_reduced_data, PreferredReducedData};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let preferred_data = use_preferred_reduced_data();
+///
+/// let image_src = move || match preferred_data.get() {
+///     PreferredReducedData::Reduce => "/images/hero-small.webp",
+///     PreferredReducedData::NoPreference | PreferredReducedData::Unsupported => "/images/hero.webp",
+/// };
+///
+/// view! { <img src=image_src /> }
+/// # }
+/// ```
+///
+/// The signal is updated whenever the user turns the data saving mode of the system on or off.
+///
+/// Only few browsers support this media query so far. In all other browsers the signal
+/// contains `PreferredReducedData::Unsupported`, which you should treat like
+/// `PreferredReducedData::NoPreference`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this returns a `Signal` that always contains the value `PreferredReducedData::Unsupported`.
+///
+/// ## See also
+///
+/// * [`use_media_query`]
+/// * [`use_preferred_reduced_motion`]
+pub fn use_preferred_reduced_data() -> Signal<PreferredReducedData> {
+    // browsers turn media queries they don't know into `not all`
+    let is_supported = use_supported(|| {
+        window()
+            .match_media("(prefers-reduced-data)")
+            .ok()
+            .flatten()
+            .is_some_and(|media_query| media_query.media() != "not all")
+    });
+
+    let is_reduce = use_media_query("(prefers-reduced-data: reduce)");
+
+    Signal::derive(move || {
+        if !is_supported.get() {
+            PreferredReducedData::Unsupported
+        } else if is_reduce.get() {
+            PreferredReducedData::Reduce
+        } else {
+            PreferredReducedData::NoPreference
+        }
+    })
+}
+
+/// Return value for [`use_preferred_reduced_data`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PreferredReducedData {
+    /// The user wants to save data, for example on a metered connection.
+    Reduce,
+    /// The user hasn't expressed a preference.
+    NoPreference,
+    /// The browser doesn't support the media query.
+    #[default]
+    Unsupported,
+}
+
+impl Display for PreferredReducedData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PreferredReducedData::Reduce => write!(f, "reduce"),
+            PreferredReducedData::NoPreference => write!(f, "no-preference"),
+            PreferredReducedData::Unsupported => write!(f, "unsupported"),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::use_media_query;
+use leptos::*;
+use std::fmt::Display;
+
+/// Reactive [prefers-reduced-motion](https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-reduced-motion) media query.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_preferred_reduced_motion, PreferredReducedMotion};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// #
+/// let preferred_motion = use_preferred_reduced_motion();
+///
+/// let transition = move || match preferred_motion.get() {
+///     PreferredReducedMotion::Reduce => "none",
+///     PreferredReducedMotion::NoPreference => "transform 300ms ease-in-out",
+/// };
+/// #
+/// #    view! { }
+/// # }
+/// ```
+///
+/// The signal is updated whenever the user changes the motion settings of the system. If it is
+/// `PreferredReducedMotion::Reduce` you should remove or tone down animations.
+///
+/// ## Server-Side Rendering
+///
+/// On the server this returns a `Signal` that always contains the value `PreferredReducedMotion::NoPreference`.
+///
+/// ## See also
+///
+/// * [`use_media_query`]
+/// * [`use_preferred_contrast`]
+pub fn use_preferred_reduced_motion() -> Signal<PreferredReducedMotion> {
+    let is_reduce = use_media_query("(prefers-reduced-motion: reduce)");
+
+    Signal::derive(move || {
+        if is_reduce.get() {
+            PreferredReducedMotion::Reduce
+        } else {
+            PreferredReducedMotion::NoPreference
+        }
+    })
+}
+
+/// Return value for [`use_preferred_reduced_motion`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PreferredReducedMotion {
+    /// The user prefers as little motion as possible.
+    Reduce,
+    /// The user hasn't expressed a preference.
+    #[default]
+    NoPreference,
+}
+
+impl Display for PreferredReducedMotion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PreferredReducedMotion::Reduce => write!(f, "reduce"),
+            PreferredReducedMotion::NoPreference => write!(f, "no-preference"),
+        }
+    }
+}
@@ -0,0 +1,354 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut, use_event_listener, use_supported};
+use leptos::ev::Custom;
+use leptos::*;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{
+    PresentationAvailability, PresentationConnection, PresentationConnectionBinaryType,
+    PresentationConnectionState, PresentationRequest,
+};
+
+/// Reactive [Presentation API](https://developer.mozilla.org/en-US/docs/Web/API/Presentation_API).
+///
+/// Shows a page on a second display like a projector or a TV and exchanges messages with it.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_presentation)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_presentation, UsePresentationReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UsePresentationReturn {
+///     is_available,
+///     is_connected,
+///     message,
+///     start,
+///     send,
+///     terminate,
+///     ..
+/// } = use_presentation("/slides");
+///
+/// view! {
+///     <button on:click=move |_| start() disabled=move || !is_available.get()>"Present"</button>
+///     <button on:click=move |_| send("next") disabled=move || !is_connected.get()>"Next slide"</button>
+///     <button on:click=move |_| terminate()>"Stop"</button>
+///     <p>"Last message: " {message}</p>
+/// }
+/// # }
+/// ```
+///
+/// `is_available` is `true` while there is a display that can show the page at the given URL.
+/// `start` has to be called in response to a user action like a click. It lets the user pick a
+/// display and opens the page on it.
+///
+/// Text messages from the presentation end up in `message` and binary messages in
+/// `message_bytes`. On the presentation page itself you can receive the messages through
+/// `navigator.presentation.receiver`.
+///
+/// `close` disconnects from the presentation but keeps it running on the display while
+/// `terminate` stops it.
+///
+/// ### Reconnect
+///
+/// A presentation that is still running, for example after the page has been reloaded, can be
+/// reconnected to with `reconnect` and the id of the presentation. The id of the current
+/// presentation is available in `presentation_id`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::storage::use_local_storage;
+/// # use leptos_use::utils::FromToStringCodec;
+/// # use leptos_use::{use_presentation, UsePresentationReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (stored_id, set_stored_id, _) = use_local_storage::<String, FromToStringCodec>("presentation-id");
+///
+/// let UsePresentationReturn {
+///     presentation_id,
+///     reconnect,
+///     ..
+/// } = use_presentation("/slides");
+///
+/// create_effect(move |_| {
+///     if let Some(id) = presentation_id.get() {
+///         set_stored_id.set(id);
+///     }
+/// });
+///
+/// view! {
+///     <button on:click=move |_| reconnect(&stored_id.get_untracked())>"Reconnect"</button>
+/// }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported`, `is_available` and `is_connected` are always `false`, the other
+/// signals are always `None` and the functions do nothing.
+#[allow(clippy::type_complexity)]
+pub fn use_presentation<S>(
+    url: S,
+) -> UsePresentationReturn<
+    impl Fn() + Clone,
+    impl Fn(&str) + Clone,
+    impl Fn(&str) + Clone,
+    impl Fn(Vec<u8>) + Clone,
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+>
+where
+    S: Into<MaybeSignal<String>>,
+{
+    let url = url.into();
+
+    let is_supported = use_supported(|| js!("PresentationRequest" in &window()));
+
+    let (request, set_request) = create_signal(None::<PresentationRequest>);
+    let (availability, set_availability) = create_signal(None::<PresentationAvailability>);
+    let (is_available, set_available) = create_signal(false);
+    let (connection, set_connection) = create_signal(None::<PresentationConnection>);
+    let (state, set_state) = create_signal(None::<PresentationConnectionState>);
+    let (presentation_id, set_presentation_id) = create_signal(None::<String>);
+    let (message, set_message) = create_signal(None::<String>);
+    let (message_bytes, set_message_bytes) = create_signal(None::<Vec<u8>>);
+    let (error, set_error) = create_signal(None::<UsePresentationError>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let _ = watch(
+            move || url.get(),
+            move |url, _, _| {
+                set_availability.set(None);
+                set_available.set(false);
+
+                if !is_supported.get_untracked() {
+                    return;
+                }
+
+                let request = PresentationRequest::new_with_url(url).ok();
+
+                // `getAvailability` rejects if the browser can't monitor the displays
+                if let Some(promise) = request
+                    .as_ref()
+                    .and_then(|request| request.get_availability().ok())
+                {
+                    spawn_local(async move {
+                        if let Ok(availability) = js_fut!(promise).await {
+                            let availability =
+                                availability.unchecked_into::<PresentationAvailability>();
+                            set_available.set(availability.value());
+                            set_availability.set(Some(availability));
+                        }
+                    });
+                }
+
+                set_request.set(request);
+            },
+            true,
+        );
+    }
+
+    let _ = use_event_listener(
+        availability,
+        Custom::<ev::Event>::new("change"),
+        move |_| {
+            set_available.set(
+                availability
+                    .get_untracked()
+                    .map(|availability| availability.value())
+                    .unwrap_or_default(),
+            );
+        },
+    );
+
+    let update_state = move || {
+        let new_state = connection
+            .get_untracked()
+            .map(|connection| connection.state());
+
+        if new_state == Some(PresentationConnectionState::Terminated) {
+            set_presentation_id.set(None);
+        }
+
+        set_state.set(new_state);
+    };
+
+    for event in ["connect", "close", "terminate"] {
+        let _ = use_event_listener(connection, Custom::<ev::Event>::new(event), move |_| {
+            update_state();
+        });
+    }
+
+    let _ = use_event_listener(
+        connection,
+        Custom::<web_sys::MessageEvent>::new("message"),
+        move |event| {
+            let data = event.data();
+
+            if let Some(text) = data.as_string() {
+                set_message.set(Some(text));
+            } else if let Ok(buffer) = data.dyn_into::<js_sys::ArrayBuffer>() {
+                set_message_bytes.set(Some(js_sys::Uint8Array::new(&buffer).to_vec()));
+            }
+        },
+    );
+
+    let connect = move |promise: Result<js_sys::Promise, JsValue>,
+                        to_error: fn(JsValue) -> UsePresentationError| {
+        #[cfg(not(feature = "ssr"))]
+        match promise {
+            Ok(promise) => spawn_local(async move {
+                match js_fut!(promise).await {
+                    Ok(new_connection) => {
+                        let new_connection =
+                            new_connection.unchecked_into::<PresentationConnection>();
+                        new_connection
+                            .set_binary_type(PresentationConnectionBinaryType::Arraybuffer);
+
+                        set_presentation_id.set(Some(new_connection.id()));
+                        set_connection.set(Some(new_connection));
+                        set_error.set(None);
+                        update_state();
+                    }
+                    Err(err) => set_error.set(Some(to_error(err))),
+                }
+            }),
+            Err(err) => set_error.set(Some(to_error(err))),
+        }
+    };
+
+    let start = move || {
+        if let Some(request) = request.get_untracked() {
+            connect(request.start(), UsePresentationError::Start);
+        }
+    };
+
+    let reconnect = move |id: &str| {
+        if let Some(request) = request.get_untracked() {
+            connect(request.reconnect(id), UsePresentationError::Reconnect);
+        }
+    };
+
+    let send = move |data: &str| {
+        if let Some(connection) = connection.get_untracked() {
+            if let Err(err) = connection.send_with_str(data) {
+                set_error.set(Some(UsePresentationError::Send(err)));
+            }
+        }
+    };
+
+    let send_bytes = move |data: Vec<u8>| {
+        if let Some(connection) = connection.get_untracked() {
+            if let Err(err) = connection.send_with_u8_array(&data) {
+                set_error.set(Some(UsePresentationError::Send(err)));
+            }
+        }
+    };
+
+    let close = move || {
+        if let Some(connection) = connection.get_untracked() {
+            let _ = connection.close();
+        }
+    };
+
+    let terminate = move || {
+        if let Some(connection) = connection.get_untracked() {
+            let _ = connection.terminate();
+        }
+    };
+
+    UsePresentationReturn {
+        is_supported,
+        is_available: is_available.into(),
+        is_connected: Signal::derive(move || {
+            state.get() == Some(PresentationConnectionState::Connected)
+        }),
+        state: state.into(),
+        connection: connection.into(),
+        presentation_id: presentation_id.into(),
+        message: message.into(),
+        message_bytes: message_bytes.into(),
+        error: error.into(),
+        start,
+        reconnect,
+        send,
+        send_bytes,
+        close,
+        terminate,
+    }
+}
+
+/// Return type of [`use_presentation`].
+pub struct UsePresentationReturn<StartFn, ReconnectFn, SendFn, SendBytesFn, CloseFn, TerminateFn>
+where
+    StartFn: Fn() + Clone,
+    ReconnectFn: Fn(&str) + Clone,
+    SendFn: Fn(&str) + Clone,
+    SendBytesFn: Fn(Vec<u8>) + Clone,
+    CloseFn: Fn() + Clone,
+    TerminateFn: Fn() + Clone,
+{
+    /// `true` if the browser supports the Presentation API.
+    pub is_supported: Signal<bool>,
+
+    /// Wether there is a display available that can show the presentation.
+    pub is_available: Signal<bool>,
+
+    /// Wether the presentation is connected.
+    pub is_connected: Signal<bool>,
+
+    /// State of the connection to the presentation. `None` if it has never been started.
+    pub state: Signal<Option<PresentationConnectionState>>,
+
+    /// The connection to the presentation.
+    pub connection: Signal<Option<PresentationConnection>>,
+
+    /// Id of the presentation that can be passed to `reconnect`. `None` once it's terminated.
+    pub presentation_id: Signal<Option<String>>,
+
+    /// Latest text message received from the presentation.
+    pub message: Signal<Option<String>>,
+
+    /// Latest binary message received from the presentation.
+    pub message_bytes: Signal<Option<Vec<u8>>>,
+
+    /// Latest error that occurred while starting, reconnecting or sending.
+    pub error: Signal<Option<UsePresentationError>>,
+
+    /// Lets the user pick a display and starts the presentation on it.
+    pub start: StartFn,
+
+    /// Reconnects to the running presentation with the given id.
+    pub reconnect: ReconnectFn,
+
+    /// Sends a text message to the presentation.
+    pub send: SendFn,
+
+    /// Sends a binary message to the presentation.
+    pub send_bytes: SendBytesFn,
+
+    /// Disconnects from the presentation but keeps it running.
+    pub close: CloseFn,
+
+    /// Stops the presentation.
+    pub terminate: TerminateFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UsePresentationError {
+    #[error("failed to start the presentation")]
+    Start(JsValue),
+    #[error("failed to reconnect to the presentation")]
+    Reconnect(JsValue),
+    #[error("failed to send a message to the presentation")]
+    Send(JsValue),
+}
@@ -0,0 +1,355 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{use_supported, NotificationPermission};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Reactive [Push API](https://developer.mozilla.org/en-US/docs/Web/API/Push_API) subscription.
+///
+/// Subscribes the registered service worker to push messages with your VAPID public key.
+/// The subscription has to be sent to your server which can then push messages to the
+/// service worker. Please note that a service worker has to be registered first,
+/// e.g. with [`use_service_worker`](crate::use_service_worker).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_push_subscription)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_push_subscription, UsePushSubscriptionReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UsePushSubscriptionReturn {
+///     info,
+///     subscribe,
+///     unsubscribe,
+///     ..
+/// } = use_push_subscription("<your VAPID public key>");
+///
+/// view! {
+///     <button on:click=move |_| subscribe()>"Subscribe"</button>
+///     <button on:click=move |_| unsubscribe()>"Unsubscribe"</button>
+///
+///     <Show when=move || info.with(Option::is_some)>
+///         <p>"Endpoint: " {move || info.get().map(|info| info.endpoint)}</p>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// `subscribe` requests the permission to show notifications first because browsers only allow
+/// push subscriptions that show a notification for every message. An already existing
+/// subscription is picked up automatically.
+///
+/// With the feature `serde` enabled, [`PushSubscriptionInfo`] implements `Serialize` and
+/// `Deserialize` so you can send it to your server easily.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is `false`, all other signals stay `None` and the functions
+/// do nothing.
+pub fn use_push_subscription(
+    vapid_public_key: &str,
+) -> UsePushSubscriptionReturn<impl Fn() + Clone, impl Fn() + Clone> {
+    use_push_subscription_with_options(vapid_public_key, UsePushSubscriptionOptions::default())
+}
+
+/// Version of [`use_push_subscription`] that takes a `UsePushSubscriptionOptions`. See [`use_push_subscription`] for how to use.
+pub fn use_push_subscription_with_options(
+    vapid_public_key: &str,
+    options: UsePushSubscriptionOptions,
+) -> UsePushSubscriptionReturn<impl Fn() + Clone, impl Fn() + Clone> {
+    let UsePushSubscriptionOptions { user_visible_only } = options;
+
+    let is_supported = use_supported(|| {
+        crate::js!("PushManager" in &window())
+            && crate::js!("serviceWorker" in &window().navigator())
+    });
+
+    let (permission, set_permission) = create_signal(NotificationPermission::default());
+    let (subscription, set_subscription) = create_signal(None::<web_sys::PushSubscription>);
+    let (error, set_error) = create_signal(None::<UsePushSubscriptionError>);
+
+    let vapid_public_key = vapid_public_key.to_string();
+
+    let subscribe = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_supported.get_untracked() {
+                return;
+            }
+
+            let vapid_public_key = vapid_public_key.clone();
+
+            spawn_local(async move {
+                match push_subscribe(&vapid_public_key, user_visible_only, set_permission).await {
+                    Ok(push_subscription) => {
+                        set_subscription.set(Some(push_subscription));
+                        set_error.set(None);
+                    }
+                    Err(err) => set_error.set(Some(err)),
+                }
+            });
+        }
+    };
+
+    let unsubscribe = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(push_subscription) = subscription.get_untracked() else {
+                return;
+            };
+
+            spawn_local(async move {
+                let result = match push_subscription.unsubscribe() {
+                    Ok(promise) => crate::js_fut!(promise).await,
+                    Err(err) => Err(err),
+                };
+
+                match result {
+                    Ok(_) => set_subscription.set(None),
+                    Err(err) => set_error.set(Some(UsePushSubscriptionError::Unsubscribe(err))),
+                }
+            });
+        }
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        set_permission.set(web_sys::Notification::permission().into());
+
+        spawn_local(async move {
+            match existing_push_subscription().await {
+                Ok(push_subscription) => set_subscription.set(push_subscription),
+                Err(err) => set_error.set(Some(err)),
+            }
+        });
+    }
+
+    let info = Signal::derive(move || {
+        subscription.with(|push_subscription| {
+            push_subscription
+                .as_ref()
+                .and_then(PushSubscriptionInfo::from_push_subscription)
+        })
+    });
+
+    UsePushSubscriptionReturn {
+        is_supported,
+        permission: permission.into(),
+        subscription: subscription.into(),
+        info,
+        error: error.into(),
+        subscribe,
+        unsubscribe,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn push_manager() -> Result<web_sys::PushManager, UsePushSubscriptionError> {
+    let ready = window()
+        .navigator()
+        .service_worker()
+        .ready()
+        .map_err(UsePushSubscriptionError::ServiceWorker)?;
+
+    crate::js_fut!(ready)
+        .await
+        .and_then(|registration| registration.dyn_into::<web_sys::ServiceWorkerRegistration>())
+        .and_then(|registration| registration.push_manager())
+        .map_err(UsePushSubscriptionError::ServiceWorker)
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn existing_push_subscription(
+) -> Result<Option<web_sys::PushSubscription>, UsePushSubscriptionError> {
+    let push_manager = push_manager().await?;
+
+    let promise = push_manager
+        .get_subscription()
+        .map_err(UsePushSubscriptionError::ServiceWorker)?;
+
+    crate::js_fut!(promise)
+        .await
+        .map(|push_subscription| {
+            push_subscription
+                .dyn_into::<web_sys::PushSubscription>()
+                .ok()
+        })
+        .map_err(UsePushSubscriptionError::ServiceWorker)
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn push_subscribe(
+    vapid_public_key: &str,
+    user_visible_only: bool,
+    set_permission: WriteSignal<NotificationPermission>,
+) -> Result<web_sys::PushSubscription, UsePushSubscriptionError> {
+    let permission = crate::request_web_notification_permission().await;
+    set_permission.set(permission);
+
+    if permission != NotificationPermission::Granted {
+        return Err(UsePushSubscriptionError::PermissionDenied);
+    }
+
+    let key = decode_base64_url(vapid_public_key).ok_or(UsePushSubscriptionError::InvalidKey)?;
+    let key = js_sys::Uint8Array::from(key.as_slice());
+
+    let mut subscribe_options = web_sys::PushSubscriptionOptionsInit::new();
+    subscribe_options
+        .user_visible_only(user_visible_only)
+        .application_server_key(Some(&key));
+
+    let push_manager = push_manager().await?;
+
+    let promise = push_manager
+        .subscribe_with_options(&subscribe_options)
+        .map_err(UsePushSubscriptionError::Subscribe)?;
+
+    crate::js_fut!(promise)
+        .await
+        .and_then(|push_subscription| push_subscription.dyn_into::<web_sys::PushSubscription>())
+        .map_err(UsePushSubscriptionError::Subscribe)
+}
+
+/// Decodes the URL safe base64 encoding that is used for VAPID keys. Padding is optional.
+fn decode_base64_url(value: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(value.len() * 3 / 4);
+    let mut buffer = 0_u32;
+    let mut bits = 0;
+
+    for c in value.trim_end_matches('=').bytes() {
+        let sextet = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'-' | b'+' => 62,
+            b'_' | b'/' => 63,
+            _ => return None,
+        };
+
+        buffer = (buffer << 6) | sextet as u32;
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Some(bytes)
+}
+
+/// Options for [`use_push_subscription_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UsePushSubscriptionOptions {
+    /// Wether every push message results in a notification that is visible to the user.
+    /// Most browsers only support `true`. Defaults to `true`.
+    user_visible_only: bool,
+}
+
+impl Default for UsePushSubscriptionOptions {
+    fn default() -> Self {
+        Self {
+            user_visible_only: true,
+        }
+    }
+}
+
+/// Return type of [`use_push_subscription`].
+pub struct UsePushSubscriptionReturn<SubscribeFn, UnsubscribeFn>
+where
+    SubscribeFn: Fn() + Clone,
+    UnsubscribeFn: Fn() + Clone,
+{
+    /// `true` if this browser supports the Push API and service workers.
+    pub is_supported: Signal<bool>,
+
+    /// The permission to show notifications.
+    pub permission: Signal<NotificationPermission>,
+
+    /// The current push subscription. `None` if there is none.
+    pub subscription: Signal<Option<web_sys::PushSubscription>>,
+
+    /// Endpoint and keys of the current push subscription. This is what your server needs to
+    /// send push messages.
+    pub info: Signal<Option<PushSubscriptionInfo>>,
+
+    /// Latest error that occurred while subscribing or unsubscribing.
+    pub error: Signal<Option<UsePushSubscriptionError>>,
+
+    /// Requests the notification permission and subscribes to push messages.
+    pub subscribe: SubscribeFn,
+
+    /// Unsubscribes the current push subscription.
+    pub unsubscribe: UnsubscribeFn,
+}
+
+/// Endpoint and keys of a push subscription. The keys are URL safe base64 encoded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PushSubscriptionInfo {
+    /// The URL your server sends the push messages to.
+    pub endpoint: String,
+
+    /// The public key of the client that is used to encrypt push messages.
+    pub p256dh: String,
+
+    /// The authentication secret of the client.
+    pub auth: String,
+}
+
+impl PushSubscriptionInfo {
+    fn from_push_subscription(push_subscription: &web_sys::PushSubscription) -> Option<Self> {
+        let json: JsValue = push_subscription.to_json().ok()?.into();
+        let keys = js_sys::Reflect::get(&json, &"keys".into()).ok()?;
+
+        let get_string = |target: &JsValue, key: &str| {
+            js_sys::Reflect::get(target, &key.into())
+                .ok()
+                .and_then(|value| value.as_string())
+        };
+
+        Some(Self {
+            endpoint: push_subscription.endpoint(),
+            p256dh: get_string(&keys, "p256dh")?,
+            auth: get_string(&keys, "auth")?,
+        })
+    }
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UsePushSubscriptionError {
+    #[error("permission to show notifications was not granted")]
+    PermissionDenied,
+    #[error("VAPID public key is not URL safe base64 encoded")]
+    InvalidKey,
+    #[error("service worker error")]
+    ServiceWorker(JsValue),
+    #[error("failed to subscribe")]
+    Subscribe(JsValue),
+    #[error("failed to unsubscribe")]
+    Unsubscribe(JsValue),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_base64_url() {
+        assert_eq!(decode_base64_url(""), Some(vec![]));
+        assert_eq!(decode_base64_url("TWFu"), Some(b"Man".to_vec()));
+        assert_eq!(decode_base64_url("TWE"), Some(b"Ma".to_vec()));
+        assert_eq!(decode_base64_url("TWE="), Some(b"Ma".to_vec()));
+        assert_eq!(decode_base64_url("-_8"), Some(vec![0xfb, 0xff]));
+        assert_eq!(decode_base64_url("TW$u"), None);
+    }
+}
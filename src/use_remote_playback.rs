@@ -0,0 +1,277 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::ElementMaybeSignal;
+use crate::{js, js_fut, use_event_listener, use_supported};
+use leptos::ev::Custom;
+use leptos::*;
+use std::rc::Rc;
+use thiserror::Error;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlMediaElement;
+
+/// Reactive [Remote Playback API](https://developer.mozilla.org/en-US/docs/Web/API/Remote_Playback_API).
+///
+/// Plays an `<audio>` or `<video>` element on a remote device like a TV or a speaker.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_remote_playback)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::html::Video;
+/// # use leptos_use::{use_remote_playback, RemotePlaybackState, UseRemotePlaybackReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let video = create_node_ref::<Video>();
+///
+/// let UseRemotePlaybackReturn {
+///     is_available,
+///     state,
+///     prompt,
+///     ..
+/// } = use_remote_playback(video);
+///
+/// view! {
+///     <video node_ref=video src="https://example.com/video.mp4" controls></video>
+///     <button on:click=move |_| prompt() disabled=move || !is_available.get()>"Cast"</button>
+///     <p>
+///         {move || match state.get() {
+///             RemotePlaybackState::Connecting => "Connecting...",
+///             RemotePlaybackState::Connected => "Playing on the remote device",
+///             RemotePlaybackState::Disconnected => "Playing locally",
+///         }}
+///     </p>
+/// }
+/// # }
+/// ```
+///
+/// `is_available` is `true` while there is at least one remote device that can play the media.
+/// `prompt` has to be called in response to a user action like a click. It opens the browser's
+/// dialog to pick a device or to disconnect from the current one.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_available` are always `false`, `state` is always
+/// `RemotePlaybackState::Disconnected` and `prompt` does nothing.
+pub fn use_remote_playback<El, T>(target: El) -> UseRemotePlaybackReturn<impl Fn() + Clone>
+where
+    El: Into<ElementMaybeSignal<T, HtmlMediaElement>>,
+    T: Into<HtmlMediaElement> + Clone + 'static,
+{
+    let target = target.into();
+    let media = Signal::derive(move || target.get().map(Into::<HtmlMediaElement>::into));
+
+    let is_supported = use_supported(|| js!("RemotePlayback" in &window()));
+
+    let remote = Signal::derive(move || {
+        media
+            .get()
+            .and_then(|media| js_sys::Reflect::get(&media, &"remote".into()).ok())
+            .filter(|remote| remote.is_object())
+            .map(JsValue::unchecked_into::<web_sys::EventTarget>)
+    });
+
+    let (is_available, set_available) = create_signal(false);
+    let (state, set_state) = create_signal(RemotePlaybackState::Disconnected);
+    let (error, set_error) = create_signal(None::<UseRemotePlaybackError>);
+
+    let update_state = move || {
+        set_state.set(
+            remote
+                .get_untracked()
+                .and_then(|remote| js_sys::Reflect::get(&remote, &"state".into()).ok())
+                .and_then(|state| state.as_string())
+                .map(|state| RemotePlaybackState::from_str(&state))
+                .unwrap_or_default(),
+        );
+    };
+
+    for event in ["connecting", "connect", "disconnect"] {
+        let _ = use_event_listener(remote, Custom::<ev::Event>::new(event), move |_| {
+            update_state();
+        });
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let on_availability = Rc::new(Closure::wrap(Box::new(move |available: bool| {
+            set_available.set(available);
+        }) as Box<dyn FnMut(bool)>));
+
+        let watch_id = store_value(None::<(web_sys::EventTarget, JsValue)>);
+
+        let cancel_watch = move || {
+            if let Some((remote, id)) = watch_id.get_value() {
+                let _ = call_remote(&remote, "cancelWatchAvailability", &id);
+                watch_id.set_value(None);
+            }
+        };
+
+        let _ = watch(
+            move || remote.get(),
+            {
+                let on_availability = Rc::clone(&on_availability);
+
+                move |remote: &Option<web_sys::EventTarget>, _, _| {
+                    cancel_watch();
+                    set_available.set(false);
+                    update_state();
+
+                    let Some(remote) = remote.clone() else {
+                        return;
+                    };
+
+                    match call_remote(
+                        &remote,
+                        "watchAvailability",
+                        on_availability.as_ref().as_ref(),
+                    )
+                    .and_then(|promise| promise.dyn_into::<js_sys::Promise>())
+                    {
+                        Ok(promise) => spawn_local(async move {
+                            match js_fut!(promise).await {
+                                Ok(id) => watch_id.set_value(Some((remote, id))),
+                                Err(err) => set_error
+                                    .set(Some(UseRemotePlaybackError::WatchAvailability(err))),
+                            }
+                        }),
+                        Err(err) => {
+                            set_error.set(Some(UseRemotePlaybackError::WatchAvailability(err)))
+                        }
+                    }
+                }
+            },
+            true,
+        );
+
+        on_cleanup(move || {
+            cancel_watch();
+            drop(on_availability);
+        });
+    }
+
+    let prompt = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(remote) = remote.get_untracked() else {
+                return;
+            };
+
+            let promise = js_sys::Reflect::get(&remote, &"prompt".into())
+                .and_then(|prompt| prompt.dyn_into::<js_sys::Function>())
+                .and_then(|prompt| prompt.call0(&remote))
+                .and_then(|promise| promise.dyn_into::<js_sys::Promise>());
+
+            match promise {
+                Ok(promise) => spawn_local(async move {
+                    match js_fut!(promise).await {
+                        Ok(_) => set_error.set(None),
+                        Err(err) => set_error.set(Some(UseRemotePlaybackError::Prompt(err))),
+                    }
+                }),
+                Err(err) => set_error.set(Some(UseRemotePlaybackError::Prompt(err))),
+            }
+        }
+    };
+
+    UseRemotePlaybackReturn {
+        is_supported,
+        is_available: is_available.into(),
+        state: state.into(),
+        error: error.into(),
+        prompt,
+    }
+}
+
+/// Calls the method `name` of the `RemotePlayback` object which has no `web_sys` binding.
+#[cfg(not(feature = "ssr"))]
+fn call_remote(
+    remote: &web_sys::EventTarget,
+    name: &str,
+    arg: &JsValue,
+) -> Result<JsValue, JsValue> {
+    js_sys::Reflect::get(remote, &name.into())?
+        .dyn_into::<js_sys::Function>()?
+        .call1(remote, arg)
+}
+
+/// Connection state of the remote device.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum RemotePlaybackState {
+    /// The browser is connecting to the remote device.
+    Connecting,
+    /// The media is played on the remote device.
+    Connected,
+    /// The media is played locally.
+    #[default]
+    Disconnected,
+}
+
+impl RemotePlaybackState {
+    fn from_str(state: &str) -> Self {
+        match state {
+            "connecting" => Self::Connecting,
+            "connected" => Self::Connected,
+            _ => Self::Disconnected,
+        }
+    }
+}
+
+/// Return type of [`use_remote_playback`].
+pub struct UseRemotePlaybackReturn<PromptFn>
+where
+    PromptFn: Fn() + Clone,
+{
+    /// `true` if the browser supports the Remote Playback API.
+    pub is_supported: Signal<bool>,
+
+    /// Wether a remote device is available to play the media.
+    pub is_available: Signal<bool>,
+
+    /// Connection state of the remote device.
+    pub state: Signal<RemotePlaybackState>,
+
+    /// Latest error that occurred while watching the availability or prompting.
+    pub error: Signal<Option<UseRemotePlaybackError>>,
+
+    /// Opens the browser's dialog to connect to or disconnect from a remote device.
+    pub prompt: PromptFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseRemotePlaybackError {
+    #[error("failed to watch the availability of remote devices")]
+    WatchAvailability(JsValue),
+    #[error("failed to prompt for a remote device")]
+    Prompt(JsValue),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_from_str() {
+        assert_eq!(
+            RemotePlaybackState::from_str("connecting"),
+            RemotePlaybackState::Connecting
+        );
+        assert_eq!(
+            RemotePlaybackState::from_str("connected"),
+            RemotePlaybackState::Connected
+        );
+        assert_eq!(
+            RemotePlaybackState::from_str("disconnected"),
+            RemotePlaybackState::Disconnected
+        );
+        assert_eq!(
+            RemotePlaybackState::from_str(""),
+            RemotePlaybackState::Disconnected
+        );
+    }
+}
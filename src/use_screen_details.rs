@@ -0,0 +1,287 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut, use_event_listener, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::Custom;
+use leptos::*;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Reactive [Window Management API](https://developer.mozilla.org/en-US/docs/Web/API/Window_Management_API)
+/// that lists all the screens connected to the device.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_screen_details)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_screen_details, UseScreenDetailsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseScreenDetailsReturn {
+///     screens,
+///     current_screen,
+///     request,
+///     ..
+/// } = use_screen_details();
+///
+/// let open_on_other_screen = move |_| {
+///     let current = current_screen.get_untracked();
+///
+///     let other_screen = screens
+///         .get_untracked()
+///         .into_iter()
+///         .find(|screen| Some(screen) != current.as_ref());
+///
+///     if let Some(screen) = other_screen {
+///         let _ = window().open_with_url_and_target_and_features(
+///             "/popup",
+///             "_blank",
+///             &format!("left={},top={},width=400,height=300", screen.avail_left, screen.avail_top),
+///         );
+///     }
+/// };
+///
+/// view! {
+///     <button on:click=move |_| request()>"Detect screens"</button>
+///     <For each=move || screens.get() key=|screen| screen.label.clone() let:screen>
+///         <p>{screen.label} ": " {screen.width} " x " {screen.height}</p>
+///     </For>
+///     <button on:click=open_on_other_screen>"Open popup on other screen"</button>
+/// }
+/// # }
+/// ```
+///
+/// The browser asks the user for the permission to access the screens. Call `request` in
+/// response to a user action like a click or set the option `request_permissions` to query the
+/// screens right away. Once the permission has been granted the list is updated whenever
+/// screens are connected or disconnected and `current_screen` whenever the window is moved to
+/// another screen.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is always `false`, `screens` is always empty,
+/// `current_screen` is always `None` and `request` does nothing.
+pub fn use_screen_details() -> UseScreenDetailsReturn<impl Fn() + Clone> {
+    use_screen_details_with_options(UseScreenDetailsOptions::default())
+}
+
+/// Version of [`use_screen_details`] that takes a `UseScreenDetailsOptions`. See [`use_screen_details`] for how to use.
+pub fn use_screen_details_with_options(
+    options: UseScreenDetailsOptions,
+) -> UseScreenDetailsReturn<impl Fn() + Clone> {
+    let UseScreenDetailsOptions {
+        request_permissions,
+    } = options;
+
+    let is_supported = use_supported(|| js!("getScreenDetails" in &window()));
+
+    let (details, set_details) = create_signal(None::<web_sys::EventTarget>);
+    let (screens, set_screens) = create_signal(Vec::<ScreenInfo>::new());
+    let (current_screen, set_current_screen) = create_signal(None::<ScreenInfo>);
+    let (error, set_error) = create_signal(None::<UseScreenDetailsError>);
+
+    let update_screens = move || {
+        let Some(details) = details.get_untracked() else {
+            return;
+        };
+
+        set_screens.set(
+            js_sys::Reflect::get(&details, &"screens".into())
+                .map(|screens| {
+                    js_sys::Array::from(&screens)
+                        .iter()
+                        .map(|screen| ScreenInfo::from_js(&screen))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        );
+    };
+
+    let update_current_screen = move || {
+        let Some(details) = details.get_untracked() else {
+            return;
+        };
+
+        set_current_screen.set(
+            js_sys::Reflect::get(&details, &"currentScreen".into())
+                .ok()
+                .filter(|screen| screen.is_object())
+                .map(|screen| ScreenInfo::from_js(&screen)),
+        );
+    };
+
+    let _ = use_event_listener(
+        details,
+        Custom::<ev::Event>::new("screenschange"),
+        move |_| {
+            update_screens();
+            update_current_screen();
+        },
+    );
+
+    let _ = use_event_listener(
+        details,
+        Custom::<ev::Event>::new("currentscreenchange"),
+        move |_| {
+            update_current_screen();
+        },
+    );
+
+    let request = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_supported.get_untracked() {
+                return;
+            }
+
+            let window = window();
+
+            let promise = js_sys::Reflect::get(&window, &"getScreenDetails".into())
+                .and_then(|get_details| get_details.dyn_into::<js_sys::Function>())
+                .and_then(|get_details| get_details.call0(&window))
+                .and_then(|promise| promise.dyn_into::<js_sys::Promise>());
+
+            match promise {
+                Ok(promise) => spawn_local(async move {
+                    match js_fut!(promise).await {
+                        Ok(new_details) => {
+                            set_details.set(Some(new_details.unchecked_into()));
+                            set_error.set(None);
+                            update_screens();
+                            update_current_screen();
+                        }
+                        Err(err) => set_error.set(Some(UseScreenDetailsError::Request(err))),
+                    }
+                }),
+                Err(err) => set_error.set(Some(UseScreenDetailsError::Request(err))),
+            }
+        }
+    };
+
+    if request_permissions {
+        request();
+    }
+
+    UseScreenDetailsReturn {
+        is_supported,
+        screens: screens.into(),
+        current_screen: current_screen.into(),
+        error: error.into(),
+        request,
+    }
+}
+
+/// Information about a screen. All positions and sizes are in CSS pixels relative to the
+/// top-left corner of the primary screen.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScreenInfo {
+    /// Name of the screen like `"Built-in Retina Display"`.
+    pub label: String,
+
+    /// Distance of the left edge of the screen.
+    pub left: i32,
+
+    /// Distance of the top edge of the screen.
+    pub top: i32,
+
+    /// Width of the screen.
+    pub width: u32,
+
+    /// Height of the screen.
+    pub height: u32,
+
+    /// Left edge of the area that can be used by windows, without taskbars and the like.
+    pub avail_left: i32,
+
+    /// Top edge of the area that can be used by windows.
+    pub avail_top: i32,
+
+    /// Width of the area that can be used by windows.
+    pub avail_width: u32,
+
+    /// Height of the area that can be used by windows.
+    pub avail_height: u32,
+
+    /// Device pixels per CSS pixel.
+    pub device_pixel_ratio: f64,
+
+    /// Wether this is the primary screen of the system.
+    pub is_primary: bool,
+
+    /// Wether this screen is built into the device like the display of a laptop.
+    pub is_internal: bool,
+}
+
+impl ScreenInfo {
+    fn from_js(screen: &JsValue) -> Self {
+        let get = |key: &str| js_sys::Reflect::get(screen, &key.into()).ok();
+        let number = |key: &str| {
+            get(key)
+                .and_then(|value| value.as_f64())
+                .unwrap_or_default()
+        };
+        let flag = |key: &str| {
+            get(key)
+                .and_then(|value| value.as_bool())
+                .unwrap_or_default()
+        };
+
+        Self {
+            label: get("label")
+                .and_then(|value| value.as_string())
+                .unwrap_or_default(),
+            left: number("left") as i32,
+            top: number("top") as i32,
+            width: number("width") as u32,
+            height: number("height") as u32,
+            avail_left: number("availLeft") as i32,
+            avail_top: number("availTop") as i32,
+            avail_width: number("availWidth") as u32,
+            avail_height: number("availHeight") as u32,
+            device_pixel_ratio: number("devicePixelRatio"),
+            is_primary: flag("isPrimary"),
+            is_internal: flag("isInternal"),
+        }
+    }
+}
+
+/// Options for [`use_screen_details_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseScreenDetailsOptions {
+    /// If `true` the screens are queried when this function is called which asks the user for
+    /// the permission if it hasn't been granted yet. Defaults to `false`.
+    request_permissions: bool,
+}
+
+/// Return type of [`use_screen_details`].
+pub struct UseScreenDetailsReturn<RequestFn>
+where
+    RequestFn: Fn() + Clone,
+{
+    /// `true` if the browser supports the Window Management API.
+    pub is_supported: Signal<bool>,
+
+    /// All the screens connected to the device.
+    pub screens: Signal<Vec<ScreenInfo>>,
+
+    /// The screen the window is currently shown on.
+    pub current_screen: Signal<Option<ScreenInfo>>,
+
+    /// Latest error that occurred while querying the screens, for example if the user denied
+    /// the permission.
+    pub error: Signal<Option<UseScreenDetailsError>>,
+
+    /// Queries the screens and asks the user for the permission if necessary.
+    pub request: RequestFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseScreenDetailsError {
+    #[error("failed to get the screen details")]
+    Request(JsValue),
+}
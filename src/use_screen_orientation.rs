@@ -0,0 +1,169 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut, use_event_listener, use_supported};
+use leptos::ev::Custom;
+use leptos::*;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+use web_sys::{OrientationLockType, OrientationType, ScreenOrientation};
+
+/// Reactive [Screen Orientation API](https://developer.mozilla.org/en-US/docs/Web/API/Screen_Orientation_API).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_screen_orientation)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_screen_orientation, UseScreenOrientationReturn};
+/// # use web_sys::{OrientationLockType, OrientationType};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseScreenOrientationReturn {
+///     orientation,
+///     angle,
+///     lock,
+///     unlock,
+///     error,
+///     ..
+/// } = use_screen_orientation();
+///
+/// let is_portrait = move || {
+///     matches!(
+///         orientation.get(),
+///         Some(OrientationType::PortraitPrimary | OrientationType::PortraitSecondary)
+///     )
+/// };
+///
+/// view! {
+///     <p>{move || if is_portrait() { "Portrait" } else { "Landscape" }} " at " {angle} "°"</p>
+///     <button on:click=move |_| lock(OrientationLockType::Landscape)>"Lock landscape"</button>
+///     <button on:click=move |_| unlock()>"Unlock"</button>
+///     <p>{move || error.get().map(|err| err.to_string())}</p>
+/// }
+/// # }
+/// ```
+///
+/// `orientation` and `angle` are updated whenever the screen is rotated.
+///
+/// Most browsers only allow to `lock` the orientation while the page is in fullscreen, for
+/// example with [`fn@crate::use_fullscreen`], and many desktop browsers don't allow it at all.
+/// If the browser rejects the lock, `error` is set to [`UseScreenOrientationError::Lock`].
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is always `false`, `orientation` is always `None`, `angle` is
+/// always `0` and the functions do nothing.
+pub fn use_screen_orientation(
+) -> UseScreenOrientationReturn<impl Fn(OrientationLockType) + Clone, impl Fn() + Clone> {
+    let is_supported = use_supported(|| {
+        window()
+            .screen()
+            .map(|screen| js!("orientation" in &screen))
+            .unwrap_or_default()
+    });
+
+    let screen_orientation = move || -> Option<ScreenOrientation> {
+        if !is_supported.get_untracked() {
+            return None;
+        }
+
+        window().screen().ok().map(|screen| screen.orientation())
+    };
+
+    let (orientation, set_orientation) = create_signal(None::<OrientationType>);
+    let (angle, set_angle) = create_signal(0_u16);
+    let (error, set_error) = create_signal(None::<UseScreenOrientationError>);
+
+    let update = move || {
+        if let Some(screen_orientation) = screen_orientation() {
+            set_orientation.set(screen_orientation.type_().ok());
+            set_angle.set(screen_orientation.angle().unwrap_or_default());
+        }
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        update();
+
+        if let Some(screen_orientation) = screen_orientation() {
+            let _ = use_event_listener(
+                screen_orientation,
+                Custom::<ev::Event>::new("change"),
+                move |_| update(),
+            );
+        }
+    }
+
+    let lock = move |lock_type: OrientationLockType| {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(screen_orientation) = screen_orientation() else {
+                return;
+            };
+
+            match screen_orientation.lock(lock_type) {
+                Ok(promise) => spawn_local(async move {
+                    match js_fut!(promise).await {
+                        Ok(_) => set_error.set(None),
+                        Err(err) => set_error.set(Some(UseScreenOrientationError::Lock(err))),
+                    }
+                }),
+                Err(err) => set_error.set(Some(UseScreenOrientationError::Lock(err))),
+            }
+        }
+    };
+
+    let unlock = move || {
+        if let Some(screen_orientation) = screen_orientation() {
+            if let Err(err) = screen_orientation.unlock() {
+                set_error.set(Some(UseScreenOrientationError::Unlock(err)));
+            }
+        }
+    };
+
+    UseScreenOrientationReturn {
+        is_supported,
+        orientation: orientation.into(),
+        angle: angle.into(),
+        error: error.into(),
+        lock,
+        unlock,
+    }
+}
+
+/// Return type of [`use_screen_orientation`].
+pub struct UseScreenOrientationReturn<LockFn, UnlockFn>
+where
+    LockFn: Fn(OrientationLockType) + Clone,
+    UnlockFn: Fn() + Clone,
+{
+    /// `true` if the browser supports the Screen Orientation API.
+    pub is_supported: Signal<bool>,
+
+    /// The current orientation of the screen.
+    pub orientation: Signal<Option<OrientationType>>,
+
+    /// The current angle of the screen in degrees.
+    pub angle: Signal<u16>,
+
+    /// Latest error that occurred while locking or unlocking the orientation.
+    pub error: Signal<Option<UseScreenOrientationError>>,
+
+    /// Locks the screen to the given orientation.
+    pub lock: LockFn,
+
+    /// Unlocks the screen orientation.
+    pub unlock: UnlockFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseScreenOrientationError {
+    #[error("the browser rejected locking the screen orientation. Most browsers only allow it in fullscreen")]
+    Lock(JsValue),
+    #[error("failed to unlock the screen orientation")]
+    Unlock(JsValue),
+}
@@ -0,0 +1,489 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut, use_event_listener, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::Custom;
+use leptos::*;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{FlowControlType, ParityType, ReadableStreamDefaultReader, SerialPort};
+
+/// Reactive [Web Serial API](https://developer.mozilla.org/en-US/docs/Web/API/Web_Serial_API).
+///
+/// Lets the user pick a serial port, opens it and reads from it continuously.
+/// The port is closed automatically when the component is cleaned up.
+///
+/// > This function requires `--cfg=web_sys_unstable_apis` to be activated as
+/// > [described in the wasm-bindgen guide](https://rustwasm.github.io/docs/wasm-bindgen/web-sys/unstable-apis.html).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_serial)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_serial_with_options, UseSerialOptions, UseSerialReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseSerialReturn {
+///     is_open,
+///     line,
+///     request_port,
+///     close,
+///     write,
+///     ..
+/// } = use_serial_with_options(UseSerialOptions::default().baud_rate(115_200));
+///
+/// let send = move |_| {
+///     let write = write.clone();
+///     spawn_local(async move {
+///         let _ = write(b"ping\n".to_vec()).await;
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=move |_| request_port()>"Connect"</button>
+///     <button on:click=send>"Send"</button>
+///     <button on:click=move |_| close()>"Close"</button>
+///     <p>{line}</p>
+/// }
+/// # }
+/// ```
+///
+/// `request_port` has to be called in response to a user action like a click. It opens the
+/// selected port right away.
+///
+/// Every chunk of received bytes is available in `data` and passed to the `on_data` callback.
+/// Received text is split at line breaks and the latest complete line is available in `line`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_open` are always `false`, the functions do nothing
+/// and `write` always fails with [`UseSerialError::NotOpen`].
+pub fn use_serial() -> UseSerialReturn<
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn(Vec<u8>) -> SerialWriteFuture + Clone,
+> {
+    use_serial_with_options(UseSerialOptions::default())
+}
+
+/// Version of [`use_serial`] that takes a `UseSerialOptions`. See [`use_serial`] for how to use.
+pub fn use_serial_with_options(
+    options: UseSerialOptions,
+) -> UseSerialReturn<
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn(Vec<u8>) -> SerialWriteFuture + Clone,
+> {
+    let UseSerialOptions {
+        baud_rate,
+        data_bits,
+        stop_bits,
+        parity,
+        flow_control,
+        buffer_size,
+        filters,
+        on_data,
+    } = options;
+
+    let is_supported = use_supported(|| js!("serial" in &window().navigator()));
+
+    let (port, set_port) = create_signal(None::<SerialPort>);
+    let (is_open, set_open) = create_signal(false);
+    let (data, set_data) = create_signal(None::<Vec<u8>>);
+    let (line, set_line) = create_signal(None::<String>);
+    let (error, set_error) = create_signal(None::<UseSerialError>);
+
+    let reader = store_value(None::<ReadableStreamDefaultReader>);
+
+    let _ = use_event_listener(port, Custom::<ev::Event>::new("disconnect"), move |_| {
+        reader.set_value(None);
+        set_open.set(false);
+    });
+
+    let open = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(port) = port.get_untracked() else {
+                set_error.set(Some(UseSerialError::NoPort));
+                return;
+            };
+
+            if is_open.get_untracked() {
+                return;
+            }
+
+            let mut serial_options = web_sys::SerialOptions::new(baud_rate);
+            if let Some(data_bits) = data_bits {
+                serial_options.data_bits(data_bits);
+            }
+            if let Some(stop_bits) = stop_bits {
+                serial_options.stop_bits(stop_bits);
+            }
+            if let Some(parity) = parity {
+                serial_options.parity(parity);
+            }
+            if let Some(flow_control) = flow_control {
+                serial_options.flow_control(flow_control);
+            }
+            if let Some(buffer_size) = buffer_size {
+                serial_options.buffer_size(buffer_size);
+            }
+
+            let on_data = Rc::clone(&on_data);
+
+            spawn_local(async move {
+                if let Err(err) = js_fut!(port.open(&serial_options)).await {
+                    set_error.set(Some(UseSerialError::Open(err)));
+                    return;
+                }
+
+                set_open.set(true);
+                set_error.set(None);
+
+                let port_reader: ReadableStreamDefaultReader =
+                    port.readable().get_reader().unchecked_into();
+                reader.set_value(Some(port_reader.clone()));
+
+                let mut lines = LineBuffer::default();
+
+                loop {
+                    let result = match js_fut!(port_reader.read()).await {
+                        Ok(result) => result,
+                        Err(err) => {
+                            set_error.set(Some(UseSerialError::Read(err)));
+                            break;
+                        }
+                    };
+
+                    if js!(result["done"]).map_or(true, |done| done.is_truthy()) {
+                        break;
+                    }
+
+                    let Ok(value) = js!(result["value"]) else {
+                        continue;
+                    };
+                    let bytes = js_sys::Uint8Array::new(&value).to_vec();
+
+                    for complete_line in lines.push(&bytes) {
+                        set_line.set(Some(complete_line));
+                    }
+
+                    #[cfg(debug_assertions)]
+                    let prev = SpecialNonReactiveZone::enter();
+
+                    on_data(bytes.clone());
+
+                    #[cfg(debug_assertions)]
+                    SpecialNonReactiveZone::exit(prev);
+
+                    set_data.set(Some(bytes));
+                }
+
+                port_reader.release_lock();
+            });
+        }
+    };
+
+    let close = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(port) = port.get_untracked() else {
+                return;
+            };
+
+            if !is_open.get_untracked() {
+                return;
+            }
+
+            set_open.set(false);
+
+            let port_reader = reader.get_value();
+            reader.set_value(None);
+
+            spawn_local(async move {
+                // the port can only be closed once the read loop has released its lock
+                if let Some(port_reader) = port_reader {
+                    let _ = js_fut!(port_reader.cancel()).await;
+                    port_reader.release_lock();
+                }
+
+                if let Err(err) = js_fut!(port.close()).await {
+                    set_error.set(Some(UseSerialError::Close(err)));
+                }
+            });
+        }
+    };
+
+    let request_port = {
+        #[cfg(not(feature = "ssr"))]
+        let open = open.clone();
+
+        move || {
+            #[cfg(not(feature = "ssr"))]
+            {
+                if !is_supported.get_untracked() {
+                    return;
+                }
+
+                let mut request_options = web_sys::SerialPortRequestOptions::new();
+                if !filters.is_empty() {
+                    request_options.filters(
+                        &filters
+                            .iter()
+                            .map(SerialFilter::to_port_filter)
+                            .collect::<js_sys::Array>(),
+                    );
+                }
+
+                let serial = window().navigator().serial();
+                let open = open.clone();
+
+                spawn_local(async move {
+                    match js_fut!(serial.request_port_with_options(&request_options)).await {
+                        Ok(new_port) => {
+                            set_port.set(Some(new_port.unchecked_into()));
+                            set_error.set(None);
+                            open();
+                        }
+                        Err(err) => set_error.set(Some(UseSerialError::RequestPort(err))),
+                    }
+                });
+            }
+        }
+    };
+
+    let write = move |bytes: Vec<u8>| {
+        Box::pin(async move {
+            #[cfg(feature = "ssr")]
+            {
+                Err(UseSerialError::NotOpen)
+            }
+
+            #[cfg(not(feature = "ssr"))]
+            {
+                let Some(port) = port.get_untracked().filter(|_| is_open.get_untracked()) else {
+                    return Err(UseSerialError::NotOpen);
+                };
+
+                let writer = port
+                    .writable()
+                    .get_writer()
+                    .map_err(UseSerialError::Write)?;
+
+                let result =
+                    js_fut!(writer.write_with_chunk(&js_sys::Uint8Array::from(bytes.as_slice())))
+                        .await;
+
+                writer.release_lock();
+
+                result.map(|_| ()).map_err(UseSerialError::Write)
+            }
+        }) as SerialWriteFuture
+    };
+
+    on_cleanup(close);
+
+    UseSerialReturn {
+        is_supported,
+        port: port.into(),
+        is_open: is_open.into(),
+        data: data.into(),
+        line: line.into(),
+        error: error.into(),
+        request_port,
+        open,
+        close,
+        write,
+    }
+}
+
+/// Future returned by the `write` function of [`use_serial`].
+pub type SerialWriteFuture = Pin<Box<dyn Future<Output = Result<(), UseSerialError>>>>;
+
+/// Collects received bytes and splits them into lines.
+#[derive(Default)]
+struct LineBuffer {
+    buffer: Vec<u8>,
+}
+
+impl LineBuffer {
+    /// Appends `bytes` and returns all lines that are complete now without their line breaks.
+    fn push(&mut self, bytes: &[u8]) -> Vec<String> {
+        self.buffer.extend_from_slice(bytes);
+
+        let mut lines = vec![];
+
+        while let Some(index) = self.buffer.iter().position(|byte| *byte == b'\n') {
+            let mut line = self.buffer.drain(..=index).collect::<Vec<_>>();
+            line.pop();
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+
+            lines.push(String::from_utf8_lossy(&line).into_owned());
+        }
+
+        lines
+    }
+}
+
+/// Options for [`use_serial_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseSerialOptions {
+    /// Baud rate the port is opened with. Defaults to `9600`.
+    baud_rate: u32,
+
+    /// Number of data bits per frame. Either `7` or `8`. Defaults to the browser's default.
+    #[builder(into)]
+    data_bits: Option<u8>,
+
+    /// Number of stop bits at the end of a frame. Either `1` or `2`. Defaults to the browser's default.
+    #[builder(into)]
+    stop_bits: Option<u8>,
+
+    /// Parity mode. Defaults to the browser's default.
+    #[builder(into)]
+    parity: Option<ParityType>,
+
+    /// Flow control mode. Defaults to the browser's default.
+    #[builder(into)]
+    flow_control: Option<FlowControlType>,
+
+    /// Size of the read and write buffers in bytes. Defaults to the browser's default.
+    #[builder(into)]
+    buffer_size: Option<u32>,
+
+    /// Only ports that match at least one of the filters are listed.
+    filters: Vec<SerialFilter>,
+
+    /// Called with every chunk of bytes that is received.
+    on_data: Rc<dyn Fn(Vec<u8>)>,
+}
+
+impl Default for UseSerialOptions {
+    fn default() -> Self {
+        Self {
+            baud_rate: 9600,
+            data_bits: None,
+            stop_bits: None,
+            parity: None,
+            flow_control: None,
+            buffer_size: None,
+            filters: vec![],
+            on_data: Rc::new(|_| {}),
+        }
+    }
+}
+
+/// Filter for the ports that [`use_serial`] lists.
+#[derive(DefaultBuilder, Default, Clone, Debug, PartialEq, Eq)]
+pub struct SerialFilter {
+    /// USB vendor id of the device.
+    #[builder(into)]
+    usb_vendor_id: Option<u16>,
+
+    /// USB product id of the device.
+    #[builder(into)]
+    usb_product_id: Option<u16>,
+}
+
+#[cfg(not(feature = "ssr"))]
+impl SerialFilter {
+    fn to_port_filter(&self) -> web_sys::SerialPortFilter {
+        let mut filter = web_sys::SerialPortFilter::new();
+
+        if let Some(usb_vendor_id) = self.usb_vendor_id {
+            filter.usb_vendor_id(usb_vendor_id);
+        }
+        if let Some(usb_product_id) = self.usb_product_id {
+            filter.usb_product_id(usb_product_id);
+        }
+
+        filter
+    }
+}
+
+/// Return type of [`use_serial`].
+pub struct UseSerialReturn<RequestPortFn, OpenFn, CloseFn, WriteFn>
+where
+    RequestPortFn: Fn() + Clone,
+    OpenFn: Fn() + Clone,
+    CloseFn: Fn() + Clone,
+    WriteFn: Fn(Vec<u8>) -> SerialWriteFuture + Clone,
+{
+    /// `true` if the browser supports the Web Serial API.
+    pub is_supported: Signal<bool>,
+
+    /// The port picked by the user.
+    pub port: Signal<Option<SerialPort>>,
+
+    /// Wether the port is currently open.
+    pub is_open: Signal<bool>,
+
+    /// The latest chunk of bytes that has been received.
+    pub data: Signal<Option<Vec<u8>>>,
+
+    /// The latest complete line that has been received.
+    pub line: Signal<Option<String>>,
+
+    /// Latest error that occurred.
+    pub error: Signal<Option<UseSerialError>>,
+
+    /// Shows the port chooser and opens the picked port.
+    pub request_port: RequestPortFn,
+
+    /// Opens the port again after it has been closed.
+    pub open: OpenFn,
+
+    /// Closes the port.
+    pub close: CloseFn,
+
+    /// Writes the given bytes to the port.
+    pub write: WriteFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseSerialError {
+    #[error("failed to request port")]
+    RequestPort(JsValue),
+    #[error("no port has been picked")]
+    NoPort,
+    #[error("the port isn't open")]
+    NotOpen,
+    #[error("failed to open port")]
+    Open(JsValue),
+    #[error("failed to read from port")]
+    Read(JsValue),
+    #[error("failed to write to port")]
+    Write(JsValue),
+    #[error("failed to close port")]
+    Close(JsValue),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_buffer() {
+        let mut lines = LineBuffer::default();
+
+        assert_eq!(lines.push(b"hel"), Vec::<String>::new());
+        assert_eq!(lines.push(b"lo\r\nwor"), vec!["hello".to_string()]);
+        assert_eq!(
+            lines.push(b"ld\n\nend\n"),
+            vec!["world".to_string(), "".to_string(), "end".to_string()]
+        );
+        assert_eq!(lines.push(b"rest"), Vec::<String>::new());
+    }
+}
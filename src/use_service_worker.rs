@@ -1,5 +1,6 @@
 use default_struct_builder::DefaultBuilder;
 use leptos::*;
+use std::cell::Cell;
 use std::rc::Rc;
 use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
 use web_sys::ServiceWorkerRegistration;
@@ -14,6 +15,47 @@ use crate::{js_fut, use_window};
 ///
 /// ```
 /// # use leptos::*;
+/// # use leptos_use::{use_service_worker, UseServiceWorkerReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseServiceWorkerReturn {
+///     update_available,
+///     skip_waiting_and_reload,
+///     ..
+/// } = use_service_worker("service-worker.js");
+///
+/// view! {
+///     <Show when=move || update_available.get()>
+///         <button on:click={
+///             let skip_waiting_and_reload = skip_waiting_and_reload.clone();
+///             move |_| skip_waiting_and_reload()
+///         }>
+///             "A new version is available. Reload"
+///         </button>
+///     </Show>
+/// }
+/// # }
+/// ```
+///
+/// `update_available` is `true` when a new service worker has been installed and is waiting
+/// while an older one still controls the page. `skip_waiting_and_reload` sends the skip waiting
+/// message to the waiting worker and reloads the page as soon as the new worker took control.
+///
+/// Your service worker has to call `self.skipWaiting()` when it receives that message:
+///
+/// ```js
+/// self.addEventListener("message", (event) => {
+///     if (event.data === "skipWaiting") {
+///         self.skipWaiting();
+///     }
+/// });
+/// ```
+///
+/// ### Options
+///
+/// ```
+/// # use leptos::*;
 /// # use leptos_use::{use_service_worker_with_options, UseServiceWorkerOptions, UseServiceWorkerReturn};
 /// #
 /// # #[component]
@@ -23,7 +65,9 @@ use crate::{js_fut, use_window};
 ///         installing,
 ///         waiting,
 ///         active,
+///         update_available,
 ///         skip_waiting,
+///         skip_waiting_and_reload,
 ///         check_for_update,
 /// } = use_service_worker_with_options(UseServiceWorkerOptions::default()
 ///     .script_url("service-worker.js")
@@ -37,19 +81,31 @@ use crate::{js_fut, use_window};
 /// ## Server-Side Rendering
 ///
 /// This function does **not** support SSR. Call it inside a `create_effect`.
-pub fn use_service_worker() -> UseServiceWorkerReturn<impl Fn() + Clone, impl Fn() + Clone> {
-    use_service_worker_with_options(UseServiceWorkerOptions::default())
+pub fn use_service_worker(
+    script_url: &str,
+) -> UseServiceWorkerReturn<impl Fn() + Clone, impl Fn() + Clone, impl Fn() + Clone> {
+    use_service_worker_with_options(UseServiceWorkerOptions::default().script_url(script_url))
 }
 
 /// Version of [`use_service_worker`] that takes a `UseServiceWorkerOptions`. See [`use_service_worker`] for how to use.
 pub fn use_service_worker_with_options(
     options: UseServiceWorkerOptions,
-) -> UseServiceWorkerReturn<impl Fn() + Clone, impl Fn() + Clone> {
+) -> UseServiceWorkerReturn<impl Fn() + Clone, impl Fn() + Clone, impl Fn() + Clone> {
+    // Set by `skip_waiting_and_reload` to reload the page instead of calling
+    // `on_controller_change` once the new ServiceWorker took control.
+    let reload_requested = Rc::new(Cell::new(false));
+
     // Trigger the user-defined action (page-reload by default)
     // whenever a new ServiceWorker is installed.
     if let Some(navigator) = use_window().navigator() {
         let on_controller_change = options.on_controller_change.clone();
+        let reload_requested = Rc::clone(&reload_requested);
         let js_closure = Closure::wrap(Box::new(move |_event: JsValue| {
+            if reload_requested.replace(false) {
+                reload_page();
+                return;
+            }
+
             #[cfg(debug_assertions)]
             let prev = SpecialNonReactiveZone::enter();
 
@@ -118,6 +174,30 @@ pub fn use_service_worker_with_options(
         })
     });
 
+    let waiting = Signal::derive(move || {
+        registration.with(|reg| {
+            reg.as_ref()
+                .map(|reg| reg.waiting().is_some())
+                .unwrap_or_default()
+        })
+    });
+
+    let skip_waiting = move || {
+        registration.with_untracked(|reg| if let Ok(reg) = reg {
+            match reg.waiting() {
+                Some(sw) => {
+                    logging::debug_warn!("Updating to newly installed SW...");
+                    if let Err(err) = sw.post_message(&JsValue::from_str(&options.skip_waiting_message)) {
+                        logging::warn!("Could not send message to active SW: Error: {err:?}");
+                    }
+                },
+                None => {
+                    logging::warn!("You tried to update the SW while no new SW was waiting. This is probably a bug.");
+                },
+            }
+        });
+    };
+
     UseServiceWorkerReturn {
         registration,
         installing: Signal::derive(move || {
@@ -127,13 +207,7 @@ pub fn use_service_worker_with_options(
                     .unwrap_or_default()
             })
         }),
-        waiting: Signal::derive(move || {
-            registration.with(|reg| {
-                reg.as_ref()
-                    .map(|reg| reg.waiting().is_some())
-                    .unwrap_or_default()
-            })
-        }),
+        waiting,
         active: Signal::derive(move || {
             registration.with(|reg| {
                 reg.as_ref()
@@ -141,6 +215,10 @@ pub fn use_service_worker_with_options(
                     .unwrap_or_default()
             })
         }),
+        update_available: Signal::derive(move || {
+            // Without a controller this is the first install and not an update.
+            waiting.get() && has_controller()
+        }),
         check_for_update: move || {
             registration.with(|reg| {
                 if let Ok(reg) = reg {
@@ -148,20 +226,12 @@ pub fn use_service_worker_with_options(
                 }
             })
         },
-        skip_waiting: move || {
-            registration.with_untracked(|reg| if let Ok(reg) = reg {
-                match reg.waiting() {
-                    Some(sw) => {
-                        logging::debug_warn!("Updating to newly installed SW...");
-                        if let Err(err) = sw.post_message(&JsValue::from_str(&options.skip_waiting_message)) {
-                            logging::warn!("Could not send message to active SW: Error: {err:?}");
-                        }
-                    },
-                    None => {
-                        logging::warn!("You tried to update the SW while no new SW was waiting. This is probably a bug.");
-                    },
-                }
-            });
+        skip_waiting: skip_waiting.clone(),
+        skip_waiting_and_reload: move || {
+            if waiting.get_untracked() {
+                reload_requested.set(true);
+            }
+            skip_waiting();
         },
     }
 }
@@ -190,25 +260,35 @@ impl Default for UseServiceWorkerOptions {
         Self {
             script_url: "service-worker.js".into(),
             skip_waiting_message: "skipWaiting".into(),
-            on_controller_change: Rc::new(move || {
-                use std::ops::Deref;
-                if let Some(window) = use_window().deref() {
-                    if let Err(err) = window.location().reload() {
-                        logging::warn!(
-                            "Detected a ServiceWorkerController change but the page reload failed! Error: {err:?}"
-                        );
-                    }
-                }
-            }),
+            on_controller_change: Rc::new(reload_page),
         }
     }
 }
 
+fn reload_page() {
+    use std::ops::Deref;
+    if let Some(window) = use_window().deref() {
+        if let Err(err) = window.location().reload() {
+            logging::warn!(
+                "Detected a ServiceWorkerController change but the page reload failed! Error: {err:?}"
+            );
+        }
+    }
+}
+
+fn has_controller() -> bool {
+    use_window()
+        .navigator()
+        .map(|navigator| navigator.service_worker().controller().is_some())
+        .unwrap_or_default()
+}
+
 /// Return type of [`use_service_worker`].
-pub struct UseServiceWorkerReturn<CheckFn, SkipFn>
+pub struct UseServiceWorkerReturn<CheckFn, SkipFn, ReloadFn>
 where
     CheckFn: Fn() + Clone,
     SkipFn: Fn() + Clone,
+    ReloadFn: Fn() + Clone,
 {
     /// The current registration state.
     pub registration: Signal<Result<ServiceWorkerRegistration, ServiceWorkerRegistrationError>>,
@@ -222,12 +302,19 @@ where
     /// Whether a SW is active.
     pub active: Signal<bool>,
 
+    /// Whether a new SW is waiting to replace the one that currently controls the page.
+    pub update_available: Signal<bool>,
+
     /// Check for a ServiceWorker update.
     pub check_for_update: CheckFn,
 
     /// Call this to activate a new ("waiting") SW if one is available.
     /// Calling this while the [`UseServiceWorkerReturn::waiting`] signal resolves to false has no effect.
     pub skip_waiting: SkipFn,
+
+    /// Like [`UseServiceWorkerReturn::skip_waiting`] but reloads the page once the new SW
+    /// took control instead of calling `on_controller_change`.
+    pub skip_waiting_and_reload: ReloadFn,
 }
 
 struct ServiceWorkerScriptUrl(pub String);
@@ -0,0 +1,225 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::utils::StringCodec;
+use crate::{use_event_listener, use_supported, UseWebWorkerError};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use std::marker::PhantomData;
+use web_sys::WorkerType;
+
+/// Reactive [Shared Worker](https://developer.mozilla.org/en-US/docs/Web/API/SharedWorker).
+///
+/// A shared worker is a single worker instance that all browsing contexts (tabs, windows,
+/// iframes) of the same origin share as long as they use the same script URL and name. Every
+/// context communicates with the worker through its own `MessagePort`. The port is started
+/// automatically and closed when the component is cleaned up. The worker itself keeps running
+/// as long as at least one context is connected.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_shared_worker)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_shared_worker, UseSharedWorkerReturn};
+/// # use leptos_use::utils::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseSharedWorkerReturn {
+///     data,
+///     post_message,
+///     ..
+/// } = use_shared_worker::<String, String, FromToStringCodec>("counter", "/counter_worker.js");
+///
+/// post_message(&"increment".to_string());
+///
+/// view! {
+///     <p>"Latest message: " {move || data.get()}</p>
+/// }
+/// # }
+/// ```
+///
+/// Just like with [`use_web_worker`] the first type parameter is the type of the messages sent
+/// to the worker and the second one the type of the messages received from it. Inside the
+/// worker you can keep track of the ports in the `connect` event to broadcast messages to all
+/// connected contexts.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no worker is created, all signals stay `None` and the functions do nothing.
+#[allow(clippy::type_complexity)]
+pub fn use_shared_worker<Tx, Rx, C>(
+    name: &str,
+    url: &str,
+) -> UseSharedWorkerReturn<
+    Tx,
+    Rx,
+    impl Fn(&Tx) + Clone,
+    impl Fn() + Clone,
+    <C as StringCodec<Tx>>::Error,
+    <C as StringCodec<Rx>>::Error,
+>
+where
+    Rx: 'static,
+    C: StringCodec<Tx> + StringCodec<Rx> + Default + Clone,
+{
+    use_shared_worker_with_options::<Tx, Rx, C>(name, url, UseSharedWorkerOptions::default())
+}
+
+/// Version of [`use_shared_worker`] that takes a `UseSharedWorkerOptions`. See [`use_shared_worker`] for how to use.
+#[allow(clippy::type_complexity)]
+pub fn use_shared_worker_with_options<Tx, Rx, C>(
+    name: &str,
+    url: &str,
+    options: UseSharedWorkerOptions,
+) -> UseSharedWorkerReturn<
+    Tx,
+    Rx,
+    impl Fn(&Tx) + Clone,
+    impl Fn() + Clone,
+    <C as StringCodec<Tx>>::Error,
+    <C as StringCodec<Rx>>::Error,
+>
+where
+    Rx: 'static,
+    C: StringCodec<Tx> + StringCodec<Rx> + Default + Clone,
+{
+    let UseSharedWorkerOptions { worker_type } = options;
+
+    let is_supported = use_supported(|| crate::js!("SharedWorker" in &window()));
+
+    let (worker, set_worker) = create_signal(None::<web_sys::SharedWorker>);
+    let (port, set_port) = create_signal(None::<web_sys::MessagePort>);
+    let (data, set_data) = create_signal(None::<Rx>);
+    let (error, set_error) = create_signal(
+        None::<UseWebWorkerError<<C as StringCodec<Tx>>::Error, <C as StringCodec<Rx>>::Error>>,
+    );
+
+    let codec = C::default();
+
+    let post_message = {
+        let codec = codec.clone();
+
+        move |value: &Tx| {
+            if let Some(port) = port.get_untracked() {
+                match codec.encode(value) {
+                    Ok(msg) => {
+                        if let Err(err) = port.post_message(&msg.into()) {
+                            set_error.set(Some(UseWebWorkerError::PostMessage(err)));
+                        }
+                    }
+                    Err(err) => set_error.set(Some(UseWebWorkerError::Encode(err))),
+                }
+            }
+        }
+    };
+
+    let close = move || {
+        if let Some(port) = port.get_untracked() {
+            port.close();
+            set_port.set(None);
+        }
+    };
+
+    if is_supported.get_untracked() {
+        let mut worker_options = web_sys::WorkerOptions::new();
+        worker_options.type_(worker_type).name(name);
+
+        match web_sys::SharedWorker::new_with_worker_options(url, &worker_options) {
+            Ok(new_worker) => {
+                let new_port = new_worker.port();
+
+                let _ = use_event_listener(new_port.clone(), ev::message, move |event| {
+                    if let Some(msg) = event.data().as_string() {
+                        match codec.decode(msg) {
+                            Ok(value) => set_data.set(Some(value)),
+                            Err(err) => set_error.set(Some(UseWebWorkerError::Decode(err))),
+                        }
+                    } else {
+                        set_error.set(Some(UseWebWorkerError::ValueNotString));
+                    }
+                });
+
+                let _ = use_event_listener(new_port.clone(), ev::messageerror, move |event| {
+                    set_error.set(Some(UseWebWorkerError::MessageEvent(event)));
+                });
+
+                let _ = use_event_listener(new_worker.clone(), ev::error, move |event| {
+                    set_error.set(Some(UseWebWorkerError::Worker(event)));
+                });
+
+                // required because the listeners are added with `addEventListener`
+                new_port.start();
+
+                set_worker.set(Some(new_worker));
+                set_port.set(Some(new_port));
+            }
+            Err(err) => set_error.set(Some(UseWebWorkerError::Create(err))),
+        }
+    }
+
+    on_cleanup(close);
+
+    UseSharedWorkerReturn {
+        is_supported,
+        worker: worker.into(),
+        port: port.into(),
+        data: data.into(),
+        error: error.into(),
+        post_message,
+        close,
+        _marker: PhantomData,
+    }
+}
+
+/// Options for [`use_shared_worker_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseSharedWorkerOptions {
+    /// Wether the worker script is a classic script or an ES module.
+    /// Defaults to `WorkerType::Classic`.
+    worker_type: WorkerType,
+}
+
+impl Default for UseSharedWorkerOptions {
+    fn default() -> Self {
+        Self {
+            worker_type: WorkerType::Classic,
+        }
+    }
+}
+
+/// Return type of [`use_shared_worker`].
+pub struct UseSharedWorkerReturn<Tx, Rx, PostFn, CloseFn, EncodeErr, DecodeErr>
+where
+    Rx: 'static,
+    PostFn: Fn(&Tx) + Clone,
+    CloseFn: Fn() + Clone,
+    EncodeErr: 'static,
+    DecodeErr: 'static,
+{
+    /// `true` if this browser supports Shared Workers.
+    pub is_supported: Signal<bool>,
+
+    /// The shared worker that is wrapped by this function.
+    pub worker: Signal<Option<web_sys::SharedWorker>>,
+
+    /// The port of this browsing context to the worker. `None` after it was closed.
+    pub port: Signal<Option<web_sys::MessagePort>>,
+
+    /// Latest message received from the worker.
+    pub data: Signal<Option<Rx>>,
+
+    /// Latest error that occurred in the worker or while exchanging messages with it.
+    pub error: Signal<Option<UseWebWorkerError<EncodeErr, DecodeErr>>>,
+
+    /// Sends a message to the worker.
+    pub post_message: PostFn,
+
+    /// Closes the port of this browsing context. The worker keeps running for other contexts.
+    pub close: CloseFn,
+
+    _marker: PhantomData<Tx>,
+}
@@ -0,0 +1,281 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, use_event_listener, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::Custom;
+use leptos::*;
+use wasm_bindgen::JsCast;
+use web_sys::{SpeechRecognition, SpeechRecognitionErrorCode};
+
+/// Reactive [SpeechRecognition](https://developer.mozilla.org/en-US/docs/Web/API/SpeechRecognition).
+///
+/// Transcribes what the user says into text.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_speech_recognition)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_speech_recognition_with_options, UseSpeechRecognitionOptions, UseSpeechRecognitionReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseSpeechRecognitionReturn {
+///     is_listening,
+///     result,
+///     start,
+///     stop,
+///     ..
+/// } = use_speech_recognition_with_options(UseSpeechRecognitionOptions::default().lang("de-DE"));
+///
+/// view! {
+///     <button on:click=move |_| start()>"Start"</button>
+///     <button on:click=move |_| stop()>"Stop"</button>
+///     <p>{result}</p>
+/// }
+/// # }
+/// ```
+///
+/// While the user is speaking the recognized text in `result` changes constantly. Once a
+/// part of it is recognized for sure it's moved from `interim_result` to `final_result`.
+/// When the recognition is started again both of them are reset.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_listening` are always `false`, the results are always
+/// empty and `start` and `stop` do nothing.
+pub fn use_speech_recognition() -> UseSpeechRecognitionReturn<impl Fn() + Clone, impl Fn() + Clone>
+{
+    use_speech_recognition_with_options(UseSpeechRecognitionOptions::default())
+}
+
+/// Version of [`use_speech_recognition`] that takes a `UseSpeechRecognitionOptions`. See [`use_speech_recognition`] for how to use.
+pub fn use_speech_recognition_with_options(
+    options: UseSpeechRecognitionOptions,
+) -> UseSpeechRecognitionReturn<impl Fn() + Clone, impl Fn() + Clone> {
+    let UseSpeechRecognitionOptions {
+        lang,
+        continuous,
+        interim_results,
+        max_alternatives,
+    } = options;
+
+    let is_supported = use_supported(|| {
+        js!("SpeechRecognition" in &window()) || js!("webkitSpeechRecognition" in &window())
+    });
+
+    let (recognition, set_recognition) = create_signal(None::<SpeechRecognition>);
+    let (is_listening, set_listening) = create_signal(false);
+    let (final_result, set_final_result) = create_signal(String::new());
+    let (interim_result, set_interim_result) = create_signal(String::new());
+    let (error, set_error) = create_signal(None::<SpeechRecognitionErrorCode>);
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        let window = window();
+
+        // Chrome and Safari only know the prefixed version
+        let constructor = js!(window["SpeechRecognition"])
+            .ok()
+            .filter(|constructor| !constructor.is_undefined())
+            .or_else(|| js!(window["webkitSpeechRecognition"]).ok())
+            .and_then(|constructor| constructor.dyn_into::<js_sys::Function>().ok());
+
+        if let Some(new_recognition) = constructor
+            .and_then(|constructor| {
+                js_sys::Reflect::construct(&constructor, &js_sys::Array::new()).ok()
+            })
+            .map(|new_recognition| new_recognition.unchecked_into::<SpeechRecognition>())
+        {
+            let _ = new_recognition.set_continuous(continuous);
+            new_recognition.set_interim_results(interim_results);
+            new_recognition.set_max_alternatives(max_alternatives);
+
+            set_recognition.set(Some(new_recognition));
+        }
+    }
+
+    let _ = watch(
+        move || (recognition.get(), lang.get()),
+        move |(recognition, lang), _, _| {
+            if let Some(recognition) = recognition {
+                recognition.set_lang(lang);
+            }
+        },
+        true,
+    );
+
+    let _ = use_event_listener(recognition, Custom::<ev::Event>::new("start"), move |_| {
+        set_listening.set(true);
+        set_final_result.set(String::new());
+        set_interim_result.set(String::new());
+        set_error.set(None);
+    });
+
+    let _ = use_event_listener(recognition, Custom::<ev::Event>::new("end"), move |_| {
+        set_listening.set(false);
+    });
+
+    let _ = use_event_listener(
+        recognition,
+        Custom::<web_sys::SpeechRecognitionError>::new("error"),
+        move |event| {
+            set_error.set(Some(event.error()));
+        },
+    );
+
+    let _ = use_event_listener(
+        recognition,
+        Custom::<web_sys::SpeechRecognitionEvent>::new("result"),
+        move |event| {
+            #[cfg(not(feature = "ssr"))]
+            if let Some(results) = event.results() {
+                let (final_text, interim_text) = join_transcripts((0..results.length()).map(|i| {
+                    let result = results.item(i);
+                    (result.item(0).transcript(), result.is_final())
+                }));
+
+                set_final_result.set(final_text);
+                set_interim_result.set(interim_text);
+            }
+        },
+    );
+
+    let start = move || {
+        if let Some(recognition) = recognition.get_untracked() {
+            if !is_listening.get_untracked() {
+                let _ = recognition.start();
+            }
+        }
+    };
+
+    let stop = move || {
+        if let Some(recognition) = recognition.get_untracked() {
+            if is_listening.get_untracked() {
+                recognition.stop();
+            }
+        }
+    };
+
+    on_cleanup(move || {
+        if let Some(recognition) = recognition.get_untracked() {
+            recognition.abort();
+        }
+    });
+
+    UseSpeechRecognitionReturn {
+        is_supported,
+        is_listening: is_listening.into(),
+        is_final: Signal::derive(move || interim_result.with(String::is_empty)),
+        result: Signal::derive(move || final_result.get() + &interim_result.get()),
+        final_result: final_result.into(),
+        interim_result: interim_result.into(),
+        error: error.into(),
+        start,
+        stop,
+    }
+}
+
+/// Joins the transcripts of the final respectively the interim results.
+fn join_transcripts(results: impl Iterator<Item = (String, bool)>) -> (String, String) {
+    let mut final_text = String::new();
+    let mut interim_text = String::new();
+
+    for (transcript, is_final) in results {
+        if is_final {
+            final_text.push_str(&transcript);
+        } else {
+            interim_text.push_str(&transcript);
+        }
+    }
+
+    (final_text, interim_text)
+}
+
+/// Options for [`use_speech_recognition_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseSpeechRecognitionOptions {
+    /// Language of the speech like `"en-US"`. Defaults to `"en-US"`.
+    #[builder(into)]
+    lang: MaybeSignal<String>,
+
+    /// Keep on listening after the user has stopped speaking. Defaults to `true`.
+    continuous: bool,
+
+    /// Report results that aren't final yet. Defaults to `true`.
+    interim_results: bool,
+
+    /// Maximum number of alternatives that are considered per result. Defaults to `1`.
+    max_alternatives: u32,
+}
+
+impl Default for UseSpeechRecognitionOptions {
+    fn default() -> Self {
+        Self {
+            lang: "en-US".to_string().into(),
+            continuous: true,
+            interim_results: true,
+            max_alternatives: 1,
+        }
+    }
+}
+
+/// Return type of [`use_speech_recognition`].
+pub struct UseSpeechRecognitionReturn<StartFn, StopFn>
+where
+    StartFn: Fn() + Clone,
+    StopFn: Fn() + Clone,
+{
+    /// `true` if the browser supports speech recognition.
+    pub is_supported: Signal<bool>,
+
+    /// Wether the recognition is currently listening.
+    pub is_listening: Signal<bool>,
+
+    /// `true` as long as there are no interim results.
+    pub is_final: Signal<bool>,
+
+    /// The complete recognized text. This is `final_result` followed by `interim_result`.
+    pub result: Signal<String>,
+
+    /// The part of the recognized text that won't change anymore.
+    pub final_result: Signal<String>,
+
+    /// The part of the recognized text that is still being recognized.
+    pub interim_result: Signal<String>,
+
+    /// The latest error that occurred.
+    pub error: Signal<Option<SpeechRecognitionErrorCode>>,
+
+    /// Starts listening.
+    pub start: StartFn,
+
+    /// Stops listening. Already captured audio is still recognized.
+    pub stop: StopFn,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_transcripts() {
+        let results = vec![
+            ("Hello".to_string(), true),
+            (" world".to_string(), true),
+            (" how are".to_string(), false),
+        ];
+
+        assert_eq!(
+            join_transcripts(results.into_iter()),
+            ("Hello world".to_string(), " how are".to_string())
+        );
+        assert_eq!(
+            join_transcripts(std::iter::empty()),
+            (String::new(), String::new())
+        );
+    }
+}
@@ -0,0 +1,316 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, use_event_listener, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::Custom;
+use leptos::*;
+use web_sys::{
+    SpeechSynthesis, SpeechSynthesisErrorCode, SpeechSynthesisUtterance, SpeechSynthesisVoice,
+};
+
+/// Reactive [SpeechSynthesis](https://developer.mozilla.org/en-US/docs/Web/API/SpeechSynthesis).
+///
+/// Reads a text out loud.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_speech_synthesis)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_speech_synthesis_with_options, UseSpeechSynthesisOptions, UseSpeechSynthesisReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (text, set_text) = create_signal("Hello, everyone! Good morning!".to_string());
+///
+/// let UseSpeechSynthesisReturn {
+///     is_playing,
+///     speak,
+///     cancel,
+///     ..
+/// } = use_speech_synthesis_with_options(text, UseSpeechSynthesisOptions::default().rate(1.5));
+///
+/// view! {
+///     <button on:click=move |_| speak()>"Speak"</button>
+///     <button on:click=move |_| cancel()>"Stop"</button>
+/// }
+/// # }
+/// ```
+///
+/// The text and the options are read when `speak` is called. Calling `speak` while the text
+/// is still being read starts over.
+///
+/// ### Voices
+///
+/// `voices` contains the voices that are available in the browser. Many browsers load them
+/// asynchronously so the list is empty at first and is updated once they're available.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_speech_synthesis_with_options, UseSpeechSynthesisOptions, UseSpeechSynthesisReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (voice, set_voice) = create_signal(None);
+///
+/// let UseSpeechSynthesisReturn { voices, .. } = use_speech_synthesis_with_options(
+///     "Hello!".to_string(),
+///     UseSpeechSynthesisOptions::default().voice(voice),
+/// );
+///
+/// // pick the first German voice
+/// create_effect(move |_| {
+///     set_voice.set(voices.get().into_iter().find(|voice| voice.lang().starts_with("de")));
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_playing` are always `false`, `voices` is always empty
+/// and the functions do nothing.
+pub fn use_speech_synthesis(
+    text: impl Into<MaybeSignal<String>>,
+) -> UseSpeechSynthesisReturn<
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+> {
+    use_speech_synthesis_with_options(text, UseSpeechSynthesisOptions::default())
+}
+
+/// Version of [`use_speech_synthesis`] that takes a `UseSpeechSynthesisOptions`. See [`use_speech_synthesis`] for how to use.
+pub fn use_speech_synthesis_with_options(
+    text: impl Into<MaybeSignal<String>>,
+    options: UseSpeechSynthesisOptions,
+) -> UseSpeechSynthesisReturn<
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+> {
+    let UseSpeechSynthesisOptions {
+        lang,
+        voice,
+        pitch,
+        rate,
+        volume,
+    } = options;
+
+    let text = text.into();
+    let text = Signal::derive(move || text.get());
+    let voice = Signal::derive(move || voice.get());
+
+    let is_supported = use_supported(|| js!("speechSynthesis" in &window()));
+
+    let (synth, set_synth) = create_signal(None::<SpeechSynthesis>);
+    let (utterance, set_utterance) = create_signal(None::<SpeechSynthesisUtterance>);
+    let (voices, set_voices) = create_signal(Vec::<SpeechSynthesisVoice>::new());
+    let (status, set_status) = create_signal(SpeechSynthesisStatus::Init);
+    let (error, set_error) = create_signal(None::<SpeechSynthesisErrorCode>);
+
+    let update_voices = move || {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(synth) = synth.get_untracked() {
+            use wasm_bindgen::JsCast;
+
+            set_voices.set(
+                synth
+                    .get_voices()
+                    .iter()
+                    .map(|voice| voice.unchecked_into())
+                    .collect(),
+            );
+        }
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        set_synth.set(window().speech_synthesis().ok());
+        update_voices();
+    }
+
+    let _ = use_event_listener(
+        synth,
+        Custom::<ev::Event>::new("voiceschanged"),
+        move |_| {
+            update_voices();
+        },
+    );
+
+    let _ = use_event_listener(utterance, Custom::<ev::Event>::new("start"), move |_| {
+        set_status.set(SpeechSynthesisStatus::Play);
+        set_error.set(None);
+    });
+
+    let _ = use_event_listener(utterance, Custom::<ev::Event>::new("pause"), move |_| {
+        set_status.set(SpeechSynthesisStatus::Pause);
+    });
+
+    let _ = use_event_listener(utterance, Custom::<ev::Event>::new("resume"), move |_| {
+        set_status.set(SpeechSynthesisStatus::Play);
+    });
+
+    let _ = use_event_listener(utterance, Custom::<ev::Event>::new("end"), move |_| {
+        set_status.set(SpeechSynthesisStatus::End);
+    });
+
+    let _ = use_event_listener(
+        utterance,
+        Custom::<web_sys::SpeechSynthesisErrorEvent>::new("error"),
+        move |event| {
+            set_error.set(Some(event.error()));
+            set_status.set(SpeechSynthesisStatus::End);
+        },
+    );
+
+    let speak = move || {
+        let Some(synth) = synth.get_untracked() else {
+            return;
+        };
+
+        let Ok(new_utterance) = SpeechSynthesisUtterance::new_with_text(&text.get_untracked())
+        else {
+            return;
+        };
+
+        new_utterance.set_lang(&lang.get_untracked());
+        new_utterance.set_voice(voice.get_untracked().as_ref());
+        new_utterance.set_pitch(pitch.get_untracked());
+        new_utterance.set_rate(rate.get_untracked());
+        new_utterance.set_volume(volume.get_untracked());
+
+        synth.cancel();
+        set_utterance.set(Some(new_utterance.clone()));
+        synth.speak(&new_utterance);
+    };
+
+    let pause = move || {
+        if let Some(synth) = synth.get_untracked() {
+            synth.pause();
+        }
+    };
+
+    let resume = move || {
+        if let Some(synth) = synth.get_untracked() {
+            synth.resume();
+        }
+    };
+
+    let cancel = move || {
+        if let Some(synth) = synth.get_untracked() {
+            synth.cancel();
+        }
+    };
+
+    on_cleanup(cancel);
+
+    UseSpeechSynthesisReturn {
+        is_supported,
+        is_playing: Signal::derive(move || status.get() == SpeechSynthesisStatus::Play),
+        status: status.into(),
+        voices: voices.into(),
+        utterance: utterance.into(),
+        error: error.into(),
+        speak,
+        pause,
+        resume,
+        cancel,
+    }
+}
+
+/// Options for [`use_speech_synthesis_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseSpeechSynthesisOptions {
+    /// Language of the text like `"en-US"`. Defaults to `"en-US"`.
+    #[builder(into)]
+    lang: MaybeSignal<String>,
+
+    /// The voice that reads the text. Defaults to `None` which means the default voice
+    /// for `lang` is used.
+    #[builder(into)]
+    voice: MaybeSignal<Option<SpeechSynthesisVoice>>,
+
+    /// Pitch of the voice between `0.0` and `2.0`. Defaults to `1.0`.
+    #[builder(into)]
+    pitch: MaybeSignal<f32>,
+
+    /// Speed of the voice between `0.1` and `10.0`. Defaults to `1.0`.
+    #[builder(into)]
+    rate: MaybeSignal<f32>,
+
+    /// Volume between `0.0` and `1.0`. Defaults to `1.0`.
+    #[builder(into)]
+    volume: MaybeSignal<f32>,
+}
+
+impl Default for UseSpeechSynthesisOptions {
+    fn default() -> Self {
+        Self {
+            lang: "en-US".to_string().into(),
+            voice: MaybeSignal::default(),
+            pitch: 1.0.into(),
+            rate: 1.0.into(),
+            volume: 1.0.into(),
+        }
+    }
+}
+
+/// Status of [`use_speech_synthesis`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SpeechSynthesisStatus {
+    /// Nothing has been spoken yet.
+    #[default]
+    Init,
+    /// The text is being read.
+    Play,
+    /// Reading has been paused.
+    Pause,
+    /// Reading has finished or has been cancelled.
+    End,
+}
+
+/// Return type of [`use_speech_synthesis`].
+pub struct UseSpeechSynthesisReturn<SpeakFn, PauseFn, ResumeFn, CancelFn>
+where
+    SpeakFn: Fn() + Clone,
+    PauseFn: Fn() + Clone,
+    ResumeFn: Fn() + Clone,
+    CancelFn: Fn() + Clone,
+{
+    /// `true` if the browser supports speech synthesis.
+    pub is_supported: Signal<bool>,
+
+    /// Wether the text is currently being read.
+    pub is_playing: Signal<bool>,
+
+    /// Status of the current utterance.
+    pub status: Signal<SpeechSynthesisStatus>,
+
+    /// The voices that are available in the browser.
+    pub voices: Signal<Vec<SpeechSynthesisVoice>>,
+
+    /// The utterance that has been created by the last call to `speak`.
+    pub utterance: Signal<Option<SpeechSynthesisUtterance>>,
+
+    /// The latest error that occurred.
+    pub error: Signal<Option<SpeechSynthesisErrorCode>>,
+
+    /// Starts reading the text.
+    pub speak: SpeakFn,
+
+    /// Pauses reading.
+    pub pause: PauseFn,
+
+    /// Resumes reading after it has been paused.
+    pub resume: ResumeFn,
+
+    /// Stops reading.
+    pub cancel: CancelFn,
+}
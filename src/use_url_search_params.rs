@@ -0,0 +1,488 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::use_event_listener;
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::{hashchange, popstate};
+use leptos::*;
+use std::collections::HashMap;
+use wasm_bindgen::JsValue;
+
+/// Query parameters of a URL. Every parameter can have multiple values.
+pub type UrlParams = HashMap<String, Vec<String>>;
+
+/// Reactive [URLSearchParams](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_url_search_params)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::use_url_search_params;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (params, set_params) = use_url_search_params();
+///
+/// let query = move || {
+///     params
+///         .get()
+///         .get("q")
+///         .and_then(|values| values.first().cloned())
+///         .unwrap_or_default()
+/// };
+///
+/// view! {
+///     <input
+///         prop:value=query
+///         on:input=move |e| {
+///             set_params.update(|params| {
+///                 params.insert("q".to_string(), vec![event_target_value(&e)]);
+///             })
+///         }
+///     />
+/// }
+/// # }
+/// ```
+///
+/// Changing the params updates the URL with `history.replaceState` so no new history entry is
+/// created. Changes that are made in the same tick are written to the URL at once. When the user
+/// navigates back or forward the params are updated from the URL.
+///
+/// If the URL doesn't contain any params the option `initial_value` is used. It is not written
+/// to the URL until the params are changed.
+///
+/// ## Modes
+///
+/// By default the params are read from and written to `location.search` like
+/// `/page?q=leptos`. With the option `mode` you can use the hash instead:
+///
+/// - `UrlSearchParamsMode::Hash`: the params after the `?` in the hash like `/#/page?q=leptos`.
+/// - `UrlSearchParamsMode::HashParams`: the whole hash like `/page#q=leptos`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_url_search_params_with_options, UrlSearchParamsMode, UrlWriteMode, UseUrlSearchParamsOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (params, set_params) = use_url_search_params_with_options(
+///     UseUrlSearchParamsOptions::default()
+///         .mode(UrlSearchParamsMode::Hash)
+///         .write_mode(UrlWriteMode::Push), // create a history entry for every change
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Multiple Values
+///
+/// A param with multiple values is written as repeated keys like `?tag=a&tag=b` by default.
+/// Set the option `array_encoding` to `UrlArrayEncoding::Comma` for `?tag=a,b` or to
+/// `UrlArrayEncoding::Brackets` for `?tag[]=a&tag[]=b`.
+///
+/// Empty values are removed from the URL. This can be disabled with `remove_empty_values`.
+/// With `remove_falsy_values` the values `"false"` and `"0"` are removed, too.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the params are always the `initial_value` and the URL is never changed.
+///
+/// ## See also
+///
+/// * [`use_location_hash`]
+pub fn use_url_search_params() -> (Signal<UrlParams>, WriteSignal<UrlParams>) {
+    use_url_search_params_with_options(UseUrlSearchParamsOptions::default())
+}
+
+/// Version of [`use_url_search_params`] that takes a `UseUrlSearchParamsOptions`. See [`use_url_search_params`] for how to use.
+pub fn use_url_search_params_with_options(
+    options: UseUrlSearchParamsOptions,
+) -> (Signal<UrlParams>, WriteSignal<UrlParams>) {
+    let UseUrlSearchParamsOptions {
+        mode,
+        initial_value,
+        array_encoding,
+        remove_empty_values,
+        remove_falsy_values,
+        write,
+        write_mode,
+    } = options;
+
+    let (params, set_params) = create_signal(initial_value);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let read = move || -> UrlParams {
+            let location = window().location();
+
+            let query = query_from_location(
+                mode,
+                &location.search().unwrap_or_default(),
+                &location.hash().unwrap_or_default(),
+            );
+
+            let pairs = web_sys::UrlSearchParams::new_with_str(&query)
+                .map(|search_params| {
+                    js_sys::Array::from(&search_params)
+                        .iter()
+                        .filter_map(|entry| {
+                            let entry = js_sys::Array::from(&entry);
+                            Some((entry.get(0).as_string()?, entry.get(1).as_string()?))
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+
+            decode_params(pairs, array_encoding)
+        };
+
+        let params_from_url = read();
+        if !params_from_url.is_empty() {
+            set_params.set(params_from_url);
+        }
+
+        // set while the params are updated from the URL so they aren't written back
+        let is_reading = store_value(false);
+
+        let update_from_url = move || {
+            is_reading.set_value(true);
+            set_params.set(read());
+            is_reading.set_value(false);
+        };
+
+        let _ = use_event_listener(window(), popstate, move |_| update_from_url());
+        let _ = use_event_listener(window(), hashchange, move |_| update_from_url());
+
+        if write {
+            let is_write_queued = store_value(false);
+
+            let write_to_url = move || {
+                is_write_queued.set_value(false);
+
+                let Ok(search_params) = web_sys::UrlSearchParams::new() else {
+                    return;
+                };
+
+                for (key, value) in encode_params(
+                    &params.get_untracked(),
+                    array_encoding,
+                    remove_empty_values,
+                    remove_falsy_values,
+                ) {
+                    search_params.append(&key, &value);
+                }
+
+                let query = String::from(search_params.to_string());
+
+                let location = window().location();
+                let pathname = location.pathname().unwrap_or_default();
+                let search = location.search().unwrap_or_default();
+                let hash = location.hash().unwrap_or_default();
+
+                let url = build_url(mode, &pathname, &search, &hash, &query);
+                if url == format!("{pathname}{search}{hash}") {
+                    return;
+                }
+
+                if let Ok(history) = window().history() {
+                    let _ = match write_mode {
+                        UrlWriteMode::Replace => {
+                            history.replace_state_with_url(&JsValue::NULL, "", Some(&url))
+                        }
+                        UrlWriteMode::Push => {
+                            history.push_state_with_url(&JsValue::NULL, "", Some(&url))
+                        }
+                    };
+                }
+            };
+
+            let _ = watch(
+                move || params.track(),
+                move |_, _, _| {
+                    if is_reading.get_value() || is_write_queued.get_value() {
+                        return;
+                    }
+
+                    is_write_queued.set_value(true);
+                    queue_microtask(write_to_url);
+                },
+                false,
+            );
+        }
+    }
+
+    (params.into(), set_params)
+}
+
+/// Returns the part of the URL that contains the params depending on `mode`.
+fn query_from_location(mode: UrlSearchParamsMode, search: &str, hash: &str) -> String {
+    match mode {
+        UrlSearchParamsMode::History => search.to_string(),
+        UrlSearchParamsMode::Hash => hash
+            .split_once('?')
+            .map(|(_, query)| query.to_string())
+            .unwrap_or_default(),
+        UrlSearchParamsMode::HashParams => hash.trim_start_matches('#').to_string(),
+    }
+}
+
+/// Builds the new URL (without origin) from the current location and the encoded `query`.
+fn build_url(
+    mode: UrlSearchParamsMode,
+    pathname: &str,
+    search: &str,
+    hash: &str,
+    query: &str,
+) -> String {
+    match mode {
+        UrlSearchParamsMode::History => {
+            if query.is_empty() {
+                format!("{pathname}{hash}")
+            } else {
+                format!("{pathname}?{query}{hash}")
+            }
+        }
+        UrlSearchParamsMode::Hash => {
+            let path = hash.split_once('?').map(|(path, _)| path).unwrap_or(hash);
+
+            if query.is_empty() {
+                format!("{pathname}{search}{path}")
+            } else if path.is_empty() {
+                format!("{pathname}{search}#?{query}")
+            } else {
+                format!("{pathname}{search}{path}?{query}")
+            }
+        }
+        UrlSearchParamsMode::HashParams => {
+            if query.is_empty() {
+                format!("{pathname}{search}")
+            } else {
+                format!("{pathname}{search}#{query}")
+            }
+        }
+    }
+}
+
+/// Groups the decoded key-value pairs of a URL by key.
+fn decode_params(pairs: Vec<(String, String)>, array_encoding: UrlArrayEncoding) -> UrlParams {
+    let mut params = UrlParams::new();
+
+    for (key, value) in pairs {
+        match array_encoding {
+            UrlArrayEncoding::Repeat => params.entry(key).or_default().push(value),
+            UrlArrayEncoding::Comma => params
+                .entry(key)
+                .or_default()
+                .extend(value.split(',').map(str::to_string)),
+            UrlArrayEncoding::Brackets => {
+                let key = key.strip_suffix("[]").map(str::to_string).unwrap_or(key);
+                params.entry(key).or_default().push(value);
+            }
+        }
+    }
+
+    params
+}
+
+/// Flattens the params into key-value pairs sorted by key.
+fn encode_params(
+    params: &UrlParams,
+    array_encoding: UrlArrayEncoding,
+    remove_empty_values: bool,
+    remove_falsy_values: bool,
+) -> Vec<(String, String)> {
+    let mut keys = params.keys().collect::<Vec<_>>();
+    keys.sort();
+
+    let mut pairs = vec![];
+
+    for key in keys {
+        let values = params[key]
+            .iter()
+            .filter(|value| !(remove_empty_values && value.is_empty()))
+            .filter(|value| !(remove_falsy_values && matches!(value.as_str(), "" | "false" | "0")))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if values.is_empty() {
+            continue;
+        }
+
+        match array_encoding {
+            UrlArrayEncoding::Repeat => {
+                pairs.extend(values.into_iter().map(|value| (key.clone(), value)))
+            }
+            UrlArrayEncoding::Comma => pairs.push((key.clone(), values.join(","))),
+            UrlArrayEncoding::Brackets if values.len() > 1 => {
+                pairs.extend(values.into_iter().map(|value| (format!("{key}[]"), value)))
+            }
+            UrlArrayEncoding::Brackets => {
+                pairs.extend(values.into_iter().map(|value| (key.clone(), value)))
+            }
+        }
+    }
+
+    pairs
+}
+
+/// Where the params are stored in the URL.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum UrlSearchParamsMode {
+    /// In `location.search` like `/page?q=leptos`.
+    #[default]
+    History,
+    /// After the `?` in the hash like `/#/page?q=leptos`.
+    Hash,
+    /// The whole hash like `/page#q=leptos`.
+    HashParams,
+}
+
+/// How params with multiple values are written to the URL.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum UrlArrayEncoding {
+    /// `?tag=a&tag=b`
+    #[default]
+    Repeat,
+    /// `?tag=a,b`
+    Comma,
+    /// `?tag[]=a&tag[]=b`. Params with a single value are written without brackets.
+    Brackets,
+}
+
+/// How the URL is updated when the params change.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum UrlWriteMode {
+    /// With `history.replaceState`. No history entry is created.
+    #[default]
+    Replace,
+    /// With `history.pushState`. Every change creates a history entry.
+    Push,
+}
+
+/// Options for [`use_url_search_params_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseUrlSearchParamsOptions {
+    /// Where the params are stored in the URL. Defaults to `UrlSearchParamsMode::History`.
+    mode: UrlSearchParamsMode,
+
+    /// The params that are used if the URL doesn't contain any. Defaults to no params.
+    initial_value: UrlParams,
+
+    /// How params with multiple values are written to the URL. Defaults to `UrlArrayEncoding::Repeat`.
+    array_encoding: UrlArrayEncoding,
+
+    /// Remove empty values from the URL. Defaults to `true`.
+    remove_empty_values: bool,
+
+    /// Also remove the values `"false"` and `"0"` from the URL. Defaults to `false`.
+    remove_falsy_values: bool,
+
+    /// Write changes of the params to the URL. If `false` the params are only read.
+    /// Defaults to `true`.
+    write: bool,
+
+    /// How the URL is updated. Defaults to `UrlWriteMode::Replace`.
+    write_mode: UrlWriteMode,
+}
+
+impl Default for UseUrlSearchParamsOptions {
+    fn default() -> Self {
+        Self {
+            mode: UrlSearchParamsMode::default(),
+            initial_value: UrlParams::new(),
+            array_encoding: UrlArrayEncoding::default(),
+            remove_empty_values: true,
+            remove_falsy_values: false,
+            write: true,
+            write_mode: UrlWriteMode::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_query_from_location() {
+        use UrlSearchParamsMode::*;
+
+        assert_eq!(query_from_location(History, "?q=1", "#/page?x=2"), "?q=1");
+        assert_eq!(query_from_location(Hash, "?q=1", "#/page?x=2"), "x=2");
+        assert_eq!(query_from_location(Hash, "?q=1", "#/page"), "");
+        assert_eq!(query_from_location(HashParams, "", "#x=2"), "x=2");
+    }
+
+    #[test]
+    fn test_build_url() {
+        use UrlSearchParamsMode::*;
+
+        assert_eq!(
+            build_url(History, "/page", "?old=1", "#top", "q=1"),
+            "/page?q=1#top"
+        );
+        assert_eq!(build_url(History, "/page", "?old=1", "", ""), "/page");
+        assert_eq!(
+            build_url(Hash, "/", "", "#/page?old=1", "q=1"),
+            "/#/page?q=1"
+        );
+        assert_eq!(build_url(Hash, "/", "", "", "q=1"), "/#?q=1");
+        assert_eq!(build_url(Hash, "/", "", "#/page?old=1", ""), "/#/page");
+        assert_eq!(
+            build_url(HashParams, "/page", "?a=1", "#old=1", "q=1"),
+            "/page?a=1#q=1"
+        );
+    }
+
+    #[test]
+    fn test_decode_params() {
+        let params = decode_params(
+            pairs(&[("tag", "a"), ("tag", "b"), ("q", "x")]),
+            UrlArrayEncoding::Repeat,
+        );
+        assert_eq!(params["tag"], vec!["a", "b"]);
+        assert_eq!(params["q"], vec!["x"]);
+
+        let params = decode_params(pairs(&[("tag", "a,b")]), UrlArrayEncoding::Comma);
+        assert_eq!(params["tag"], vec!["a", "b"]);
+
+        let params = decode_params(
+            pairs(&[("tag[]", "a"), ("tag[]", "b")]),
+            UrlArrayEncoding::Brackets,
+        );
+        assert_eq!(params["tag"], vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_encode_params() {
+        let params = UrlParams::from([
+            ("tag".to_string(), vec!["a".to_string(), "b".to_string()]),
+            ("q".to_string(), vec!["".to_string()]),
+            ("open".to_string(), vec!["false".to_string()]),
+        ]);
+
+        assert_eq!(
+            encode_params(&params, UrlArrayEncoding::Repeat, true, false),
+            pairs(&[("open", "false"), ("tag", "a"), ("tag", "b")])
+        );
+        assert_eq!(
+            encode_params(&params, UrlArrayEncoding::Comma, true, true),
+            pairs(&[("tag", "a,b")])
+        );
+        assert_eq!(
+            encode_params(&params, UrlArrayEncoding::Brackets, false, false),
+            pairs(&[("open", "false"), ("q", ""), ("tag[]", "a"), ("tag[]", "b")])
+        );
+    }
+}
@@ -0,0 +1,281 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::MaybeRwSignal;
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Reactive [`mediaDevices.getUserMedia`](https://developer.mozilla.org/en-US/docs/Web/API/MediaDevices/getUserMedia) streaming.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_user_media)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_user_media, UseUserMediaReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let video_ref = create_node_ref::<leptos::html::Video>();
+///
+/// let UseUserMediaReturn { stream, start, .. } = use_user_media();
+///
+/// start();
+///
+/// create_effect(move |_|
+///     video_ref.get().map(|v| {
+///         match stream.get() {
+///             Some(Ok(s)) => v.set_src_object(Some(&s)),
+///             Some(Err(e)) => logging::error!("Failed to get media stream: {:?}", e),
+///             None => logging::log!("No stream yet"),
+///         }
+///     })
+/// );
+///
+/// view! { <video node_ref=video_ref controls=false autoplay=true muted=true></video> }
+/// # }
+/// ```
+///
+/// ### Switching Devices
+///
+/// The constraints for audio and video can be signals. When they change while the stream is
+/// enabled, the stream is restarted with the new constraints. Use this for example together with
+/// [`use_devices_list`](crate::use_devices_list) to switch between cameras.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_user_media_with_options, MediaDeviceConstraint, UseUserMediaOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (camera, set_camera) = create_signal(MediaDeviceConstraint::Default);
+///
+/// let media = use_user_media_with_options(
+///     UseUserMediaOptions::default()
+///         .enabled(true)
+///         .audio(MediaDeviceConstraint::None)
+///         .video(camera),
+/// );
+///
+/// // later
+/// set_camera.set(MediaDeviceConstraint::DeviceId("<device id>".to_string()));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// The tracks of the stream are stopped when the component is cleaned up.
+///
+/// ## Server-Side Rendering
+///
+/// On the server calls to `start` or any other way to enable the stream will be ignored
+/// and the stream will always be `None`.
+pub fn use_user_media() -> UseUserMediaReturn<impl Fn() + Clone, impl Fn() + Clone> {
+    use_user_media_with_options(UseUserMediaOptions::default())
+}
+
+/// Version of [`use_user_media`] that accepts a [`UseUserMediaOptions`].
+pub fn use_user_media_with_options(
+    options: UseUserMediaOptions,
+) -> UseUserMediaReturn<impl Fn() + Clone, impl Fn() + Clone> {
+    let UseUserMediaOptions {
+        enabled,
+        audio,
+        video,
+    } = options;
+
+    let (enabled, set_enabled) = enabled.into_signal();
+    let audio = Signal::derive(move || audio.get());
+    let video = Signal::derive(move || video.get());
+
+    let (stream, set_stream) = create_signal(None::<Result<web_sys::MediaStream, JsValue>>);
+
+    let _start = move || async move {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if stream.get_untracked().is_some() {
+                return;
+            }
+
+            let stream = create_media(audio.get_untracked(), video.get_untracked()).await;
+
+            set_stream.update(|s| *s = Some(stream));
+        }
+    };
+
+    let _stop = move || {
+        if let Some(Ok(stream)) = stream.get_untracked() {
+            for track in stream.get_tracks() {
+                track.unchecked_ref::<web_sys::MediaStreamTrack>().stop();
+            }
+        }
+
+        set_stream.set(None);
+    };
+
+    let start = move || {
+        #[cfg(not(feature = "ssr"))]
+        spawn_local(async move {
+            _start().await;
+            stream.with_untracked(move |stream| {
+                if let Some(Ok(_)) = stream {
+                    set_enabled.set(true);
+                }
+            });
+        });
+    };
+
+    let stop = move || {
+        _stop();
+        set_enabled.set(false);
+    };
+
+    let _ = watch(
+        move || enabled.get(),
+        move |enabled, _, _| {
+            if *enabled {
+                spawn_local(async move {
+                    _start().await;
+                });
+            } else {
+                _stop();
+            }
+        },
+        true,
+    );
+
+    // restart the stream with the new constraints
+    let _ = watch(
+        move || (audio.get(), video.get()),
+        move |_, _, _| {
+            if enabled.get_untracked() {
+                _stop();
+                spawn_local(async move {
+                    _start().await;
+                });
+            }
+        },
+        false,
+    );
+
+    on_cleanup(_stop);
+
+    UseUserMediaReturn {
+        stream: stream.into(),
+        start,
+        stop,
+        enabled,
+        set_enabled,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn create_media(
+    audio: MediaDeviceConstraint,
+    video: MediaDeviceConstraint,
+) -> Result<web_sys::MediaStream, JsValue> {
+    use crate::js_fut;
+    use crate::use_window::use_window;
+
+    let media = use_window()
+        .navigator()
+        .ok_or_else(|| JsValue::from_str("Failed to access window.navigator"))
+        .and_then(|n| n.media_devices())?;
+
+    let mut constraints = web_sys::MediaStreamConstraints::new();
+    constraints
+        .audio(&audio.to_js_value())
+        .video(&video.to_js_value());
+
+    let promise = media.get_user_media_with_constraints(&constraints)?;
+    let res = js_fut!(promise).await?;
+
+    Ok::<_, JsValue>(web_sys::MediaStream::unchecked_from_js(res))
+}
+
+/// Which device to use for the audio or the video track of [`use_user_media`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum MediaDeviceConstraint {
+    /// The stream doesn't contain this kind of track.
+    None,
+    /// The browser picks the device.
+    #[default]
+    Default,
+    /// The device with this id as reported by [`use_devices_list`](crate::use_devices_list).
+    DeviceId(String),
+}
+
+impl MediaDeviceConstraint {
+    #[cfg(not(feature = "ssr"))]
+    fn to_js_value(&self) -> JsValue {
+        match self {
+            Self::None => JsValue::FALSE,
+            Self::Default => JsValue::TRUE,
+            Self::DeviceId(device_id) => {
+                let exact = js_sys::Object::new();
+                crate::js!(exact["exact"] = device_id);
+
+                let constraint = js_sys::Object::new();
+                crate::js!(constraint["deviceId"] = exact);
+
+                constraint.into()
+            }
+        }
+    }
+}
+
+/// Options for [`use_user_media`].
+#[derive(DefaultBuilder, Clone, Debug)]
+pub struct UseUserMediaOptions {
+    /// If the stream is enabled. Defaults to `false`.
+    #[builder(into)]
+    enabled: MaybeRwSignal<bool>,
+
+    /// The microphone to use. Defaults to `MediaDeviceConstraint::Default`.
+    #[builder(into)]
+    audio: MaybeSignal<MediaDeviceConstraint>,
+
+    /// The camera to use. Defaults to `MediaDeviceConstraint::Default`.
+    #[builder(into)]
+    video: MaybeSignal<MediaDeviceConstraint>,
+}
+
+impl Default for UseUserMediaOptions {
+    fn default() -> Self {
+        Self {
+            enabled: false.into(),
+            audio: MediaDeviceConstraint::Default.into(),
+            video: MediaDeviceConstraint::Default.into(),
+        }
+    }
+}
+
+/// Return type of [`use_user_media`]
+#[derive(Clone)]
+pub struct UseUserMediaReturn<StartFn, StopFn>
+where
+    StartFn: Fn() + Clone,
+    StopFn: Fn() + Clone,
+{
+    /// The current [`MediaStream`](https://developer.mozilla.org/en-US/docs/Web/API/MediaStream) if it exists.
+    /// Initially this is `None` until `start` resolved successfully.
+    /// In case the stream couldn't be started, for example because the user didn't grant permission,
+    /// this has the value `Some(Err(...))`.
+    pub stream: Signal<Option<Result<web_sys::MediaStream, JsValue>>>,
+
+    /// Starts the streaming. Triggers the ask for permission if not already granted.
+    pub start: StartFn,
+
+    /// Stops the streaming and all of its tracks.
+    pub stop: StopFn,
+
+    /// A value of `true` indicates that the returned [`MediaStream`](https://developer.mozilla.org/en-US/docs/Web/API/MediaStream)
+    /// has resolved successfully and thus the stream is enabled.
+    pub enabled: Signal<bool>,
+
+    /// A value of `true` is the same as calling `start()` whereas `false` is the same as calling `stop()`.
+    pub set_enabled: WriteSignal<bool>,
+}
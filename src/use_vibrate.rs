@@ -0,0 +1,160 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::utils::Pausable;
+use crate::{js, use_interval_fn_with_options, use_supported, UseIntervalFnOptions};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+
+/// Reactive [Vibration API](https://developer.mozilla.org/en-US/docs/Web/API/Vibration_API).
+///
+/// Most modern mobile devices include vibration hardware, which lets software code provide
+/// physical feedback to the user by causing the device to shake. Desktop browsers usually
+/// don't support it.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_vibrate)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_vibrate, UseVibrateReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// // Vibrate for 300 ms, pause for 100 ms and vibrate for 300 ms again
+/// let UseVibrateReturn {
+///     is_supported,
+///     vibrate,
+///     stop,
+///     ..
+/// } = use_vibrate(vec![300, 100, 300]);
+///
+/// view! {
+///     <button on:click=move |_| vibrate()>"Vibrate"</button>
+///     <button on:click=move |_| stop()>"Stop"</button>
+/// }
+/// # }
+/// ```
+///
+/// The pattern can also be a signal. To repeat the pattern until `stop` is called, set an
+/// `interval` in milliseconds.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_vibrate_with_options, UseVibrateOptions};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (pattern, set_pattern) = create_signal(vec![200, 100]);
+///
+/// let vibrate = use_vibrate_with_options(pattern, UseVibrateOptions::default().interval(1000));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is always `false` and the functions do nothing.
+pub fn use_vibrate<P>(pattern: P) -> UseVibrateReturn<impl Fn() + Clone, impl Fn() + Clone>
+where
+    P: Into<MaybeSignal<Vec<u32>>>,
+{
+    use_vibrate_with_options(pattern, UseVibrateOptions::default())
+}
+
+/// Version of [`use_vibrate`] that takes a `UseVibrateOptions`. See [`use_vibrate`] for how to use.
+pub fn use_vibrate_with_options<P>(
+    pattern: P,
+    options: UseVibrateOptions,
+) -> UseVibrateReturn<impl Fn() + Clone, impl Fn() + Clone>
+where
+    P: Into<MaybeSignal<Vec<u32>>>,
+{
+    let UseVibrateOptions { interval } = options;
+
+    let pattern: MaybeSignal<Vec<u32>> = pattern.into();
+    let pattern = Signal::derive(move || pattern.get());
+
+    let is_supported = use_supported(|| js!("vibrate" in &window().navigator()));
+
+    let vibrate_once = move || {
+        #[cfg(not(feature = "ssr"))]
+        if is_supported.get_untracked() {
+            let pattern = pattern
+                .get_untracked()
+                .into_iter()
+                .map(wasm_bindgen::JsValue::from)
+                .collect::<js_sys::Array>();
+
+            let _ = window().navigator().vibrate_with_pattern(&pattern);
+        }
+    };
+
+    let Pausable {
+        is_active,
+        pause,
+        resume,
+    } = use_interval_fn_with_options(
+        vibrate_once,
+        interval,
+        UseIntervalFnOptions::default().immediate(false),
+    );
+
+    let vibrate = move || {
+        vibrate_once();
+
+        if interval > 0 {
+            resume();
+        }
+    };
+
+    let stop = move || {
+        pause();
+
+        #[cfg(not(feature = "ssr"))]
+        if is_supported.get_untracked() {
+            let _ = window().navigator().vibrate_with_duration(0);
+        }
+    };
+
+    UseVibrateReturn {
+        is_supported,
+        pattern,
+        is_active,
+        vibrate,
+        stop,
+    }
+}
+
+/// Options for [`use_vibrate_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseVibrateOptions {
+    /// If greater than `0` the pattern is repeated every `interval` milliseconds until `stop`
+    /// is called. Defaults to `0`.
+    interval: u64,
+}
+
+/// Return type of [`use_vibrate`].
+pub struct UseVibrateReturn<VibrateFn, StopFn>
+where
+    VibrateFn: Fn() + Clone,
+    StopFn: Fn() + Clone,
+{
+    /// `true` if the device supports the Vibration API.
+    pub is_supported: Signal<bool>,
+
+    /// The vibration pattern. Alternating durations of vibration and pause in milliseconds.
+    pub pattern: Signal<Vec<u32>>,
+
+    /// Wether the pattern is currently repeated. Only relevant with `interval` set.
+    pub is_active: Signal<bool>,
+
+    /// Starts the vibration.
+    pub vibrate: VibrateFn,
+
+    /// Stops the vibration.
+    pub stop: StopFn,
+}
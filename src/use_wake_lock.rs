@@ -0,0 +1,185 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut, use_document_visibility, use_event_listener, use_supported};
+use leptos::ev::Custom;
+use leptos::*;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Reactive [Screen Wake Lock API](https://developer.mozilla.org/en-US/docs/Web/API/Screen_Wake_Lock_API).
+///
+/// Prevents the screen from dimming or locking. The browser releases the wake lock when the
+/// page is hidden. This function requests it again automatically when the page becomes
+/// visible as long as `release` hasn't been called.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_wake_lock)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_wake_lock, UseWakeLockReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWakeLockReturn {
+///     is_supported,
+///     is_active,
+///     request,
+///     release,
+///     ..
+/// } = use_wake_lock();
+///
+/// view! {
+///     <button on:click=move |_| request()>"Keep screen on"</button>
+///     <button on:click=move |_| release()>"Allow screen to turn off"</button>
+/// }
+/// # }
+/// ```
+///
+/// If `request` is called while the page is hidden, the wake lock is requested as soon as
+/// the page becomes visible.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_active` are always `false` and the functions do nothing.
+pub fn use_wake_lock() -> UseWakeLockReturn<impl Fn() + Clone, impl Fn() + Clone> {
+    let is_supported = use_supported(|| js!("wakeLock" in &window().navigator()));
+
+    let (sentinel, set_sentinel) = create_signal(None::<web_sys::EventTarget>);
+    let (is_active, set_active) = create_signal(false);
+    let (error, set_error) = create_signal(None::<UseWakeLockError>);
+
+    let requested = store_value(false);
+
+    let document_visibility = use_document_visibility();
+
+    let acquire = move || {
+        #[cfg(not(feature = "ssr"))]
+        spawn_local(async move {
+            match request_wake_lock().await {
+                Ok(new_sentinel) => {
+                    // `release` was called while the request was pending
+                    if !requested.get_value() {
+                        let _ = release_wake_lock(&new_sentinel).await;
+                        return;
+                    }
+
+                    set_sentinel.set(Some(new_sentinel));
+                    set_active.set(true);
+                    set_error.set(None);
+                }
+                Err(err) => set_error.set(Some(UseWakeLockError::Request(err))),
+            }
+        });
+    };
+
+    let _ = use_event_listener(sentinel, Custom::<ev::Event>::new("release"), move |_| {
+        set_active.set(false);
+        set_sentinel.set(None);
+    });
+
+    let _ = watch(
+        move || document_visibility.get(),
+        move |visibility, _, _| {
+            if *visibility == web_sys::VisibilityState::Visible
+                && requested.get_value()
+                && !is_active.get_untracked()
+            {
+                acquire();
+            }
+        },
+        false,
+    );
+
+    let request = move || {
+        if !is_supported.get_untracked() || requested.get_value() {
+            return;
+        }
+
+        requested.set_value(true);
+
+        if document_visibility.get_untracked() == web_sys::VisibilityState::Visible {
+            acquire();
+        }
+    };
+
+    let release = move || {
+        requested.set_value(false);
+
+        #[cfg(not(feature = "ssr"))]
+        if let Some(sentinel) = sentinel.get_untracked() {
+            spawn_local(async move {
+                if let Err(err) = release_wake_lock(&sentinel).await {
+                    set_error.set(Some(UseWakeLockError::Release(err)));
+                }
+            });
+        }
+    };
+
+    on_cleanup(release);
+
+    UseWakeLockReturn {
+        is_supported,
+        is_active: is_active.into(),
+        error: error.into(),
+        request,
+        release,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn request_wake_lock() -> Result<web_sys::EventTarget, JsValue> {
+    // The Screen Wake Lock API is only available with `web_sys_unstable_apis`
+    let wake_lock = js_sys::Reflect::get(&window().navigator(), &"wakeLock".into())?;
+
+    let promise = js_sys::Reflect::get(&wake_lock, &"request".into())?
+        .dyn_into::<js_sys::Function>()?
+        .call1(&wake_lock, &"screen".into())?
+        .dyn_into::<js_sys::Promise>()?;
+
+    Ok(js_fut!(promise).await?.unchecked_into())
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn release_wake_lock(sentinel: &web_sys::EventTarget) -> Result<(), JsValue> {
+    let promise = js_sys::Reflect::get(sentinel, &"release".into())?
+        .dyn_into::<js_sys::Function>()?
+        .call0(sentinel)?
+        .dyn_into::<js_sys::Promise>()?;
+
+    js_fut!(promise).await.map(|_| ())
+}
+
+/// Return type of [`use_wake_lock`].
+pub struct UseWakeLockReturn<RequestFn, ReleaseFn>
+where
+    RequestFn: Fn() + Clone,
+    ReleaseFn: Fn() + Clone,
+{
+    /// `true` if the browser supports the Screen Wake Lock API.
+    pub is_supported: Signal<bool>,
+
+    /// Wether the wake lock is currently held.
+    pub is_active: Signal<bool>,
+
+    /// Latest error that occurred while requesting or releasing the wake lock.
+    pub error: Signal<Option<UseWakeLockError>>,
+
+    /// Requests the wake lock. It is requested again whenever the page becomes visible
+    /// until `release` is called.
+    pub request: RequestFn,
+
+    /// Releases the wake lock.
+    pub release: ReleaseFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseWakeLockError {
+    #[error("failed to request wake lock")]
+    Request(JsValue),
+    #[error("failed to release wake lock")]
+    Release(JsValue),
+}
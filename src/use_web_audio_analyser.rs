@@ -0,0 +1,435 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, use_raf_fn_with_options, use_supported, UseRafFnOptions};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+use web_sys::{AnalyserNode, AudioContext, AudioNode, HtmlMediaElement, MediaStream};
+
+/// Analyses audio with the [Web Audio API](https://developer.mozilla.org/en-US/docs/Web/API/AnalyserNode)
+/// to build visualizers or VU meters.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_web_audio_analyser)
+///
+/// ## Usage
+///
+/// The source can be a `MediaStream`, for example the microphone from [`fn@crate::use_user_media`].
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_user_media, use_web_audio_analyser, UseUserMediaReturn, UseWebAudioAnalyserReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseUserMediaReturn { stream, start, .. } = use_user_media();
+///
+/// let UseWebAudioAnalyserReturn { volume, .. } =
+///     use_web_audio_analyser(Signal::derive(move || stream.get().into()));
+///
+/// view! {
+///     <button on:click=move |_| start()>"Start microphone"</button>
+///     <meter min="0" max="1" value=volume></meter>
+/// }
+/// # }
+/// ```
+///
+/// Or it can be an `<audio>` or `<video>` element.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos::html::Audio;
+/// # use leptos_use::{use_web_audio_analyser_with_options, UseWebAudioAnalyserOptions, UseWebAudioAnalyserReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let audio = create_node_ref::<Audio>();
+///
+/// let UseWebAudioAnalyserReturn { frequency_data, .. } = use_web_audio_analyser_with_options(
+///     Signal::derive(move || audio.get().map(|audio| (*audio).clone()).into()),
+///     UseWebAudioAnalyserOptions::default().fft_size(64),
+/// );
+///
+/// view! {
+///     <audio node_ref=audio src="https://example.com/song.mp3" controls></audio>
+///     <div style="display: flex; align-items: end; height: 256px">
+///         {move || frequency_data.get().into_iter().map(|value| view! {
+///             <div style=format!("width: 4px; height: {value}px; background: teal")></div>
+///         }).collect_view()}
+///     </div>
+/// }
+/// # }
+/// ```
+///
+/// The data is sampled on every animation frame. `frequency_data` contains `fft_size / 2`
+/// values and `time_domain_data` contains `fft_size` values between `0` and `255`. `volume` is
+/// the loudness between `0.0` and `1.0` computed from the time domain data.
+///
+/// The audio of an element is still played on the speakers while it's analysed. The audio of a
+/// stream is not, so you don't hear yourself when analysing the microphone.
+///
+/// Browsers only allow audio processing after the user has interacted with the page. The
+/// sampling can be paused with `pause` and started again with `resume`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_active` are always `false`, the data is always empty
+/// and the functions do nothing.
+pub fn use_web_audio_analyser<S>(
+    source: S,
+) -> UseWebAudioAnalyserReturn<impl Fn() + Clone, impl Fn() + Clone>
+where
+    S: Into<MaybeSignal<AudioAnalyserSource>>,
+{
+    use_web_audio_analyser_with_options(source, UseWebAudioAnalyserOptions::default())
+}
+
+/// Version of [`use_web_audio_analyser`] that takes a `UseWebAudioAnalyserOptions`. See [`use_web_audio_analyser`] for how to use.
+pub fn use_web_audio_analyser_with_options<S>(
+    source: S,
+    options: UseWebAudioAnalyserOptions,
+) -> UseWebAudioAnalyserReturn<impl Fn() + Clone, impl Fn() + Clone>
+where
+    S: Into<MaybeSignal<AudioAnalyserSource>>,
+{
+    let UseWebAudioAnalyserOptions {
+        fft_size,
+        smoothing_time_constant,
+        min_decibels,
+        max_decibels,
+        immediate,
+    } = options;
+
+    let source = source.into();
+
+    let is_supported = use_supported(|| js!("AudioContext" in &window()));
+
+    let (analyser, set_analyser) = create_signal(None::<AnalyserNode>);
+    let (frequency_data, set_frequency_data) = create_signal(Vec::<u8>::new());
+    let (time_domain_data, set_time_domain_data) = create_signal(Vec::<u8>::new());
+    let (volume, set_volume) = create_signal(0.0);
+    let (error, set_error) = create_signal(None::<UseWebAudioAnalyserError>);
+
+    let context = store_value(None::<AudioContext>);
+    let connected_node = store_value(None::<AudioNode>);
+
+    let resume_context = move || {
+        if let Some(context) = context.get_value() {
+            let _ = context.resume();
+        }
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        // An element can only be turned into a source node once so they are kept around
+        let element_nodes = store_value(Vec::<(HtmlMediaElement, AudioNode)>::new());
+
+        let ensure_context = move || -> Result<(AudioContext, AnalyserNode), JsValue> {
+            if let (Some(context), Some(analyser)) = (context.get_value(), analyser.get_untracked())
+            {
+                return Ok((context, analyser));
+            }
+
+            let new_context = AudioContext::new()?;
+            let new_analyser = new_context.create_analyser()?;
+            new_analyser.set_fft_size(fft_size);
+            new_analyser.set_smoothing_time_constant(smoothing_time_constant);
+            new_analyser.set_min_decibels(min_decibels);
+            new_analyser.set_max_decibels(max_decibels);
+
+            context.set_value(Some(new_context.clone()));
+            set_analyser.set(Some(new_analyser.clone()));
+
+            Ok((new_context, new_analyser))
+        };
+
+        let disconnect = move || {
+            if let (Some(node), Some(analyser)) =
+                (connected_node.get_value(), analyser.get_untracked())
+            {
+                let _ = node.disconnect_with_audio_node(&analyser);
+            }
+            connected_node.set_value(None);
+        };
+
+        let connect = move |source: &AudioAnalyserSource| -> Result<(), UseWebAudioAnalyserError> {
+            if *source == AudioAnalyserSource::None {
+                return Ok(());
+            }
+
+            let (context, analyser) =
+                ensure_context().map_err(UseWebAudioAnalyserError::Context)?;
+
+            let node = match source {
+                AudioAnalyserSource::None => return Ok(()),
+                AudioAnalyserSource::Stream(stream) => context
+                    .create_media_stream_source(stream)
+                    .map_err(UseWebAudioAnalyserError::Source)?
+                    .into(),
+                AudioAnalyserSource::Element(element) => {
+                    let existing = element_nodes.with_value(|nodes| {
+                        nodes
+                            .iter()
+                            .find(|(el, _)| el == element)
+                            .map(|(_, node)| node.clone())
+                    });
+
+                    match existing {
+                        Some(node) => node,
+                        None => {
+                            let node: AudioNode = context
+                                .create_media_element_source(element)
+                                .map_err(UseWebAudioAnalyserError::Source)?
+                                .into();
+
+                            // keep playing the element on the speakers
+                            let _ = node.connect_with_audio_node(&context.destination());
+
+                            element_nodes
+                                .update_value(|nodes| nodes.push((element.clone(), node.clone())));
+
+                            node
+                        }
+                    }
+                }
+            };
+
+            node.connect_with_audio_node(&analyser)
+                .map_err(UseWebAudioAnalyserError::Source)?;
+            connected_node.set_value(Some(node));
+
+            let _ = context.resume();
+
+            Ok(())
+        };
+
+        let _ = watch(
+            move || source.get(),
+            move |source, _, _| {
+                disconnect();
+                set_frequency_data.set(Vec::new());
+                set_time_domain_data.set(Vec::new());
+                set_volume.set(0.0);
+
+                if !is_supported.get_untracked() {
+                    return;
+                }
+
+                match connect(source) {
+                    Ok(_) => set_error.set(None),
+                    Err(err) => set_error.set(Some(err)),
+                }
+            },
+            true,
+        );
+
+        on_cleanup(move || {
+            disconnect();
+
+            if let Some(context) = context.get_value() {
+                let _ = context.close();
+            }
+        });
+    }
+
+    let pausable = use_raf_fn_with_options(
+        move |_| {
+            #[cfg(not(feature = "ssr"))]
+            {
+                if connected_node.with_value(Option::is_none) {
+                    return;
+                }
+
+                let Some(analyser) = analyser.get_untracked() else {
+                    return;
+                };
+
+                let mut frequencies = vec![0; analyser.frequency_bin_count() as usize];
+                analyser.get_byte_frequency_data(&mut frequencies);
+
+                let mut samples = vec![0; analyser.fft_size() as usize];
+                analyser.get_byte_time_domain_data(&mut samples);
+
+                set_volume.set(volume_of(&samples));
+                set_frequency_data.set(frequencies);
+                set_time_domain_data.set(samples);
+            }
+        },
+        UseRafFnOptions::default().immediate(immediate),
+    );
+
+    let resume = {
+        let resume_raf = pausable.resume;
+
+        move || {
+            resume_context();
+            resume_raf();
+        }
+    };
+
+    UseWebAudioAnalyserReturn {
+        is_supported,
+        analyser: analyser.into(),
+        frequency_data: frequency_data.into(),
+        time_domain_data: time_domain_data.into(),
+        volume: volume.into(),
+        error: error.into(),
+        is_active: pausable.is_active,
+        pause: pausable.pause,
+        resume,
+    }
+}
+
+/// Root mean square of the time domain samples which are centered around `128`.
+fn volume_of(samples: &[u8]) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+
+    let sum = samples
+        .iter()
+        .map(|sample| {
+            let value = (*sample as f64 - 128.0) / 128.0;
+            value * value
+        })
+        .sum::<f64>();
+
+    (sum / samples.len() as f64).sqrt().min(1.0)
+}
+
+/// The audio that is analysed by [`use_web_audio_analyser`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum AudioAnalyserSource {
+    /// Nothing is analysed.
+    #[default]
+    None,
+    /// A stream like the microphone from [`fn@crate::use_user_media`].
+    Stream(MediaStream),
+    /// An `<audio>` or `<video>` element.
+    Element(HtmlMediaElement),
+}
+
+impl From<MediaStream> for AudioAnalyserSource {
+    fn from(stream: MediaStream) -> Self {
+        Self::Stream(stream)
+    }
+}
+
+impl From<HtmlMediaElement> for AudioAnalyserSource {
+    fn from(element: HtmlMediaElement) -> Self {
+        Self::Element(element)
+    }
+}
+
+impl From<web_sys::HtmlAudioElement> for AudioAnalyserSource {
+    fn from(element: web_sys::HtmlAudioElement) -> Self {
+        Self::Element(element.into())
+    }
+}
+
+impl From<web_sys::HtmlVideoElement> for AudioAnalyserSource {
+    fn from(element: web_sys::HtmlVideoElement) -> Self {
+        Self::Element(element.into())
+    }
+}
+
+impl<T: Into<AudioAnalyserSource>> From<Option<T>> for AudioAnalyserSource {
+    fn from(source: Option<T>) -> Self {
+        source.map(Into::into).unwrap_or_default()
+    }
+}
+
+impl<T: Into<AudioAnalyserSource>, E> From<Result<T, E>> for AudioAnalyserSource {
+    fn from(source: Result<T, E>) -> Self {
+        source.map(Into::into).unwrap_or_default()
+    }
+}
+
+/// Options for [`use_web_audio_analyser_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseWebAudioAnalyserOptions {
+    /// Size of the FFT. Has to be a power of two between `32` and `32768`. Defaults to `2048`.
+    fft_size: u32,
+
+    /// How much the frequency data is averaged over time between `0.0` and `1.0`.
+    /// Defaults to `0.8`.
+    smoothing_time_constant: f64,
+
+    /// The decibels that are mapped to `0` in the frequency data. Defaults to `-100.0`.
+    min_decibels: f64,
+
+    /// The decibels that are mapped to `255` in the frequency data. Defaults to `-30.0`.
+    max_decibels: f64,
+
+    /// If `true` the sampling starts right away. Defaults to `true`.
+    immediate: bool,
+}
+
+impl Default for UseWebAudioAnalyserOptions {
+    fn default() -> Self {
+        Self {
+            fft_size: 2048,
+            smoothing_time_constant: 0.8,
+            min_decibels: -100.0,
+            max_decibels: -30.0,
+            immediate: true,
+        }
+    }
+}
+
+/// Return type of [`use_web_audio_analyser`].
+pub struct UseWebAudioAnalyserReturn<PauseFn, ResumeFn>
+where
+    PauseFn: Fn() + Clone,
+    ResumeFn: Fn() + Clone,
+{
+    /// `true` if the browser supports the Web Audio API.
+    pub is_supported: Signal<bool>,
+
+    /// The `AnalyserNode` once it has been created.
+    pub analyser: Signal<Option<AnalyserNode>>,
+
+    /// Latest frequency data with `fft_size / 2` values between `0` and `255`.
+    pub frequency_data: Signal<Vec<u8>>,
+
+    /// Latest waveform with `fft_size` values between `0` and `255`.
+    pub time_domain_data: Signal<Vec<u8>>,
+
+    /// Latest loudness between `0.0` and `1.0`.
+    pub volume: Signal<f64>,
+
+    /// Latest error that occurred while connecting the source.
+    pub error: Signal<Option<UseWebAudioAnalyserError>>,
+
+    /// Wether the data is sampled.
+    pub is_active: Signal<bool>,
+
+    /// Pauses the sampling.
+    pub pause: PauseFn,
+
+    /// Resumes the sampling.
+    pub resume: ResumeFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseWebAudioAnalyserError {
+    #[error("failed to create the audio context")]
+    Context(JsValue),
+    #[error("failed to connect the audio source")]
+    Source(JsValue),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_volume_of() {
+        assert_eq!(volume_of(&[]), 0.0);
+        assert_eq!(volume_of(&[128, 128, 128]), 0.0);
+        assert_eq!(volume_of(&[0, 0]), 1.0);
+        assert!((volume_of(&[0, 128]) - 0.5_f64.sqrt()).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,493 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut, use_event_listener, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::Custom;
+use leptos::*;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{BluetoothDevice, BluetoothRemoteGattCharacteristic, BluetoothRemoteGattServer};
+
+/// Reactive [Web Bluetooth API](https://developer.mozilla.org/en-US/docs/Web/API/Web_Bluetooth_API).
+///
+/// Lets the user pick a Bluetooth Low Energy device and connects to its GATT server.
+/// The connection is closed automatically when the component is cleaned up.
+///
+/// > This function requires `--cfg=web_sys_unstable_apis` to be activated as
+/// > [described in the wasm-bindgen guide](https://rustwasm.github.io/docs/wasm-bindgen/web-sys/unstable-apis.html).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_web_bluetooth)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_web_bluetooth_with_options, BluetoothFilter, UseWebBluetoothOptions, UseWebBluetoothReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebBluetoothReturn {
+///     is_supported,
+///     device,
+///     is_connected,
+///     request_device,
+///     disconnect,
+///     ..
+/// } = use_web_bluetooth_with_options(
+///     UseWebBluetoothOptions::default()
+///         .filters(vec![BluetoothFilter::default().services(vec!["heart_rate".to_string()])]),
+/// );
+///
+/// view! {
+///     <button on:click=move |_| request_device()>"Connect"</button>
+///     <button on:click=move |_| disconnect()>"Disconnect"</button>
+///     <p>{move || device.get().and_then(|device| device.name())}</p>
+/// }
+/// # }
+/// ```
+///
+/// `request_device` has to be called in response to a user action like a click. It connects
+/// to the selected device right away.
+///
+/// ### Characteristics
+///
+/// Use [`use_web_bluetooth_characteristic`] to read, write and get notified about changes of
+/// a characteristic of the connected device.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_web_bluetooth, use_web_bluetooth_characteristic, UseWebBluetoothReturn, UseWebBluetoothCharacteristicReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebBluetoothReturn { server, .. } = use_web_bluetooth();
+///
+/// let UseWebBluetoothCharacteristicReturn {
+///     value,
+///     start_notifications,
+///     ..
+/// } = use_web_bluetooth_characteristic(server, "heart_rate", "heart_rate_measurement");
+///
+/// let heart_rate = move || value.get().and_then(|value| value.get(1).copied());
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_connected` are always `false` and the functions
+/// do nothing.
+pub fn use_web_bluetooth(
+) -> UseWebBluetoothReturn<impl Fn() + Clone, impl Fn() + Clone, impl Fn() + Clone> {
+    use_web_bluetooth_with_options(UseWebBluetoothOptions::default())
+}
+
+/// Version of [`use_web_bluetooth`] that takes a `UseWebBluetoothOptions`. See [`use_web_bluetooth`] for how to use.
+pub fn use_web_bluetooth_with_options(
+    options: UseWebBluetoothOptions,
+) -> UseWebBluetoothReturn<impl Fn() + Clone, impl Fn() + Clone, impl Fn() + Clone> {
+    let UseWebBluetoothOptions {
+        accept_all_devices,
+        filters,
+        optional_services,
+    } = options;
+
+    let is_supported = use_supported(|| js!("bluetooth" in &window().navigator()));
+
+    let (device, set_device) = create_signal(None::<BluetoothDevice>);
+    let (server, set_server) = create_signal(None::<BluetoothRemoteGattServer>);
+    let (is_connected, set_connected) = create_signal(false);
+    let (error, set_error) = create_signal(None::<UseWebBluetoothError>);
+
+    let _ = use_event_listener(
+        device,
+        Custom::<ev::Event>::new("gattserverdisconnected"),
+        move |_| set_connected.set(false),
+    );
+
+    let connect = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(gatt) = device.get_untracked().and_then(|device| device.gatt()) else {
+                set_error.set(Some(UseWebBluetoothError::NoGattServer));
+                return;
+            };
+
+            spawn_local(async move {
+                match js_fut!(gatt.connect()).await {
+                    Ok(connected_server) => {
+                        set_server.set(Some(connected_server.unchecked_into()));
+                        set_connected.set(true);
+                        set_error.set(None);
+                    }
+                    Err(err) => set_error.set(Some(UseWebBluetoothError::Connect(err))),
+                }
+            });
+        }
+    };
+
+    let request_device = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(bluetooth) = window().navigator().bluetooth() else {
+                return;
+            };
+
+            let mut request_options = web_sys::RequestDeviceOptions::new();
+            if accept_all_devices {
+                request_options.accept_all_devices(true);
+            } else {
+                request_options.filters(
+                    &filters
+                        .iter()
+                        .map(BluetoothFilter::to_scan_filter)
+                        .collect::<js_sys::Array>(),
+                );
+            }
+            if !optional_services.is_empty() {
+                request_options.optional_services(
+                    &optional_services
+                        .iter()
+                        .map(|service| JsValue::from_str(service))
+                        .collect::<js_sys::Array>(),
+                );
+            }
+
+            spawn_local(async move {
+                match js_fut!(bluetooth.request_device(&request_options)).await {
+                    Ok(new_device) => {
+                        set_device.set(Some(new_device.unchecked_into()));
+                        set_error.set(None);
+                        connect();
+                    }
+                    Err(err) => set_error.set(Some(UseWebBluetoothError::RequestDevice(err))),
+                }
+            });
+        }
+    };
+
+    let disconnect = move || {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(server) = server.get_untracked() {
+            if server.connected() {
+                server.disconnect();
+            }
+        }
+
+        set_connected.set(false);
+    };
+
+    on_cleanup(disconnect);
+
+    UseWebBluetoothReturn {
+        is_supported,
+        device: device.into(),
+        server: server.into(),
+        is_connected: is_connected.into(),
+        error: error.into(),
+        request_device,
+        connect,
+        disconnect,
+    }
+}
+
+/// Reactive characteristic of a connected Bluetooth device. See [`use_web_bluetooth`] for how to use.
+///
+/// The characteristic is looked up whenever `server` changes. Notifications are stopped
+/// automatically when the component is cleaned up.
+pub fn use_web_bluetooth_characteristic(
+    server: Signal<Option<BluetoothRemoteGattServer>>,
+    service: &str,
+    characteristic: &str,
+) -> UseWebBluetoothCharacteristicReturn<
+    impl Fn() + Clone,
+    impl Fn(Vec<u8>) + Clone,
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+> {
+    let (gatt_characteristic, set_gatt_characteristic) =
+        create_signal(None::<BluetoothRemoteGattCharacteristic>);
+    let (value, set_value) = create_signal(None::<Vec<u8>>);
+    let (is_notifying, set_notifying) = create_signal(false);
+    let (error, set_error) = create_signal(None::<UseWebBluetoothError>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let service = service.to_string();
+        let characteristic = characteristic.to_string();
+
+        let _ = watch(
+            move || server.get(),
+            move |server, _, _| {
+                set_gatt_characteristic.set(None);
+                set_notifying.set(false);
+
+                let Some(server) = server.clone() else {
+                    return;
+                };
+
+                let service = service.clone();
+                let characteristic = characteristic.clone();
+
+                spawn_local(async move {
+                    match get_characteristic(&server, &service, &characteristic).await {
+                        Ok(found) => {
+                            set_gatt_characteristic.set(Some(found));
+                            set_error.set(None);
+                        }
+                        Err(err) => set_error.set(Some(UseWebBluetoothError::Characteristic(err))),
+                    }
+                });
+            },
+            true,
+        );
+    }
+
+    let _ = use_event_listener(
+        gatt_characteristic,
+        Custom::<ev::Event>::new("characteristicvaluechanged"),
+        move |_| {
+            #[cfg(not(feature = "ssr"))]
+            if let Some(characteristic) = gatt_characteristic.get_untracked() {
+                set_value.set(characteristic.value().map(|value| data_view_to_vec(&value)));
+            }
+        },
+    );
+
+    let read = move || {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(characteristic) = gatt_characteristic.get_untracked() {
+            spawn_local(async move {
+                match js_fut!(characteristic.read_value()).await {
+                    Ok(data_view) => {
+                        set_value.set(Some(data_view_to_vec(&data_view.unchecked_into())))
+                    }
+                    Err(err) => set_error.set(Some(UseWebBluetoothError::Read(err))),
+                }
+            });
+        }
+    };
+
+    let write = move |data: Vec<u8>| {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(characteristic) = gatt_characteristic.get_untracked() {
+            spawn_local(async move {
+                let mut data = data;
+
+                if let Err(err) =
+                    js_fut!(characteristic.write_value_with_response_with_u8_array(&mut data)).await
+                {
+                    set_error.set(Some(UseWebBluetoothError::Write(err)));
+                }
+            });
+        }
+    };
+
+    let start_notifications = move || {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(characteristic) = gatt_characteristic.get_untracked() {
+            spawn_local(async move {
+                match js_fut!(characteristic.start_notifications()).await {
+                    Ok(_) => set_notifying.set(true),
+                    Err(err) => set_error.set(Some(UseWebBluetoothError::Notifications(err))),
+                }
+            });
+        }
+    };
+
+    let stop_notifications = move || {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(characteristic) = gatt_characteristic.get_untracked() {
+            if !is_notifying.get_untracked() {
+                return;
+            }
+
+            set_notifying.set(false);
+
+            spawn_local(async move {
+                if let Err(err) = js_fut!(characteristic.stop_notifications()).await {
+                    set_error.set(Some(UseWebBluetoothError::Notifications(err)));
+                }
+            });
+        }
+    };
+
+    on_cleanup(stop_notifications);
+
+    UseWebBluetoothCharacteristicReturn {
+        characteristic: gatt_characteristic.into(),
+        value: value.into(),
+        is_notifying: is_notifying.into(),
+        error: error.into(),
+        read,
+        write,
+        start_notifications,
+        stop_notifications,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+async fn get_characteristic(
+    server: &BluetoothRemoteGattServer,
+    service: &str,
+    characteristic: &str,
+) -> Result<BluetoothRemoteGattCharacteristic, JsValue> {
+    let service: web_sys::BluetoothRemoteGattService =
+        js_fut!(server.get_primary_service_with_str(service))
+            .await?
+            .unchecked_into();
+
+    Ok(js_fut!(service.get_characteristic_with_str(characteristic))
+        .await?
+        .unchecked_into())
+}
+
+#[cfg(not(feature = "ssr"))]
+fn data_view_to_vec(data_view: &js_sys::DataView) -> Vec<u8> {
+    js_sys::Uint8Array::new_with_byte_offset_and_length(
+        &data_view.buffer(),
+        data_view.byte_offset() as u32,
+        data_view.byte_length() as u32,
+    )
+    .to_vec()
+}
+
+/// Options for [`use_web_bluetooth_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseWebBluetoothOptions {
+    /// If `true` the user can pick any device and `filters` are ignored. You have to list the
+    /// services you want to access in `optional_services` then. Defaults to `false`.
+    accept_all_devices: bool,
+
+    /// Only devices that match at least one of the filters are listed.
+    filters: Vec<BluetoothFilter>,
+
+    /// Services that can be accessed in addition to the ones in `filters`.
+    optional_services: Vec<String>,
+}
+
+/// Filter for the devices that [`use_web_bluetooth`] lists.
+#[derive(DefaultBuilder, Default, Clone, Debug, PartialEq, Eq)]
+pub struct BluetoothFilter {
+    /// The device has to offer all of these services.
+    services: Vec<String>,
+
+    /// The exact name of the device.
+    #[builder(into)]
+    name: Option<String>,
+
+    /// The start of the name of the device.
+    #[builder(into)]
+    name_prefix: Option<String>,
+}
+
+#[cfg(not(feature = "ssr"))]
+impl BluetoothFilter {
+    fn to_scan_filter(&self) -> web_sys::BluetoothLeScanFilterInit {
+        let mut filter = web_sys::BluetoothLeScanFilterInit::new();
+
+        if !self.services.is_empty() {
+            filter.services(
+                &self
+                    .services
+                    .iter()
+                    .map(|service| JsValue::from_str(service))
+                    .collect::<js_sys::Array>(),
+            );
+        }
+        if let Some(name) = &self.name {
+            filter.name(name);
+        }
+        if let Some(name_prefix) = &self.name_prefix {
+            filter.name_prefix(name_prefix);
+        }
+
+        filter
+    }
+}
+
+/// Return type of [`use_web_bluetooth`].
+pub struct UseWebBluetoothReturn<RequestDeviceFn, ConnectFn, DisconnectFn>
+where
+    RequestDeviceFn: Fn() + Clone,
+    ConnectFn: Fn() + Clone,
+    DisconnectFn: Fn() + Clone,
+{
+    /// `true` if the browser supports the Web Bluetooth API.
+    pub is_supported: Signal<bool>,
+
+    /// The device picked by the user.
+    pub device: Signal<Option<BluetoothDevice>>,
+
+    /// The GATT server of the device after connecting.
+    pub server: Signal<Option<BluetoothRemoteGattServer>>,
+
+    /// Wether the GATT server is currently connected.
+    pub is_connected: Signal<bool>,
+
+    /// Latest error that occurred while requesting or connecting the device.
+    pub error: Signal<Option<UseWebBluetoothError>>,
+
+    /// Shows the device chooser and connects to the picked device.
+    pub request_device: RequestDeviceFn,
+
+    /// Connects again to the device after it has been disconnected.
+    pub connect: ConnectFn,
+
+    /// Disconnects from the device.
+    pub disconnect: DisconnectFn,
+}
+
+/// Return type of [`use_web_bluetooth_characteristic`].
+pub struct UseWebBluetoothCharacteristicReturn<ReadFn, WriteFn, StartFn, StopFn>
+where
+    ReadFn: Fn() + Clone,
+    WriteFn: Fn(Vec<u8>) + Clone,
+    StartFn: Fn() + Clone,
+    StopFn: Fn() + Clone,
+{
+    /// The characteristic. `None` as long as it hasn't been found on the connected device.
+    pub characteristic: Signal<Option<BluetoothRemoteGattCharacteristic>>,
+
+    /// The latest value of the characteristic that has been read or notified.
+    pub value: Signal<Option<Vec<u8>>>,
+
+    /// Wether notifications are currently enabled.
+    pub is_notifying: Signal<bool>,
+
+    /// Latest error that occurred.
+    pub error: Signal<Option<UseWebBluetoothError>>,
+
+    /// Reads the value of the characteristic into `value`.
+    pub read: ReadFn,
+
+    /// Writes the given bytes to the characteristic.
+    pub write: WriteFn,
+
+    /// Starts the notifications about changes of the value.
+    pub start_notifications: StartFn,
+
+    /// Stops the notifications.
+    pub stop_notifications: StopFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseWebBluetoothError {
+    #[error("failed to request device")]
+    RequestDevice(JsValue),
+    #[error("the device has no GATT server")]
+    NoGattServer,
+    #[error("failed to connect to GATT server")]
+    Connect(JsValue),
+    #[error("failed to get characteristic")]
+    Characteristic(JsValue),
+    #[error("failed to read value")]
+    Read(JsValue),
+    #[error("failed to write value")]
+    Write(JsValue),
+    #[error("failed to start or stop notifications")]
+    Notifications(JsValue),
+}
@@ -0,0 +1,401 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut, use_event_listener, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::Custom;
+use leptos::*;
+use std::future::Future;
+use std::pin::Pin;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Hid, HidConnectionEvent, HidDevice, HidInputReportEvent};
+
+/// Reactive [WebHID API](https://developer.mozilla.org/en-US/docs/Web/API/WebHID_API).
+///
+/// Lets the user pick a human interface device like a game controller or a macro keyboard,
+/// opens it and receives its input reports. The device is closed automatically when the
+/// component is cleaned up.
+///
+/// > This function requires `--cfg=web_sys_unstable_apis` to be activated as
+/// > [described in the wasm-bindgen guide](https://rustwasm.github.io/docs/wasm-bindgen/web-sys/unstable-apis.html).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_web_hid)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_web_hid_with_options, HidFilter, UseWebHidOptions, UseWebHidReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebHidReturn {
+///     device,
+///     input_report,
+///     request_device,
+///     send_report,
+///     ..
+/// } = use_web_hid_with_options(
+///     UseWebHidOptions::default().filters(vec![HidFilter::default().vendor_id(0x057e_u32)]),
+/// );
+///
+/// let set_led = move |_| {
+///     let send_report = send_report.clone();
+///     spawn_local(async move {
+///         let _ = send_report(1, vec![0x01]).await;
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=move |_| request_device()>"Connect"</button>
+///     <button on:click=set_led>"LED on"</button>
+///     <p>{move || input_report.get().map(|report| format!("{:?}", report.data))}</p>
+/// }
+/// # }
+/// ```
+///
+/// `request_device` has to be called in response to a user action like a click. It opens the
+/// selected device right away.
+///
+/// `devices` contains all devices the user has granted access to that are currently plugged in.
+/// It's updated whenever a device is connected or disconnected.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_open` are always `false`, the functions do nothing
+/// and `send_report` always fails with [`UseWebHidError::NotOpen`].
+#[allow(clippy::type_complexity)]
+pub fn use_web_hid() -> UseWebHidReturn<
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn(u8, Vec<u8>) -> HidSendReportFuture + Clone,
+> {
+    use_web_hid_with_options(UseWebHidOptions::default())
+}
+
+/// Version of [`use_web_hid`] that takes a `UseWebHidOptions`. See [`use_web_hid`] for how to use.
+#[allow(clippy::type_complexity)]
+pub fn use_web_hid_with_options(
+    options: UseWebHidOptions,
+) -> UseWebHidReturn<
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn(u8, Vec<u8>) -> HidSendReportFuture + Clone,
+> {
+    let UseWebHidOptions { filters } = options;
+
+    let is_supported = use_supported(|| js!("hid" in &window().navigator()));
+
+    let (hid, set_hid) = create_signal(None::<Hid>);
+    let (devices, set_devices) = create_signal(Vec::<HidDevice>::new());
+    let (device, set_device) = create_signal(None::<HidDevice>);
+    let (is_open, set_open) = create_signal(false);
+    let (input_report, set_input_report) = create_signal(None::<HidInputReport>);
+    let (error, set_error) = create_signal(None::<UseWebHidError>);
+
+    let update_devices = move || {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(hid) = hid.get_untracked() {
+            spawn_local(async move {
+                if let Ok(paired) = js_fut!(hid.get_devices()).await {
+                    set_devices.set(array_to_devices(paired));
+                }
+            });
+        }
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        set_hid.set(Some(window().navigator().hid()));
+        update_devices();
+    }
+
+    let _ = use_event_listener(hid, Custom::<ev::Event>::new("connect"), move |_| {
+        update_devices();
+    });
+
+    let _ = use_event_listener(
+        hid,
+        Custom::<HidConnectionEvent>::new("disconnect"),
+        move |event| {
+            if device.get_untracked().as_ref() == Some(&event.device()) {
+                set_open.set(false);
+                set_device.set(None);
+            }
+
+            update_devices();
+        },
+    );
+
+    let _ = use_event_listener(
+        device,
+        Custom::<HidInputReportEvent>::new("inputreport"),
+        move |event| {
+            #[cfg(not(feature = "ssr"))]
+            {
+                let data = event.data();
+
+                set_input_report.set(Some(HidInputReport {
+                    report_id: event.report_id(),
+                    data: js_sys::Uint8Array::new_with_byte_offset_and_length(
+                        &data.buffer(),
+                        data.byte_offset() as u32,
+                        data.byte_length() as u32,
+                    )
+                    .to_vec(),
+                }));
+            }
+        },
+    );
+
+    let open = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(device) = device.get_untracked() else {
+                set_error.set(Some(UseWebHidError::NoDevice));
+                return;
+            };
+
+            if device.opened() {
+                set_open.set(true);
+                return;
+            }
+
+            spawn_local(async move {
+                match js_fut!(device.open()).await {
+                    Ok(_) => {
+                        set_open.set(true);
+                        set_error.set(None);
+                    }
+                    Err(err) => set_error.set(Some(UseWebHidError::Open(err))),
+                }
+            });
+        }
+    };
+
+    let close = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(device) = device.get_untracked() else {
+                return;
+            };
+
+            set_open.set(false);
+
+            if !device.opened() {
+                return;
+            }
+
+            spawn_local(async move {
+                if let Err(err) = js_fut!(device.close()).await {
+                    set_error.set(Some(UseWebHidError::Close(err)));
+                }
+            });
+        }
+    };
+
+    let request_device = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(hid) = hid.get_untracked() else {
+                return;
+            };
+
+            let request_options = web_sys::HidDeviceRequestOptions::new(
+                &filters
+                    .iter()
+                    .map(HidFilter::to_device_filter)
+                    .collect::<js_sys::Array>(),
+            );
+
+            spawn_local(async move {
+                match js_fut!(hid.request_device(&request_options)).await {
+                    Ok(picked) => {
+                        // the chooser resolves to an empty list if it has been cancelled
+                        let Some(new_device) = array_to_devices(picked).into_iter().next() else {
+                            return;
+                        };
+
+                        if device.get_untracked().as_ref() != Some(&new_device) {
+                            close();
+                            set_device.set(Some(new_device));
+                        }
+
+                        set_error.set(None);
+                        update_devices();
+                        open();
+                    }
+                    Err(err) => set_error.set(Some(UseWebHidError::RequestDevice(err))),
+                }
+            });
+        }
+    };
+
+    let send_report = move |report_id: u8, data: Vec<u8>| {
+        Box::pin(async move {
+            #[cfg(feature = "ssr")]
+            {
+                Err(UseWebHidError::NotOpen)
+            }
+
+            #[cfg(not(feature = "ssr"))]
+            {
+                let Some(device) = device.get_untracked().filter(|device| device.opened()) else {
+                    return Err(UseWebHidError::NotOpen);
+                };
+
+                let mut data = data;
+
+                js_fut!(device.send_report_with_u8_array(report_id, &mut data))
+                    .await
+                    .map(|_| ())
+                    .map_err(UseWebHidError::SendReport)
+            }
+        }) as HidSendReportFuture
+    };
+
+    on_cleanup(close);
+
+    UseWebHidReturn {
+        is_supported,
+        devices: devices.into(),
+        device: device.into(),
+        is_open: is_open.into(),
+        input_report: input_report.into(),
+        error: error.into(),
+        request_device,
+        open,
+        close,
+        send_report,
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn array_to_devices(array: JsValue) -> Vec<HidDevice> {
+    array
+        .unchecked_into::<js_sys::Array>()
+        .iter()
+        .map(|device| device.unchecked_into())
+        .collect()
+}
+
+/// Future returned by the `send_report` function of [`use_web_hid`].
+pub type HidSendReportFuture = Pin<Box<dyn Future<Output = Result<(), UseWebHidError>>>>;
+
+/// Options for [`use_web_hid_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseWebHidOptions {
+    /// Only devices that match at least one of the filters are listed.
+    filters: Vec<HidFilter>,
+}
+
+/// Filter for the devices that [`use_web_hid`] lists.
+#[derive(DefaultBuilder, Default, Clone, Debug, PartialEq, Eq)]
+pub struct HidFilter {
+    /// USB or Bluetooth vendor id of the device.
+    #[builder(into)]
+    vendor_id: Option<u32>,
+
+    /// USB or Bluetooth product id of the device.
+    #[builder(into)]
+    product_id: Option<u16>,
+
+    /// HID usage page of one of the top level collections of the device.
+    #[builder(into)]
+    usage_page: Option<u16>,
+
+    /// HID usage of one of the top level collections of the device.
+    #[builder(into)]
+    usage: Option<u16>,
+}
+
+#[cfg(not(feature = "ssr"))]
+impl HidFilter {
+    fn to_device_filter(&self) -> web_sys::HidDeviceFilter {
+        let mut filter = web_sys::HidDeviceFilter::new();
+
+        if let Some(vendor_id) = self.vendor_id {
+            filter.vendor_id(vendor_id);
+        }
+        if let Some(product_id) = self.product_id {
+            filter.product_id(product_id);
+        }
+        if let Some(usage_page) = self.usage_page {
+            filter.usage_page(usage_page);
+        }
+        if let Some(usage) = self.usage {
+            filter.usage(usage);
+        }
+
+        filter
+    }
+}
+
+/// An input report received from the device opened by [`use_web_hid`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HidInputReport {
+    /// Id of the report or `0` if the device doesn't use report ids.
+    pub report_id: u8,
+
+    /// The bytes of the report without the report id.
+    pub data: Vec<u8>,
+}
+
+/// Return type of [`use_web_hid`].
+pub struct UseWebHidReturn<RequestDeviceFn, OpenFn, CloseFn, SendReportFn>
+where
+    RequestDeviceFn: Fn() + Clone,
+    OpenFn: Fn() + Clone,
+    CloseFn: Fn() + Clone,
+    SendReportFn: Fn(u8, Vec<u8>) -> HidSendReportFuture + Clone,
+{
+    /// `true` if the browser supports the WebHID API.
+    pub is_supported: Signal<bool>,
+
+    /// All connected devices the user has granted access to.
+    pub devices: Signal<Vec<HidDevice>>,
+
+    /// The device picked by the user.
+    pub device: Signal<Option<HidDevice>>,
+
+    /// Wether the device is open.
+    pub is_open: Signal<bool>,
+
+    /// The latest input report received from the device.
+    pub input_report: Signal<Option<HidInputReport>>,
+
+    /// Latest error that occurred while requesting, opening or closing the device.
+    pub error: Signal<Option<UseWebHidError>>,
+
+    /// Shows the device chooser and opens the picked device.
+    pub request_device: RequestDeviceFn,
+
+    /// Opens the device again after it has been closed.
+    pub open: OpenFn,
+
+    /// Closes the device.
+    pub close: CloseFn,
+
+    /// Sends an output report with the given report id and bytes to the device.
+    pub send_report: SendReportFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseWebHidError {
+    #[error("failed to request device")]
+    RequestDevice(JsValue),
+    #[error("no device has been picked")]
+    NoDevice,
+    #[error("the device isn't open")]
+    NotOpen,
+    #[error("failed to open device")]
+    Open(JsValue),
+    #[error("failed to close device")]
+    Close(JsValue),
+    #[error("failed to send report")]
+    SendReport(JsValue),
+}
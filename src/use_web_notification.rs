@@ -37,6 +37,39 @@ use std::rc::Rc;
 /// # }
 /// ```
 ///
+/// ### Action Buttons
+///
+/// Browsers only show action buttons for notifications that are displayed by a service worker.
+/// Set `service_worker` to `true` to show notifications through the active service worker
+/// registration, for example from [`use_service_worker`](crate::use_service_worker).
+/// Clicks on the actions are then delivered to the `notificationclick` event of the service
+/// worker with the `action` of the clicked button.
+///
+/// Browsers deliver all events of these notifications to the service worker, so `on_click`,
+/// `on_close` and `on_show` are not called. `on_error` is called if the notification
+/// can't be shown. `notification` and `close` only work for notifications that have a `tag`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_web_notification_with_options, NotificationAction, ShowOptions, UseWebNotificationOptions, UseWebNotificationReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebNotificationReturn { show, .. } = use_web_notification_with_options(
+///     UseWebNotificationOptions::default()
+///         .service_worker(true)
+///         .actions(vec![
+///             NotificationAction::new("archive", "Archive"),
+///             NotificationAction::new("reply", "Reply").icon("/reply.png"),
+///         ]),
+/// );
+///
+/// show(ShowOptions::default().title("New message"));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// This function is basically ignored on the server. You can safely call `show` but it will do nothing.
@@ -148,11 +181,44 @@ pub fn use_web_notification_with_options(
                     let mut notification_options = web_sys::NotificationOptions::from(&options);
                     options_override.override_notification_options(&mut notification_options);
 
-                    let notification_value = web_sys::Notification::new_with_options(
-                        &options_override.title.unwrap_or(options.title),
-                        &notification_options,
-                    )
-                    .expect("Notification should be created");
+                    let tag = options_override.tag.clone().or(options.tag);
+                    let title = options_override.title.unwrap_or(options.title);
+
+                    // The events of these notifications go to the service worker so no
+                    // handlers are set.
+                    if options.service_worker {
+                        match show_service_worker_notification(
+                            &title,
+                            &notification_options,
+                            tag.as_deref(),
+                        )
+                        .await
+                        {
+                            Ok(notification_value) => set_notification.set(notification_value),
+                            Err(err) => {
+                                let mut init = web_sys::ErrorEventInit::new();
+                                init.error(&err);
+
+                                if let Ok(event) =
+                                    web_sys::ErrorEvent::new_with_event_init_dict("error", &init)
+                                {
+                                    #[cfg(debug_assertions)]
+                                    let prev = SpecialNonReactiveZone::enter();
+
+                                    (options.on_error)(event.into());
+
+                                    #[cfg(debug_assertions)]
+                                    SpecialNonReactiveZone::exit(prev);
+                                }
+                            }
+                        }
+
+                        return;
+                    }
+
+                    let notification_value =
+                        web_sys::Notification::new_with_options(&title, &notification_options)
+                            .expect("Notification should be created");
 
                     notification_value.set_onclick(Some(on_click_closure.unchecked_ref()));
                     notification_value.set_onclose(Some(on_close_closure.unchecked_ref()));
@@ -205,6 +271,36 @@ pub fn use_web_notification_with_options(
     }
 }
 
+/// Shows the notification with the service worker registration. Returns it if it has a `tag`
+/// because only then it can be told apart from the other notifications of the registration.
+#[cfg(not(feature = "ssr"))]
+async fn show_service_worker_notification(
+    title: &str,
+    options: &web_sys::NotificationOptions,
+    tag: Option<&str>,
+) -> Result<Option<web_sys::Notification>, wasm_bindgen::JsValue> {
+    use crate::js_fut;
+    use wasm_bindgen::JsCast;
+
+    let registration: web_sys::ServiceWorkerRegistration =
+        js_fut!(window().navigator().service_worker().ready()?)
+            .await?
+            .unchecked_into();
+
+    js_fut!(registration.show_notification_with_options(title, options)?).await?;
+
+    let Some(tag) = tag else {
+        return Ok(None);
+    };
+
+    let notifications = js_fut!(registration.get_notifications()?).await?;
+
+    Ok(js_sys::Array::from(&notifications)
+        .iter()
+        .filter_map(|notification| notification.dyn_into::<web_sys::Notification>().ok())
+        .find(|notification| notification.tag().as_deref() == Some(tag)))
+}
+
 #[derive(Default, Clone, Copy, Eq, PartialEq, Debug)]
 pub enum NotificationDirection {
     #[default]
@@ -268,21 +364,29 @@ pub struct UseWebNotificationOptions {
     /// user clicks or dismisses it, rather than closing automatically.
     require_interaction: bool,
 
+    /// Buttons that are displayed with the notification. Only supported together with
+    /// `service_worker`.
+    actions: Vec<NotificationAction>,
+
+    /// If `true` the notification is shown by the active service worker registration instead of
+    /// the page. This is required for `actions`. Defaults to `false`.
+    service_worker: bool,
+
     // /// A boolean value specifying whether the user should be notified after a new notification replaces an old one.
     // /// The default is `false`, which means they won't be notified. If `true`, then `tag` also must be set.
     // #[builder(into)]
     // renotify: bool,
-    /// Called when the user clicks on displayed `Notification`.
+    /// Called when the user clicks on displayed `Notification`. Not called with `service_worker`.
     on_click: Rc<dyn Fn(web_sys::Event)>,
 
-    /// Called when the user closes a `Notification`.
+    /// Called when the user closes a `Notification`. Not called with `service_worker`.
     on_close: Rc<dyn Fn(web_sys::Event)>,
 
     /// Called when something goes wrong with a `Notification`
     /// (in many cases an error preventing the notification from being displayed.)
     on_error: Rc<dyn Fn(web_sys::Event)>,
 
-    /// Called when a `Notification` is displayed
+    /// Called when a `Notification` is displayed. Not called with `service_worker`.
     on_show: Rc<dyn Fn(web_sys::Event)>,
 }
 
@@ -296,6 +400,8 @@ impl Default for UseWebNotificationOptions {
             tag: None,
             icon: None,
             require_interaction: false,
+            actions: vec![],
+            service_worker: false,
             // renotify: false,
             on_click: Rc::new(|_| {}),
             on_close: Rc::new(|_| {}),
@@ -330,10 +436,60 @@ impl From<&UseWebNotificationOptions> for web_sys::NotificationOptions {
             web_sys_options.tag(tag);
         }
 
+        if !options.actions.is_empty() {
+            web_sys_options.actions(&actions_to_js(&options.actions));
+        }
+
         web_sys_options
     }
 }
 
+/// A button of a notification. See [`UseWebNotificationOptions::actions`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotificationAction {
+    /// The identifier of the action that is passed to the `notificationclick` event of the
+    /// service worker.
+    pub action: String,
+
+    /// The label of the button.
+    pub title: String,
+
+    /// The URL of an icon that is displayed with the button.
+    pub icon: Option<String>,
+}
+
+impl NotificationAction {
+    pub fn new(action: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            action: action.into(),
+            title: title.into(),
+            icon: None,
+        }
+    }
+
+    /// Sets the URL of the icon of the button.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+}
+
+fn actions_to_js(actions: &[NotificationAction]) -> js_sys::Array {
+    actions
+        .iter()
+        .map(|action| {
+            let mut web_sys_action =
+                web_sys::NotificationAction::new(&action.action, &action.title);
+
+            if let Some(icon) = &action.icon {
+                web_sys_action.icon(icon);
+            }
+
+            web_sys_action
+        })
+        .collect()
+}
+
 /// Options for [`UseWebNotificationReturn::show`].
 /// This can be used to override options passed to [`use_web_notification`].
 /// See [MDN Docs](https://developer.mozilla.org/en-US/docs/Web/API/notification) for more info.
@@ -380,6 +536,11 @@ pub struct ShowOptions {
     /// user clicks or dismisses it, rather than closing automatically.
     #[builder(into)]
     require_interaction: Option<bool>,
+
+    /// Buttons that are displayed with the notification. Only supported if `service_worker`
+    /// is enabled in [`UseWebNotificationOptions`].
+    #[builder(into)]
+    actions: Option<Vec<NotificationAction>>,
     // /// A boolean value specifying whether the user should be notified after a new notification replaces an old one.
     // /// The default is `false`, which means they won't be notified. If `true`, then `tag` also must be set.
     // #[builder(into)]
@@ -413,6 +574,10 @@ impl ShowOptions {
             options.tag(tag);
         }
 
+        if let Some(actions) = &self.actions {
+            options.actions(&actions_to_js(actions));
+        }
+
         // if let Some(renotify) = &self.renotify {
         //     options.renotify(renotify);
         // }
@@ -457,7 +622,7 @@ impl From<web_sys::NotificationPermission> for NotificationPermission {
 /// at least once before using [`use_web_notification`] to make sure
 /// you have the permission to send notifications.
 #[cfg(not(feature = "ssr"))]
-async fn request_web_notification_permission() -> NotificationPermission {
+pub(crate) async fn request_web_notification_permission() -> NotificationPermission {
     if let Ok(notification_permission) = web_sys::Notification::request_permission() {
         let _ = crate::js_fut!(notification_permission).await;
     }
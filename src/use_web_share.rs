@@ -0,0 +1,228 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use std::future::Future;
+use std::pin::Pin;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Reactive [Web Share API](https://developer.mozilla.org/en-US/docs/Web/API/Web_Share_API).
+///
+/// Shares text, links and files with other apps through the native share dialog of the
+/// operating system.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_web_share)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_web_share, ShareData, UseWebShareReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebShareReturn { is_supported, share, .. } = use_web_share();
+///
+/// let on_click = move |_| {
+///     let share = share.clone();
+///
+///     spawn_local(async move {
+///         let _ = share(
+///             ShareData::default()
+///                 .title("leptos-use")
+///                 .text("Collection of essential Leptos utilities")
+///                 .url("https://leptos-use.rs"),
+///         )
+///         .await;
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=on_click disabled=move || !is_supported.get()>"Share"</button>
+/// }
+/// # }
+/// ```
+///
+/// Not every browser that supports sharing text can also share files. Use `can_share` to check
+/// if a specific payload can be shared.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_web_share, ShareData, UseWebShareReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let file = web_sys::File::new_with_str_sequence(&js_sys::Array::new(), "image.png").unwrap();
+/// let UseWebShareReturn { can_share, .. } = use_web_share();
+///
+/// let can_share_files = can_share(&ShareData::default().files(vec![file]));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// `share` has to be called in response to a user action like a click.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` is always `false`, `can_share` returns `false` and `share`
+/// fails with `UseWebShareError::NotSupported`.
+pub fn use_web_share(
+) -> UseWebShareReturn<impl Fn(&ShareData) -> bool + Clone, impl Fn(ShareData) -> ShareFuture + Clone>
+{
+    let is_supported = use_supported(|| js!("share" in &window().navigator()));
+
+    let can_share = move |data: &ShareData| {
+        #[cfg(feature = "ssr")]
+        {
+            false
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            if !is_supported.get_untracked() {
+                return false;
+            }
+
+            let navigator = window().navigator();
+
+            match js_sys::Reflect::get(&navigator, &"canShare".into())
+                .and_then(|can_share| can_share.dyn_into::<js_sys::Function>())
+            {
+                Ok(can_share) => can_share
+                    .call1(&navigator, &data.to_js())
+                    .map(|result| result.is_truthy())
+                    .unwrap_or_default(),
+                // browsers without `canShare` can't share files
+                Err(_) => data.files.is_empty(),
+            }
+        }
+    };
+
+    let share = move |data: ShareData| {
+        Box::pin(async move {
+            #[cfg(feature = "ssr")]
+            {
+                Err(UseWebShareError::NotSupported)
+            }
+
+            #[cfg(not(feature = "ssr"))]
+            {
+                if !is_supported.get_untracked() {
+                    return Err(UseWebShareError::NotSupported);
+                }
+
+                let navigator = window().navigator();
+
+                let promise = js_sys::Reflect::get(&navigator, &"share".into())
+                    .and_then(|share| share.dyn_into::<js_sys::Function>())
+                    .and_then(|share| share.call1(&navigator, &data.to_js()))
+                    .and_then(|promise| promise.dyn_into::<js_sys::Promise>())
+                    .map_err(UseWebShareError::from_js)?;
+
+                js_fut!(promise)
+                    .await
+                    .map(|_| ())
+                    .map_err(UseWebShareError::from_js)
+            }
+        }) as ShareFuture
+    };
+
+    UseWebShareReturn {
+        is_supported,
+        can_share,
+        share,
+    }
+}
+
+/// Future returned by [`UseWebShareReturn::share`].
+pub type ShareFuture = Pin<Box<dyn Future<Output = Result<(), UseWebShareError>>>>;
+
+/// The data to share with [`use_web_share`].
+#[derive(DefaultBuilder, Default, Clone, Debug, PartialEq)]
+pub struct ShareData {
+    /// The title of the shared content.
+    #[builder(into)]
+    pub title: Option<String>,
+
+    /// The text to share.
+    #[builder(into)]
+    pub text: Option<String>,
+
+    /// The URL to share.
+    #[builder(into)]
+    pub url: Option<String>,
+
+    /// The files to share.
+    pub files: Vec<web_sys::File>,
+}
+
+#[cfg(not(feature = "ssr"))]
+impl ShareData {
+    fn to_js(&self) -> JsValue {
+        let data = js_sys::Object::new();
+
+        if let Some(title) = &self.title {
+            js!(data["title"] = title);
+        }
+        if let Some(text) = &self.text {
+            js!(data["text"] = text);
+        }
+        if let Some(url) = &self.url {
+            js!(data["url"] = url);
+        }
+        if !self.files.is_empty() {
+            let files = self.files.iter().collect::<js_sys::Array>();
+            js!(data["files"] = files);
+        }
+
+        data.into()
+    }
+}
+
+/// Return type of [`use_web_share`].
+pub struct UseWebShareReturn<CanShareFn, ShareFn>
+where
+    CanShareFn: Fn(&ShareData) -> bool + Clone,
+    ShareFn: Fn(ShareData) -> ShareFuture + Clone,
+{
+    /// `true` if the browser supports the Web Share API.
+    pub is_supported: Signal<bool>,
+
+    /// Returns `true` if the given data can be shared. File sharing is not supported by every
+    /// browser that supports sharing text.
+    pub can_share: CanShareFn,
+
+    /// Opens the share dialog. The returned future resolves when the user has shared the data.
+    pub share: ShareFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseWebShareError {
+    #[error("the Web Share API is not supported")]
+    NotSupported,
+    #[error("sharing has been canceled")]
+    Aborted,
+    #[error("failed to share")]
+    Failed(JsValue),
+}
+
+#[cfg(not(feature = "ssr"))]
+impl UseWebShareError {
+    fn from_js(err: JsValue) -> Self {
+        let is_abort = js_sys::Reflect::get(&err, &"name".into())
+            .ok()
+            .and_then(|name| name.as_string())
+            .is_some_and(|name| name == "AbortError");
+
+        if is_abort {
+            Self::Aborted
+        } else {
+            Self::Failed(err)
+        }
+    }
+}
@@ -0,0 +1,478 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::{js, js_fut, use_event_listener, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::Custom;
+use leptos::*;
+use std::future::Future;
+use std::pin::Pin;
+use thiserror::Error;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Usb, UsbConnectionEvent, UsbDevice, UsbTransferStatus};
+
+/// Reactive [WebUSB API](https://developer.mozilla.org/en-US/docs/Web/API/WebUSB_API).
+///
+/// Lets the user pick a USB device, opens it and claims one of its interfaces.
+/// The interface is released and the device is closed automatically when the component
+/// is cleaned up.
+///
+/// > This function requires `--cfg=web_sys_unstable_apis` to be activated as
+/// > [described in the wasm-bindgen guide](https://rustwasm.github.io/docs/wasm-bindgen/web-sys/unstable-apis.html).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_web_usb)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_web_usb_with_options, UsbFilter, UseWebUsbOptions, UseWebUsbReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebUsbReturn {
+///     device,
+///     is_open,
+///     request_device,
+///     transfer_in,
+///     transfer_out,
+///     ..
+/// } = use_web_usb_with_options(
+///     UseWebUsbOptions::default()
+///         .filters(vec![UsbFilter::default().vendor_id(0x2341_u16)])
+///         .interface(2),
+/// );
+///
+/// let ping = move |_| {
+///     let transfer_in = transfer_in.clone();
+///     let transfer_out = transfer_out.clone();
+///
+///     spawn_local(async move {
+///         if transfer_out(4, b"ping".to_vec()).await.is_ok() {
+///             let answer = transfer_in(5, 64).await;
+///         }
+///     });
+/// };
+///
+/// view! {
+///     <button on:click=move |_| request_device()>"Connect"</button>
+///     <button on:click=ping>"Ping"</button>
+///     <p>{move || device.get().and_then(|device| device.product_name())}</p>
+/// }
+/// # }
+/// ```
+///
+/// `request_device` has to be called in response to a user action like a click. It opens the
+/// selected device right away.
+///
+/// `devices` contains all devices the user has granted access to that are currently plugged in.
+/// It's updated whenever a device is connected or disconnected.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `is_supported` and `is_open` are always `false`, the functions do nothing
+/// and the transfers always fail with [`UseWebUsbError::NotOpen`].
+#[allow(clippy::type_complexity)]
+pub fn use_web_usb() -> UseWebUsbReturn<
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn(u8, u32) -> UsbTransferInFuture + Clone,
+    impl Fn(u8, Vec<u8>) -> UsbTransferOutFuture + Clone,
+> {
+    use_web_usb_with_options(UseWebUsbOptions::default())
+}
+
+/// Version of [`use_web_usb`] that takes a `UseWebUsbOptions`. See [`use_web_usb`] for how to use.
+#[allow(clippy::type_complexity)]
+pub fn use_web_usb_with_options(
+    options: UseWebUsbOptions,
+) -> UseWebUsbReturn<
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn(u8, u32) -> UsbTransferInFuture + Clone,
+    impl Fn(u8, Vec<u8>) -> UsbTransferOutFuture + Clone,
+> {
+    let UseWebUsbOptions {
+        filters,
+        configuration,
+        interface,
+    } = options;
+
+    let is_supported = use_supported(|| js!("usb" in &window().navigator()));
+
+    let (usb, set_usb) = create_signal(None::<Usb>);
+    let (devices, set_devices) = create_signal(Vec::<UsbDevice>::new());
+    let (device, set_device) = create_signal(None::<UsbDevice>);
+    let (is_open, set_open) = create_signal(false);
+    let (error, set_error) = create_signal(None::<UseWebUsbError>);
+
+    let update_devices = move || {
+        #[cfg(not(feature = "ssr"))]
+        if let Some(usb) = usb.get_untracked() {
+            spawn_local(async move {
+                if let Ok(paired) = js_fut!(usb.get_devices()).await {
+                    set_devices.set(
+                        paired
+                            .unchecked_into::<js_sys::Array>()
+                            .iter()
+                            .map(|device| device.unchecked_into())
+                            .collect(),
+                    );
+                }
+            });
+        }
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    if is_supported.get_untracked() {
+        set_usb.set(Some(window().navigator().usb()));
+        update_devices();
+    }
+
+    let _ = use_event_listener(usb, Custom::<ev::Event>::new("connect"), move |_| {
+        update_devices();
+    });
+
+    let _ = use_event_listener(
+        usb,
+        Custom::<UsbConnectionEvent>::new("disconnect"),
+        move |event| {
+            if device.get_untracked().as_ref() == Some(&event.device()) {
+                set_open.set(false);
+                set_device.set(None);
+            }
+
+            update_devices();
+        },
+    );
+
+    let open = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(device) = device.get_untracked() else {
+                set_error.set(Some(UseWebUsbError::NoDevice));
+                return;
+            };
+
+            if is_open.get_untracked() {
+                return;
+            }
+
+            spawn_local(async move {
+                if let Err(err) = js_fut!(device.open()).await {
+                    set_error.set(Some(UseWebUsbError::Open(err)));
+                    return;
+                }
+
+                if device.configuration().is_none() {
+                    if let Err(err) = js_fut!(device.select_configuration(configuration)).await {
+                        set_error.set(Some(UseWebUsbError::Open(err)));
+                        return;
+                    }
+                }
+
+                match js_fut!(device.claim_interface(interface)).await {
+                    Ok(_) => {
+                        set_open.set(true);
+                        set_error.set(None);
+                    }
+                    Err(err) => set_error.set(Some(UseWebUsbError::ClaimInterface(err))),
+                }
+            });
+        }
+    };
+
+    let close = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(device) = device.get_untracked() else {
+                return;
+            };
+
+            if !device.opened() {
+                return;
+            }
+
+            let was_open = is_open.get_untracked();
+            set_open.set(false);
+
+            spawn_local(async move {
+                if was_open {
+                    let _ = js_fut!(device.release_interface(interface)).await;
+                }
+
+                if let Err(err) = js_fut!(device.close()).await {
+                    set_error.set(Some(UseWebUsbError::Close(err)));
+                }
+            });
+        }
+    };
+
+    let request_device = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let Some(usb) = usb.get_untracked() else {
+                return;
+            };
+
+            let request_options = web_sys::UsbDeviceRequestOptions::new(
+                &filters
+                    .iter()
+                    .map(UsbFilter::to_device_filter)
+                    .collect::<js_sys::Array>(),
+            );
+
+            spawn_local(async move {
+                match js_fut!(usb.request_device(&request_options)).await {
+                    Ok(new_device) => {
+                        let new_device: UsbDevice = new_device.unchecked_into();
+                        if device.get_untracked().as_ref() == Some(&new_device) {
+                            open();
+                            return;
+                        }
+
+                        close();
+                        set_device.set(Some(new_device));
+                        set_error.set(None);
+                        update_devices();
+                        open();
+                    }
+                    Err(err) => set_error.set(Some(UseWebUsbError::RequestDevice(err))),
+                }
+            });
+        }
+    };
+
+    let transfer_in = move |endpoint: u8, length: u32| {
+        Box::pin(async move {
+            #[cfg(feature = "ssr")]
+            {
+                Err(UseWebUsbError::NotOpen)
+            }
+
+            #[cfg(not(feature = "ssr"))]
+            {
+                let Some(device) = device.get_untracked().filter(|_| is_open.get_untracked())
+                else {
+                    return Err(UseWebUsbError::NotOpen);
+                };
+
+                let result: web_sys::UsbInTransferResult =
+                    js_fut!(device.transfer_in(endpoint, length))
+                        .await
+                        .map_err(UseWebUsbError::Transfer)?
+                        .unchecked_into();
+
+                match result.status() {
+                    UsbTransferStatus::Ok => Ok(result
+                        .data()
+                        .map(|data| {
+                            js_sys::Uint8Array::new_with_byte_offset_and_length(
+                                &data.buffer(),
+                                data.byte_offset() as u32,
+                                data.byte_length() as u32,
+                            )
+                            .to_vec()
+                        })
+                        .unwrap_or_default()),
+                    status => Err(UseWebUsbError::TransferStatus(status)),
+                }
+            }
+        }) as UsbTransferInFuture
+    };
+
+    let transfer_out = move |endpoint: u8, data: Vec<u8>| {
+        Box::pin(async move {
+            #[cfg(feature = "ssr")]
+            {
+                Err(UseWebUsbError::NotOpen)
+            }
+
+            #[cfg(not(feature = "ssr"))]
+            {
+                let Some(device) = device.get_untracked().filter(|_| is_open.get_untracked())
+                else {
+                    return Err(UseWebUsbError::NotOpen);
+                };
+
+                let mut data = data;
+
+                let result: web_sys::UsbOutTransferResult =
+                    js_fut!(device.transfer_out_with_u8_array(endpoint, &mut data))
+                        .await
+                        .map_err(UseWebUsbError::Transfer)?
+                        .unchecked_into();
+
+                match result.status() {
+                    UsbTransferStatus::Ok => Ok(result.bytes_written()),
+                    status => Err(UseWebUsbError::TransferStatus(status)),
+                }
+            }
+        }) as UsbTransferOutFuture
+    };
+
+    on_cleanup(close);
+
+    UseWebUsbReturn {
+        is_supported,
+        devices: devices.into(),
+        device: device.into(),
+        is_open: is_open.into(),
+        error: error.into(),
+        request_device,
+        open,
+        close,
+        transfer_in,
+        transfer_out,
+    }
+}
+
+/// Future returned by the `transfer_in` function of [`use_web_usb`]. Resolves to the received bytes.
+pub type UsbTransferInFuture = Pin<Box<dyn Future<Output = Result<Vec<u8>, UseWebUsbError>>>>;
+
+/// Future returned by the `transfer_out` function of [`use_web_usb`]. Resolves to the number
+/// of bytes written.
+pub type UsbTransferOutFuture = Pin<Box<dyn Future<Output = Result<u32, UseWebUsbError>>>>;
+
+/// Options for [`use_web_usb_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseWebUsbOptions {
+    /// Only devices that match at least one of the filters are listed.
+    filters: Vec<UsbFilter>,
+
+    /// Configuration that is selected when opening a device that has none selected yet.
+    /// Defaults to `1`.
+    configuration: u8,
+
+    /// Number of the interface that is claimed after opening a device. Defaults to `0`.
+    interface: u8,
+}
+
+impl Default for UseWebUsbOptions {
+    fn default() -> Self {
+        Self {
+            filters: vec![],
+            configuration: 1,
+            interface: 0,
+        }
+    }
+}
+
+/// Filter for the devices that [`use_web_usb`] lists.
+#[derive(DefaultBuilder, Default, Clone, Debug, PartialEq, Eq)]
+pub struct UsbFilter {
+    /// USB vendor id of the device.
+    #[builder(into)]
+    vendor_id: Option<u16>,
+
+    /// USB product id of the device.
+    #[builder(into)]
+    product_id: Option<u16>,
+
+    /// USB class code of the device or one of its interfaces.
+    #[builder(into)]
+    class_code: Option<u8>,
+
+    /// USB subclass code of the device or one of its interfaces.
+    #[builder(into)]
+    subclass_code: Option<u8>,
+
+    /// USB protocol code of the device or one of its interfaces.
+    #[builder(into)]
+    protocol_code: Option<u8>,
+
+    /// Serial number of the device.
+    #[builder(into)]
+    serial_number: Option<String>,
+}
+
+#[cfg(not(feature = "ssr"))]
+impl UsbFilter {
+    fn to_device_filter(&self) -> web_sys::UsbDeviceFilter {
+        let mut filter = web_sys::UsbDeviceFilter::new();
+
+        if let Some(vendor_id) = self.vendor_id {
+            filter.vendor_id(vendor_id);
+        }
+        if let Some(product_id) = self.product_id {
+            filter.product_id(product_id);
+        }
+        if let Some(class_code) = self.class_code {
+            filter.class_code(class_code);
+        }
+        if let Some(subclass_code) = self.subclass_code {
+            filter.subclass_code(subclass_code);
+        }
+        if let Some(protocol_code) = self.protocol_code {
+            filter.protocol_code(protocol_code);
+        }
+        if let Some(serial_number) = &self.serial_number {
+            filter.serial_number(serial_number);
+        }
+
+        filter
+    }
+}
+
+/// Return type of [`use_web_usb`].
+pub struct UseWebUsbReturn<RequestDeviceFn, OpenFn, CloseFn, TransferInFn, TransferOutFn>
+where
+    RequestDeviceFn: Fn() + Clone,
+    OpenFn: Fn() + Clone,
+    CloseFn: Fn() + Clone,
+    TransferInFn: Fn(u8, u32) -> UsbTransferInFuture + Clone,
+    TransferOutFn: Fn(u8, Vec<u8>) -> UsbTransferOutFuture + Clone,
+{
+    /// `true` if the browser supports the WebUSB API.
+    pub is_supported: Signal<bool>,
+
+    /// All connected devices the user has granted access to.
+    pub devices: Signal<Vec<UsbDevice>>,
+
+    /// The device picked by the user.
+    pub device: Signal<Option<UsbDevice>>,
+
+    /// Wether the device is open and the interface is claimed.
+    pub is_open: Signal<bool>,
+
+    /// Latest error that occurred while requesting, opening or closing the device.
+    pub error: Signal<Option<UseWebUsbError>>,
+
+    /// Shows the device chooser and opens the picked device.
+    pub request_device: RequestDeviceFn,
+
+    /// Opens the device again after it has been closed.
+    pub open: OpenFn,
+
+    /// Releases the interface and closes the device.
+    pub close: CloseFn,
+
+    /// Receives up to `length` bytes from the given endpoint.
+    pub transfer_in: TransferInFn,
+
+    /// Sends the given bytes to the given endpoint.
+    pub transfer_out: TransferOutFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseWebUsbError {
+    #[error("failed to request device")]
+    RequestDevice(JsValue),
+    #[error("no device has been picked")]
+    NoDevice,
+    #[error("the device isn't open")]
+    NotOpen,
+    #[error("failed to open device")]
+    Open(JsValue),
+    #[error("failed to claim interface")]
+    ClaimInterface(JsValue),
+    #[error("failed to close device")]
+    Close(JsValue),
+    #[error("failed to transfer data")]
+    Transfer(JsValue),
+    #[error("transfer finished with status {0:?}")]
+    TransferStatus(UsbTransferStatus),
+}
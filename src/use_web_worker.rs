@@ -0,0 +1,247 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::utils::StringCodec;
+use crate::{use_event_listener, use_supported};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use std::marker::PhantomData;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+use web_sys::WorkerType;
+
+/// Reactive [Web Worker](https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API/Using_web_workers).
+///
+/// Spawns a dedicated worker from a script URL. Messages are encoded and decoded with a codec.
+/// The worker is terminated automatically when the component is cleaned up.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_web_worker)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_web_worker, UseWebWorkerReturn};
+/// # use leptos_use::utils::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebWorkerReturn {
+///     data,
+///     post_message,
+///     terminate,
+///     ..
+/// } = use_web_worker::<u64, u64, FromToStringCodec>("/fibonacci_worker.js");
+///
+/// post_message(&40);
+///
+/// view! {
+///     <p>"Result: " {move || data.get()}</p>
+/// }
+/// # }
+/// ```
+///
+/// The first type parameter is the type of the messages sent to the worker and the second one
+/// the type of the messages received from it. The worker has to exchange the messages as strings
+/// encoded in the same way as the codec, e.g. as JSON when using
+/// [`JsonCodec`](crate::utils::JsonCodec) with the feature `serde` enabled.
+///
+/// To load the worker as an ES module set `worker_type` to `WorkerType::Module` in the options.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no worker is created, all signals stay `None` and the functions do nothing.
+#[allow(clippy::type_complexity)]
+pub fn use_web_worker<Tx, Rx, C>(
+    url: &str,
+) -> UseWebWorkerReturn<
+    Tx,
+    Rx,
+    impl Fn(&Tx) + Clone,
+    impl Fn() + Clone,
+    <C as StringCodec<Tx>>::Error,
+    <C as StringCodec<Rx>>::Error,
+>
+where
+    Rx: 'static,
+    C: StringCodec<Tx> + StringCodec<Rx> + Default + Clone,
+{
+    use_web_worker_with_options::<Tx, Rx, C>(url, UseWebWorkerOptions::default())
+}
+
+/// Version of [`use_web_worker`] that takes a `UseWebWorkerOptions`. See [`use_web_worker`] for how to use.
+#[allow(clippy::type_complexity)]
+pub fn use_web_worker_with_options<Tx, Rx, C>(
+    url: &str,
+    options: UseWebWorkerOptions,
+) -> UseWebWorkerReturn<
+    Tx,
+    Rx,
+    impl Fn(&Tx) + Clone,
+    impl Fn() + Clone,
+    <C as StringCodec<Tx>>::Error,
+    <C as StringCodec<Rx>>::Error,
+>
+where
+    Rx: 'static,
+    C: StringCodec<Tx> + StringCodec<Rx> + Default + Clone,
+{
+    let UseWebWorkerOptions { worker_type, name } = options;
+
+    let is_supported = use_supported(|| crate::js!("Worker" in &window()));
+
+    let (worker, set_worker) = create_signal(None::<web_sys::Worker>);
+    let (data, set_data) = create_signal(None::<Rx>);
+    let (error, set_error) = create_signal(
+        None::<UseWebWorkerError<<C as StringCodec<Tx>>::Error, <C as StringCodec<Rx>>::Error>>,
+    );
+    let (is_terminated, set_terminated) = create_signal(false);
+
+    let codec = C::default();
+
+    let post_message = {
+        let codec = codec.clone();
+
+        move |value: &Tx| {
+            if let Some(worker) = worker.get_untracked() {
+                match codec.encode(value) {
+                    Ok(msg) => {
+                        if let Err(err) = worker.post_message(&msg.into()) {
+                            set_error.set(Some(UseWebWorkerError::PostMessage(err)));
+                        }
+                    }
+                    Err(err) => set_error.set(Some(UseWebWorkerError::Encode(err))),
+                }
+            }
+        }
+    };
+
+    let terminate = move || {
+        if let Some(worker) = worker.get_untracked() {
+            worker.terminate();
+            set_worker.set(None);
+            set_terminated.set(true);
+        }
+    };
+
+    if is_supported.get_untracked() {
+        let mut worker_options = web_sys::WorkerOptions::new();
+        worker_options.type_(worker_type);
+        if let Some(name) = &name {
+            worker_options.name(name);
+        }
+
+        match web_sys::Worker::new_with_options(url, &worker_options) {
+            Ok(new_worker) => {
+                let _ = use_event_listener(new_worker.clone(), ev::message, move |event| {
+                    if let Some(msg) = event.data().as_string() {
+                        match codec.decode(msg) {
+                            Ok(value) => set_data.set(Some(value)),
+                            Err(err) => set_error.set(Some(UseWebWorkerError::Decode(err))),
+                        }
+                    } else {
+                        set_error.set(Some(UseWebWorkerError::ValueNotString));
+                    }
+                });
+
+                let _ = use_event_listener(new_worker.clone(), ev::messageerror, move |event| {
+                    set_error.set(Some(UseWebWorkerError::MessageEvent(event)));
+                });
+
+                let _ = use_event_listener(new_worker.clone(), ev::error, move |event| {
+                    set_error.set(Some(UseWebWorkerError::Worker(event)));
+                });
+
+                set_worker.set(Some(new_worker));
+            }
+            Err(err) => set_error.set(Some(UseWebWorkerError::Create(err))),
+        }
+    }
+
+    on_cleanup(terminate);
+
+    UseWebWorkerReturn {
+        is_supported,
+        worker: worker.into(),
+        data: data.into(),
+        error: error.into(),
+        is_terminated: is_terminated.into(),
+        post_message,
+        terminate,
+        _marker: PhantomData,
+    }
+}
+
+/// Options for [`use_web_worker_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseWebWorkerOptions {
+    /// Wether the worker script is a classic script or an ES module.
+    /// Defaults to `WorkerType::Classic`.
+    worker_type: WorkerType,
+
+    /// Name of the worker that is mainly useful for debugging. Defaults to `None`.
+    #[builder(into)]
+    name: Option<String>,
+}
+
+impl Default for UseWebWorkerOptions {
+    fn default() -> Self {
+        Self {
+            worker_type: WorkerType::Classic,
+            name: None,
+        }
+    }
+}
+
+/// Return type of [`use_web_worker`].
+pub struct UseWebWorkerReturn<Tx, Rx, PostFn, TerminateFn, EncodeErr, DecodeErr>
+where
+    Rx: 'static,
+    PostFn: Fn(&Tx) + Clone,
+    TerminateFn: Fn() + Clone,
+    EncodeErr: 'static,
+    DecodeErr: 'static,
+{
+    /// `true` if this browser supports Web Workers.
+    pub is_supported: Signal<bool>,
+
+    /// The worker that is wrapped by this function. `None` after it was terminated.
+    pub worker: Signal<Option<web_sys::Worker>>,
+
+    /// Latest message received from the worker.
+    pub data: Signal<Option<Rx>>,
+
+    /// Latest error that occurred in the worker or while exchanging messages with it.
+    pub error: Signal<Option<UseWebWorkerError<EncodeErr, DecodeErr>>>,
+
+    /// Wether the worker has been terminated.
+    pub is_terminated: Signal<bool>,
+
+    /// Sends a message to the worker.
+    pub post_message: PostFn,
+
+    /// Terminates the worker immediately.
+    pub terminate: TerminateFn,
+
+    _marker: PhantomData<Tx>,
+}
+
+/// Error enum of [`use_web_worker`] and [`use_shared_worker`](crate::use_shared_worker)
+#[derive(Debug, Error, Clone)]
+pub enum UseWebWorkerError<EncodeErr, DecodeErr> {
+    #[error("failed to create worker")]
+    Create(JsValue),
+    #[error("worker error")]
+    Worker(web_sys::ErrorEvent),
+    #[error("failed to post message")]
+    PostMessage(JsValue),
+    #[error("worker message error")]
+    MessageEvent(web_sys::MessageEvent),
+    #[error("failed to encode value")]
+    Encode(EncodeErr),
+    #[error("failed to decode value")]
+    Decode(DecodeErr),
+    #[error("received value is not a string")]
+    ValueNotString,
+}
@@ -0,0 +1,611 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::js;
+use crate::utils::StringCodec;
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use std::fmt;
+use std::marker::PhantomData;
+use thiserror::Error;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Run a function in a [Web Worker](https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API/Using_web_workers)
+/// to keep long computations from blocking the main thread.
+///
+/// The function is part of your own wasm binary. It is defined with the [`web_worker_fn!`]
+/// macro which exports it from the wasm module under a name that is derived from the name of
+/// the constant. For every call of `execute` a new worker is started that instantiates the same
+/// wasm module and calls the exported function with the given input. Input and output are sent
+/// between the threads encoded with a codec so they have to be supported by the codec, e.g.
+/// implement `Serialize` and `Deserialize` when using the [`JsonCodec`](crate::utils::JsonCodec).
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_web_worker_fn)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_web_worker_fn, web_worker_fn, UseWebWorkerFnReturn, WebWorkerFnStatus};
+/// # use leptos_use::utils::FromToStringCodec;
+/// #
+/// fn fibonacci(n: u64) -> u64 {
+///     if n < 2 { n } else { fibonacci(n - 1) + fibonacci(n - 2) }
+/// }
+///
+/// web_worker_fn!(FIBONACCI: fn(u64) -> u64 = fibonacci, FromToStringCodec);
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let worker_fn = use_web_worker_fn(FIBONACCI);
+///
+/// let (result, set_result) = create_signal(None::<u64>);
+///
+/// let on_click = {
+///     let worker_fn = worker_fn.clone();
+///
+///     move |_| {
+///         let worker_fn = worker_fn.clone();
+///
+///         spawn_local(async move {
+///             if let Ok(value) = worker_fn.execute(40).await {
+///                 set_result.set(Some(value));
+///             }
+///         });
+///     }
+/// };
+///
+/// let status = worker_fn.status;
+///
+/// view! {
+///     <button on:click=on_click disabled=move || status.get() == WebWorkerFnStatus::Running>
+///         "Compute"
+///     </button>
+///     <p>{move || result.get()}</p>
+/// }
+/// # }
+/// ```
+///
+/// Only one execution can run at a time. Calling `execute` while another one is running
+/// returns `UseWebWorkerFnError::AlreadyRunning`.
+///
+/// ### Setting up the main function
+///
+/// Because the worker instantiates your whole wasm module, your `main` function runs in the
+/// worker as well. It has to return early there because there is no DOM in a worker:
+///
+/// ```no_run
+/// # use leptos::*;
+/// # use leptos_use::is_worker;
+/// #
+/// # #[component]
+/// # fn App() -> impl IntoView { view! { } }
+/// #
+/// fn main() {
+///     if is_worker() {
+///         return;
+///     }
+///
+///     mount_to_body(App);
+/// }
+/// ```
+///
+/// The worker needs to load the JavaScript glue code of your app that is generated by
+/// `wasm-bindgen` with `--target web` (this is what Trunk and cargo-leptos do). By default its URL
+/// is detected from the `<link rel="modulepreload">` element that both tools add to the page.
+/// If that doesn't work for your setup, provide the URL with the option `script_url`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server `execute` always returns `UseWebWorkerFnError::NotSupported`.
+pub fn use_web_worker_fn<I, O, C>(worker_fn: WebWorkerFn<I, O, C>) -> UseWebWorkerFnReturn<I, O, C>
+where
+    I: 'static,
+    O: 'static,
+    C: StringCodec<I> + StringCodec<O> + Default + Clone,
+{
+    use_web_worker_fn_with_options(worker_fn, UseWebWorkerFnOptions::default())
+}
+
+/// Version of [`use_web_worker_fn`] that takes a `UseWebWorkerFnOptions`. See [`use_web_worker_fn`] for how to use.
+pub fn use_web_worker_fn_with_options<I, O, C>(
+    worker_fn: WebWorkerFn<I, O, C>,
+    options: UseWebWorkerFnOptions,
+) -> UseWebWorkerFnReturn<I, O, C>
+where
+    I: 'static,
+    O: 'static,
+    C: StringCodec<I> + StringCodec<O> + Default + Clone,
+{
+    let UseWebWorkerFnOptions {
+        script_url,
+        timeout,
+    } = options;
+
+    let (status, set_status) = create_signal(WebWorkerFnStatus::Pending);
+
+    let blob_url_ref = store_value(None::<String>);
+    let running_ref = store_value(None::<RunningWorker>);
+
+    on_cleanup(move || {
+        if let Some(running) = running_ref.try_update_value(Option::take).flatten() {
+            running.abort();
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        if let Some(blob_url) = blob_url_ref.try_update_value(Option::take).flatten() {
+            let _ = web_sys::Url::revoke_object_url(&blob_url);
+        }
+    });
+
+    UseWebWorkerFnReturn {
+        status: status.into(),
+        worker_fn,
+        script_url: store_value(script_url),
+        timeout,
+        set_status,
+        blob_url_ref,
+        running_ref,
+    }
+}
+
+/// Defines a function that can be run in a web worker with [`use_web_worker_fn`].
+///
+/// This defines a constant of type [`WebWorkerFn`] and exports the function from the wasm
+/// module so the worker can call it. The name of the constant has to be unique in your app.
+///
+/// ```
+/// # use leptos_use::web_worker_fn;
+/// # use leptos_use::utils::FromToStringCodec;
+/// #
+/// fn is_prime(n: u64) -> bool {
+///     n > 1 && (2..n).take_while(|i| i * i <= n).all(|i| n % i != 0)
+/// }
+///
+/// web_worker_fn!(
+///     /// Checks in a worker if `n` is a prime number
+///     pub IS_PRIME: fn(u64) -> bool = is_prime,
+///     FromToStringCodec
+/// );
+/// ```
+#[macro_export]
+macro_rules! web_worker_fn {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $name:ident: fn($input:ty) -> $output:ty = $f:path, $codec:ty $(,)?
+    ) => {
+        $(#[$meta])*
+        $vis const $name: $crate::WebWorkerFn<$input, $output, $codec> =
+            $crate::WebWorkerFn::__new(concat!("__leptos_use_web_worker_fn_", stringify!($name)));
+
+        const _: () = {
+            $crate::__private::paste::paste! {
+                #[allow(non_snake_case)]
+                #[$crate::__private::wasm_bindgen::prelude::wasm_bindgen(
+                    wasm_bindgen = $crate::__private::wasm_bindgen
+                )]
+                pub fn [<__leptos_use_web_worker_fn_ $name>](
+                    input: String,
+                ) -> $crate::__private::wasm_bindgen::JsValue {
+                    $crate::__private::run_web_worker_fn::<$input, $output, $codec>($f, input)
+                }
+            }
+        };
+    };
+}
+
+/// A function that can be run in a web worker with [`use_web_worker_fn`].
+/// Defined with [`web_worker_fn!`].
+pub struct WebWorkerFn<I, O, C> {
+    // Name under which the function is exported from the wasm module
+    export_name: &'static str,
+    _marker: PhantomData<fn(I) -> (O, C)>,
+}
+
+impl<I, O, C> WebWorkerFn<I, O, C> {
+    #[doc(hidden)]
+    pub const fn __new(export_name: &'static str) -> Self {
+        Self {
+            export_name,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<I, O, C> Clone for WebWorkerFn<I, O, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I, O, C> Copy for WebWorkerFn<I, O, C> {}
+
+/// Returns `true` if the code is running inside a worker and not in a window.
+///
+/// See [`use_web_worker_fn`] for why this is needed.
+pub fn is_worker() -> bool {
+    #[cfg(feature = "ssr")]
+    {
+        false
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        js_sys::global().dyn_into::<web_sys::Window>().is_err()
+    }
+}
+
+/// Options for [`use_web_worker_fn_with_options`].
+#[derive(DefaultBuilder, Default)]
+pub struct UseWebWorkerFnOptions {
+    /// URL of the JavaScript glue code generated by `wasm-bindgen`.
+    /// Defaults to `None` which means that it's detected from the `<link rel="modulepreload">`
+    /// element of the page.
+    #[builder(into)]
+    script_url: Option<String>,
+
+    /// Time in ms after which the worker is terminated and `execute` returns
+    /// `UseWebWorkerFnError::Timeout`. Defaults to `None` which means no timeout.
+    #[builder(into)]
+    timeout: Option<u64>,
+}
+
+/// Status of the execution of a [`use_web_worker_fn`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum WebWorkerFnStatus {
+    /// Nothing has been executed yet.
+    #[default]
+    Pending,
+    /// The function is currently running in the worker.
+    Running,
+    /// The last execution finished successfully.
+    Success,
+    /// The last execution failed.
+    Error,
+    /// The last execution took longer than the configured timeout.
+    TimeoutExpired,
+}
+
+impl fmt::Display for WebWorkerFnStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pending => write!(f, "Pending"),
+            Self::Running => write!(f, "Running"),
+            Self::Success => write!(f, "Success"),
+            Self::Error => write!(f, "Error"),
+            Self::TimeoutExpired => write!(f, "TimeoutExpired"),
+        }
+    }
+}
+
+/// Return type of [`use_web_worker_fn`].
+pub struct UseWebWorkerFnReturn<I, O, C>
+where
+    I: 'static,
+    O: 'static,
+    C: StringCodec<I> + StringCodec<O> + Default + Clone,
+{
+    /// Status of the current or last execution.
+    pub status: Signal<WebWorkerFnStatus>,
+
+    worker_fn: WebWorkerFn<I, O, C>,
+    script_url: StoredValue<Option<String>>,
+    timeout: Option<u64>,
+    set_status: WriteSignal<WebWorkerFnStatus>,
+    blob_url_ref: StoredValue<Option<String>>,
+    running_ref: StoredValue<Option<RunningWorker>>,
+}
+
+impl<I, O, C> Clone for UseWebWorkerFnReturn<I, O, C>
+where
+    I: 'static,
+    O: 'static,
+    C: StringCodec<I> + StringCodec<O> + Default + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            status: self.status,
+            worker_fn: self.worker_fn,
+            script_url: self.script_url,
+            timeout: self.timeout,
+            set_status: self.set_status,
+            blob_url_ref: self.blob_url_ref,
+            running_ref: self.running_ref,
+        }
+    }
+}
+
+type WebWorkerFnError<I, O, C> =
+    UseWebWorkerFnError<<C as StringCodec<I>>::Error, <C as StringCodec<O>>::Error>;
+
+impl<I, O, C> UseWebWorkerFnReturn<I, O, C>
+where
+    I: 'static,
+    O: 'static,
+    C: StringCodec<I> + StringCodec<O> + Default + Clone,
+{
+    /// Runs the function with `input` in a new worker and returns its output.
+    pub async fn execute(&self, input: I) -> Result<O, WebWorkerFnError<I, O, C>> {
+        #[cfg(feature = "ssr")]
+        {
+            Err(UseWebWorkerFnError::NotSupported)
+        }
+
+        #[cfg(not(feature = "ssr"))]
+        {
+            // the values are gone if the component has been cleaned up
+            match self.running_ref.try_with_value(Option::is_some) {
+                Some(true) => return Err(UseWebWorkerFnError::AlreadyRunning),
+                Some(false) => {}
+                None => return Err(UseWebWorkerFnError::Terminated),
+            }
+
+            let codec = C::default();
+            let input = codec.encode(&input).map_err(UseWebWorkerFnError::Encode)?;
+
+            let worker = self.create_worker()?;
+
+            let message = js_sys::Object::new();
+            js!(message["module"] = wasm_bindgen::module());
+            js!(message["name"] = self.worker_fn.export_name);
+            js!(message["input"] = input);
+
+            let mut abort = None::<js_sys::Function>;
+            let result = js_sys::Promise::new(&mut |resolve, reject| {
+                worker.set_onmessage(Some(&resolve));
+                worker.set_onerror(Some(&reject));
+                abort = Some(reject);
+            });
+            let abort = abort.expect("the promise executor is called synchronously");
+
+            let timeout_handle = self.timeout.and_then(|timeout| {
+                let abort = abort.clone();
+
+                set_timeout_with_handle(
+                    move || {
+                        let _ = abort.call1(&JsValue::NULL, &JsValue::from_str(TIMEOUT_MARKER));
+                    },
+                    std::time::Duration::from_millis(timeout),
+                )
+                .ok()
+            });
+
+            self.running_ref.set_value(Some(RunningWorker {
+                worker: worker.clone(),
+                abort,
+            }));
+            self.set_status.set(WebWorkerFnStatus::Running);
+
+            let result = match worker.post_message(&message) {
+                Ok(()) => wasm_bindgen_futures::JsFuture::from(result).await,
+                Err(err) => Err(err),
+            };
+
+            if let Some(timeout_handle) = timeout_handle {
+                timeout_handle.clear();
+            }
+            worker.terminate();
+            // the value might be gone already if the component has been cleaned up
+            let _ = self.running_ref.try_set_value(None);
+
+            let result = match result {
+                Ok(event) => {
+                    let data = event.unchecked_into::<web_sys::MessageEvent>().data();
+
+                    match js!(data["output"])
+                        .ok()
+                        .and_then(|output| output.as_string())
+                    {
+                        Some(output) => codec.decode(output).map_err(UseWebWorkerFnError::Decode),
+                        None => Err(UseWebWorkerFnError::Worker(
+                            js!(data["error"])
+                                .ok()
+                                .and_then(|error| error.as_string())
+                                .unwrap_or_default(),
+                        )),
+                    }
+                }
+                Err(err) if err.as_string().as_deref() == Some(TIMEOUT_MARKER) => {
+                    Err(UseWebWorkerFnError::Timeout)
+                }
+                Err(err) if err.as_string().as_deref() == Some(TERMINATED_MARKER) => {
+                    Err(UseWebWorkerFnError::Terminated)
+                }
+                Err(err) => Err(UseWebWorkerFnError::Worker(
+                    err.dyn_ref::<web_sys::ErrorEvent>()
+                        .map(|event| event.message())
+                        .unwrap_or_else(|| format!("{:?}", err)),
+                )),
+            };
+
+            let _ = self.set_status.try_set(match &result {
+                Ok(_) => WebWorkerFnStatus::Success,
+                Err(UseWebWorkerFnError::Timeout) => WebWorkerFnStatus::TimeoutExpired,
+                Err(_) => WebWorkerFnStatus::Error,
+            });
+
+            result
+        }
+    }
+
+    /// Terminates the currently running worker. The running `execute` returns
+    /// `UseWebWorkerFnError::Terminated`.
+    pub fn terminate(&self) {
+        if let Some(running) = self.running_ref.try_update_value(Option::take).flatten() {
+            running.abort();
+        }
+    }
+
+    fn create_worker(&self) -> Result<web_sys::Worker, WebWorkerFnError<I, O, C>> {
+        let blob_url = match self.blob_url_ref.get_value() {
+            Some(blob_url) => blob_url,
+            None => {
+                let script_url = self
+                    .script_url
+                    .get_value()
+                    .or_else(detect_script_url)
+                    .ok_or(UseWebWorkerFnError::ScriptUrlNotFound)?;
+
+                let blob_url = create_blob_url(&worker_script(&script_url))
+                    .map_err(UseWebWorkerFnError::Create)?;
+                self.blob_url_ref.set_value(Some(blob_url.clone()));
+                blob_url
+            }
+        };
+
+        let mut options = web_sys::WorkerOptions::new();
+        options.type_(web_sys::WorkerType::Module);
+
+        web_sys::Worker::new_with_options(&blob_url, &options).map_err(UseWebWorkerFnError::Create)
+    }
+}
+
+const TIMEOUT_MARKER: &str = "__leptos_use_web_worker_fn_timeout";
+const TERMINATED_MARKER: &str = "__leptos_use_web_worker_fn_terminated";
+
+/// A worker that is currently executing a function.
+struct RunningWorker {
+    worker: web_sys::Worker,
+    /// Rejects the promise that `execute` is waiting for
+    abort: js_sys::Function,
+}
+
+impl RunningWorker {
+    fn abort(self) {
+        self.worker.terminate();
+        let _ = self
+            .abort
+            .call1(&JsValue::NULL, &JsValue::from_str(TERMINATED_MARKER));
+    }
+}
+
+/// Decodes the input, calls the function and encodes the output. Runs inside the worker.
+fn run_in_worker<I, O, C>(f: fn(I) -> O, input: String) -> Result<String, String>
+where
+    C: StringCodec<I> + StringCodec<O> + Default,
+{
+    let codec = C::default();
+    let input = StringCodec::<I>::decode(&codec, input)
+        .map_err(|_| "failed to decode input".to_string())?;
+
+    codec
+        .encode(&f(input))
+        .map_err(|_| "failed to encode output".to_string())
+}
+
+/// Used by the code that [`web_worker_fn!`] generates.
+#[doc(hidden)]
+pub mod __private {
+    use super::run_in_worker;
+    use crate::js;
+    use crate::utils::StringCodec;
+    use wasm_bindgen::JsValue;
+
+    pub use paste;
+    pub use wasm_bindgen;
+
+    /// Called by the functions that are exported by `web_worker_fn!`.
+    pub fn run_web_worker_fn<I, O, C>(f: fn(I) -> O, input: String) -> JsValue
+    where
+        C: StringCodec<I> + StringCodec<O> + Default,
+    {
+        let result = js_sys::Object::new();
+        match run_in_worker::<I, O, C>(f, input) {
+            Ok(output) => {
+                js!(result["output"] = output);
+            }
+            Err(error) => {
+                js!(result["error"] = error);
+            }
+        }
+        result.into()
+    }
+}
+
+fn worker_script(script_url: &str) -> String {
+    format!(
+        r#"import init, * as wasm from "{script_url}";
+
+self.onmessage = async (event) => {{
+  const {{ module, name, input }} = event.data;
+  try {{
+    await init(module);
+    self.postMessage(wasm[name](input));
+  }} catch (e) {{
+    self.postMessage({{ error: String(e) }});
+  }}
+}};
+"#
+    )
+}
+
+fn create_blob_url(script: &str) -> Result<String, JsValue> {
+    let parts = js_sys::Array::of1(&JsValue::from_str(script));
+    let mut options = web_sys::BlobPropertyBag::new();
+    options.type_("text/javascript");
+
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    web_sys::Url::create_object_url_with_blob(&blob)
+}
+
+fn detect_script_url() -> Option<String> {
+    let link = document()
+        .query_selector(r#"link[rel="modulepreload"][href$=".js"]"#)
+        .ok()
+        .flatten()?;
+
+    // the property contains the absolute URL
+    js!(link["href"]).ok().and_then(|href| href.as_string())
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseWebWorkerFnError<EncodeErr, DecodeErr> {
+    #[error("web workers are not supported")]
+    NotSupported,
+    #[error("another execution is already running")]
+    AlreadyRunning,
+    #[error("the URL of the wasm-bindgen script could not be detected")]
+    ScriptUrlNotFound,
+    #[error("failed to create worker")]
+    Create(JsValue),
+    #[error("failed to encode input")]
+    Encode(EncodeErr),
+    #[error("failed to decode output")]
+    Decode(DecodeErr),
+    #[error("worker error: {0}")]
+    Worker(String),
+    #[error("timeout expired")]
+    Timeout,
+    #[error("worker was terminated")]
+    Terminated,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::FromToStringCodec;
+
+    fn double(n: u32) -> u32 {
+        n * 2
+    }
+
+    web_worker_fn!(DOUBLE: fn(u32) -> u32 = double, FromToStringCodec);
+
+    #[test]
+    fn test_run_in_worker() {
+        let run = run_in_worker::<u32, u32, FromToStringCodec>;
+
+        assert_eq!(run(double, "21".to_string()), Ok("42".to_string()));
+        assert_eq!(
+            run(double, "abc".to_string()),
+            Err("failed to decode input".to_string())
+        );
+    }
+
+    #[test]
+    fn test_export_name() {
+        assert_eq!(DOUBLE.export_name, "__leptos_use_web_worker_fn_DOUBLE");
+    }
+}
@@ -0,0 +1,748 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::ConnectionReadyState;
+use crate::{js, js_fut};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use std::rc::Rc;
+use thiserror::Error;
+use wasm_bindgen::prelude::*;
+use web_sys::{
+    Event, MessageEvent, RtcDataChannel, RtcDataChannelEvent, RtcPeerConnection,
+    RtcPeerConnectionIceEvent, RtcPeerConnectionState, RtcSdpType, RtcSessionDescriptionInit,
+};
+
+/// Peer-to-peer messaging over a [WebRTC data channel](https://developer.mozilla.org/en-US/docs/Web/API/RTCDataChannel).
+///
+/// This manages the lifecycle of an `RTCPeerConnection` with a single data channel. WebRTC
+/// needs a signaling server to exchange session descriptions (SDP) and ICE candidates between
+/// the peers. This function doesn't make any assumptions about how this is done. Every signal
+/// that has to be sent to the other peer is passed to the `on_signal` callback and every signal
+/// received from the other peer has to be passed to `receive_signal`.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_webrtc_data_channel)
+///
+/// ## Usage
+///
+/// One peer calls `connect` to create an offer. The other peer answers automatically once the
+/// offer is passed to its `receive_signal`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_webrtc_data_channel, UseWebRtcDataChannelReturn, WebRtcSignal};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let send_to_signaling_server = |_: WebRtcSignal| {};
+/// let UseWebRtcDataChannelReturn {
+///     connection_state,
+///     ready_state,
+///     message,
+///     connect,
+///     receive_signal,
+///     send,
+///     ..
+/// } = use_webrtc_data_channel("chat", move |signal: WebRtcSignal| {
+///     // send it to the other peer through your signaling server (e.g. a websocket)
+///     send_to_signaling_server(signal);
+/// });
+///
+/// // every signal from the other peer has to be passed on
+/// // receive_signal(signal_from_other_peer);
+///
+/// let call = move |_| connect();
+/// let say_hello = move |_| send("Hello!");
+///
+/// view! {
+///     <button on:click=call>"Call"</button>
+///     <button on:click=say_hello>"Send"</button>
+///     <p>{move || message.get()}</p>
+/// }
+/// # }
+/// ```
+///
+/// With the feature `serde` enabled, [`WebRtcSignal`] implements `Serialize` and `Deserialize`
+/// so it can easily be sent as JSON.
+///
+/// ### ICE Servers
+///
+/// By default Google's public STUN server is used. You can provide your own STUN or TURN
+/// servers with the option `ice_servers`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_webrtc_data_channel_with_options, UseWebRtcDataChannelOptions, WebRtcIceServer};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let webrtc = use_webrtc_data_channel_with_options(
+///     "chat",
+///     |_| {},
+///     UseWebRtcDataChannelOptions::default().ice_servers(vec![
+///         WebRtcIceServer::new("stun:stun.example.com:3478"),
+///         WebRtcIceServer::new("turn:turn.example.com:3478").username("user").credential("secret"),
+///     ]),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server no connection is created. `connection_state` is always
+/// `RtcPeerConnectionState::New`, `ready_state` is always `ConnectionReadyState::Closed` and
+/// all functions are no-ops.
+#[allow(clippy::type_complexity)]
+pub fn use_webrtc_data_channel<F>(
+    label: &str,
+    on_signal: F,
+) -> UseWebRtcDataChannelReturn<
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn(WebRtcSignal) + Clone,
+    impl Fn(&str) + Clone,
+    impl Fn(Vec<u8>) + Clone,
+>
+where
+    F: Fn(WebRtcSignal) + 'static,
+{
+    use_webrtc_data_channel_with_options(label, on_signal, UseWebRtcDataChannelOptions::default())
+}
+
+/// Version of [`use_webrtc_data_channel`] that takes a `UseWebRtcDataChannelOptions`. See [`use_webrtc_data_channel`] for how to use.
+#[allow(clippy::type_complexity)]
+pub fn use_webrtc_data_channel_with_options<F>(
+    label: &str,
+    on_signal: F,
+    options: UseWebRtcDataChannelOptions,
+) -> UseWebRtcDataChannelReturn<
+    impl Fn() + Clone,
+    impl Fn() + Clone,
+    impl Fn(WebRtcSignal) + Clone,
+    impl Fn(&str) + Clone,
+    impl Fn(Vec<u8>) + Clone,
+>
+where
+    F: Fn(WebRtcSignal) + 'static,
+{
+    let UseWebRtcDataChannelOptions {
+        ice_servers,
+        ordered,
+        max_retransmits,
+        on_open,
+        on_message,
+        on_message_bytes,
+        on_error,
+        on_close,
+    } = options;
+
+    let label = label.to_string();
+    let on_signal: Rc<dyn Fn(WebRtcSignal)> = Rc::new(on_signal);
+
+    let (connection_state, set_connection_state) = create_signal(RtcPeerConnectionState::New);
+    let (ready_state, set_ready_state) = create_signal(ConnectionReadyState::Closed);
+    let (message, set_message) = create_signal(None::<String>);
+    let (message_bytes, set_message_bytes) = create_signal(None::<Vec<u8>>);
+
+    let (peer_connection_signal, set_peer_connection) = create_signal(None::<RtcPeerConnection>);
+    let channel_ref = store_value(None::<RtcDataChannel>);
+    // ICE candidates that arrive before the remote description is set
+    let pending_candidates_ref = store_value(Vec::<WebRtcIceCandidate>::new());
+
+    let emit_signal = {
+        let on_signal = Rc::clone(&on_signal);
+
+        move |signal: WebRtcSignal| {
+            #[cfg(debug_assertions)]
+            let prev = SpecialNonReactiveZone::enter();
+
+            on_signal(signal);
+
+            #[cfg(debug_assertions)]
+            SpecialNonReactiveZone::exit(prev);
+        }
+    };
+
+    let emit_error = {
+        let on_error = Rc::clone(&on_error);
+
+        move |error: WebRtcError| {
+            #[cfg(debug_assertions)]
+            let prev = SpecialNonReactiveZone::enter();
+
+            on_error(error);
+
+            #[cfg(debug_assertions)]
+            SpecialNonReactiveZone::exit(prev);
+        }
+    };
+
+    let close = move || {
+        #[cfg(not(feature = "ssr"))]
+        {
+            if let Some(channel) = channel_ref.get_value() {
+                detach_channel_handlers(&channel);
+                channel.close();
+            }
+            if let Some(peer_connection) = peer_connection_signal.get_untracked() {
+                detach_peer_connection_handlers(&peer_connection);
+                peer_connection.close();
+            }
+
+            channel_ref.set_value(None);
+            set_peer_connection.set(None);
+            pending_candidates_ref.update_value(Vec::clear);
+
+            set_ready_state.set(ConnectionReadyState::Closed);
+            set_connection_state.set(RtcPeerConnectionState::Closed);
+        }
+    };
+
+    // In an `Rc` because under `ssr` the bare closure is `Copy` and cloning it is a clippy error
+    let setup_channel = Rc::new({
+        let emit_error = emit_error.clone();
+
+        move |channel: RtcDataChannel| {
+            #[cfg(not(feature = "ssr"))]
+            {
+                channel.set_binary_type(web_sys::RtcDataChannelType::Arraybuffer);
+                set_ready_state.set(ConnectionReadyState::Connecting);
+
+                // onopen handler
+                {
+                    let on_open = Rc::clone(&on_open);
+
+                    let onopen_closure = Closure::wrap(Box::new(move |e: Event| {
+                        set_ready_state.set(ConnectionReadyState::Open);
+
+                        #[cfg(debug_assertions)]
+                        let prev = SpecialNonReactiveZone::enter();
+
+                        on_open(e);
+
+                        #[cfg(debug_assertions)]
+                        SpecialNonReactiveZone::exit(prev);
+                    })
+                        as Box<dyn FnMut(Event)>);
+                    channel.set_onopen(Some(onopen_closure.as_ref().unchecked_ref()));
+                    // Forget the closure to keep it alive
+                    onopen_closure.forget();
+                }
+
+                // onmessage handler
+                {
+                    let on_message = Rc::clone(&on_message);
+                    let on_message_bytes = Rc::clone(&on_message_bytes);
+
+                    let onmessage_closure = Closure::wrap(Box::new(move |e: MessageEvent| {
+                        let data = e.data();
+
+                        if let Some(txt) = data.as_string() {
+                            #[cfg(debug_assertions)]
+                            let prev = SpecialNonReactiveZone::enter();
+
+                            on_message(txt.clone());
+
+                            #[cfg(debug_assertions)]
+                            SpecialNonReactiveZone::exit(prev);
+
+                            set_message.set(Some(txt));
+                        } else if let Some(array_buffer) = data.dyn_ref::<js_sys::ArrayBuffer>() {
+                            let array = js_sys::Uint8Array::new(array_buffer).to_vec();
+
+                            #[cfg(debug_assertions)]
+                            let prev = SpecialNonReactiveZone::enter();
+
+                            on_message_bytes(array.clone());
+
+                            #[cfg(debug_assertions)]
+                            SpecialNonReactiveZone::exit(prev);
+
+                            set_message_bytes.set(Some(array));
+                        } else {
+                            logging::debug_warn!(
+                                "use_webrtc_data_channel received message of unknown type: {:?}",
+                                data
+                            );
+                        }
+                    })
+                        as Box<dyn FnMut(MessageEvent)>);
+                    channel.set_onmessage(Some(onmessage_closure.as_ref().unchecked_ref()));
+                    onmessage_closure.forget();
+                }
+
+                // onerror handler
+                {
+                    let emit_error = emit_error.clone();
+
+                    let onerror_closure = Closure::wrap(Box::new(move |e: Event| {
+                        emit_error(WebRtcError::DataChannel(e.into()));
+                    })
+                        as Box<dyn FnMut(Event)>);
+                    channel.set_onerror(Some(onerror_closure.as_ref().unchecked_ref()));
+                    onerror_closure.forget();
+                }
+
+                // onclose handler
+                {
+                    let on_close = Rc::clone(&on_close);
+
+                    let onclose_closure = Closure::wrap(Box::new(move |e: Event| {
+                        set_ready_state.set(ConnectionReadyState::Closed);
+
+                        #[cfg(debug_assertions)]
+                        let prev = SpecialNonReactiveZone::enter();
+
+                        on_close(e);
+
+                        #[cfg(debug_assertions)]
+                        SpecialNonReactiveZone::exit(prev);
+                    })
+                        as Box<dyn FnMut(Event)>);
+                    channel.set_onclose(Some(onclose_closure.as_ref().unchecked_ref()));
+                    onclose_closure.forget();
+                }
+
+                channel_ref.set_value(Some(channel));
+            }
+        }
+    });
+
+    let create_peer_connection = {
+        let emit_signal = emit_signal.clone();
+        let emit_error = emit_error.clone();
+        let setup_channel = Rc::clone(&setup_channel);
+
+        move || -> Option<RtcPeerConnection> {
+            if let Some(peer_connection) = peer_connection_signal.get_untracked() {
+                detach_peer_connection_handlers(&peer_connection);
+                peer_connection.close();
+            }
+            if let Some(channel) = channel_ref.get_value() {
+                detach_channel_handlers(&channel);
+            }
+            channel_ref.set_value(None);
+
+            let mut config = web_sys::RtcConfiguration::new();
+            if !ice_servers.is_empty() {
+                let servers = ice_servers
+                    .iter()
+                    .map(WebRtcIceServer::to_js)
+                    .collect::<js_sys::Array>();
+                config.ice_servers(&servers);
+            }
+
+            let peer_connection = match RtcPeerConnection::new_with_configuration(&config) {
+                Ok(peer_connection) => peer_connection,
+                Err(err) => {
+                    emit_error(WebRtcError::CreatePeerConnection(err));
+                    return None;
+                }
+            };
+
+            // onicecandidate handler
+            {
+                let emit_signal = emit_signal.clone();
+
+                let onicecandidate_closure =
+                    Closure::wrap(Box::new(move |e: RtcPeerConnectionIceEvent| {
+                        // `None` signals the end of candidate gathering
+                        if let Some(candidate) = e.candidate() {
+                            emit_signal(WebRtcSignal::IceCandidate(WebRtcIceCandidate {
+                                candidate: candidate.candidate(),
+                                sdp_mid: candidate.sdp_mid(),
+                                sdp_m_line_index: candidate.sdp_m_line_index(),
+                            }));
+                        }
+                    })
+                        as Box<dyn FnMut(RtcPeerConnectionIceEvent)>);
+                peer_connection
+                    .set_onicecandidate(Some(onicecandidate_closure.as_ref().unchecked_ref()));
+                onicecandidate_closure.forget();
+            }
+
+            // onconnectionstatechange handler
+            {
+                let state_peer_connection = peer_connection.clone();
+
+                let onconnectionstatechange_closure = Closure::wrap(Box::new(move |_: Event| {
+                    set_connection_state.set(state_peer_connection.connection_state());
+                })
+                    as Box<dyn FnMut(Event)>);
+                peer_connection.set_onconnectionstatechange(Some(
+                    onconnectionstatechange_closure.as_ref().unchecked_ref(),
+                ));
+                onconnectionstatechange_closure.forget();
+            }
+
+            // ondatachannel handler (answering peer)
+            {
+                let setup_channel = Rc::clone(&setup_channel);
+
+                let ondatachannel_closure = Closure::wrap(Box::new(move |e: RtcDataChannelEvent| {
+                    setup_channel(e.channel());
+                })
+                    as Box<dyn FnMut(RtcDataChannelEvent)>);
+                peer_connection
+                    .set_ondatachannel(Some(ondatachannel_closure.as_ref().unchecked_ref()));
+                ondatachannel_closure.forget();
+            }
+
+            set_connection_state.set(peer_connection.connection_state());
+            set_peer_connection.set(Some(peer_connection.clone()));
+
+            Some(peer_connection)
+        }
+    };
+
+    // Adds the ICE candidates that were received before the remote description was set
+    let add_pending_candidates = move |peer_connection: &RtcPeerConnection| {
+        for candidate in pending_candidates_ref
+            .try_update_value(std::mem::take)
+            .unwrap_or_default()
+        {
+            let _ = peer_connection
+                .add_ice_candidate_with_opt_rtc_ice_candidate_init(Some(&candidate.to_js()));
+        }
+    };
+
+    let connect = {
+        let create_peer_connection = create_peer_connection.clone();
+        let setup_channel = Rc::clone(&setup_channel);
+        let emit_signal = emit_signal.clone();
+        let emit_error = emit_error.clone();
+
+        move || {
+            #[cfg(not(feature = "ssr"))]
+            {
+                pending_candidates_ref.update_value(Vec::clear);
+
+                let Some(peer_connection) = create_peer_connection() else {
+                    return;
+                };
+
+                let mut init = web_sys::RtcDataChannelInit::new();
+                init.ordered(ordered);
+                if let Some(max_retransmits) = max_retransmits {
+                    init.max_retransmits(max_retransmits);
+                }
+                setup_channel(
+                    peer_connection.create_data_channel_with_data_channel_dict(&label, &init),
+                );
+
+                let emit_signal = emit_signal.clone();
+                let emit_error = emit_error.clone();
+
+                spawn_local(async move {
+                    match create_local_description(&peer_connection, RtcSdpType::Offer).await {
+                        Ok(sdp) => emit_signal(WebRtcSignal::Offer(sdp)),
+                        Err(err) => emit_error(err),
+                    }
+                });
+            }
+        }
+    };
+
+    let receive_signal = move |signal: WebRtcSignal| {
+        #[cfg(not(feature = "ssr"))]
+        match signal {
+            WebRtcSignal::Offer(sdp) => {
+                let Some(peer_connection) = create_peer_connection() else {
+                    return;
+                };
+
+                let emit_signal = emit_signal.clone();
+                let emit_error = emit_error.clone();
+
+                spawn_local(async move {
+                    if let Err(err) =
+                        set_remote_description(&peer_connection, RtcSdpType::Offer, &sdp).await
+                    {
+                        emit_error(err);
+                        return;
+                    }
+                    add_pending_candidates(&peer_connection);
+
+                    match create_local_description(&peer_connection, RtcSdpType::Answer).await {
+                        Ok(sdp) => emit_signal(WebRtcSignal::Answer(sdp)),
+                        Err(err) => emit_error(err),
+                    }
+                });
+            }
+            WebRtcSignal::Answer(sdp) => {
+                let Some(peer_connection) = peer_connection_signal.get_untracked() else {
+                    return;
+                };
+
+                let emit_error = emit_error.clone();
+
+                spawn_local(async move {
+                    if let Err(err) =
+                        set_remote_description(&peer_connection, RtcSdpType::Answer, &sdp).await
+                    {
+                        emit_error(err);
+                        return;
+                    }
+                    add_pending_candidates(&peer_connection);
+                });
+            }
+            WebRtcSignal::IceCandidate(candidate) => match peer_connection_signal.get_untracked() {
+                Some(peer_connection) if peer_connection.remote_description().is_some() => {
+                    let emit_error = emit_error.clone();
+
+                    spawn_local(async move {
+                        if let Err(err) = js_fut!(peer_connection
+                            .add_ice_candidate_with_opt_rtc_ice_candidate_init(Some(
+                                &candidate.to_js()
+                            )))
+                        .await
+                        {
+                            emit_error(WebRtcError::AddIceCandidate(err));
+                        }
+                    });
+                }
+                _ => pending_candidates_ref.update_value(|pending| pending.push(candidate)),
+            },
+        }
+    };
+
+    let send = move |data: &str| {
+        if ready_state.get_untracked() == ConnectionReadyState::Open {
+            if let Some(channel) = channel_ref.get_value() {
+                let _ = channel.send_with_str(data);
+            }
+        }
+    };
+
+    let send_bytes = move |data: Vec<u8>| {
+        if ready_state.get_untracked() == ConnectionReadyState::Open {
+            if let Some(channel) = channel_ref.get_value() {
+                let _ = channel.send_with_u8_array(&data);
+            }
+        }
+    };
+
+    on_cleanup(close);
+
+    UseWebRtcDataChannelReturn {
+        peer_connection: peer_connection_signal.into(),
+        connection_state: connection_state.into(),
+        ready_state: ready_state.into(),
+        message: message.into(),
+        message_bytes: message_bytes.into(),
+        connect,
+        close,
+        receive_signal,
+        send,
+        send_bytes,
+    }
+}
+
+async fn create_local_description(
+    peer_connection: &RtcPeerConnection,
+    sdp_type: RtcSdpType,
+) -> Result<String, WebRtcError> {
+    let description = match sdp_type {
+        RtcSdpType::Offer => js_fut!(peer_connection.create_offer())
+            .await
+            .map_err(WebRtcError::CreateOffer)?,
+        _ => js_fut!(peer_connection.create_answer())
+            .await
+            .map_err(WebRtcError::CreateAnswer)?,
+    };
+
+    js_fut!(peer_connection.set_local_description(description.unchecked_ref()))
+        .await
+        .map_err(WebRtcError::SetLocalDescription)?;
+
+    Ok(js!(description["sdp"])
+        .ok()
+        .and_then(|sdp| sdp.as_string())
+        .unwrap_or_default())
+}
+
+async fn set_remote_description(
+    peer_connection: &RtcPeerConnection,
+    sdp_type: RtcSdpType,
+    sdp: &str,
+) -> Result<(), WebRtcError> {
+    let mut description = RtcSessionDescriptionInit::new(sdp_type);
+    description.sdp(sdp);
+
+    js_fut!(peer_connection.set_remote_description(&description))
+        .await
+        .map(|_| ())
+        .map_err(WebRtcError::SetRemoteDescription)
+}
+
+fn detach_channel_handlers(channel: &RtcDataChannel) {
+    channel.set_onopen(None);
+    channel.set_onmessage(None);
+    channel.set_onerror(None);
+    channel.set_onclose(None);
+}
+
+fn detach_peer_connection_handlers(peer_connection: &RtcPeerConnection) {
+    peer_connection.set_onicecandidate(None);
+    peer_connection.set_onconnectionstatechange(None);
+    peer_connection.set_ondatachannel(None);
+}
+
+/// Options for [`use_webrtc_data_channel_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseWebRtcDataChannelOptions {
+    /// STUN and TURN servers used to find a route between the peers.
+    /// Defaults to Google's public STUN server `stun:stun.l.google.com:19302`.
+    ice_servers: Vec<WebRtcIceServer>,
+    /// If `true` messages are delivered in the order they were sent. Defaults to `true`.
+    ordered: bool,
+    /// Maximum number of times a message is retransmitted. `None` means messages are sent
+    /// reliably. Defaults to `None`.
+    #[builder(into)]
+    max_retransmits: Option<u16>,
+    /// Data channel open callback.
+    on_open: Rc<dyn Fn(Event)>,
+    /// Data channel message callback for text.
+    on_message: Rc<dyn Fn(String)>,
+    /// Data channel message callback for binary.
+    on_message_bytes: Rc<dyn Fn(Vec<u8>)>,
+    /// Error callback.
+    on_error: Rc<dyn Fn(WebRtcError)>,
+    /// Data channel close callback.
+    on_close: Rc<dyn Fn(Event)>,
+}
+
+impl Default for UseWebRtcDataChannelOptions {
+    fn default() -> Self {
+        Self {
+            ice_servers: vec![WebRtcIceServer::new("stun:stun.l.google.com:19302")],
+            ordered: true,
+            max_retransmits: None,
+            on_open: Rc::new(|_| {}),
+            on_message: Rc::new(|_| {}),
+            on_message_bytes: Rc::new(|_| {}),
+            on_error: Rc::new(|_| {}),
+            on_close: Rc::new(|_| {}),
+        }
+    }
+}
+
+/// A STUN or TURN server used by [`use_webrtc_data_channel`].
+#[derive(DefaultBuilder, Clone, Debug, Default, PartialEq, Eq)]
+pub struct WebRtcIceServer {
+    /// URL of the server like `stun:stun.example.com:3478`.
+    #[builder(into)]
+    url: String,
+    /// Username for TURN servers.
+    #[builder(into)]
+    username: Option<String>,
+    /// Credential for TURN servers.
+    #[builder(into)]
+    credential: Option<String>,
+}
+
+impl WebRtcIceServer {
+    /// Creates a server config with the given URL.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+
+    fn to_js(&self) -> web_sys::RtcIceServer {
+        let mut server = web_sys::RtcIceServer::new();
+        server.urls(&JsValue::from_str(&self.url));
+        if let Some(username) = &self.username {
+            server.username(username);
+        }
+        if let Some(credential) = &self.credential {
+            server.credential(credential);
+        }
+        server
+    }
+}
+
+/// A message that has to be exchanged with the other peer through a signaling channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WebRtcSignal {
+    /// SDP of an offer created by `connect`.
+    Offer(String),
+    /// SDP of the answer to a received offer.
+    Answer(String),
+    /// A local ICE candidate.
+    IceCandidate(WebRtcIceCandidate),
+}
+
+/// An ICE candidate as part of a [`WebRtcSignal`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WebRtcIceCandidate {
+    pub candidate: String,
+    pub sdp_mid: Option<String>,
+    pub sdp_m_line_index: Option<u16>,
+}
+
+impl WebRtcIceCandidate {
+    fn to_js(&self) -> web_sys::RtcIceCandidateInit {
+        let mut init = web_sys::RtcIceCandidateInit::new(&self.candidate);
+        init.sdp_mid(self.sdp_mid.as_deref());
+        init.sdp_m_line_index(self.sdp_m_line_index);
+        init
+    }
+}
+
+/// Error enum for [`UseWebRtcDataChannelOptions::on_error`]
+#[derive(Debug, Clone, Error)]
+pub enum WebRtcError {
+    #[error("Failed to create peer connection: {0:?}")]
+    CreatePeerConnection(JsValue),
+    #[error("Failed to create offer: {0:?}")]
+    CreateOffer(JsValue),
+    #[error("Failed to create answer: {0:?}")]
+    CreateAnswer(JsValue),
+    #[error("Failed to set local description: {0:?}")]
+    SetLocalDescription(JsValue),
+    #[error("Failed to set remote description: {0:?}")]
+    SetRemoteDescription(JsValue),
+    #[error("Failed to add ICE candidate: {0:?}")]
+    AddIceCandidate(JsValue),
+    #[error("Data channel error: {0:?}")]
+    DataChannel(JsValue),
+}
+
+/// Return type of [`use_webrtc_data_channel`].
+#[derive(Clone)]
+pub struct UseWebRtcDataChannelReturn<ConnectFn, CloseFn, ReceiveSignalFn, SendFn, SendBytesFn>
+where
+    ConnectFn: Fn() + Clone + 'static,
+    CloseFn: Fn() + Clone + 'static,
+    ReceiveSignalFn: Fn(WebRtcSignal) + Clone + 'static,
+    SendFn: Fn(&str) + Clone + 'static,
+    SendBytesFn: Fn(Vec<u8>) + Clone + 'static,
+{
+    /// The current `RtcPeerConnection`. `None` until `connect` was called or an offer was received.
+    pub peer_connection: Signal<Option<RtcPeerConnection>>,
+    /// The current state of the peer connection.
+    pub connection_state: Signal<RtcPeerConnectionState>,
+    /// The current state of the data channel.
+    pub ready_state: Signal<ConnectionReadyState>,
+    /// Latest text message received through the data channel.
+    pub message: Signal<Option<String>>,
+    /// Latest binary message received through the data channel.
+    pub message_bytes: Signal<Option<Vec<u8>>>,
+    /// Creates a new peer connection with a data channel and sends an offer to the other peer.
+    pub connect: ConnectFn,
+    /// Closes the data channel and the peer connection.
+    pub close: CloseFn,
+    /// Handles a signal received from the other peer.
+    pub receive_signal: ReceiveSignalFn,
+    /// Sends `text` (string) based data
+    pub send: SendFn,
+    /// Sends binary data
+    pub send_bytes: SendBytesFn,
+}
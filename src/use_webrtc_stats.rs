@@ -0,0 +1,287 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::js_fut;
+use crate::use_interval_fn;
+use crate::utils::Pausable;
+use leptos::*;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{RtcPeerConnection, RtcStatsReport};
+
+/// Reactive statistics of a [WebRTC peer connection](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/getStats).
+///
+/// Polls `getStats()` every `interval` milliseconds and derives the most commonly needed
+/// values from the stats report: the inbound and outbound bitrate, the packet loss and the
+/// round trip time.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_webrtc_stats)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_webrtc_stats, UseWebRtcStatsReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// # let (peer_connection, _) = create_signal(None::<web_sys::RtcPeerConnection>);
+/// let UseWebRtcStatsReturn {
+///     inbound_bitrate,
+///     packet_loss,
+///     round_trip_time,
+///     ..
+/// } = use_webrtc_stats(peer_connection, 1000);
+///
+/// view! {
+///     <p>"Bitrate: " {move || inbound_bitrate.get().map(|b| format!("{:.0} kbit/s", b / 1000.0))}</p>
+///     <p>"Packet loss: " {move || packet_loss.get().map(|l| format!("{:.1} %", l * 100.0))}</p>
+///     <p>"RTT: " {move || round_trip_time.get().map(|rtt| format!("{:.0} ms", rtt * 1000.0))}</p>
+/// }
+/// # }
+/// ```
+///
+/// The bitrates and the packet loss are computed from the difference between two consecutive
+/// reports so they are `None` until the second report has been received. The bitrates include
+/// all media streams and data channels of the connection. If you need other values, the latest
+/// raw report is available as `report`.
+///
+/// ## Server-Side Rendering
+///
+/// On the server the stats are never polled and all signals are always `None`.
+pub fn use_webrtc_stats<P, N>(
+    peer_connection: P,
+    interval: N,
+) -> UseWebRtcStatsReturn<impl Fn() + Clone, impl Fn() + Clone>
+where
+    P: Into<MaybeSignal<Option<RtcPeerConnection>>>,
+    N: Into<MaybeSignal<u64>>,
+{
+    let peer_connection = peer_connection.into();
+
+    let (report, set_report) = create_signal(None::<RtcStatsReport>);
+    let (stats, set_stats) = create_signal(WebRtcStats::default());
+
+    // the previous sample and the peer connection it was taken from
+    let prev_sample_ref = store_value(None::<(RtcPeerConnection, WebRtcStatsSample)>);
+
+    let Pausable {
+        is_active,
+        pause,
+        resume,
+    } = use_interval_fn(
+        move || {
+            let Some(peer_connection) = peer_connection.get_untracked() else {
+                prev_sample_ref.set_value(None);
+                return;
+            };
+
+            spawn_local(async move {
+                let Ok(result) = js_fut!(peer_connection.get_stats()).await else {
+                    return;
+                };
+                let stats_report: RtcStatsReport = result.unchecked_into();
+
+                let sample = WebRtcStatsSample::from_report(&stats_report);
+                let prev_sample = prev_sample_ref
+                    .get_value()
+                    .filter(|(prev_peer_connection, _)| prev_peer_connection == &peer_connection)
+                    .map(|(_, prev_sample)| prev_sample);
+
+                set_stats.set(sample.stats_since(prev_sample.as_ref()));
+                set_report.set(Some(stats_report));
+
+                prev_sample_ref.set_value(Some((peer_connection, sample)));
+            });
+        },
+        interval,
+    );
+
+    UseWebRtcStatsReturn {
+        inbound_bitrate: Signal::derive(move || stats.get().inbound_bitrate),
+        outbound_bitrate: Signal::derive(move || stats.get().outbound_bitrate),
+        packet_loss: Signal::derive(move || stats.get().packet_loss),
+        round_trip_time: Signal::derive(move || stats.get().round_trip_time),
+        report: report.into(),
+        is_active,
+        pause,
+        resume,
+    }
+}
+
+/// Values derived from two consecutive stats reports.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+struct WebRtcStats {
+    inbound_bitrate: Option<f64>,
+    outbound_bitrate: Option<f64>,
+    packet_loss: Option<f64>,
+    round_trip_time: Option<f64>,
+}
+
+/// Cumulative counters read from a single stats report.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+struct WebRtcStatsSample {
+    /// Time of the report in ms
+    timestamp: f64,
+    bytes_received: f64,
+    bytes_sent: f64,
+    packets_received: f64,
+    packets_lost: f64,
+    /// Round trip time of the active candidate pair in seconds
+    round_trip_time: Option<f64>,
+}
+
+impl WebRtcStatsSample {
+    fn from_report(report: &RtcStatsReport) -> Self {
+        let mut sample = Self::default();
+
+        // The transport stats include the bytes of all media streams and data channels but
+        // aren't available in every browser. The RTP streams are used as a fallback.
+        let mut transport_bytes = None::<(f64, f64)>;
+
+        for stat in report.values().into_iter().flatten() {
+            if let Some(timestamp) = number(&stat, "timestamp") {
+                sample.timestamp = sample.timestamp.max(timestamp);
+            }
+
+            match string(&stat, "type").as_deref() {
+                Some("transport") => {
+                    let (received, sent) = transport_bytes.get_or_insert((0.0, 0.0));
+                    *received += number(&stat, "bytesReceived").unwrap_or_default();
+                    *sent += number(&stat, "bytesSent").unwrap_or_default();
+                }
+                Some("inbound-rtp") => {
+                    sample.bytes_received += number(&stat, "bytesReceived").unwrap_or_default();
+                    sample.packets_received += number(&stat, "packetsReceived").unwrap_or_default();
+                    sample.packets_lost += number(&stat, "packetsLost").unwrap_or_default();
+                }
+                Some("outbound-rtp") => {
+                    sample.bytes_sent += number(&stat, "bytesSent").unwrap_or_default();
+                }
+                Some("candidate-pair")
+                    if string(&stat, "state").as_deref() == Some("succeeded")
+                        && js_sys::Reflect::get(&stat, &"nominated".into())
+                            .map(|nominated| nominated.is_truthy())
+                            .unwrap_or_default() =>
+                {
+                    sample.round_trip_time = number(&stat, "currentRoundTripTime");
+                }
+                _ => {}
+            }
+        }
+
+        if let Some((received, sent)) = transport_bytes {
+            sample.bytes_received = received;
+            sample.bytes_sent = sent;
+        }
+
+        sample
+    }
+
+    /// Computes the stats for the time between `prev` and `self`.
+    fn stats_since(&self, prev: Option<&Self>) -> WebRtcStats {
+        let mut stats = WebRtcStats {
+            round_trip_time: self.round_trip_time,
+            ..Default::default()
+        };
+
+        let Some(prev) = prev else {
+            return stats;
+        };
+
+        let seconds = (self.timestamp - prev.timestamp) / 1000.0;
+        if seconds > 0.0 {
+            stats.inbound_bitrate =
+                Some(((self.bytes_received - prev.bytes_received) * 8.0 / seconds).max(0.0));
+            stats.outbound_bitrate =
+                Some(((self.bytes_sent - prev.bytes_sent) * 8.0 / seconds).max(0.0));
+        }
+
+        let received = self.packets_received - prev.packets_received;
+        let lost = self.packets_lost - prev.packets_lost;
+        if received + lost > 0.0 {
+            stats.packet_loss = Some((lost / (received + lost)).clamp(0.0, 1.0));
+        }
+
+        stats
+    }
+}
+
+fn number(stat: &JsValue, key: &str) -> Option<f64> {
+    js_sys::Reflect::get(stat, &key.into())
+        .ok()
+        .and_then(|value| value.as_f64())
+}
+
+fn string(stat: &JsValue, key: &str) -> Option<String> {
+    js_sys::Reflect::get(stat, &key.into())
+        .ok()
+        .and_then(|value| value.as_string())
+}
+
+/// Return type of [`use_webrtc_stats`].
+pub struct UseWebRtcStatsReturn<PauseFn, ResumeFn>
+where
+    PauseFn: Fn() + Clone,
+    ResumeFn: Fn() + Clone,
+{
+    /// Received bits per second summed over all media streams and data channels.
+    pub inbound_bitrate: Signal<Option<f64>>,
+    /// Sent bits per second summed over all media streams and data channels.
+    pub outbound_bitrate: Signal<Option<f64>>,
+    /// Ratio of lost inbound packets between `0.0` and `1.0`.
+    pub packet_loss: Signal<Option<f64>>,
+    /// Round trip time of the active candidate pair in seconds.
+    pub round_trip_time: Signal<Option<f64>>,
+    /// The latest raw stats report.
+    pub report: Signal<Option<RtcStatsReport>>,
+    /// Whether the stats are currently polled.
+    pub is_active: Signal<bool>,
+    /// Pause polling.
+    pub pause: PauseFn,
+    /// Resume polling.
+    pub resume: ResumeFn,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_since() {
+        let prev = WebRtcStatsSample {
+            timestamp: 1000.0,
+            bytes_received: 10_000.0,
+            bytes_sent: 5_000.0,
+            packets_received: 90.0,
+            packets_lost: 0.0,
+            round_trip_time: Some(0.05),
+        };
+        let sample = WebRtcStatsSample {
+            timestamp: 3000.0,
+            bytes_received: 35_000.0,
+            bytes_sent: 7_500.0,
+            packets_received: 180.0,
+            packets_lost: 10.0,
+            round_trip_time: Some(0.08),
+        };
+
+        assert_eq!(
+            sample.stats_since(Some(&prev)),
+            WebRtcStats {
+                inbound_bitrate: Some(100_000.0),
+                outbound_bitrate: Some(10_000.0),
+                packet_loss: Some(0.1),
+                round_trip_time: Some(0.08),
+            }
+        );
+
+        assert_eq!(
+            sample.stats_since(None),
+            WebRtcStats {
+                round_trip_time: Some(0.08),
+                ..Default::default()
+            }
+        );
+    }
+}
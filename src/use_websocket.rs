@@ -1,12 +1,18 @@
 #![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
 
 use cfg_if::cfg_if;
-use leptos::{leptos_dom::helpers::TimeoutHandle, *};
+use leptos::{
+    leptos_dom::helpers::{IntervalHandle, TimeoutHandle},
+    *,
+};
 use std::cell::Cell;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
 use std::rc::Rc;
 use std::time::Duration;
 
-use crate::core::ConnectionReadyState;
+use crate::core::{ConnectionReadyState, RetryDelay};
+use crate::utils::{WebSocketCodec, WebSocketMessage};
 use default_struct_builder::DefaultBuilder;
 use js_sys::Array;
 use wasm_bindgen::prelude::*;
@@ -74,118 +80,154 @@ use web_sys::{BinaryType, CloseEvent, Event, MessageEvent, WebSocket};
 /// # }
 /// ```
 ///
-/// ## Relative Paths
+/// ## Reconnect
 ///
-/// If the provided `url` is relative, it will be resolved relative to the current page.
-/// Urls will be resolved like this the following. Please note that the protocol (http vs https) will
-/// be taken into account as well.
-///
-/// | Current Page                   | Relative Url             | Resolved Url                        |
-/// |--------------------------------|--------------------------|-------------------------------------|
-/// | http://example.com/some/where  | /api/ws                  | ws://example.com/api/ws             |
-/// | https://example.com/some/where | /api/ws                  | wss://example.com/api/ws            |
-/// | https://example.com/some/where | api/ws                   | wss://example.com/some/where/api/ws |
-/// | https://example.com/some/where | //otherdomain.com/api/ws | wss://otherdomain.com/api/ws        |
+/// When the connection is closed unexpectedly `use_websocket` tries to reconnect up to
+/// `reconnect_limit` times. The delay between attempts is configured by `reconnect_interval`
+/// which can either be a fixed interval in ms or an exponential backoff. The number of attempts
+/// since the connection was last open is available as the `retries` signal. Once all attempts
+/// have failed `on_failed` is called.
 ///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_websocket_with_options, UseWebSocketOptions, UseWebsocketReturn};
+/// # use leptos_use::core::RetryDelay;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebsocketReturn { retries, .. } = use_websocket_with_options(
+///     "wss://echo.websocket.events/",
+///     UseWebSocketOptions::default()
+///         .reconnect_limit(10)
+///         .reconnect_interval(RetryDelay::exponential(500))
+///         .on_failed(|| logging::error!("Connection lost")),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
 ///
-/// ## Usage with `provide_context`
+/// Calling `close` stops reconnecting.
 ///
-/// The return value of `use_websocket` utilizes several type parameters which can make it
-/// cumbersome to use with `provide_context` + `expect_context`.
-/// The following example shows how to avoid type parameters with dynamic dispatch.
-/// This sacrifices a little bit of performance for the sake of ergonomics. However,
-/// compared to network transmission speeds this loss of performance is negligible.
+/// ## Heartbeat
 ///
-/// First we define the `struct` that is going to be passed around as context.
+/// Connections behind proxies are often closed silently when they are idle. With the
+/// `heartbeat` option a ping message is sent periodically. If no pong arrives within
+/// `pong_timeout` ms the connection is considered broken and a reconnect is triggered.
 ///
 /// ```
 /// # use leptos::*;
-/// use std::rc::Rc;
+/// # use leptos_use::{use_websocket_with_options, UseWebSocketOptions, WebSocketHeartbeat};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let ws = use_websocket_with_options(
+///     "wss://echo.websocket.events/",
+///     UseWebSocketOptions::default().heartbeat(
+///         WebSocketHeartbeat::default()
+///             .message("ping")
+///             .pong_message("pong")
+///             .interval(15_000),
+///     ),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
 ///
-/// #[derive(Clone)]
-/// pub struct WebsocketContext {
-///     pub message: Signal<Option<String>>,
-///     send: Rc<dyn Fn(&str)>,  // use Rc to make it easily cloneable
-/// }
+/// ## Send Buffer
+///
+/// By default messages that are sent while the connection is not open are dropped.
+/// Set `buffer_size` to buffer up to this many messages while connecting or reconnecting.
+/// They are sent as soon as the connection is (re-)established. What happens when the buffer
+/// is full is controlled by `buffer_overflow`. Calling `close` clears the buffer and messages
+/// sent afterwards are dropped until `open` is called again.
 ///
-/// impl WebsocketContext {
-///     pub fn new(message: Signal<Option<String>>, send: Rc<dyn Fn(&str)>) -> Self {
-///         Self {
-///             message,
-///             send,
-///         }
-///     }
-///
-///     // create a method to avoid having to use parantheses around the field
-///     #[inline(always)]
-///     pub fn send(&self, message: &str) {
-///         (self.send)(message)
-///     }
-/// }
 /// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_websocket_with_options, UseWebSocketOptions, WebSocketBufferOverflow};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let ws = use_websocket_with_options(
+///     "wss://echo.websocket.events/",
+///     UseWebSocketOptions::default()
+///         .buffer_size(100)
+///         .buffer_overflow(WebSocketBufferOverflow::Error)
+///         .on_buffer_overflow(|message| logging::error!("Dropped message {message:?}")),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Binary Messages
 ///
-/// Now you can provide the context like the following.
+/// Binary messages are received as `Vec<u8>` in the `message_bytes` signal and the
+/// `on_message_bytes` callback, separately from text messages in `message` and `on_message`.
+/// Use `send_bytes` to send binary data. This works well together with binary formats like
+/// Protocol Buffers or MessagePack.
 ///
 /// ```
 /// # use leptos::*;
 /// # use leptos_use::{use_websocket, UseWebsocketReturn};
-/// # use std::rc::Rc;
-/// # #[derive(Clone)]
-/// # pub struct WebsocketContext {
-/// #     pub message: Signal<Option<String>>,
-/// #     send: Rc<dyn Fn(&str)>,
-/// # }
 /// #
-/// # impl WebsocketContext {
-/// #     pub fn new(message: Signal<Option<String>>, send: Rc<dyn Fn(&str)>) -> Self {
-/// #         Self {
-/// #             message,
-/// #             send,
-/// #         }
-/// #     }
-/// # }
-///
 /// # #[component]
 /// # fn Demo() -> impl IntoView {
 /// let UseWebsocketReturn {
-///     message,
-///     send,
+///     message_bytes,
+///     send_bytes,
 ///     ..
-/// } = use_websocket("ws:://some.websocket.io");
+/// } = use_websocket("wss://echo.websocket.events/");
+///
+/// send_bytes(vec![0x01, 0x02, 0x03]);
 ///
-/// provide_context(WebsocketContext::new(message, Rc::new(send.clone())));
+/// let length = move || message_bytes.get().map(|bytes| bytes.len());
 /// #
-/// # view! {}
+/// # view! { }
 /// # }
 /// ```
 ///
-/// Finally let's use the context:
+/// ## Relative Paths
+///
+/// If the provided `url` is relative, it will be resolved relative to the current page.
+/// Urls will be resolved like this the following. Please note that the protocol (http vs https) will
+/// be taken into account as well.
+///
+/// | Current Page                   | Relative Url             | Resolved Url                        |
+/// |--------------------------------|--------------------------|-------------------------------------|
+/// | http://example.com/some/where  | /api/ws                  | ws://example.com/api/ws             |
+/// | https://example.com/some/where | /api/ws                  | wss://example.com/api/ws            |
+/// | https://example.com/some/where | api/ws                   | wss://example.com/some/where/api/ws |
+/// | https://example.com/some/where | //otherdomain.com/api/ws | wss://otherdomain.com/api/ws        |
+///
+///
+/// ## Shared Connection
+///
+/// If several components need the same connection, use [`provide_websocket`] in a common
+/// parent component and [`use_websocket_context`] in the children instead of opening a socket
+/// in every component. The connection is opened when the first child subscribes and closed
+/// when the last one is disposed.
 ///
 /// ```
 /// # use leptos::*;
-/// # use leptos_use::{use_websocket, UseWebsocketReturn};
-/// # use std::rc::Rc;
-/// # #[derive(Clone)]
-/// # pub struct WebsocketContext {
-/// #     pub message: Signal<Option<String>>,
-/// #     send: Rc<dyn Fn(&str)>,
-/// # }
+/// # use leptos_use::{provide_websocket, use_websocket_context};
 /// #
-/// # impl WebsocketContext {
-/// #     #[inline(always)]
-/// #     pub fn send(&self, message: &str) {
-/// #         (self.send)(message)
-/// #     }
-/// # }
+/// #[component]
+/// fn Parent() -> impl IntoView {
+///     provide_websocket("wss://echo.websocket.events/");
 ///
-/// # #[component]
-/// # fn Demo() -> impl IntoView {
-/// let websocket = expect_context::<WebsocketContext>();
+///     view! { <Child /> <Child /> }
+/// }
 ///
-/// websocket.send("Hello World!");
-/// #
-/// # view! {}
-/// # }
+/// #[component]
+/// fn Child() -> impl IntoView {
+///     let websocket = use_websocket_context();
+///
+///     websocket.send("Hello World!");
+///
+///     view! { <p>{move || websocket.message.get()}</p> }
+/// }
 /// ```
 ///
 /// ## Server-Side Rendering
@@ -219,10 +261,15 @@ pub fn use_websocket_with_options(
         on_message_bytes,
         on_error,
         on_close,
+        on_failed,
         reconnect_limit,
         reconnect_interval,
         immediate,
         protocols,
+        heartbeat,
+        buffer_size,
+        buffer_overflow,
+        on_buffer_overflow,
     } = options;
 
     let (ready_state, set_ready_state) = create_signal(ConnectionReadyState::Closed);
@@ -232,47 +279,144 @@ pub fn use_websocket_with_options(
 
     let reconnect_timer_ref: StoredValue<Option<TimeoutHandle>> = store_value(None);
 
-    let reconnect_times_ref: StoredValue<u64> = store_value(0);
+    let (retries, set_retries) = create_signal(0_u64);
+    let manually_closed_ref: StoredValue<bool> = store_value(false);
 
     let unmounted = Rc::new(Cell::new(false));
 
     let connect_ref: StoredValue<Option<Rc<dyn Fn()>>> = store_value(None);
 
+    let buffer_ref: StoredValue<VecDeque<WebSocketMessage>> = store_value(VecDeque::new());
+
+    let heartbeat_interval_ref: StoredValue<Option<IntervalHandle>> = store_value(None);
+    let pong_timeout_ref: StoredValue<Option<TimeoutHandle>> = store_value(None);
+
+    let clear_pong_timeout = move || {
+        if let Some(timeout) = pong_timeout_ref.get_value() {
+            timeout.clear();
+        }
+        pong_timeout_ref.set_value(None);
+    };
+
+    let stop_heartbeat = move || {
+        if let Some(interval) = heartbeat_interval_ref.get_value() {
+            interval.clear();
+        }
+        heartbeat_interval_ref.set_value(None);
+        clear_pong_timeout();
+    };
+
     #[cfg(not(feature = "ssr"))]
     {
         let reconnect_ref: StoredValue<Option<Rc<dyn Fn()>>> = store_value(None);
         reconnect_ref.set_value({
-            let ws = ws_ref.get_value();
             Some(Rc::new(move || {
-                if reconnect_times_ref.get_value() < reconnect_limit
-                    && ws
-                        .clone()
-                        .map_or(false, |ws: WebSocket| ws.ready_state() != WebSocket::OPEN)
+                if manually_closed_ref.get_value()
+                    || reconnect_limit == 0
+                    || reconnect_timer_ref.get_value().is_some()
                 {
+                    return;
+                }
+
+                let retry = retries.get_untracked();
+
+                if retry < reconnect_limit {
+                    let delay = reconnect_interval
+                        .delay(retry.saturating_add(1).min(u32::MAX as u64) as u32);
+
                     reconnect_timer_ref.set_value(
                         set_timeout_with_handle(
                             move || {
                                 if let Some(connect) = connect_ref.get_value() {
+                                    set_retries.update(|retries| *retries += 1);
                                     connect();
-                                    reconnect_times_ref.update_value(|current| *current += 1);
                                 }
                             },
-                            Duration::from_millis(reconnect_interval),
+                            Duration::from_millis(delay),
                         )
                         .ok(),
                     );
+                } else {
+                    #[cfg(debug_assertions)]
+                    let prev = SpecialNonReactiveZone::enter();
+
+                    on_failed();
+
+                    #[cfg(debug_assertions)]
+                    SpecialNonReactiveZone::exit(prev);
                 }
             }))
         });
 
+        let start_heartbeat = {
+            let heartbeat = heartbeat.clone();
+
+            move || {
+                stop_heartbeat();
+
+                let Some(heartbeat) = heartbeat.clone() else {
+                    return;
+                };
+                let pong_timeout = heartbeat.pong_timeout;
+
+                heartbeat_interval_ref.set_value(
+                    set_interval_with_handle(
+                        move || {
+                            // still waiting for the pong of the last ping
+                            if pong_timeout_ref.get_value().is_some() {
+                                return;
+                            }
+
+                            if let Some(web_socket) = ws_ref.get_value() {
+                                let _ = web_socket.send_with_str(&heartbeat.message);
+                            }
+
+                            pong_timeout_ref.set_value(
+                                set_timeout_with_handle(
+                                    move || {
+                                        pong_timeout_ref.set_value(None);
+                                        stop_heartbeat();
+
+                                        // The connection is considered broken. Closing it
+                                        // gracefully can take very long so the handlers are
+                                        // detached and the reconnect is triggered right away.
+                                        if let Some(web_socket) = ws_ref.get_value() {
+                                            detach_handlers(&web_socket);
+                                            let _ = web_socket.close();
+                                        }
+
+                                        set_ready_state.set(ConnectionReadyState::Closed);
+
+                                        if let Some(reconnect) = &reconnect_ref.get_value() {
+                                            reconnect();
+                                        }
+                                    },
+                                    Duration::from_millis(pong_timeout),
+                                )
+                                .ok(),
+                            );
+                        },
+                        Duration::from_millis(heartbeat.interval),
+                    )
+                    .ok(),
+                );
+            }
+        };
+
         connect_ref.set_value({
-            let ws = ws_ref.get_value();
             let unmounted = Rc::clone(&unmounted);
 
             Some(Rc::new(move || {
+                if let Some(timer) = reconnect_timer_ref.get_value() {
+                    timer.clear();
+                }
                 reconnect_timer_ref.set_value(None);
 
-                if let Some(web_socket) = &ws {
+                stop_heartbeat();
+
+                if let Some(web_socket) = ws_ref.get_value() {
+                    // detach the handlers so closing the old socket doesn't trigger a reconnect
+                    detach_handlers(&web_socket);
                     let _ = web_socket.close();
                 }
 
@@ -296,6 +440,7 @@ pub fn use_websocket_with_options(
                 {
                     let unmounted = Rc::clone(&unmounted);
                     let on_open = Rc::clone(&on_open);
+                    let start_heartbeat = start_heartbeat.clone();
 
                     let onopen_closure = Closure::wrap(Box::new(move |e: Event| {
                         if unmounted.get() {
@@ -310,7 +455,19 @@ pub fn use_websocket_with_options(
                         #[cfg(debug_assertions)]
                         SpecialNonReactiveZone::exit(prev);
 
+                        set_retries.set(0);
                         set_ready_state.set(ConnectionReadyState::Open);
+
+                        if let Some(web_socket) = ws_ref.get_value() {
+                            for message in buffer_ref
+                                .try_update_value(std::mem::take)
+                                .unwrap_or_default()
+                            {
+                                send_message(&web_socket, &message);
+                            }
+                        }
+
+                        start_heartbeat();
                     })
                         as Box<dyn FnMut(Event)>);
                     web_socket.set_onopen(Some(onopen_closure.as_ref().unchecked_ref()));
@@ -322,52 +479,76 @@ pub fn use_websocket_with_options(
                 {
                     let unmounted = Rc::clone(&unmounted);
                     let on_message = Rc::clone(&on_message);
-                    let on_message_bytes = Rc::clone(&on_message_bytes);
+                    let receive_bytes: Rc<dyn Fn(&js_sys::ArrayBuffer)> = {
+                        let on_message_bytes = Rc::clone(&on_message_bytes);
+
+                        Rc::new(move |array_buffer| {
+                            let array = js_sys::Uint8Array::new(array_buffer).to_vec();
+
+                            #[cfg(debug_assertions)]
+                            let prev = SpecialNonReactiveZone::enter();
+
+                            on_message_bytes(array.clone());
+
+                            #[cfg(debug_assertions)]
+                            SpecialNonReactiveZone::exit(prev);
+
+                            set_message_bytes.set(Some(array));
+                        })
+                    };
+                    let pong_message = heartbeat.as_ref().map(|h| h.pong_message.clone());
 
                     let onmessage_closure = Closure::wrap(Box::new(move |e: MessageEvent| {
                         if unmounted.get() {
                             return;
                         }
 
-                        e.data().dyn_into::<js_sys::ArrayBuffer>().map_or_else(
-                            |_| {
-                                e.data().dyn_into::<js_sys::JsString>().map_or_else(
-                                    |_| {
-                                        unreachable!(
-                                            "message event, received Unknown: {:?}",
-                                            e.data()
-                                        );
-                                    },
-                                    |txt| {
-                                        let txt = String::from(&txt);
+                        match &pong_message {
+                            Some(Some(pong_message))
+                                if e.data().as_string().as_ref() == Some(pong_message) =>
+                            {
+                                clear_pong_timeout();
+                                return;
+                            }
+                            // every message counts as pong
+                            Some(None) => clear_pong_timeout(),
+                            _ => {}
+                        }
 
-                                        #[cfg(debug_assertions)]
-                                        let prev = SpecialNonReactiveZone::enter();
+                        let data = e.data();
 
-                                        on_message(txt.clone());
+                        if let Some(txt) = data.as_string() {
+                            #[cfg(debug_assertions)]
+                            let prev = SpecialNonReactiveZone::enter();
 
-                                        #[cfg(debug_assertions)]
-                                        SpecialNonReactiveZone::exit(prev);
+                            on_message(txt.clone());
 
-                                        set_message.set(Some(txt));
-                                    },
-                                );
-                            },
-                            |array_buffer| {
-                                let array = js_sys::Uint8Array::new(&array_buffer);
-                                let array = array.to_vec();
+                            #[cfg(debug_assertions)]
+                            SpecialNonReactiveZone::exit(prev);
 
-                                #[cfg(debug_assertions)]
-                                let prev = SpecialNonReactiveZone::enter();
+                            set_message.set(Some(txt));
+                        } else if let Some(array_buffer) = data.dyn_ref::<js_sys::ArrayBuffer>() {
+                            receive_bytes(array_buffer);
+                        } else if let Ok(blob) = data.dyn_into::<web_sys::Blob>() {
+                            // only happens if the `binary_type` of the `WebSocket` was changed
+                            let unmounted = Rc::clone(&unmounted);
+                            let receive_bytes = Rc::clone(&receive_bytes);
 
-                                on_message_bytes(array.clone());
-
-                                #[cfg(debug_assertions)]
-                                SpecialNonReactiveZone::exit(prev);
-
-                                set_message_bytes.set(Some(array));
-                            },
-                        );
+                            spawn_local(async move {
+                                if let Ok(array_buffer) =
+                                    wasm_bindgen_futures::JsFuture::from(blob.array_buffer()).await
+                                {
+                                    if !unmounted.get() {
+                                        receive_bytes(array_buffer.unchecked_ref());
+                                    }
+                                }
+                            });
+                        } else {
+                            logging::debug_warn!(
+                                "use_websocket received message of unknown type: {:?}",
+                                e.data()
+                            );
+                        }
                     })
                         as Box<dyn FnMut(MessageEvent)>);
                     web_socket.set_onmessage(Some(onmessage_closure.as_ref().unchecked_ref()));
@@ -384,9 +565,7 @@ pub fn use_websocket_with_options(
                             return;
                         }
 
-                        if let Some(reconnect) = &reconnect_ref.get_value() {
-                            reconnect();
-                        }
+                        // no reconnect here because an error is always followed by a close event
 
                         #[cfg(debug_assertions)]
                         let prev = SpecialNonReactiveZone::enter();
@@ -413,10 +592,6 @@ pub fn use_websocket_with_options(
                             return;
                         }
 
-                        if let Some(reconnect) = &reconnect_ref.get_value() {
-                            reconnect();
-                        }
-
                         #[cfg(debug_assertions)]
                         let prev = SpecialNonReactiveZone::enter();
 
@@ -426,6 +601,11 @@ pub fn use_websocket_with_options(
                         SpecialNonReactiveZone::exit(prev);
 
                         set_ready_state.set(ConnectionReadyState::Closed);
+                        stop_heartbeat();
+
+                        if let Some(reconnect) = &reconnect_ref.get_value() {
+                            reconnect();
+                        }
                     })
                         as Box<dyn FnMut(CloseEvent)>);
                     web_socket.set_onclose(Some(onclose_closure.as_ref().unchecked_ref()));
@@ -437,29 +617,52 @@ pub fn use_websocket_with_options(
         });
     }
 
-    // Send text (String)
-    let send = {
-        Box::new(move |data: &str| {
-            if ready_state.get_untracked() == ConnectionReadyState::Open {
-                if let Some(web_socket) = ws_ref.get_value() {
-                    let _ = web_socket.send_with_str(data);
-                }
-            }
-        })
-    };
-
-    // Send bytes
-    let send_bytes = move |data: Vec<u8>| {
+    // Sends the message if the connection is open or puts it into the buffer otherwise.
+    // After `close` has been called the message is dropped.
+    let send_or_buffer = move |message: WebSocketMessage| {
         if ready_state.get_untracked() == ConnectionReadyState::Open {
             if let Some(web_socket) = ws_ref.get_value() {
-                let _ = web_socket.send_with_u8_array(&data);
+                send_message(&web_socket, &message);
+            }
+        } else if buffer_size > 0 && !manually_closed_ref.get_value() {
+            let rejected = buffer_ref
+                .try_update_value(|buffer| {
+                    if buffer.len() < buffer_size {
+                        buffer.push_back(message);
+                        return None;
+                    }
+
+                    match buffer_overflow {
+                        WebSocketBufferOverflow::DropOldest => {
+                            buffer.pop_front();
+                            buffer.push_back(message);
+                            None
+                        }
+                        WebSocketBufferOverflow::DropNewest => None,
+                        WebSocketBufferOverflow::Error => Some(message),
+                    }
+                })
+                .flatten();
+
+            if let Some(rejected) = rejected {
+                on_buffer_overflow(rejected);
             }
         }
     };
 
+    // Send text (String)
+    let send = {
+        let send_or_buffer = send_or_buffer.clone();
+        Box::new(move |data: &str| send_or_buffer(WebSocketMessage::Text(data.to_string())))
+    };
+
+    // Send bytes
+    let send_bytes = move |data: Vec<u8>| send_or_buffer(WebSocketMessage::Binary(data));
+
     // Open connection
     let open = move || {
-        reconnect_times_ref.set_value(0);
+        manually_closed_ref.set_value(false);
+        set_retries.set(0);
         if let Some(connect) = connect_ref.get_value() {
             connect();
         }
@@ -467,10 +670,14 @@ pub fn use_websocket_with_options(
 
     // Close connection
     let close = {
-        reconnect_timer_ref.set_value(None);
-
         move || {
-            reconnect_times_ref.set_value(reconnect_limit);
+            manually_closed_ref.set_value(true);
+            buffer_ref.update_value(|buffer| buffer.clear());
+            stop_heartbeat();
+            if let Some(timer) = reconnect_timer_ref.get_value() {
+                timer.clear();
+            }
+            reconnect_timer_ref.set_value(None);
             if let Some(web_socket) = ws_ref.get_value() {
                 let _ = web_socket.close();
             }
@@ -494,6 +701,7 @@ pub fn use_websocket_with_options(
         ready_state: ready_state.into(),
         message: message.into(),
         message_bytes: message_bytes.into(),
+        retries: retries.into(),
         ws: ws_ref.get_value(),
         open,
         close,
@@ -502,6 +710,316 @@ pub fn use_websocket_with_options(
     }
 }
 
+/// Version of [`use_websocket`] that encodes outgoing messages of type `Tx` and decodes
+/// incoming messages into values of type `Rx` with the codec `C`.
+///
+/// Messages that can't be decoded are passed to the `on_decode_error` callback. See
+/// [`WebSocketCodec`] for the available codecs.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_websocket_with_codec, UseWebSocketCodecOptions, UseWebSocketCodecReturn};
+/// # use leptos_use::utils::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWebSocketCodecReturn { message, send, .. } =
+///     use_websocket_with_codec::<i32, i32, FromToStringCodec>(
+///         "wss://echo.websocket.events/",
+///         UseWebSocketCodecOptions::default()
+///             .on_decode_error(|err| logging::error!("Invalid number: {err}")),
+///     );
+///
+/// let _ = send(&42);
+///
+/// view! { <p>{move || message.get()}</p> }
+/// # }
+/// ```
+///
+/// With the feature `serde` enabled you can use the `JsonCodec` to send and receive
+/// structs, with `prost`, `msgpack` or `bincode` respectively the `ProstCodec`, `MsgpackCodec`
+/// or `BincodeCodec` for binary messages.
+#[allow(clippy::type_complexity)]
+pub fn use_websocket_with_codec<Tx, Rx, C>(
+    url: &str,
+    options: UseWebSocketCodecOptions<Tx, Rx, C>,
+) -> UseWebSocketCodecReturn<
+    Rx,
+    impl Fn() + Clone + 'static,
+    impl Fn() + Clone + 'static,
+    impl Fn(&Tx) -> Result<(), C::Error> + Clone + 'static,
+>
+where
+    Tx: 'static,
+    Rx: Clone + 'static,
+    C: WebSocketCodec<Tx, Rx>,
+{
+    let UseWebSocketCodecOptions {
+        codec,
+        on_decode_error,
+        options: mut socket_options,
+        _marker,
+    } = options;
+
+    let (message, set_message) = create_signal(None::<Rx>);
+
+    let receive = {
+        let codec = codec.clone();
+
+        Rc::new(
+            move |message: WebSocketMessage| match codec.decode(message) {
+                Ok(message) => set_message.set(Some(message)),
+                Err(err) => on_decode_error(err),
+            },
+        )
+    };
+
+    let on_message = Rc::clone(&socket_options.on_message);
+    socket_options.on_message = {
+        let receive = Rc::clone(&receive);
+
+        Rc::new(move |text: String| {
+            on_message(text.clone());
+            receive(WebSocketMessage::Text(text));
+        })
+    };
+
+    let on_message_bytes = Rc::clone(&socket_options.on_message_bytes);
+    socket_options.on_message_bytes = Rc::new(move |bytes: Vec<u8>| {
+        on_message_bytes(bytes.clone());
+        receive(WebSocketMessage::Binary(bytes));
+    });
+
+    let UseWebsocketReturn {
+        ready_state,
+        retries,
+        ws,
+        open,
+        close,
+        send,
+        send_bytes,
+        ..
+    } = use_websocket_with_options(url, socket_options);
+
+    let send = move |value: &Tx| {
+        match codec.encode(value)? {
+            WebSocketMessage::Text(text) => send(&text),
+            WebSocketMessage::Binary(bytes) => send_bytes(bytes),
+        }
+
+        Ok(())
+    };
+
+    UseWebSocketCodecReturn {
+        ready_state,
+        message: message.into(),
+        retries,
+        ws,
+        open,
+        close,
+        send,
+    }
+}
+
+/// Options for [`use_websocket_with_codec`].
+#[derive(DefaultBuilder)]
+pub struct UseWebSocketCodecOptions<Tx, Rx, C>
+where
+    Tx: 'static,
+    Rx: 'static,
+    C: WebSocketCodec<Tx, Rx>,
+{
+    /// Encodes outgoing and decodes incoming messages.
+    #[builder(skip)]
+    codec: C,
+
+    /// Called when an incoming message can't be decoded.
+    #[builder(skip)]
+    on_decode_error: Rc<dyn Fn(C::Error)>,
+
+    /// Options of the underlying `WebSocket` connection.
+    options: UseWebSocketOptions,
+
+    #[builder(skip)]
+    _marker: PhantomData<(Tx, Rx)>,
+}
+
+impl<Tx, Rx, C: WebSocketCodec<Tx, Rx>> UseWebSocketCodecOptions<Tx, Rx, C> {
+    /// Encodes outgoing and decodes incoming messages.
+    pub fn codec(self, codec: C) -> Self {
+        Self { codec, ..self }
+    }
+
+    /// Called when an incoming message can't be decoded.
+    pub fn on_decode_error(self, on_decode_error: impl Fn(C::Error) + 'static) -> Self {
+        Self {
+            on_decode_error: Rc::new(on_decode_error),
+            ..self
+        }
+    }
+}
+
+impl<Tx, Rx, C: WebSocketCodec<Tx, Rx> + Default> Default for UseWebSocketCodecOptions<Tx, Rx, C> {
+    fn default() -> Self {
+        Self {
+            codec: C::default(),
+            on_decode_error: Rc::new(|_| {}),
+            options: UseWebSocketOptions::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Return type of [`use_websocket_with_codec`].
+#[derive(Clone)]
+pub struct UseWebSocketCodecReturn<Rx, OpenFn, CloseFn, SendFn>
+where
+    Rx: 'static,
+    OpenFn: Fn() + Clone + 'static,
+    CloseFn: Fn() + Clone + 'static,
+    SendFn: Clone + 'static,
+{
+    /// The current state of the `WebSocket` connection.
+    pub ready_state: Signal<ConnectionReadyState>,
+    /// Latest decoded message received from `WebSocket`.
+    pub message: Signal<Option<Rx>>,
+    /// Number of reconnect attempts since the connection was last open.
+    pub retries: Signal<u64>,
+    /// The `WebSocket` instance.
+    pub ws: Option<WebSocket>,
+    /// Opens the `WebSocket` connection
+    pub open: OpenFn,
+    /// Closes the `WebSocket` connection
+    pub close: CloseFn,
+    /// Encodes and sends a message. Returns an error if encoding fails.
+    pub send: SendFn,
+}
+
+/// Provides a shared `WebSocket` connection to all child components. Use
+/// [`use_websocket_context`] to access it. See [`use_websocket`] for details.
+pub fn provide_websocket(url: &str) {
+    provide_websocket_with_options(url, UseWebSocketOptions::default());
+}
+
+/// Version of [`provide_websocket`] that takes `UseWebSocketOptions`.
+///
+/// If `immediate` is `true` (the default) the connection is opened when the first component
+/// subscribes with [`use_websocket_context`]. In any case the connection is closed when the
+/// last subscribed component is disposed.
+pub fn provide_websocket_with_options(url: &str, options: UseWebSocketOptions) {
+    let immediate = options.immediate;
+
+    let UseWebsocketReturn {
+        ready_state,
+        message,
+        message_bytes,
+        retries,
+        open,
+        close,
+        send,
+        send_bytes,
+        ..
+    } = use_websocket_with_options(
+        url,
+        UseWebSocketOptions {
+            immediate: false,
+            ..options
+        },
+    );
+
+    provide_context(WebSocketContext {
+        ready_state,
+        message,
+        message_bytes,
+        retries,
+        open: Rc::new(open),
+        close: Rc::new(close),
+        send: Rc::new(send),
+        send_bytes: Rc::new(send_bytes),
+        subscribers: Rc::new(Cell::new(0)),
+        immediate,
+    });
+}
+
+/// Subscribes to the shared `WebSocket` connection provided by [`provide_websocket`] in a parent
+/// component. The connection is kept open as long as at least one subscriber exists.
+///
+/// ## Panics
+///
+/// Panics if no [`provide_websocket`] has been called in a parent component.
+pub fn use_websocket_context() -> WebSocketContext {
+    let context = expect_context::<WebSocketContext>();
+
+    let subscribers = Rc::clone(&context.subscribers);
+    subscribers.set(subscribers.get() + 1);
+
+    if subscribers.get() == 1
+        && context.immediate
+        && context.ready_state.get_untracked() == ConnectionReadyState::Closed
+    {
+        context.open();
+    }
+
+    on_cleanup({
+        let context = context.clone();
+
+        move || {
+            subscribers.set(subscribers.get().saturating_sub(1));
+
+            if subscribers.get() == 0 {
+                context.close();
+            }
+        }
+    });
+
+    context
+}
+
+/// Shared `WebSocket` connection returned by [`use_websocket_context`].
+#[derive(Clone)]
+pub struct WebSocketContext {
+    /// The current state of the `WebSocket` connection.
+    pub ready_state: Signal<ConnectionReadyState>,
+    /// Latest text message received from `WebSocket`.
+    pub message: Signal<Option<String>>,
+    /// Latest binary message received from `WebSocket`.
+    pub message_bytes: Signal<Option<Vec<u8>>>,
+    /// Number of reconnect attempts since the connection was last open.
+    pub retries: Signal<u64>,
+    open: Rc<dyn Fn()>,
+    close: Rc<dyn Fn()>,
+    send: Rc<dyn Fn(&str)>,
+    send_bytes: Rc<dyn Fn(Vec<u8>)>,
+    subscribers: Rc<Cell<usize>>,
+    immediate: bool,
+}
+
+impl WebSocketContext {
+    /// Opens the `WebSocket` connection
+    #[inline(always)]
+    pub fn open(&self) {
+        (self.open)()
+    }
+
+    /// Closes the `WebSocket` connection
+    #[inline(always)]
+    pub fn close(&self) {
+        (self.close)()
+    }
+
+    /// Sends `text` (string) based data
+    #[inline(always)]
+    pub fn send(&self, data: &str) {
+        (self.send)(data)
+    }
+
+    /// Sends binary data
+    #[inline(always)]
+    pub fn send_bytes(&self, data: Vec<u8>) {
+        (self.send_bytes)(data)
+    }
+}
+
 /// Options for [`use_websocket_with_options`].
 #[derive(DefaultBuilder)]
 pub struct UseWebSocketOptions {
@@ -515,16 +1033,34 @@ pub struct UseWebSocketOptions {
     on_error: Rc<dyn Fn(Event)>,
     /// `WebSocket` close callback.
     on_close: Rc<dyn Fn(CloseEvent)>,
-    /// Retry times. Defaults to 3.
+    /// Called when the connection was lost and all reconnect attempts have failed.
+    on_failed: Rc<dyn Fn()>,
+    /// Retry times. Set to `0` to disable reconnecting. Defaults to 3.
     reconnect_limit: u64,
-    /// Retry interval in ms. Defaults to 3000.
-    reconnect_interval: u64,
+    /// Delay before a reconnect attempt. Can be a fixed interval in ms or an exponential
+    /// backoff. Defaults to a fixed interval of 3000 ms.
+    #[builder(into)]
+    reconnect_interval: RetryDelay,
     /// If `true` the `WebSocket` connection will immediately be opened when calling this function.
     /// If `false` you have to manually call the `open` function.
     /// Defaults to `true`.
     immediate: bool,
     /// Sub protocols. See [MDN Docs](https://developer.mozilla.org/en-US/docs/Web/API/WebSocket/WebSocket#protocols).
     protocols: Option<Vec<String>>,
+    /// If set, a ping message is sent periodically to keep the connection alive and to detect
+    /// broken connections. Defaults to `None`.
+    #[builder(into)]
+    heartbeat: Option<WebSocketHeartbeat>,
+    /// Maximum number of messages that are buffered while the connection is not open.
+    /// Buffered messages are sent once the connection is (re-)established.
+    /// Defaults to `0` which disables buffering.
+    buffer_size: usize,
+    /// What happens when a message is sent while the buffer is full.
+    /// Defaults to [`WebSocketBufferOverflow::DropOldest`].
+    buffer_overflow: WebSocketBufferOverflow,
+    /// Called with the rejected message when the buffer is full and `buffer_overflow` is
+    /// [`WebSocketBufferOverflow::Error`].
+    on_buffer_overflow: Rc<dyn Fn(WebSocketMessage)>,
 }
 
 impl Default for UseWebSocketOptions {
@@ -535,10 +1071,55 @@ impl Default for UseWebSocketOptions {
             on_message_bytes: Rc::new(|_| {}),
             on_error: Rc::new(|_| {}),
             on_close: Rc::new(|_| {}),
+            on_failed: Rc::new(|| {}),
             reconnect_limit: 3,
-            reconnect_interval: 3000,
+            reconnect_interval: RetryDelay::Fixed(3000),
             immediate: true,
             protocols: Default::default(),
+            heartbeat: None,
+            buffer_size: 0,
+            buffer_overflow: WebSocketBufferOverflow::default(),
+            on_buffer_overflow: Rc::new(|_| {}),
+        }
+    }
+}
+
+/// Overflow policy of the send buffer of [`use_websocket`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum WebSocketBufferOverflow {
+    /// The oldest buffered message is dropped to make room for the new one.
+    #[default]
+    DropOldest,
+    /// The new message is dropped.
+    DropNewest,
+    /// The new message is rejected and passed to `on_buffer_overflow`.
+    Error,
+}
+
+/// Heartbeat options of [`UseWebSocketOptions`].
+#[derive(DefaultBuilder, Clone, Debug)]
+pub struct WebSocketHeartbeat {
+    /// The ping message that is sent. Defaults to `"ping"`.
+    #[builder(into)]
+    message: String,
+    /// If set, only this message is considered a pong and it's not passed on to `message`
+    /// and `on_message`. Otherwise every received message counts as pong. Defaults to `None`.
+    #[builder(into)]
+    pong_message: Option<String>,
+    /// Interval between pings in ms. Defaults to 30000.
+    interval: u64,
+    /// Time in ms to wait for a pong before the connection is considered broken and a
+    /// reconnect is triggered. Defaults to 10000.
+    pong_timeout: u64,
+}
+
+impl Default for WebSocketHeartbeat {
+    fn default() -> Self {
+        Self {
+            message: "ping".to_string(),
+            pong_message: None,
+            interval: 30_000,
+            pong_timeout: 10_000,
         }
     }
 }
@@ -558,6 +1139,8 @@ where
     pub message: Signal<Option<String>>,
     /// Latest binary message received from `WebSocket`.
     pub message_bytes: Signal<Option<Vec<u8>>>,
+    /// Number of reconnect attempts since the connection was last open.
+    pub retries: Signal<u64>,
     /// The `WebSocket` instance.
     pub ws: Option<WebSocket>,
     /// Opens the `WebSocket` connection
@@ -570,6 +1153,21 @@ where
     pub send_bytes: SendBytesFn,
 }
 
+fn send_message(web_socket: &WebSocket, message: &WebSocketMessage) {
+    let _ = match message {
+        WebSocketMessage::Text(text) => web_socket.send_with_str(text),
+        WebSocketMessage::Binary(bytes) => web_socket.send_with_u8_array(bytes),
+    };
+}
+
+#[cfg(not(feature = "ssr"))]
+fn detach_handlers(web_socket: &WebSocket) {
+    web_socket.set_onopen(None);
+    web_socket.set_onmessage(None);
+    web_socket.set_onerror(None);
+    web_socket.set_onclose(None);
+}
+
 fn normalize_url(url: &str) -> String {
     cfg_if! { if #[cfg(feature = "ssr")] {
         url.to_string()
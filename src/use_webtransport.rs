@@ -1,27 +1,26 @@
-use crate::core::ConnectionReadyState;
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::{ConnectionReadyState, RetryDelay};
+use crate::utils::BinCodec;
+use crate::{js, js_fut};
 use async_trait::async_trait;
 use default_struct_builder::DefaultBuilder;
-use js_sys::Reflect;
 use leptos::leptos_dom::helpers::TimeoutHandle;
 use leptos::*;
 use std::cell::{Cell, RefCell};
+use std::convert::Infallible;
 use std::rc::Rc;
 use std::time::Duration;
-use wasm_bindgen::prelude::*;
-use wasm_bindgen_futures::JsFuture;
-
-#[cfg(any(feature = "bincode", feature = "msgpack"))]
-use serde::{Deserialize, Serialize};
-
-#[cfg(feature = "msgpack")]
-use rmp_serde::{from_slice, to_vec};
 use thiserror::Error;
+use wasm_bindgen::prelude::*;
 use web_sys::WebTransportBidirectionalStream;
 
-#[cfg(feature = "bincode")]
-use bincode::serde::{decode_from_slice as from_slice, encode_to_vec as to_vec};
-
+/// Reactive [WebTransport](https://developer.mozilla.org/en-US/docs/Web/API/WebTransport_API).
+/// WebTransport is a low latency protocol on top of HTTP/3 that supports unreliable datagrams
+/// as well as reliable unidirectional and bidirectional streams.
 ///
+/// > This function requires `--cfg=web_sys_unstable_apis` to be activated as
+/// > [described in the wasm-bindgen guide](https://rustwasm.github.io/docs/wasm-bindgen/web-sys/unstable-apis.html).
 ///
 /// ## Demo
 ///
@@ -31,15 +30,59 @@ use bincode::serde::{decode_from_slice as from_slice, encode_to_vec as to_vec};
 ///
 /// ```
 /// # use leptos::*;
-/// # use leptos_use::use_webtransport;
+/// # use leptos_use::{use_webtransport, UseWebTransportReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let transport = use_webtransport("https://echo.webtransport.day");
+///
+/// let ready_state = transport.ready_state;
+/// let datagrams = transport.datagrams;
+///
+/// transport.send_datagrams(b"Hello World!");
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Streams
+///
+/// Streams are opened with `open_send_stream` (unidirectional) or `open_bidir_stream`
+/// (bidirectional). Streams that are opened by the server are passed to the callbacks
+/// `on_receive_stream` and `on_bidir_stream`.
+///
+/// The traits [`SendableStream`] and [`ReceivableStream`] provide methods to send and receive
+/// raw bytes or values encoded with a [`BinCodec`].
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_webtransport, SendableStream, ReceivableStream};
+/// # use leptos_use::utils::FromToBytesCodec;
 /// #
 /// # #[component]
 /// # fn Demo() -> impl IntoView {
-/// use_webtransport();
+/// let transport = use_webtransport("https://echo.webtransport.day");
+///
+/// spawn_local({
+///     let transport = transport.clone();
+///
+///     async move {
+///         if let Ok(stream) = transport.open_bidir_stream().await {
+///             stream.send::<u32, FromToBytesCodec>(&42);
+///
+///             let received = stream.receive::<u32, FromToBytesCodec>();
+///         }
+///     }
+/// });
 /// #
 /// # view! { }
 /// # }
 /// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server `ready_state` is always `ConnectionReadyState::Closed`, `datagrams` is always
+/// `None` and no connection is opened.
 pub fn use_webtransport(url: &str) -> UseWebTransportReturn {
     use_webtransport_with_options(url, UseWebTransportOptions::default())
 }
@@ -51,7 +94,7 @@ pub fn use_webtransport_with_options(
 ) -> UseWebTransportReturn {
     let UseWebTransportOptions {
         on_open,
-        // on_error,
+        on_error,
         on_close,
         on_receive_stream,
         on_bidir_stream,
@@ -78,100 +121,138 @@ pub fn use_webtransport_with_options(
     let reconnect = Rc::new({
         let reconnect_timer = Rc::clone(&reconnect_timer);
         let reconnect_count = Rc::clone(&reconnect_count);
+        let unmounted = Rc::clone(&unmounted);
 
         move || {
-            if reconnect_count.get() < reconnect_limit
-                && ready_state.get_untracked() == ConnectionReadyState::Open
-            {
-                reconnect_timer.set(
-                    set_timeout_with_handle(
-                        move || {
-                            if let Some(connect) = connect_ref.get_value() {
-                                connect();
-                                reconnect_count.set(reconnect_count.get() + 1);
-                            }
-                        },
-                        Duration::from_millis(reconnect_interval),
-                    )
-                    .ok(),
-                )
+            if unmounted.get() || reconnect_count.get() >= reconnect_limit {
+                return;
             }
+
+            reconnect_count.set(reconnect_count.get() + 1);
+            let delay = reconnect_interval.delay(reconnect_count.get().min(u32::MAX as u64) as u32);
+
+            reconnect_timer.set(
+                set_timeout_with_handle(
+                    move || {
+                        if let Some(connect) = connect_ref.get_value() {
+                            connect();
+                        }
+                    },
+                    Duration::from_millis(delay),
+                )
+                .ok(),
+            )
         }
     });
 
     connect_ref.set_value(Some(Rc::new({
+        let on_close = Rc::clone(&on_close);
         let transport = Rc::clone(&transport);
+        let datagrams_writer = Rc::clone(&datagrams_writer);
         let reconnect_timer = Rc::clone(&reconnect_timer);
-        let on_open = Rc::clone(&on_open);
-        let on_bidir_stream = Rc::clone(&on_bidir_stream);
-        let on_receive_stream = Rc::clone(&on_receive_stream);
+        let reconnect_count = Rc::clone(&reconnect_count);
+        let reconnect = Rc::clone(&reconnect);
+        let unmounted = Rc::clone(&unmounted);
 
         move || {
-            reconnect_timer.set(None);
+            if let Some(timer) = reconnect_timer.take() {
+                timer.clear();
+            }
 
-            if let Some(transport) = transport.borrow().as_ref() {
+            if let Some(transport) = transport.take() {
                 transport.close();
             }
-
-            let options = web_sys::WebTransportOptions::new();
-            transport.replace(Some(
-                web_sys::WebTransport::new_with_options(&url, &options).unwrap_throw(),
-            ));
+            datagrams_writer.replace(None);
+
+            let web_transport = match web_sys::WebTransport::new_with_options(
+                &url,
+                &web_sys::WebTransportOptions::new(),
+            ) {
+                Ok(web_transport) => web_transport,
+                Err(err) => {
+                    on_error(WebTransportError::FailedToConnect(err));
+                    return;
+                }
+            };
+            transport.replace(Some(web_transport.clone()));
 
             set_ready_state.set(ConnectionReadyState::Connecting);
 
-            spawn_local({
-                let transport = Rc::clone(&transport);
-                let on_open = Rc::clone(&on_open);
-                let on_bidir_stream = Rc::clone(&on_bidir_stream);
-                let on_receive_stream = Rc::clone(&on_receive_stream);
-
-                async move {
-                    let transport = transport.borrow();
-                    let transport = transport.as_ref().expect("Transport should be set");
-
-                    match js_fut!(transport.ready()).await {
-                        Ok(_) => {
-                            set_ready_state.set(ConnectionReadyState::Open);
-                            on_open();
-
-                            listen_to_stream(
-                                transport.incoming_bidirectional_streams(),
-                                move |value| {
-                                    let stream: web_sys::WebTransportBidirectionalStream =
-                                        value.unchecked_into();
-
-                                    if let Ok(stream) = create_bidir_stream(stream, ready_state) {
-                                        on_bidir_stream(stream);
-                                    }
-                                },
-                                || {},
-                            );
-                            listen_to_stream(
-                                transport.incoming_unidirectional_streams(),
-                                move |value| {
-                                    let stream: web_sys::ReadableStream = value.unchecked_into();
-
-                                    let (state, set_state, bytes) = create_state_and_bytes_signal(
-                                        stream.unchecked_into(),
-                                        ready_state,
-                                    );
-
-                                    on_receive_stream(ReceiveStream {
-                                        bytes,
-                                        state,
-                                        set_state,
-                                    });
-                                },
-                                || {},
-                            );
-                        }
-                        Err(e) => {
-                            // TODO : handle error?
-                            set_ready_state.set(ConnectionReadyState::Closed);
-                        }
+            let on_open = Rc::clone(&on_open);
+            let on_error = Rc::clone(&on_error);
+            let on_close = Rc::clone(&on_close);
+            let on_bidir_stream = Rc::clone(&on_bidir_stream);
+            let on_receive_stream = Rc::clone(&on_receive_stream);
+            let transport = Rc::clone(&transport);
+            let reconnect_count = Rc::clone(&reconnect_count);
+            let reconnect = Rc::clone(&reconnect);
+            let unmounted = Rc::clone(&unmounted);
+
+            spawn_local(async move {
+                let result = js_fut!(web_transport.ready()).await;
+
+                if unmounted.get() || !is_current(&transport, &web_transport) {
+                    return;
+                }
+
+                match result {
+                    Ok(_) => {
+                        reconnect_count.set(0);
+                        set_ready_state.set(ConnectionReadyState::Open);
+                        on_open();
+
+                        listen_to_stream(
+                            web_transport.incoming_bidirectional_streams(),
+                            move |value| {
+                                let stream: web_sys::WebTransportBidirectionalStream =
+                                    value.unchecked_into();
+
+                                if let Ok(stream) = create_bidir_stream(stream, ready_state) {
+                                    on_bidir_stream(stream);
+                                }
+                            },
+                            || {},
+                        );
+                        listen_to_stream(
+                            web_transport.incoming_unidirectional_streams(),
+                            move |value| {
+                                let stream: web_sys::ReadableStream = value.unchecked_into();
+
+                                let (state, set_state, bytes) =
+                                    create_state_and_bytes_signal(stream, ready_state);
+
+                                on_receive_stream(ReceiveStream {
+                                    bytes,
+                                    state,
+                                    set_state,
+                                });
+                            },
+                            || {},
+                        );
+                    }
+                    Err(err) => {
+                        set_ready_state.set(ConnectionReadyState::Closed);
+                        on_error(WebTransportError::FailedToConnect(err));
+                        reconnect();
+                        return;
                     }
                 }
+
+                let result = js_fut!(web_transport.closed()).await;
+
+                if unmounted.get() || !is_current(&transport, &web_transport) {
+                    return;
+                }
+
+                transport.replace(None);
+                set_ready_state.set(ConnectionReadyState::Closed);
+
+                if let Err(err) = result {
+                    on_error(WebTransportError::ConnectionLost(err));
+                }
+                on_close();
+
+                reconnect();
             });
         }
     })));
@@ -181,48 +262,40 @@ pub fn use_webtransport_with_options(
 
         move || {
             reconnect_count.set(0);
+
+            #[cfg(not(feature = "ssr"))]
             if let Some(connect) = connect_ref.get_value() {
                 connect();
             }
         }
     };
 
-    let on_closed = {
-        let reconnect = Rc::clone(&reconnect);
-        let unmounted = Rc::clone(&unmounted);
-
-        move || {
-            if unmounted.get() {
-                return;
-            }
-
-            // TODO
-            // reconnect();
-        }
-    };
-
     let close = {
         let transport = Rc::clone(&transport);
         let reconnect_count = Rc::clone(&reconnect_count);
+        let reconnect_timer = Rc::clone(&reconnect_timer);
+        let unmounted = Rc::clone(&unmounted);
 
         move || {
             reconnect_count.set(reconnect_limit);
 
-            if let Some(transport) = transport.take() {
-                transport.close();
+            if let Some(timer) = reconnect_timer.take() {
+                timer.clear();
+            }
+
+            if let Some(web_transport) = transport.take() {
+                web_transport.close();
                 set_ready_state.set(ConnectionReadyState::Closing);
 
-                spawn_local(async move {
-                    let result = js_fut!(transport.closed()).await;
-                    set_ready_state.set(ConnectionReadyState::Closed);
+                let on_close = Rc::clone(&on_close);
+                let unmounted = Rc::clone(&unmounted);
 
-                    on_closed();
+                spawn_local(async move {
+                    let _ = js_fut!(web_transport.closed()).await;
 
-                    match result {
-                        Ok(_) => {}
-                        Err(e) => {
-                            // TODO : handle error?
-                        }
+                    if !unmounted.get() {
+                        set_ready_state.set(ConnectionReadyState::Closed);
+                        on_close();
                     }
                 });
             }
@@ -259,6 +332,7 @@ pub fn use_webtransport_with_options(
 
     {
         let unmounted = Rc::clone(&unmounted);
+        let close = close.clone();
 
         on_cleanup(move || {
             unmounted.set(true);
@@ -275,25 +349,34 @@ pub fn use_webtransport_with_options(
         ready_state,
         datagrams,
         datagrams_writer,
+        open: Rc::new(open),
+        close: Rc::new(close),
     }
 }
 
+fn is_current(
+    transport: &Rc<RefCell<Option<web_sys::WebTransport>>>,
+    web_transport: &web_sys::WebTransport,
+) -> bool {
+    transport.borrow().as_ref() == Some(web_transport)
+}
+
 fn get_or_create_datagrams_writer(
     datagrams_writer: Rc<RefCell<Option<web_sys::WritableStreamDefaultWriter>>>,
     transport: &web_sys::WebTransport,
-) -> web_sys::WritableStreamDefaultWriter {
+) -> Result<web_sys::WritableStreamDefaultWriter, WebTransportError> {
     let writer = datagrams_writer.borrow().clone();
 
     if let Some(writer) = writer {
-        writer
+        Ok(writer)
     } else {
         let writer = transport
             .datagrams()
             .writable()
             .get_writer()
-            .expect("should be able to get the writer");
+            .map_err(WebTransportError::FailedToOpenWriter)?;
         datagrams_writer.replace(Some(writer.clone()));
-        writer
+        Ok(writer)
     }
 }
 
@@ -323,15 +406,13 @@ fn lazy_initialize_reader(
     on_value: impl Fn(JsValue) + 'static,
     on_done: impl Fn() + 'static,
 ) {
-    if ready_state.get() == ConnectionReadyState::Open {
-        if !initialized.get() {
-            initialized.set(true);
+    if ready_state.get() == ConnectionReadyState::Open && !initialized.get() {
+        initialized.set(true);
 
-            listen_to_stream(get_readable_stream(), on_value, move || {
-                initialized.set(false);
-                on_done();
-            });
-        }
+        listen_to_stream(get_readable_stream(), on_value, move || {
+            initialized.set(false);
+            on_done();
+        });
     }
 }
 
@@ -344,28 +425,19 @@ fn listen_to_stream(
         readable_stream.get_reader().unchecked_into();
 
     spawn_local(async move {
-        loop {
-            let result = js_fut!(reader.read()).await;
-            match result {
-                Ok(result) => {
-                    let done = js!(result["done"])
-                        .expect("done should always be there")
-                        .as_bool()
-                        .unwrap_or(true);
-
-                    if done {
-                        // TODO : close connection?
-                        break;
-                    }
-
-                    let value = js!(result["value"]).expect("if not done there should be a value");
+        // reading fails when the stream or the connection is closed
+        while let Ok(result) = js_fut!(reader.read()).await {
+            let done = js!(result["done"])
+                .ok()
+                .and_then(|done| done.as_bool())
+                .unwrap_or(true);
+
+            if done {
+                break;
+            }
 
-                    on_value(value);
-                }
-                Err(..) => {
-                    // TODO : error handling?
-                    break;
-                }
+            if let Ok(value) = js!(result["value"]) {
+                on_value(value);
             }
         }
 
@@ -380,7 +452,7 @@ pub struct UseWebTransportOptions {
     on_open: Rc<dyn Fn()>,
 
     /// Error callback.
-    // TODO : ? on_error: Rc<dyn Fn(WebTransportError)>,
+    on_error: Rc<dyn Fn(WebTransportError)>,
 
     /// Callback when `WebTransport` is closed.
     on_close: Rc<dyn Fn()>,
@@ -391,13 +463,15 @@ pub struct UseWebTransportOptions {
     /// Callback when the server opens a bidirectional stream.
     on_bidir_stream: Rc<dyn Fn(BidirStream)>,
 
-    /// Retry times. Defaults to 3.
+    /// Retry times. Set to `0` to disable reconnecting. Defaults to 3.
     reconnect_limit: u64,
 
-    /// Retry interval in ms. Defaults to 3000.
-    reconnect_interval: u64,
+    /// Delay before a reconnect attempt. Can be a fixed interval in ms or an exponential
+    /// backoff. Defaults to a fixed interval of 3000 ms.
+    #[builder(into)]
+    reconnect_interval: RetryDelay,
 
-    /// If `true` the `WebSocket` connection will immediately be opened when calling this function.
+    /// If `true` the `WebTransport` connection will immediately be opened when calling this function.
     /// If `false` you have to manually call the `open` function.
     /// Defaults to `true`.
     immediate: bool,
@@ -407,12 +481,12 @@ impl Default for UseWebTransportOptions {
     fn default() -> Self {
         Self {
             on_open: Rc::new(|| {}),
-            // on_error: Rc::new(|_| {}),
+            on_error: Rc::new(|_| {}),
             on_close: Rc::new(|| {}),
             on_receive_stream: Rc::new(|_| {}),
             on_bidir_stream: Rc::new(|_| {}),
             reconnect_limit: 3,
-            reconnect_interval: 3000,
+            reconnect_interval: RetryDelay::Fixed(3000),
             immediate: true,
         }
     }
@@ -446,7 +520,7 @@ pub trait SendableStream: CloseableStream {
 
     /// Send data in the form of bytes ignoring potential errors
     fn send_bytes(&self, data: &[u8]) {
-        if self.state().get() == StreamState::Open {
+        if self.state().get_untracked() == StreamState::Open {
             let arr = js_sys::Uint8Array::from(data);
             let _ = self.writer().write_with_chunk(&arr);
         }
@@ -454,48 +528,90 @@ pub trait SendableStream: CloseableStream {
 
     /// Send data in the form of bytes asynchronously with a result providing potential errors
     async fn send_bytes_async(&self, data: &[u8]) -> Result<(), SendError> {
-        if self.state().get() != StreamState::Open {
+        if self.state().get_untracked() != StreamState::Open {
             return Err(SendError::StreamNotOpen);
         }
 
         let arr = js_sys::Uint8Array::from(data);
-        let _ = js_fut!(self.writer().write_with_chunk(&arr))
+        js_fut!(self.writer().write_with_chunk(&arr))
             .await
-            .map_err(|e| SendError::FailedToWrite(e));
+            .map_err(SendError::FailedToWrite)?;
 
         Ok(())
     }
 
-    #[cfg(any(feature = "msgpack", feature = "bincode"))]
-    /// Send data in the form of a serializable object ignoring potential errors
-    /// Requires the feature `msgpack` or `bincode`
-    fn send<T: Serialize>(&self, data: &T) {
-        self.send_bytes(
-            to_vec(data)
-                .expect("Serialization should not fail")
-                .as_slice(),
-        );
+    /// Send a value encoded with the codec `C` ignoring potential errors
+    fn send<T, C>(&self, data: &T)
+    where
+        C: BinCodec<T> + Default,
+    {
+        if let Ok(bytes) = C::default().encode(data) {
+            self.send_bytes(&bytes);
+        }
     }
 
-    #[cfg(any(feature = "msgpack", feature = "bincode"))]
-    /// Send data in the form of a serializable object asynchronously with a result providing potential errors
-    /// Requires the feature `msgpack` or `bincode`
-    async fn send_async<T: Serialize>(&self, data: &T) -> Result<(), SendError> {
-        let serialized = to_vec(data)?;
-        self.send_bytes_async(&serialized).await
+    /// Send a value encoded with the codec `C` asynchronously with a result providing potential errors
+    async fn send_async<T, C>(&self, data: &T) -> Result<(), SendError<C::Error>>
+    where
+        C: BinCodec<T> + Default,
+    {
+        let bytes = C::default().encode(data).map_err(SendError::Encode)?;
+
+        self.send_bytes_async(&bytes)
+            .await
+            .map_err(|err| match err {
+                SendError::StreamNotOpen => SendError::StreamNotOpen,
+                SendError::FailedToWrite(err) => SendError::FailedToWrite(err),
+            })
     }
 }
 
-#[async_trait(?Send)]
 /// Trait to receive data in a stream
 pub trait ReceivableStream: CloseableStream {
-    #[cfg(any(feature = "msgpack", feature = "bincode"))]
-    /// Receive data in the form of a serializable object ignoring potential errors
-    fn receive<T: for<'a> Deserialize<'a>>(&self) -> Signal<Option<T>>;
+    /// Latest bytes received in the stream
+    fn bytes(&self) -> Signal<Option<Vec<u8>>>;
+
+    /// Latest value received in the stream decoded with the codec `C`. Values that can't be
+    /// decoded are ignored.
+    fn receive<T, C>(&self) -> Signal<Option<T>>
+    where
+        T: 'static,
+        C: BinCodec<T> + Default,
+    {
+        let state = self.state();
+        let bytes = self.bytes();
+        let codec = C::default();
+
+        Signal::derive(move || {
+            if state.get() != StreamState::Open {
+                None
+            } else {
+                bytes
+                    .get()
+                    .and_then(|bytes| codec.decode(bytes.as_slice()).ok())
+            }
+        })
+    }
 
-    #[cfg(any(feature = "msgpack", feature = "bincode"))]
-    /// Receive data in the form of a serializable object asynchronously with a result providing potential errors
-    fn try_receive<T: for<'a> Deserialize<'a>>(&self) -> Signal<Option<Result<T, ReceiveError>>>;
+    /// Latest value received in the stream decoded with the codec `C` including decoding errors.
+    fn try_receive<T, C>(&self) -> Signal<Option<Result<T, C::Error>>>
+    where
+        T: 'static,
+        C: BinCodec<T> + Default,
+        C::Error: 'static,
+    {
+        let state = self.state();
+        let bytes = self.bytes();
+        let codec = C::default();
+
+        Signal::derive(move || {
+            if state.get() != StreamState::Open {
+                None
+            } else {
+                bytes.get().map(|bytes| codec.decode(bytes.as_slice()))
+            }
+        })
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -508,15 +624,12 @@ pub struct SendStream {
 
 #[derive(Clone, Debug)]
 /// Stream for receiving data
-#[allow(dead_code)]
 pub struct ReceiveStream {
     pub bytes: Signal<Option<Vec<u8>>>,
     state: Signal<StreamState>,
     set_state: WriteSignal<StreamState>,
 }
 
-// TODO : implement ReceiveStream
-
 #[derive(Clone, Debug)]
 /// Bidirectional stream for sending and receiving data
 pub struct BidirStream {
@@ -528,35 +641,10 @@ pub struct BidirStream {
 
 macro_rules! impl_receivable_stream {
     ($ty:ty) => {
-        impl BidirStream {
-            #[cfg(any(feature = "msgpack", feature = "bincode"))]
-            pub fn receive<T: for<'a> Deserialize<'a>>(&self) -> Signal<Option<T>> {
-                let bytes = self.bytes;
-
-                Signal::derive(move || {
-                    if self.state.get() != StreamState::Open {
-                        None
-                    } else {
-                        bytes
-                            .get()
-                            .and_then(|bytes| from_slice(bytes.as_slice()).ok())
-                    }
-                })
-            }
-
-            #[cfg(any(feature = "msgpack", feature = "bincode"))]
-            pub fn try_receive<T: for<'a> Deserialize<'a>>(
-                &self,
-            ) -> Signal<Option<Result<T, ReceiveError>>> {
-                let bytes = self.bytes;
-
-                Signal::derive(move || {
-                    if self.state.get() != StreamState::Open {
-                        None
-                    } else {
-                        bytes.get().map(|bytes| Ok(from_slice(bytes.as_slice())?))
-                    }
-                })
+        impl ReceivableStream for $ty {
+            #[inline(always)]
+            fn bytes(&self) -> Signal<Option<Vec<u8>>> {
+                self.bytes
             }
         }
     };
@@ -591,21 +679,19 @@ macro_rules! impl_closable_stream {
 
             #[inline(always)]
             fn close(&self) {
-                spawn_local(async {
-                    self.close_async().await.ok();
+                let stream = self.clone();
+
+                spawn_local(async move {
+                    stream.close_async().await.ok();
                 })
             }
 
             async fn close_async(&self) -> Result<(), WebTransportError> {
-                let _ = js_fut!(self.writer.close()).await.map_err(|e| {
-                    let error = WebTransportError::OnCloseWriter(e);
-                    self.set_state.set(StreamState::Closed);
-                    error
-                })?;
+                let result = js_fut!(self.writer.close()).await;
 
                 self.set_state.set(StreamState::Closed);
 
-                Ok(())
+                result.map(|_| ()).map_err(WebTransportError::OnCloseWriter)
             }
         }
     };
@@ -614,11 +700,31 @@ macro_rules! impl_closable_stream {
 impl_closable_stream!(SendStream);
 impl_closable_stream!(BidirStream);
 
+#[async_trait(?Send)]
+impl CloseableStream for ReceiveStream {
+    #[inline(always)]
+    fn state(&self) -> Signal<StreamState> {
+        self.state
+    }
+
+    /// Stops receiving data from this stream
+    fn close(&self) {
+        self.set_state.set(StreamState::Closed);
+    }
+
+    async fn close_async(&self) -> Result<(), WebTransportError> {
+        self.close();
+        Ok(())
+    }
+}
+
 /// Return type of [`use_webtransport`].
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct UseWebTransportReturn {
     transport: Rc<RefCell<Option<web_sys::WebTransport>>>,
     datagrams_writer: Rc<RefCell<Option<web_sys::WritableStreamDefaultWriter>>>,
+    open: Rc<dyn Fn()>,
+    close: Rc<dyn Fn()>,
 
     /// The current state of the `WebTransport` connection.
     pub ready_state: Signal<ConnectionReadyState>,
@@ -629,59 +735,80 @@ pub struct UseWebTransportReturn {
 
 impl UseWebTransportReturn {
     /// Access to the underlying `WebTransport`
-    pub async fn transport(&self) -> Option<web_sys::WebTransport> {
+    pub fn transport(&self) -> Option<web_sys::WebTransport> {
         self.transport.borrow().clone()
     }
 
-    /// Sends binary data through the datagrams stream
+    /// Opens the `WebTransport` connection. Closes a currently open connection first.
+    pub fn open(&self) {
+        (self.open)()
+    }
+
+    /// Closes the `WebTransport` connection.
+    pub fn close(&self) {
+        (self.close)()
+    }
+
+    /// Sends binary data through the datagrams stream ignoring potential errors
     pub fn send_datagrams(&self, data: &[u8]) {
         if let Some(transport) = self.transport.borrow().as_ref() {
-            let writer =
-                get_or_create_datagrams_writer(Rc::clone(&self.datagrams_writer), transport);
-
-            let arr = js_sys::Uint8Array::from(data);
-            let _ = writer.write_with_chunk(&arr);
+            if let Ok(writer) =
+                get_or_create_datagrams_writer(Rc::clone(&self.datagrams_writer), transport)
+            {
+                let arr = js_sys::Uint8Array::from(data);
+                let _ = writer.write_with_chunk(&arr);
+            }
         }
     }
 
-    // TODO : send_datagrams_async
+    /// Sends binary data through the datagrams stream asynchronously with a result providing
+    /// potential errors
+    pub async fn send_datagrams_async(&self, data: &[u8]) -> Result<(), WebTransportError> {
+        let writer = {
+            let transport = self.transport.borrow();
+            let transport = transport.as_ref().ok_or(WebTransportError::NotConnected)?;
+            get_or_create_datagrams_writer(Rc::clone(&self.datagrams_writer), transport)?
+        };
+
+        let arr = js_sys::Uint8Array::from(data);
+        js_fut!(writer.write_with_chunk(&arr))
+            .await
+            .map_err(WebTransportError::FailedToWrite)?;
+
+        Ok(())
+    }
 
     /// Open a unidirectional send stream
     pub async fn open_send_stream(&self) -> Result<SendStream, WebTransportError> {
-        if let Some(transport) = self.transport.borrow().as_ref() {
-            let result = js_fut!(transport.create_unidirectional_stream())
-                .await
-                .map_err(|e| WebTransportError::FailedToOpenStream(e))?;
-            let stream: web_sys::WritableStream = result.unchecked_into();
-            let writer = stream
-                .get_writer()
-                .map_err(|e| WebTransportError::FailedToOpenWriter(e))?;
-
-            let (state, set_state) = create_signal(StreamState::Open);
-
-            Ok(SendStream {
-                writer,
-                state: state.into(),
-                set_state,
-            })
-        } else {
-            Err(WebTransportError::NotConnected)
-        }
+        let transport = self.transport().ok_or(WebTransportError::NotConnected)?;
+
+        let result = js_fut!(transport.create_unidirectional_stream())
+            .await
+            .map_err(WebTransportError::FailedToOpenStream)?;
+        let stream: web_sys::WritableStream = result.unchecked_into();
+        let writer = stream
+            .get_writer()
+            .map_err(WebTransportError::FailedToOpenWriter)?;
+
+        let (state, set_state) = create_signal(StreamState::Open);
+
+        Ok(SendStream {
+            writer,
+            state: state.into(),
+            set_state,
+        })
     }
 
     /// Open a bidirectional stream
     pub async fn open_bidir_stream(&self) -> Result<BidirStream, WebTransportError> {
-        if let Some(transport) = self.transport.borrow().as_ref() {
-            let result = js_fut!(transport.create_bidirectional_stream())
-                .await
-                .map_err(|e| WebTransportError::FailedToOpenStream(e))?;
-            let stream: web_sys::WebTransportBidirectionalStream = result.unchecked_into();
-            let ready_state = self.ready_state;
-
-            create_bidir_stream(stream, ready_state)
-        } else {
-            Err(WebTransportError::NotConnected)
-        }
+        let transport = self.transport().ok_or(WebTransportError::NotConnected)?;
+
+        let result = js_fut!(transport.create_bidirectional_stream())
+            .await
+            .map_err(WebTransportError::FailedToOpenStream)?;
+        let stream: web_sys::WebTransportBidirectionalStream = result.unchecked_into();
+
+        create_bidir_stream(stream, self.ready_state)
     }
 }
 
@@ -699,15 +826,13 @@ fn create_state_and_bytes_signal(
         let reader_initialized = Rc::new(Cell::new(false));
         let (message_signal, set_message) = create_signal(None::<Vec<u8>>);
 
-        let stream = stream.clone();
-
         move || {
             let stream = stream.clone();
 
             lazy_initialize_u8_reader(
                 ready_state,
                 Rc::clone(&reader_initialized),
-                move || stream.clone().unchecked_into(),
+                move || stream.clone(),
                 set_message,
                 move || {
                     set_state.set(StreamState::Closed);
@@ -728,7 +853,7 @@ fn create_bidir_stream(
     let writer = stream
         .writable()
         .get_writer()
-        .map_err(|e| WebTransportError::FailedToOpenWriter(e))?;
+        .map_err(WebTransportError::FailedToOpenWriter)?;
 
     let (state, set_state, bytes) =
         create_state_and_bytes_signal(stream.readable().unchecked_into(), ready_state);
@@ -748,45 +873,29 @@ fn create_bidir_stream(
 pub enum WebTransportError {
     #[error("The `WebTransport` is not connected yet. Call `open` first.")]
     NotConnected,
+    #[error("Failed to connect: {0:?}")]
+    FailedToConnect(JsValue),
+    #[error("Connection lost: {0:?}")]
+    ConnectionLost(JsValue),
     #[error("Failed to open stream: {0:?}")]
     FailedToOpenStream(JsValue),
     #[error("Failed to open writer: {0:?}")]
     FailedToOpenWriter(JsValue),
-    #[error("Failed to open reader: {0:?}")]
-    FailedToOpenReader(JsValue),
-    #[error("Failed to read from stream: {0:?}")]
-    FailedToRead(JsValue),
+    #[error("Failed to write: {0:?}")]
+    FailedToWrite(JsValue),
     #[error("Failed to close writer: {0:?}")]
     OnCloseWriter(JsValue),
-    #[error("Failed to close reader: {0:?}")]
-    OnCloseReader(JsValue),
 }
 
-/// Error enum for [`SendStream::send`]
+/// Error enum for [`SendableStream::send_async`] and [`SendableStream::send_bytes_async`]
 #[derive(Error, Debug)]
-pub enum SendError {
+pub enum SendError<E = Infallible> {
     #[error("Stream is not open")]
     StreamNotOpen,
 
     #[error("Failed to write to stream")]
     FailedToWrite(JsValue),
 
-    #[cfg(feature = "bincode")]
-    #[error("Serialization failed: {0}")]
-    SerializationFailed(#[from] bincode::Error),
-
-    #[cfg(feature = "msgpack")]
-    #[error("Serialization failed: {0}")]
-    SerializationFailed(#[from] rmp_serde::encode::Error),
-}
-
-#[derive(Error, Debug)]
-pub enum ReceiveError {
-    #[cfg(feature = "bincode")]
-    #[error("Serialization failed: {0}")]
-    DeserializationFailed(#[from] bincode::Error),
-
-    #[cfg(feature = "msgpack")]
-    #[error("Serialization failed: {0}")]
-    DeserializationFailed(#[from] rmp_serde::decode::Error),
+    #[error("Failed to encode value")]
+    Encode(E),
 }
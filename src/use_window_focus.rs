@@ -26,6 +26,28 @@ use leptos::*;
 /// # }
 /// ```
 ///
+/// This is useful for example to mark new messages as read only while the user is actually
+/// looking at the window.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::use_window_focus;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let focused = use_window_focus();
+/// let (unread, set_unread) = create_signal(0);
+///
+/// create_effect(move |_| {
+///     if focused.get() && unread.get() > 0 {
+///         set_unread.set(0); // mark all messages as read
+///     }
+/// });
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
 /// On the server this returns a `Signal` that is always `true`.
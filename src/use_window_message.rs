@@ -0,0 +1,249 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::utils::StringCodec;
+use crate::{use_event_listener, use_window};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use std::rc::Rc;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+
+/// Reactive [`window.postMessage`](https://developer.mozilla.org/en-US/docs/Web/API/Window/postMessage)
+/// communication.
+///
+/// Listens to `message` events on `window` and decodes the received data with a codec. This is
+/// useful to talk to an `iframe` that is embedded in your page or to the host page when your app
+/// runs inside an `iframe`.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_window_message)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_window_message, UseWindowMessageReturn};
+/// # use leptos_use::utils::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWindowMessageReturn {
+///     data,
+///     origin,
+///     post_to,
+///     ..
+/// } = use_window_message::<String, FromToStringCodec>();
+///
+/// // send a message to the host page
+/// if let Some(parent) = window().parent().ok().flatten() {
+///     post_to(&parent, &"Hello".to_string(), "https://host.example.com");
+/// }
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ### Origin Filtering
+///
+/// By default only messages from the same origin as the page are accepted. Messages from other
+/// origins are ignored. To receive messages from other origins you have to list them explicitly.
+/// `"*"` accepts messages from any origin but you should only use it if you don't
+/// rely on the sender of the messages.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_window_message_with_options, UseWindowMessageOptions};
+/// # use leptos_use::utils::FromToStringCodec;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let message = use_window_message_with_options::<String, FromToStringCodec>(
+///     UseWindowMessageOptions::default()
+///         .allowed_origins(vec!["https://widget.example.com".to_string()]),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// Just like with [`use_broadcast_channel`] you can use any [`StringCodec`], e.g. the
+/// [`JsonCodec`](crate::utils::JsonCodec) with the feature `serde` enabled. Messages that
+/// are not strings are ignored.
+///
+/// ## Server-Side Rendering
+///
+/// On the server no messages are received and `post_to` does nothing.
+pub fn use_window_message<T, C>(
+) -> UseWindowMessageReturn<T, impl Fn(&web_sys::Window, &T, &str) + Clone, C::Error>
+where
+    T: Clone + 'static,
+    C: StringCodec<T> + Default + Clone,
+{
+    use_window_message_with_options::<T, C>(UseWindowMessageOptions::default())
+}
+
+/// Version of [`use_window_message`] that takes a `UseWindowMessageOptions`. See [`use_window_message`] for how to use.
+pub fn use_window_message_with_options<T, C>(
+    options: UseWindowMessageOptions<T>,
+) -> UseWindowMessageReturn<T, impl Fn(&web_sys::Window, &T, &str) + Clone, C::Error>
+where
+    T: Clone + 'static,
+    C: StringCodec<T> + Default + Clone,
+{
+    let UseWindowMessageOptions {
+        allowed_origins,
+        on_message,
+    } = options;
+
+    let (data, set_data) = create_signal(None::<T>);
+    let (origin, set_origin) = create_signal(None::<String>);
+    let (error, set_error) = create_signal(None::<UseWindowMessageError<C::Error>>);
+
+    let codec = C::default();
+
+    let post_to = {
+        let codec = codec.clone();
+
+        move |target: &web_sys::Window, value: &T, target_origin: &str| {
+            #[cfg(not(feature = "ssr"))]
+            match codec.encode(value) {
+                Ok(msg) => {
+                    if let Err(err) = target.post_message(&msg.into(), target_origin) {
+                        set_error.set(Some(UseWindowMessageError::PostMessage(err)));
+                    }
+                }
+                Err(err) => set_error.set(Some(UseWindowMessageError::Encode(err))),
+            }
+        }
+    };
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let own_origin = window().location().origin().unwrap_or_default();
+
+        let _ = use_event_listener(use_window(), ev::message, move |event| {
+            let event_origin = event.origin();
+
+            if !is_origin_allowed(&event_origin, &allowed_origins, &own_origin) {
+                return;
+            }
+
+            let Some(message) = event.data().as_string() else {
+                return;
+            };
+
+            match codec.decode(message) {
+                Ok(value) => {
+                    #[cfg(debug_assertions)]
+                    let prev = SpecialNonReactiveZone::enter();
+
+                    on_message(value.clone(), event_origin.clone());
+
+                    #[cfg(debug_assertions)]
+                    SpecialNonReactiveZone::exit(prev);
+
+                    set_origin.set(Some(event_origin));
+                    set_data.set(Some(value));
+                }
+                Err(err) => set_error.set(Some(UseWindowMessageError::Decode(err))),
+            }
+        });
+    }
+
+    UseWindowMessageReturn {
+        data: data.into(),
+        origin: origin.into(),
+        error: error.into(),
+        post_to,
+    }
+}
+
+fn is_origin_allowed(origin: &str, allowed_origins: &[String], own_origin: &str) -> bool {
+    if allowed_origins.is_empty() {
+        origin == own_origin
+    } else {
+        allowed_origins
+            .iter()
+            .any(|allowed| allowed == "*" || allowed == origin)
+    }
+}
+
+/// Options for [`use_window_message_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseWindowMessageOptions<T>
+where
+    T: 'static,
+{
+    /// Origins that messages are accepted from. `"*"` accepts messages from any origin.
+    /// If empty, only messages from the same origin as the page are accepted.
+    /// Defaults to an empty list.
+    allowed_origins: Vec<String>,
+
+    /// Called with every accepted message and the origin it was sent from.
+    on_message: Rc<dyn Fn(T, String)>,
+}
+
+impl<T> Default for UseWindowMessageOptions<T> {
+    fn default() -> Self {
+        Self {
+            allowed_origins: vec![],
+            on_message: Rc::new(|_, _| {}),
+        }
+    }
+}
+
+/// Return type of [`use_window_message`].
+pub struct UseWindowMessageReturn<T, PostFn, Err>
+where
+    T: 'static,
+    PostFn: Fn(&web_sys::Window, &T, &str) + Clone,
+    Err: 'static,
+{
+    /// Latest accepted message.
+    pub data: Signal<Option<T>>,
+
+    /// Origin of the latest accepted message.
+    pub origin: Signal<Option<String>>,
+
+    /// Latest error that occurred while encoding, decoding or posting a message.
+    pub error: Signal<Option<UseWindowMessageError<Err>>>,
+
+    /// Posts a message to the given window. The last argument is the origin the target
+    /// window must have for the message to be delivered; `"*"` delivers it to any origin.
+    pub post_to: PostFn,
+}
+
+#[derive(Debug, Error, Clone)]
+pub enum UseWindowMessageError<Err> {
+    #[error("failed to post message")]
+    PostMessage(JsValue),
+    #[error("failed to encode value")]
+    Encode(Err),
+    #[error("failed to decode value")]
+    Decode(Err),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_origin_allowed() {
+        let own = "https://app.example.com";
+
+        assert!(is_origin_allowed(own, &[], own));
+        assert!(!is_origin_allowed("https://evil.example.com", &[], own));
+
+        let allowed = vec!["https://widget.example.com".to_string()];
+        assert!(is_origin_allowed(
+            "https://widget.example.com",
+            &allowed,
+            own
+        ));
+        assert!(!is_origin_allowed(own, &allowed, own));
+
+        let any = vec!["*".to_string()];
+        assert!(is_origin_allowed("https://evil.example.com", &any, own));
+    }
+}
@@ -1,8 +1,13 @@
-#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports))]
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
 
-use crate::{use_event_listener_with_options, use_window, UseEventListenerOptions};
+use crate::core::Directions;
+use crate::{
+    use_debounce_fn, use_event_listener_with_options, use_window, ScrollBehavior,
+    UseEventListenerOptions,
+};
 use cfg_if::cfg_if;
-use leptos::ev::scroll;
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::{scroll, scrollend};
 use leptos::*;
 
 /// Reactive window scroll.
@@ -25,10 +30,72 @@ use leptos::*;
 /// # }
 /// ```
 ///
+/// ### Setting Scroll Position and Scroll State
+///
+/// [`use_window_scroll_with_options`] returns a [`UseWindowScrollReturn`]. Call `set_x` or `set_y`
+/// to scroll the window to that position. `is_scrolling` is `true` while the window is scrolled
+/// and `directions` contains the directions in which it is currently scrolled. It is reset when
+/// scrolling stops.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_window_scroll_with_options, UseWindowScrollOptions, UseWindowScrollReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWindowScrollReturn {
+///     set_y,
+///     is_scrolling,
+///     directions,
+///     ..
+/// } = use_window_scroll_with_options(UseWindowScrollOptions::default());
+///
+/// view! {
+///     <button on:click=move |_| set_y(0.0)>"Back to top"</button>
+/// }
+/// # }
+/// ```
+///
+/// ### Smooth Scrolling
+///
+/// Set `behavior` to `ScrollBehavior::Smooth` to scroll smoothly. It can also be a `Signal`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_window_scroll_with_options, ScrollBehavior, UseWindowScrollOptions, UseWindowScrollReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWindowScrollReturn { set_y, .. } = use_window_scroll_with_options(
+///     UseWindowScrollOptions::default().behavior(ScrollBehavior::Smooth),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
 /// ## Server-Side Rendering
 ///
-/// On the server this returns `Signal`s that are always `0.0`.
+/// On the server this returns `Signal`s that are always `0.0` and setters that do nothing.
+///
+/// ## See also
+///
+/// * [`use_scroll`]
 pub fn use_window_scroll() -> (Signal<f64>, Signal<f64>) {
+    let UseWindowScrollReturn { x, y, .. } =
+        use_window_scroll_with_options(UseWindowScrollOptions::default());
+
+    (x, y)
+}
+
+/// Version of [`use_window_scroll`] that takes a `UseWindowScrollOptions` and returns a
+/// [`UseWindowScrollReturn`] to set the scroll position and to track the scroll state.
+/// See [`use_window_scroll`] for how to use.
+pub fn use_window_scroll_with_options(
+    options: UseWindowScrollOptions,
+) -> UseWindowScrollReturn<impl Fn(f64) + Clone, impl Fn(f64) + Clone> {
+    let UseWindowScrollOptions { behavior, idle } = options;
+
     cfg_if! { if #[cfg(feature = "ssr")] {
         let initial_x = 0.0;
         let initial_y = 0.0;
@@ -38,18 +105,146 @@ pub fn use_window_scroll() -> (Signal<f64>, Signal<f64>) {
     }}
     let (x, set_x) = create_signal(initial_x);
     let (y, set_y) = create_signal(initial_y);
+    let (is_scrolling, set_is_scrolling) = create_signal(false);
+    let (directions, set_directions) = create_signal(Directions::default());
+
+    let on_stop = move || {
+        if !is_scrolling.get_untracked() {
+            return;
+        }
+
+        set_is_scrolling.set(false);
+        set_directions.set(Directions::default());
+    };
+
+    let on_stop_debounced = use_debounce_fn(on_stop, idle);
 
     let _ = use_event_listener_with_options(
         use_window(),
         scroll,
         move |_| {
-            set_x.set(window().scroll_x().unwrap_or_default());
-            set_y.set(window().scroll_y().unwrap_or_default());
+            let new_x = window().scroll_x().unwrap_or_default();
+            let new_y = window().scroll_y().unwrap_or_default();
+
+            set_directions.set(scroll_directions(
+                (x.get_untracked(), y.get_untracked()),
+                (new_x, new_y),
+            ));
+            set_x.set(new_x);
+            set_y.set(new_y);
+            set_is_scrolling.set(true);
+
+            on_stop_debounced();
         },
         UseEventListenerOptions::default()
             .capture(false)
             .passive(true),
     );
 
-    (x.into(), y.into())
+    let _ = use_event_listener_with_options(
+        use_window(),
+        scrollend,
+        move |_| on_stop(),
+        UseEventListenerOptions::default()
+            .capture(false)
+            .passive(true),
+    );
+
+    let scroll_to = move |x: Option<f64>, y: Option<f64>| {
+        #[cfg(not(feature = "ssr"))]
+        {
+            let mut scroll_options = web_sys::ScrollToOptions::new();
+            scroll_options.behavior(behavior.get_untracked().into());
+
+            if let Some(x) = x {
+                scroll_options.left(x);
+            }
+            if let Some(y) = y {
+                scroll_options.top(y);
+            }
+
+            window().scroll_to_with_scroll_to_options(&scroll_options);
+        }
+    };
+
+    UseWindowScrollReturn {
+        x: x.into(),
+        set_x: move |x| scroll_to(Some(x), None),
+        y: y.into(),
+        set_y: move |y| scroll_to(None, Some(y)),
+        is_scrolling: is_scrolling.into(),
+        directions: directions.into(),
+    }
+}
+
+/// The directions in which the window was scrolled from `prev` to `current` (both `(x, y)`).
+fn scroll_directions(prev: (f64, f64), current: (f64, f64)) -> Directions {
+    Directions {
+        left: current.0 < prev.0,
+        right: current.0 > prev.0,
+        top: current.1 < prev.1,
+        bottom: current.1 > prev.1,
+    }
+}
+
+/// Options for [`use_window_scroll_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseWindowScrollOptions {
+    /// When calling `set_x` or `set_y` this specifies the scroll behaviour.
+    /// Can be `Auto` (= not smooth) or `Smooth`. Defaults to `Auto`.
+    #[builder(into)]
+    behavior: MaybeSignal<ScrollBehavior>,
+
+    /// Milliseconds without scroll events after which scrolling is considered to have stopped
+    /// if the browser doesn't fire `scrollend`. Defaults to 200.
+    idle: f64,
+}
+
+impl Default for UseWindowScrollOptions {
+    fn default() -> Self {
+        Self {
+            behavior: Default::default(),
+            idle: 200.0,
+        }
+    }
+}
+
+/// Return type of [`use_window_scroll_with_options`].
+pub struct UseWindowScrollReturn<SetXFn, SetYFn>
+where
+    SetXFn: Fn(f64) + Clone,
+    SetYFn: Fn(f64) + Clone,
+{
+    /// X coordinate of the scroll position
+    pub x: Signal<f64>,
+
+    /// Scrolls the window horizontally to this position.
+    pub set_x: SetXFn,
+
+    /// Y coordinate of the scroll position
+    pub y: Signal<f64>,
+
+    /// Scrolls the window vertically to this position.
+    pub set_y: SetYFn,
+
+    /// Is true while the window is being scrolled.
+    pub is_scrolling: Signal<bool>,
+
+    /// The directions in which the window is being scrolled are set to true.
+    pub directions: Signal<Directions>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_directions() {
+        let directions = scroll_directions((10.0, 10.0), (20.0, 5.0));
+        assert!(directions.right && directions.top);
+        assert!(!directions.left && !directions.bottom);
+
+        let directions = scroll_directions((10.0, 10.0), (10.0, 10.0));
+        assert!(!directions.left && !directions.right && !directions.top && !directions.bottom);
+    }
 }
@@ -0,0 +1,178 @@
+#![cfg_attr(feature = "ssr", allow(unused_variables, unused_imports, dead_code))]
+
+use crate::core::Size;
+use crate::{use_event_listener_with_options, use_window, UseEventListenerOptions};
+use default_struct_builder::DefaultBuilder;
+use leptos::ev::{resize, Custom};
+use leptos::*;
+
+/// Reactive window size.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_window_size)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_window_size, UseWindowSizeReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWindowSizeReturn { width, height, .. } = use_window_size();
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// `width` and `height` are the inner size of the window, including the scrollbars by default.
+/// `outer_width` and `outer_height` are the size of the whole browser window. All values are
+/// updated on `resize` and `orientationchange`.
+///
+/// To exclude the scrollbars set the option `include_scrollbar` to `false`.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_window_size_with_options, UseWindowSizeOptions, UseWindowSizeReturn};
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWindowSizeReturn { width, height, .. } =
+///     use_window_size_with_options(UseWindowSizeOptions::default().include_scrollbar(false));
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## Server-Side Rendering
+///
+/// On the server the returned signals always contain the value of the `initial_size` option
+/// which defaults to `0.0` for all dimensions. Set it to the size you want the server rendered
+/// layout to be made for, for example a typical desktop size.
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::{use_window_size_with_options, UseWindowSizeOptions, UseWindowSizeReturn};
+/// # use leptos_use::core::Size;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let UseWindowSizeReturn { width, height, .. } = use_window_size_with_options(
+///     UseWindowSizeOptions::default().initial_size(Size {
+///         width: 1280.0,
+///         height: 800.0,
+///     }),
+/// );
+/// #
+/// # view! { }
+/// # }
+/// ```
+///
+/// ## See also
+///
+/// * [`use_element_size`]
+/// * [`use_breakpoints`]
+pub fn use_window_size() -> UseWindowSizeReturn {
+    use_window_size_with_options(UseWindowSizeOptions::default())
+}
+
+/// Version of [`use_window_size`] that takes a `UseWindowSizeOptions`. See [`use_window_size`] for how to use.
+pub fn use_window_size_with_options(options: UseWindowSizeOptions) -> UseWindowSizeReturn {
+    let UseWindowSizeOptions {
+        initial_size,
+        include_scrollbar,
+        listen_orientation,
+    } = options;
+
+    let (width, set_width) = create_signal(initial_size.width);
+    let (height, set_height) = create_signal(initial_size.height);
+    let (outer_width, set_outer_width) = create_signal(initial_size.width);
+    let (outer_height, set_outer_height) = create_signal(initial_size.height);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let update = move || {
+            let window = window();
+            let value = |value: Result<wasm_bindgen::JsValue, _>| {
+                value
+                    .ok()
+                    .and_then(|value| value.as_f64())
+                    .unwrap_or_default()
+            };
+
+            if include_scrollbar {
+                set_width.set(value(window.inner_width()));
+                set_height.set(value(window.inner_height()));
+            } else if let Some(document_element) = document().document_element() {
+                set_width.set(document_element.client_width() as f64);
+                set_height.set(document_element.client_height() as f64);
+            }
+
+            set_outer_width.set(value(window.outer_width()));
+            set_outer_height.set(value(window.outer_height()));
+        };
+
+        update();
+
+        let _ = use_event_listener_with_options(
+            use_window(),
+            resize,
+            move |_| update(),
+            UseEventListenerOptions::default().passive(true),
+        );
+
+        if listen_orientation {
+            let _ = use_event_listener_with_options(
+                use_window(),
+                Custom::<ev::Event>::new("orientationchange"),
+                move |_| update(),
+                UseEventListenerOptions::default().passive(true),
+            );
+        }
+    }
+
+    UseWindowSizeReturn {
+        width: width.into(),
+        height: height.into(),
+        outer_width: outer_width.into(),
+        outer_height: outer_height.into(),
+    }
+}
+
+/// Options for [`use_window_size_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseWindowSizeOptions {
+    /// The size that is returned on the server. `outer_width` and `outer_height` use it, too.
+    /// Defaults to `0.0` for width and height.
+    initial_size: Size,
+
+    /// If `true` the width and height include the scrollbars (`window.innerWidth`), otherwise
+    /// they don't (`document.documentElement.clientWidth`). Defaults to `true`.
+    include_scrollbar: bool,
+
+    /// Also update the size on `orientationchange`. Defaults to `true`.
+    listen_orientation: bool,
+}
+
+impl Default for UseWindowSizeOptions {
+    fn default() -> Self {
+        Self {
+            initial_size: Size::default(),
+            include_scrollbar: true,
+            listen_orientation: true,
+        }
+    }
+}
+
+/// Return type of [`use_window_size`].
+pub struct UseWindowSizeReturn {
+    /// The inner width of the window.
+    pub width: Signal<f64>,
+    /// The inner height of the window.
+    pub height: Signal<f64>,
+    /// The width of the whole browser window.
+    pub outer_width: Signal<f64>,
+    /// The height of the whole browser window.
+    pub outer_height: Signal<f64>,
+}
@@ -0,0 +1,43 @@
+use super::BinCodec;
+
+/// A codec that relies on [`bincode`] to encode values into a compact binary representation.
+///
+/// Works with any value that implements `serde::Serialize` and `serde::Deserialize`.
+/// Please note that bincode has no support for versioning so both sides have to agree on the
+/// exact same types.
+#[derive(Copy, Clone, Default, PartialEq)]
+pub struct BincodeCodec;
+
+impl<T: serde::Serialize + serde::de::DeserializeOwned> BinCodec<T> for BincodeCodec {
+    type Error = bincode::Error;
+
+    fn encode(&self, val: &T) -> Result<Vec<u8>, Self::Error> {
+        bincode::serialize(val)
+    }
+
+    fn decode(&self, val: &[u8]) -> Result<T, Self::Error> {
+        bincode::deserialize(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bincode_codec() {
+        #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Test {
+            s: String,
+            i: i32,
+        }
+        let t = Test {
+            s: String::from("party time 🎉"),
+            i: 42,
+        };
+        let codec = BincodeCodec;
+        let enc = codec.encode(&t).unwrap();
+        let dec: Test = codec.decode(&enc).unwrap();
+        assert_eq!(dec, t);
+    }
+}
@@ -1,4 +1,7 @@
 mod bin;
 mod string;
+mod websocket;
 
+pub use bin::*;
 pub use string::*;
+pub use websocket::*;
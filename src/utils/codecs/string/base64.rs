@@ -0,0 +1,80 @@
+use super::StringCodec;
+use crate::utils::BinCodec;
+use base64::Engine;
+use thiserror::Error;
+
+/// A codec that wraps a [`BinCodec`] and represents its bytes as a [`base64`](https://docs.rs/base64) string.
+///
+/// This makes every binary codec usable wherever a [`StringCodec`] is needed, for example to store
+/// binary data in browser storage.
+///
+/// ## Example
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::storage::use_local_storage;
+/// # use leptos_use::utils::{Base64Codec, FromToBytesCodec};
+/// #
+/// # pub fn Demo() -> impl IntoView {
+/// let (get, set, remove) = use_local_storage::<u64, Base64Codec<FromToBytesCodec>>("my-key");
+/// #    view! { }
+/// # }
+/// ```
+///
+/// To use it you have to enable the feature `"base64"`.
+#[derive(Copy, Clone, Default, PartialEq)]
+pub struct Base64Codec<C>(pub C);
+
+#[derive(Error, Debug, PartialEq)]
+pub enum Base64CodecError<E> {
+    #[error("failed to decode base64")]
+    DecodeBase64(base64::DecodeError),
+    #[error("failed to encode or decode the bytes")]
+    Bin(E),
+}
+
+impl<T, C: BinCodec<T>> StringCodec<T> for Base64Codec<C> {
+    type Error = Base64CodecError<C::Error>;
+
+    fn encode(&self, val: &T) -> Result<String, Self::Error> {
+        let buf = self.0.encode(val).map_err(Base64CodecError::Bin)?;
+        Ok(base64::engine::general_purpose::STANDARD.encode(buf))
+    }
+
+    fn decode(&self, str: String) -> Result<T, Self::Error> {
+        let buf = base64::engine::general_purpose::STANDARD
+            .decode(str)
+            .map_err(Base64CodecError::DecodeBase64)?;
+        self.0.decode(&buf).map_err(Base64CodecError::Bin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::FromToBytesCodec;
+
+    #[test]
+    fn test_base64_codec() {
+        let codec = Base64Codec(FromToBytesCodec);
+
+        let encoded = codec.encode(&42_u32).unwrap();
+        assert_eq!(encoded, "AAAAKg==");
+
+        let decoded: u32 = codec.decode(encoded).unwrap();
+        assert_eq!(decoded, 42);
+    }
+
+    #[test]
+    fn test_base64_codec_invalid() {
+        let codec = Base64Codec(FromToBytesCodec);
+
+        assert!(matches!(
+            StringCodec::<u32>::decode(&codec, "not base64!".to_string()),
+            Err(Base64CodecError::DecodeBase64(_))
+        ));
+        assert!(matches!(
+            StringCodec::<u32>::decode(&codec, "AAA=".to_string()),
+            Err(Base64CodecError::Bin(_))
+        ));
+    }
+}
@@ -1,9 +1,13 @@
+#[cfg(feature = "base64")]
+mod base64;
 mod from_to_string;
 #[cfg(feature = "serde_json")]
 mod json;
 #[cfg(feature = "prost")]
 mod prost;
 
+#[cfg(feature = "base64")]
+pub use self::base64::*;
 pub use from_to_string::*;
 #[cfg(feature = "serde_json")]
 pub use json::*;
@@ -26,6 +30,8 @@ pub use prost::*;
 /// - The [`ProstCodec`](super::ProstCodec) uses [Protocol buffers](https://protobuf.dev/overview/) designed to solve the problem of long-term storage. It provides semantics for versioning that are not present in JSON or other formats.
 ///
 /// - The [`JsonCodec`](super::JsonCodec) stores data as JSON. We can then rely on serde or by providing our own manual version handling. See the codec for more details.
+///
+/// - The [`Base64Codec`](super::Base64Codec) wraps any [`BinCodec`](super::BinCodec) so versioning depends on the wrapped codec.
 pub trait StringCodec<T>: Clone + 'static {
     /// The error type returned when encoding or decoding fails.
     type Error;
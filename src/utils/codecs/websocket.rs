@@ -0,0 +1,143 @@
+use super::FromToStringCodec;
+use std::str::FromStr;
+
+/// A message that is sent or received over a `WebSocket`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WebSocketMessage {
+    /// A text frame.
+    Text(String),
+    /// A binary frame.
+    Binary(Vec<u8>),
+}
+
+/// A codec for encoding outgoing messages of type `Tx` and decoding incoming messages of
+/// type `Rx` as used by [`use_websocket_with_codec`](crate::use_websocket_with_codec).
+///
+/// The codec decides if messages are sent as text or binary frames. Decoding should accept
+/// both kinds of frames if possible.
+///
+/// Implementations are provided for
+/// - [`FromToStringCodec`] (text frames)
+/// - [`JsonCodec`](super::JsonCodec) (text frames, requires feature `serde`)
+/// - [`ProstCodec`](super::ProstCodec) (binary frames, requires feature `prost`)
+/// - [`MsgpackCodec`](super::MsgpackCodec) (binary frames, requires feature `msgpack`)
+/// - [`BincodeCodec`](super::BincodeCodec) (binary frames, requires feature `bincode`)
+pub trait WebSocketCodec<Tx, Rx>: Clone + 'static {
+    /// The error type returned when encoding or decoding fails.
+    type Error;
+    /// Encodes a value to a message.
+    fn encode(&self, val: &Tx) -> Result<WebSocketMessage, Self::Error>;
+    /// Decodes a received message to a value.
+    fn decode(&self, message: WebSocketMessage) -> Result<Rx, Self::Error>;
+}
+
+impl<Tx: ToString, Rx: FromStr> WebSocketCodec<Tx, Rx> for FromToStringCodec {
+    type Error = Rx::Err;
+
+    fn encode(&self, val: &Tx) -> Result<WebSocketMessage, Self::Error> {
+        Ok(WebSocketMessage::Text(val.to_string()))
+    }
+
+    fn decode(&self, message: WebSocketMessage) -> Result<Rx, Self::Error> {
+        match message {
+            WebSocketMessage::Text(text) => Rx::from_str(&text),
+            WebSocketMessage::Binary(bytes) => Rx::from_str(&String::from_utf8_lossy(&bytes)),
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<Tx: serde::Serialize, Rx: serde::de::DeserializeOwned> WebSocketCodec<Tx, Rx>
+    for super::JsonCodec
+{
+    type Error = serde_json::Error;
+
+    fn encode(&self, val: &Tx) -> Result<WebSocketMessage, Self::Error> {
+        Ok(WebSocketMessage::Text(serde_json::to_string(val)?))
+    }
+
+    fn decode(&self, message: WebSocketMessage) -> Result<Rx, Self::Error> {
+        match message {
+            WebSocketMessage::Text(text) => serde_json::from_str(&text),
+            WebSocketMessage::Binary(bytes) => serde_json::from_slice(&bytes),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+impl<Tx: prost::Message, Rx: prost::Message + Default> WebSocketCodec<Tx, Rx>
+    for super::ProstCodec
+{
+    type Error = super::ProstCodecError;
+
+    fn encode(&self, val: &Tx) -> Result<WebSocketMessage, Self::Error> {
+        Ok(WebSocketMessage::Binary(val.encode_to_vec()))
+    }
+
+    fn decode(&self, message: WebSocketMessage) -> Result<Rx, Self::Error> {
+        match message {
+            // text frames are expected to be base64 encoded like with the `StringCodec`
+            WebSocketMessage::Text(text) => super::StringCodec::decode(self, text),
+            WebSocketMessage::Binary(bytes) => Ok(Rx::decode(bytes.as_slice())?),
+        }
+    }
+}
+
+#[cfg(feature = "msgpack")]
+impl<Tx: serde::Serialize, Rx: serde::de::DeserializeOwned> WebSocketCodec<Tx, Rx>
+    for super::MsgpackCodec
+{
+    type Error = super::MsgpackCodecError;
+
+    fn encode(&self, val: &Tx) -> Result<WebSocketMessage, Self::Error> {
+        Ok(WebSocketMessage::Binary(rmp_serde::to_vec(val)?))
+    }
+
+    fn decode(&self, message: WebSocketMessage) -> Result<Rx, Self::Error> {
+        let bytes = match &message {
+            WebSocketMessage::Text(text) => text.as_bytes(),
+            WebSocketMessage::Binary(bytes) => bytes.as_slice(),
+        };
+        Ok(rmp_serde::from_slice(bytes)?)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<Tx: serde::Serialize, Rx: serde::de::DeserializeOwned> WebSocketCodec<Tx, Rx>
+    for super::BincodeCodec
+{
+    type Error = bincode::Error;
+
+    fn encode(&self, val: &Tx) -> Result<WebSocketMessage, Self::Error> {
+        Ok(WebSocketMessage::Binary(bincode::serialize(val)?))
+    }
+
+    fn decode(&self, message: WebSocketMessage) -> Result<Rx, Self::Error> {
+        match &message {
+            WebSocketMessage::Text(text) => bincode::deserialize(text.as_bytes()),
+            WebSocketMessage::Binary(bytes) => bincode::deserialize(bytes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_to_string_websocket_codec() {
+        let codec = FromToStringCodec;
+        assert_eq!(
+            WebSocketCodec::<i32, i32>::encode(&codec, &42),
+            Ok(WebSocketMessage::Text("42".to_string()))
+        );
+        assert_eq!(
+            WebSocketCodec::<i32, i32>::decode(&codec, WebSocketMessage::Text("42".to_string())),
+            Ok(42)
+        );
+        assert_eq!(
+            WebSocketCodec::<i32, i32>::decode(&codec, WebSocketMessage::Binary(b"42".to_vec())),
+            Ok(42)
+        );
+    }
+}